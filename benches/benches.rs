@@ -1,6 +1,8 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use enterpolation::bezier::Bezier;
 use enterpolation::bspline::BSpline;
-use enterpolation::{Curve, Generator};
+use enterpolation::packed::{F32x2, F32x4};
+use enterpolation::{Curve, Generator, InterpolationSearch};
 
 const ELEMENTS: [f64; 100] = [
     943.0, 978.0, 579.0, 15.0, 608.0, 938.0, 669.0, 98.0, 720.0, 303.0, 345.0, 421.0, 767.0, 798.0,
@@ -147,5 +149,98 @@ fn creation(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, sampling, creation);
+fn search(c: &mut Criterion) {
+    let sample_size = 200;
+    let len = 1_000_000;
+    // near equidistant knots
+    let knots: Vec<f64> = (0..len + DEG - 1)
+        .map(|i| i as f64 + (i as f64).sin() * 0.25)
+        .collect();
+    let elements: Vec<f64> = (0..len).map(|i| ELEMENTS[i % ELEMENTS.len()]).collect();
+    let binary_bspline = BSpline::builder()
+        .elements(elements.clone())
+        .knots(knots.clone())
+        .constant::<SPACE>()
+        .build()
+        .unwrap();
+    let interpolation_bspline = BSpline::builder()
+        .elements(elements)
+        .knots(knots)
+        .search(InterpolationSearch)
+        .constant::<SPACE>()
+        .build()
+        .unwrap();
+    c.bench_function("search_binary_bspline", |b| {
+        b.iter::<Vec<f64>, _>(|| {
+            binary_bspline
+                .by_ref()
+                .take(black_box(sample_size))
+                .collect()
+        });
+    });
+    c.bench_function("search_interpolation_bspline", |b| {
+        b.iter::<Vec<f64>, _>(|| {
+            interpolation_bspline
+                .by_ref()
+                .take(black_box(sample_size))
+                .collect()
+        });
+    });
+}
+
+fn lut(c: &mut Criterion) {
+    let sample_size = 200;
+    let bspline = BSpline::builder()
+        .elements(ELEMENTS)
+        .knots(KNOTS)
+        .constant::<SPACE>()
+        .build()
+        .unwrap();
+    let lut = bspline.bake_lut(4096);
+    c.bench_function("lut_source_bspline", |b| {
+        b.iter::<Vec<f64>, _>(|| bspline.by_ref().take(black_box(sample_size)).collect());
+    });
+    c.bench_function("lut_baked_bspline", |b| {
+        b.iter::<Vec<f64>, _>(|| lut.by_ref().take(black_box(sample_size)).collect());
+    });
+}
+
+/// Compare with and without the `simd` feature to see the effect of SIMD arithmetic.
+fn packed(c: &mut Criterion) {
+    let sample_size = 200;
+    let points: Vec<F32x4> = ELEMENTS
+        .iter()
+        .zip(ELEMENTS.iter().rev())
+        .map(|(&x, &y)| F32x4::new([x as f32, y as f32, (x - y) as f32, 1.0]))
+        .collect();
+    let bspline = BSpline::builder()
+        .elements(points)
+        .knots(KNOTS.map(|knot| knot as f32))
+        .constant::<SPACE>()
+        .build()
+        .unwrap();
+    let bezier = Bezier::builder()
+        .elements([
+            F32x2::new([0.0, 0.0]),
+            F32x2::new([1.0, 3.0]),
+            F32x2::new([3.0, -1.0]),
+            F32x2::new([4.0, 2.0]),
+        ])
+        .normalized::<f32>()
+        .constant::<4>()
+        .build()
+        .unwrap();
+    let inputs: Vec<f32> = (0..sample_size)
+        .map(|i| 97.0 * i as f32 / (sample_size - 1) as f32)
+        .collect();
+    let mut out = vec![F32x4::default(); sample_size];
+    c.bench_function("packed_batch_bspline", |b| {
+        b.iter(|| bspline.gen_batch(black_box(&inputs), &mut out));
+    });
+    c.bench_function("packed_sampling_bezier", |b| {
+        b.iter::<Vec<F32x2>, _>(|| bezier.by_ref().take(black_box(sample_size)).collect());
+    });
+}
+
+criterion_group!(benches, sampling, creation, search, lut, packed);
 criterion_main!(benches);
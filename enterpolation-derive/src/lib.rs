@@ -0,0 +1,156 @@
+//! Derive macro for the [`Merge`] trait of [enterpolation].
+//!
+//! Deriving [`Merge`] for a struct merges each field on its own with the same factor,
+//! such that structs with many interpolatable fields can be used as elements of curves
+//! without implementing the trait by hand. Fields which are arrays are merged element-wise.
+//!
+//! The derive macro is re-exported by enterpolation if its `derive` feature is enabled.
+//! The generated code refers to the trait as `::enterpolation::Merge`, such that enterpolation
+//! has to be a dependency of the crate using the derive.
+//!
+//! ```rust
+//! use enterpolation::Merge;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Merge)]
+//! struct Transform {
+//!     position: [f64; 3],
+//!     scale: f64,
+//! }
+//!
+//! let start = Transform { position: [0.0, 2.0, 4.0], scale: 1.0 };
+//! let end = Transform { position: [2.0, 2.0, 0.0], scale: 3.0 };
+//! assert_eq!(start.merge(end, 0.5), Transform { position: [1.0, 2.0, 2.0], scale: 2.0 });
+//! ```
+//!
+//! The derived implementation is generic over the factor and requires every field to implement
+//! `Merge` with it. Fields inside of arrays also have to be `Copy`.
+//! As `Merge` is already implemented for all types which are `Add`, `Mul` and `Copy`,
+//! the derive should only be used on structs which do not implement these arithmetic traits.
+//!
+//! [`Merge`]: https://docs.rs/topology-traits/latest/topology_traits/trait.Merge.html
+//! [enterpolation]: https://docs.rs/enterpolation
+#![warn(
+    anonymous_parameters,
+    missing_debug_implementations,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    single_use_lifetimes,
+    trivial_casts,
+    trivial_numeric_casts,
+    unreachable_pub,
+    unused_extern_crates,
+    unused_qualifications
+)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, Index, Type, WherePredicate,
+};
+
+/// Derive `Merge` for a struct by merging each of its fields.
+#[proc_macro_derive(Merge)]
+pub fn derive_merge(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Merge can only be derived for structs",
+            ))
+        }
+    };
+    let factor = format_ident!("__Factor");
+    let mut generics = input.generics.clone();
+    generics
+        .params
+        .push(parse_quote!(#factor: ::core::marker::Copy));
+    let where_clause = generics.make_where_clause();
+    let mut merged = Vec::with_capacity(fields.len());
+    for (index, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = Index::from(index);
+                quote!(#index)
+            }
+        };
+        let (expr, bound) = merge_field(
+            &field.ty,
+            quote!(self.#member),
+            quote!(to.#member),
+            &factor,
+            0,
+        );
+        where_clause.predicates.push(bound);
+        merged.push(match &field.ident {
+            Some(ident) => quote!(#ident: #expr),
+            None => expr,
+        });
+    }
+    let body = match fields {
+        Fields::Named(_) => quote!(Self { #(#merged,)* }),
+        Fields::Unnamed(_) => quote!(Self(#(#merged,)*)),
+        Fields::Unit => quote!(Self),
+    };
+    // without fields, the arguments are not used
+    let (to, factor_arg) = if fields.is_empty() {
+        (format_ident!("_to"), format_ident!("_factor"))
+    } else {
+        (format_ident!("to"), format_ident!("factor"))
+    };
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::enterpolation::Merge<#factor> for #name #ty_generics #where_clause {
+            fn merge(self, #to: Self, #factor_arg: #factor) -> Self {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns the expression merging `from` and `to` of the given type and the bound it needs.
+///
+/// Arrays are merged element-wise, which is done recursively for nested arrays.
+fn merge_field(
+    ty: &Type,
+    from: TokenStream2,
+    to: TokenStream2,
+    factor: &Ident,
+    depth: usize,
+) -> (TokenStream2, WherePredicate) {
+    match ty {
+        Type::Array(array) => {
+            let index = format_ident!("__index{}", depth);
+            let (expr, bound) = merge_field(
+                &array.elem,
+                quote!(#from[#index]),
+                quote!(#to[#index]),
+                factor,
+                depth + 1,
+            );
+            (quote!(::core::array::from_fn(|#index| #expr)), bound)
+        }
+        Type::Paren(paren) => merge_field(&paren.elem, from, to, factor, depth),
+        Type::Group(group) => merge_field(&group.elem, from, to, factor, depth),
+        _ if depth > 0 => (
+            quote!(::enterpolation::Merge::merge(#from, #to, factor)),
+            parse_quote!(#ty: ::enterpolation::Merge<#factor> + ::core::marker::Copy),
+        ),
+        _ => (
+            quote!(::enterpolation::Merge::merge(#from, #to, factor)),
+            parse_quote!(#ty: ::enterpolation::Merge<#factor>),
+        ),
+    }
+}
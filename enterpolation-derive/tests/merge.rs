@@ -0,0 +1,81 @@
+use enterpolation::{linear::Linear, Curve, Generator, Merge};
+
+#[derive(Debug, Clone, Copy, PartialEq, Merge)]
+struct Transform {
+    pos: [f64; 3],
+    scale: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Merge)]
+struct Pair<T>(T, [[T; 2]; 2]);
+
+#[derive(Debug, Clone, Copy, PartialEq, Merge)]
+struct Marker;
+
+#[test]
+fn transform() {
+    let from = Transform {
+        pos: [0.0, 1.0, -2.0],
+        scale: 1.0,
+    };
+    let to = Transform {
+        pos: [4.0, 1.0, 2.0],
+        scale: 3.0,
+    };
+    assert_eq!(from.merge(to, 0.0), from);
+    assert_eq!(to.merge(from, 0.0), to);
+    assert_eq!(
+        from.merge(to, 0.25),
+        Transform {
+            pos: [1.0, 1.0, -1.0],
+            scale: 1.5,
+        }
+    );
+}
+
+#[test]
+fn curve() {
+    let keyframes = [
+        Transform {
+            pos: [0.0, 0.0, 0.0],
+            scale: 1.0,
+        },
+        Transform {
+            pos: [2.0, 4.0, 0.0],
+            scale: 2.0,
+        },
+        Transform {
+            pos: [2.0, 0.0, -4.0],
+            scale: 0.0,
+        },
+    ];
+    let animation = Linear::builder()
+        .elements(keyframes)
+        .knots([0.0, 1.0, 3.0])
+        .build()
+        .unwrap();
+    assert_eq!(animation.domain(), [0.0, 3.0]);
+    assert_eq!(animation.gen(1.0), keyframes[1]);
+    assert_eq!(
+        animation.gen(0.5),
+        Transform {
+            pos: [1.0, 2.0, 0.0],
+            scale: 1.5,
+        }
+    );
+    assert_eq!(
+        animation.gen(2.0),
+        Transform {
+            pos: [2.0, 2.0, -2.0],
+            scale: 1.0,
+        }
+    );
+}
+
+#[test]
+fn generic_and_unit() {
+    let from = Pair(1.0f32, [[0.0, 2.0], [4.0, -4.0]]);
+    let to = Pair(3.0f32, [[2.0, 2.0], [0.0, 4.0]]);
+    assert_eq!(from.merge(to, 0.5f32), Pair(2.0, [[1.0, 2.0], [2.0, 0.0]]));
+    assert_eq!(Marker.merge(Marker, 0.5), Marker);
+}
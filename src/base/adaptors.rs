@@ -1,7 +1,17 @@
-use crate::{ConstDiscreteGenerator, Curve, DiscreteGenerator, Generator};
-use core::ops::{Add, Bound, Mul, RangeBounds};
+use crate::utils::intersect_domains;
+use crate::{
+    Bounded, ConstDiscreteGenerator, Curve, Derivative, DiscreteGenerator, Generator, Stepper,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt;
+use core::ops::{Add, Bound, Mul, Neg, RangeBounds, Sub};
 use num_traits::clamp;
 use num_traits::real::Real;
+use num_traits::{FromPrimitive, ToPrimitive, Zero};
+#[cfg(feature = "std")]
+use std::error::Error;
 
 /// Wrapper for curves to clamp input to their domain.
 ///
@@ -41,6 +51,10 @@ where
     fn domain(&self) -> [R; 2] {
         self.0.domain()
     }
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        self.0.knot_midpoints()
+    }
 }
 
 /// Acts like a slice of a curve.
@@ -99,6 +113,10 @@ where
     fn domain(&self) -> [R; 2] {
         self.0.inner.domain()
     }
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        self.0.midpoints_within(self.domain())
+    }
 }
 
 /// Struct which transforms the input before sending it to the underlying generator.
@@ -133,6 +151,30 @@ where
     pub fn normalized_to_domain(generator: G, start: R, end: R) -> Self {
         Self::new(generator, -start, (end - start).recip())
     }
+
+    /// Returns the midpoints of the inner curve mapped back to our input, keeping only those within `domain`.
+    ///
+    /// If no midpoint remains, the middle of `domain` is returned instead.
+    #[cfg(feature = "alloc")]
+    fn midpoints_within(&self, domain: [R; 2]) -> Vec<R> {
+        let [start, end] = domain;
+        let (min, max) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        let mut midpoints: Vec<R> = IntoIterator::into_iter(self.inner.knot_midpoints())
+            .map(|midpoint| (midpoint - self.addition) / self.multiplication)
+            .filter(|&midpoint| min < midpoint && midpoint < max)
+            .collect();
+        if self.multiplication < R::zero() {
+            midpoints.reverse();
+        }
+        if midpoints.is_empty() {
+            midpoints.push((start + end) / (R::one() + R::one()));
+        }
+        midpoints
+    }
 }
 
 impl<G, A, M, I> Generator<I> for TransformInput<G, A, M>
@@ -160,6 +202,10 @@ where
         let end = (orig[1] - self.addition) / self.multiplication;
         [start, end]
     }
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        self.midpoints_within(self.domain())
+    }
 }
 
 /// Struct which composite two generator together to act as one generator.
@@ -198,6 +244,185 @@ where
     }
 }
 
+impl<A, B> DiscreteGenerator for Composite<A, B>
+where
+    A: DiscreteGenerator,
+    B: Generator<A::Output>,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<A, B, const N: usize> ConstDiscreteGenerator<N> for Composite<A, B>
+where
+    A: ConstDiscreteGenerator<N>,
+    B: Generator<A::Output>,
+{
+}
+
+/// How a curve remapped by a time curve handles times outside of its domain.
+///
+/// This is used by [`Curve::compose_with_time_curve()`]. See its documentation for more.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum RetimeMode {
+    /// Clamp the time generated by the time curve to the domain of the value curve.
+    Clamp,
+    /// Return an error if the time curve leaves the domain of the value curve.
+    ///
+    /// The time curve is checked at the given number of equidistant samples over its domain.
+    /// As only these samples are checked, the time curve may still leave the domain between them,
+    /// in which case the curve is evaluated outside of its domain.
+    Error {
+        /// Number of samples of the time curve to check.
+        samples: usize,
+    },
+}
+
+/// Error returned if a time curve leaves the domain of the curve it remaps.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct OutOfDomain<R> {
+    input: R,
+    time: R,
+    domain: [R; 2],
+}
+
+impl<R> OutOfDomain<R> {
+    /// Create a new error in which the time curve generated `time` at `input`,
+    /// which is outside of the given domain.
+    pub fn new(input: R, time: R, domain: [R; 2]) -> Self {
+        OutOfDomain {
+            input,
+            time,
+            domain,
+        }
+    }
+    /// Returns the input at which the time curve left the domain.
+    pub fn input(&self) -> R
+    where
+        R: Copy,
+    {
+        self.input
+    }
+    /// Returns the time generated by the time curve, which is outside of the domain.
+    pub fn time(&self) -> R
+    where
+        R: Copy,
+    {
+        self.time
+    }
+    /// Returns the domain of the remapped curve.
+    pub fn domain(&self) -> [R; 2]
+    where
+        R: Copy,
+    {
+        self.domain
+    }
+}
+
+impl<R> fmt::Display for OutOfDomain<R>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The time curve generates {:?} at input {:?}, which is outside of the domain {:?} of the remapped curve.",
+            self.time, self.input, self.domain
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R> Error for OutOfDomain<R> where R: fmt::Debug {}
+
+/// Curve adaptor which remaps the time of a curve by another curve.
+///
+/// This `struct` is created by [`Curve::compose_with_time_curve()`]. See its documentation for more.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Retime<C, T> {
+    curve: C,
+    time: T,
+    clamp: bool,
+}
+
+impl<C, T> Retime<C, T> {
+    /// Remap the time of `curve` by the curve `time`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OutOfDomain`] if the mode is [`RetimeMode::Error`] and a sample of the time curve
+    /// is outside of the domain of `curve`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mode is [`RetimeMode::Error`] with 0 samples
+    /// or if `samples - 1` can not be converted to the type `R`.
+    pub fn new<R>(curve: C, time: T, mode: RetimeMode) -> Result<Self, OutOfDomain<R>>
+    where
+        C: Curve<R>,
+        T: Curve<R, Output = R>,
+        R: Real + FromPrimitive,
+    {
+        let clamp = match mode {
+            RetimeMode::Clamp => true,
+            RetimeMode::Error { samples } => {
+                let [start, end] = time.domain();
+                let domain = curve.domain();
+                assert!(samples > 0, "at least one sample has to be taken");
+                for input in Stepper::new(samples, start, end) {
+                    let value = time.gen(input);
+                    // also rejects NaN
+                    if !(domain[0] <= value && value <= domain[1]) {
+                        return Err(OutOfDomain::new(input, value, domain));
+                    }
+                }
+                false
+            }
+        };
+        Ok(Retime { curve, time, clamp })
+    }
+    /// Returns the remapped curve.
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+    /// Returns the time curve.
+    pub fn time(&self) -> &T {
+        &self.time
+    }
+}
+
+impl<C, T, R> Generator<R> for Retime<C, T>
+where
+    C: Curve<R>,
+    T: Curve<R, Output = R>,
+    R: Real,
+{
+    type Output = C::Output;
+    fn gen(&self, input: R) -> Self::Output {
+        let time = self.time.gen(input);
+        if self.clamp {
+            let [start, end] = self.curve.domain();
+            return self.curve.gen(clamp(time, start, end));
+        }
+        self.curve.gen(time)
+    }
+}
+
+impl<C, T, R> Curve<R> for Retime<C, T>
+where
+    C: Curve<R>,
+    T: Curve<R, Output = R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.time.domain()
+    }
+}
+
 /// DiscreteGenerator adaptor which stacks two generators.
 ///
 /// That it, the struct holds two generators with output S and T and outputs (S,T).
@@ -256,110 +481,1418 @@ where
     }
 }
 
-/// DiscreteGenerator Adaptor which repeats the underlying elements.
+/// Curve adaptor which smoothes the output of a curve by a moving average.
+///
+/// This `struct` is created by [`Curve::smooth`]. See its documentation for more.
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-pub struct Repeat<G>(G);
+pub struct Smooth<G, R> {
+    inner: G,
+    window: R,
+    subsamples: usize,
+}
 
-impl<G> Repeat<G> {
-    /// Repeat a given DiscreteGenerator pseudo-endlessly.
-    ///
-    /// In reality this adaptpor repeats the underlying elements until `usize::MAX` is reached.
-    pub fn new(gen: G) -> Self {
-        Repeat(gen)
+impl<G, R> Smooth<G, R> {
+    /// Create a curve which averages the given curve over `[t - window, t + window]` for each input `t`,
+    /// using the given number of equidistant subsamples.
+    pub fn new(curve: G, window: R, subsamples: usize) -> Self {
+        Smooth {
+            inner: curve,
+            window,
+            subsamples,
+        }
     }
 }
 
-impl<G> Generator<usize> for Repeat<G>
+impl<G, R> Generator<R> for Smooth<G, R>
 where
-    G: DiscreteGenerator,
+    G: Generator<R>,
+    G::Output: Add<Output = G::Output> + Mul<R, Output = G::Output>,
+    R: Real + FromPrimitive,
 {
     type Output = G::Output;
-    fn gen(&self, input: usize) -> Self::Output {
-        self.0.gen(input % self.0.len())
+    fn gen(&self, input: R) -> Self::Output {
+        if self.subsamples <= 1 {
+            return self.inner.gen(input);
+        }
+        let start = input - self.window;
+        let step = (self.window + self.window) / R::from_usize(self.subsamples - 1).unwrap();
+        let mut sum = self.inner.gen(start);
+        for i in 1..self.subsamples {
+            sum = sum + self.inner.gen(start + step * R::from_usize(i).unwrap());
+        }
+        sum * R::from_usize(self.subsamples).unwrap().recip()
     }
 }
 
-impl<G> DiscreteGenerator for Repeat<G>
+impl<G, R> Curve<R> for Smooth<G, R>
 where
-    G: DiscreteGenerator,
+    G: Curve<R>,
+    G::Output: Add<Output = G::Output> + Mul<R, Output = G::Output>,
+    R: Real + FromPrimitive,
 {
-    fn len(&self) -> usize {
-        usize::MAX
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
     }
 }
 
-impl<G> ConstDiscreteGenerator<{ usize::MAX }> for Repeat<G> where G: DiscreteGenerator {}
+/// Generator adaptor which raises the output of a generator to a power.
+///
+/// This `struct` is created by [`Curve::pow`]. See its documentation for more.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Pow<G, P> {
+    inner: G,
+    exponent: P,
+}
 
-/// Generator adaptor which repeats a fixed amount of first elements.
+impl<G, P> Pow<G, P> {
+    /// Create a generator which raises all outputs of the given generator to the power of `exponent`.
+    pub fn new(gen: G, exponent: P) -> Self {
+        Pow {
+            inner: gen,
+            exponent,
+        }
+    }
+}
+
+impl<G, P, Input> Generator<Input> for Pow<G, P>
+where
+    G: Generator<Input, Output = P>,
+    P: Real,
+{
+    type Output = P;
+    fn gen(&self, input: Input) -> Self::Output {
+        self.inner.gen(input).powf(self.exponent)
+    }
+}
+
+impl<G, R> Curve<R> for Pow<G, G::Output>
+where
+    G: Curve<R>,
+    G::Output: Real,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
+    }
+}
+
+/// Curve adaptor which reflects the planar output of a curve about an axis.
+///
+/// This `struct` is created by [`Curve::reflect`]. See its documentation for more.
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-pub struct Wrap<G> {
+pub struct Reflect<G, R> {
     inner: G,
-    n: usize,
+    point: [R; 2],
+    direction: [R; 2],
 }
 
-impl<G> Wrap<G> {
-    /// Wrap the first `n` elements to the end.
-    pub fn new(gen: G, n: usize) -> Self {
-        Wrap { inner: gen, n }
+impl<G, R> Reflect<G, R> {
+    /// Create a curve which reflects the outputs of the given curve about the axis
+    /// going through `point` in `direction`.
+    pub fn new(curve: G, point: [R; 2], direction: [R; 2]) -> Self {
+        Reflect {
+            inner: curve,
+            point,
+            direction,
+        }
     }
 }
 
-impl<G> Generator<usize> for Wrap<G>
+impl<G, R> Generator<R> for Reflect<G, R>
 where
-    G: DiscreteGenerator,
+    G: Generator<R>,
+    G::Output: Into<[R; 2]> + From<[R; 2]>,
+    R: Real,
 {
     type Output = G::Output;
-    fn gen(&self, input: usize) -> Self::Output {
-        self.inner.gen(input % self.inner.len())
+    fn gen(&self, input: R) -> Self::Output {
+        let [x, y] = self.inner.gen(input).into();
+        let [px, py] = self.point;
+        let [dx, dy] = self.direction;
+        let (vx, vy) = (x - px, y - py);
+        // twice the factor of the projection onto the axis
+        let factor = (vx * dx + vy * dy) / (dx * dx + dy * dy);
+        let factor = factor + factor;
+        [px + factor * dx - vx, py + factor * dy - vy].into()
     }
 }
 
-impl<G> DiscreteGenerator for Wrap<G>
+impl<G, R> Curve<R> for Reflect<G, R>
 where
-    G: DiscreteGenerator,
+    G: Curve<R>,
+    G::Output: Into<[R; 2]> + From<[R; 2]>,
+    R: Real,
 {
-    fn len(&self) -> usize {
-        self.inner.len() + self.n
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::easing::Identity;
+/// Curve adaptor which approximates the derivative of a curve with finite differences.
+///
+/// This `struct` is created by [`Curve::finite_difference`]. See its documentation for more.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FiniteDifference<G>(G);
 
-    #[test]
-    fn input_transform() {
-        let identity = Identity {};
-        let transformed = TransformInput::new(identity, 0.0, 2.0);
-        assert_f64_near!(transformed.gen(1.0), 2.0);
-        let results = [0.0, 1.0, 2.0];
-        // try to extract
-        let extractor = transformed.extract([0.0, 0.5, 1.0]);
-        for (val, res) in extractor.zip(results.iter()) {
-            assert_f64_near!(val, res);
-        }
-        // try to take - should be the same as before as the domain should have changed accordingly
-        let transformed = TransformInput::new(identity, 0.0, 2.0);
-        for (val, res) in transformed
-            .take(results.len())
-            .zip(<Identity as Curve<f64>>::take(identity, results.len()))
-        {
-            assert_f64_near!(val, res);
-        }
+impl<G> FiniteDifference<G> {
+    /// Create a curve whose derivative is approximated with finite differences of the given curve.
+    pub fn new(curve: G) -> Self {
+        FiniteDifference(curve)
     }
+}
 
-    #[test]
-    fn slice() {
-        let identity = Identity {};
-        let slice = Slice::new(identity, 10.0..100.0);
-        let results = [10.0, 100.0];
-        assert_f64_near!(slice.gen(0.0), 10.0);
-        assert_f64_near!(slice.gen(1.0), 100.0);
-        for (val, res) in slice.take(results.len()).zip(results.iter()) {
-            assert_f64_near!(val, res);
-        }
+impl<G, Input> Generator<Input> for FiniteDifference<G>
+where
+    G: Generator<Input>,
+{
+    type Output = G::Output;
+    fn gen(&self, input: Input) -> Self::Output {
+        self.0.gen(input)
+    }
+}
+
+impl<G, R> Curve<R> for FiniteDifference<G>
+where
+    G: Curve<R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.0.domain()
+    }
+}
+
+impl<G, R> Derivative<R> for FiniteDifference<G>
+where
+    G: Curve<R>,
+    G::Output: Sub<Output = G::Output> + Mul<R, Output = G::Output>,
+    R: Real,
+{
+    /// Approximate the derivative with a central difference.
+    ///
+    /// The step size is chosen relative to the domain. Near the borders of the domain a one-sided
+    /// difference is used, such the curve is never evaluated outside of its domain.
+    fn derivative(&self, scalar: R) -> Self::Output {
+        let [start, end] = self.0.domain();
+        let step = R::epsilon().cbrt() * (end - start);
+        let before = (scalar - step).max(start);
+        let after = (scalar + step).min(end);
+        (self.0.gen(after) - self.0.gen(before)) * (after - before).recip()
+    }
+}
+
+/// Curve adaptor which generates the speed of a curve, the norm of its derivative.
+///
+/// This `struct` is created by [`Curve::speed`]. See its documentation for more.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Speed<G, F>(G, F);
+
+impl<G, F> Speed<G, F> {
+    /// Create a curve which generates the norm of the derivative of the given curve.
+    pub fn new(curve: G, norm: F) -> Self {
+        Speed(curve, norm)
+    }
+}
+
+impl<G, F, R> Generator<R> for Speed<G, F>
+where
+    G: Derivative<R>,
+    F: Fn(G::Output) -> R,
+    R: Real,
+{
+    type Output = R;
+    fn gen(&self, scalar: R) -> R {
+        (self.1)(self.0.derivative(scalar))
+    }
+}
+
+impl<G, F, R> Curve<R> for Speed<G, F>
+where
+    G: Derivative<R>,
+    F: Fn(G::Output) -> R,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.0.domain()
+    }
+}
+
+/// Generator adaptor which combines the outputs of two generators with a closure.
+///
+/// This `struct` is created by [`Generator::zip_with`]. See its documentation for more.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ZipWith<G, H, F>(G, H, F);
+
+impl<G, H, F> ZipWith<G, H, F> {
+    /// Creates a generator which combines the outputs of both generators with the given closure.
+    pub fn new(first: G, second: H, func: F) -> Self {
+        ZipWith(first, second, func)
+    }
+}
+
+impl<G, H, F, Input, T> Generator<Input> for ZipWith<G, H, F>
+where
+    G: Generator<Input>,
+    H: Generator<Input>,
+    F: Fn(G::Output, H::Output) -> T,
+    Input: Copy,
+{
+    type Output = T;
+    fn gen(&self, input: Input) -> Self::Output {
+        (self.2)(self.0.gen(input), self.1.gen(input))
+    }
+}
+
+impl<G, H, F, T> DiscreteGenerator for ZipWith<G, H, F>
+where
+    G: DiscreteGenerator,
+    H: DiscreteGenerator,
+    F: Fn(G::Output, H::Output) -> T,
+{
+    fn len(&self) -> usize {
+        self.0.len().min(self.1.len())
+    }
+}
+
+impl<G, H, F, T, const N: usize> ConstDiscreteGenerator<N> for ZipWith<G, H, F>
+where
+    G: ConstDiscreteGenerator<N>,
+    H: ConstDiscreteGenerator<N>,
+    F: Fn(G::Output, H::Output) -> T,
+{
+}
+
+impl<G, H, F, R, T> Curve<R> for ZipWith<G, H, F>
+where
+    G: Curve<R>,
+    H: Curve<R>,
+    F: Fn(G::Output, H::Output) -> T,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        intersection(self.0.domain(), self.1.domain())
+    }
+}
+
+/// Adaptor which combines the outputs of two curves with a closure.
+///
+/// This is another name for [`ZipWith`], as combining two curves is the same as zipping them.
+/// The domain of the combined curve is the intersection of both domains.
+/// Common combinations are available as [`Curve::add_curve()`], [`Curve::sub_curve()`] and [`Curve::scale()`].
+pub type Combine<A, B, F> = ZipWith<A, B, F>;
+
+/// Curve adaptor which evaluates one of many curves, chosen by a selector curve.
+///
+/// This `struct` is created by [`Curve::select`]. See its documentation for more.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Select<S, C> {
+    selector: S,
+    curves: C,
+}
+
+impl<S, C> Select<S, C> {
+    /// Create a curve which evaluates the curve with the index given by the selector.
+    ///
+    /// # Panics
+    ///
+    /// Generating values panics if `curves` is empty.
+    pub fn new(selector: S, curves: C) -> Self {
+        Select { selector, curves }
+    }
+}
+
+impl<S, C, Input> Generator<Input> for Select<S, C>
+where
+    S: Generator<Input>,
+    S::Output: Real,
+    C: DiscreteGenerator,
+    C::Output: Generator<Input>,
+    Input: Copy,
+{
+    type Output = <C::Output as Generator<Input>>::Output;
+    fn gen(&self, input: Input) -> Self::Output {
+        let last = self.curves.len() - 1;
+        // max also maps NaN to zero
+        let index = self
+            .selector
+            .gen(input)
+            .round()
+            .max(S::Output::zero())
+            .to_usize()
+            .map_or(last, |index| index.min(last));
+        self.curves.gen(index).gen(input)
+    }
+}
+
+impl<S, C, R> Curve<R> for Select<S, C>
+where
+    S: Curve<R>,
+    S::Output: Real,
+    C: DiscreteGenerator,
+    C::Output: Generator<R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.selector.domain()
+    }
+}
+
+/// Curve adaptor which smoothly blends the minimum of two curves.
+///
+/// This `struct` is created by [`Curve::soft_min`]. See its documentation for more.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SoftMin<G, H, R> {
+    first: G,
+    second: H,
+    sharpness: R,
+}
+
+impl<G, H, R> SoftMin<G, H, R> {
+    /// Create a generator which blends the outputs of both generators to their soft minimum.
+    ///
+    /// A negative `sharpness` results in the soft maximum instead.
+    /// A `sharpness` of zero results in the average of both outputs.
+    pub fn new(first: G, second: H, sharpness: R) -> Self {
+        SoftMin {
+            first,
+            second,
+            sharpness,
+        }
+    }
+}
+
+impl<G, H, R, Input> Generator<Input> for SoftMin<G, H, R>
+where
+    G: Generator<Input, Output = R>,
+    H: Generator<Input, Output = R>,
+    R: Real,
+    Input: Copy,
+{
+    type Output = R;
+    fn gen(&self, input: Input) -> Self::Output {
+        if self.sharpness.is_zero() {
+            // the soft minimum diverges, fall back to the average instead of dividing by zero
+            let two = R::one() + R::one();
+            return (self.first.gen(input) + self.second.gen(input)) / two;
+        }
+        let a = -self.sharpness * self.first.gen(input);
+        let b = -self.sharpness * self.second.gen(input);
+        // log-sum-exp, shifted by the maximum such that no exponential overflows
+        let (max, min) = if a < b { (b, a) } else { (a, b) };
+        -(max + (min - max).exp().ln_1p()) / self.sharpness
+    }
+}
+
+impl<G, H, R> Curve<R> for SoftMin<G, H, R>
+where
+    G: Curve<R, Output = R>,
+    H: Curve<R, Output = R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        intersection(self.first.domain(), self.second.domain())
+    }
+}
+
+/// DiscreteGenerator Adaptor which repeats the underlying elements.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Repeat<G>(G);
+
+impl<G> Repeat<G> {
+    /// Repeat a given DiscreteGenerator pseudo-endlessly.
+    ///
+    /// In reality this adaptpor repeats the underlying elements until `usize::MAX` is reached.
+    pub fn new(gen: G) -> Self {
+        Repeat(gen)
+    }
+}
+
+impl<G> Generator<usize> for Repeat<G>
+where
+    G: DiscreteGenerator,
+{
+    type Output = G::Output;
+    fn gen(&self, input: usize) -> Self::Output {
+        self.0.gen(input % self.0.len())
+    }
+}
+
+impl<G> DiscreteGenerator for Repeat<G>
+where
+    G: DiscreteGenerator,
+{
+    fn len(&self) -> usize {
+        usize::MAX
+    }
+}
+
+impl<G> ConstDiscreteGenerator<{ usize::MAX }> for Repeat<G> where G: DiscreteGenerator {}
+
+/// Generator adaptor which repeats a fixed amount of first elements.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Wrap<G> {
+    inner: G,
+    n: usize,
+}
+
+impl<G> Wrap<G> {
+    /// Wrap the first `n` elements to the end.
+    pub fn new(gen: G, n: usize) -> Self {
+        Wrap { inner: gen, n }
+    }
+}
+
+impl<G> Generator<usize> for Wrap<G>
+where
+    G: DiscreteGenerator,
+{
+    type Output = G::Output;
+    fn gen(&self, input: usize) -> Self::Output {
+        self.inner.gen(input % self.inner.len())
+    }
+}
+
+impl<G> DiscreteGenerator for Wrap<G>
+where
+    G: DiscreteGenerator,
+{
+    fn len(&self) -> usize {
+        self.inner.len() + self.n
+    }
+}
+
+/// Policy to synthesize elements outside of a discrete generator.
+///
+/// Interpolations over grids, like images, need elements beyond the edges of the grid
+/// to interpolate near these edges. Examples for a generator with the elements `[1, 2, 3, 4]`:
+///
+/// | index    | -2 | -1 | 4 | 5 |
+/// |----------|----|----|---|---|
+/// | `Clamp`  | 1  | 1  | 4 | 4 |
+/// | `Mirror` | 3  | 2  | 3 | 2 |
+/// | `Wrap`   | 3  | 4  | 1 | 2 |
+/// | `Zero`   | 0  | 0  | 0 | 0 |
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum BoundaryMode {
+    /// Repeat the element at the nearest edge.
+    Clamp,
+    /// Reflect the elements at the edges, without repeating the edges themselves.
+    Mirror,
+    /// Continue with the elements at the other edge, as if the elements are periodic.
+    Wrap,
+    /// Use the default element, which is zero for numbers.
+    Zero,
+}
+
+/// Generator adaptor which synthesizes elements outside of a discrete generator.
+///
+/// The adaptor takes signed indices as input. Indices inside of the wrapped generator
+/// return its elements, all other indices return an element synthesized by a [`BoundaryMode`].
+///
+/// This struct is created by [`DiscreteGenerator::boundary()`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{BoundaryMode, DiscreteGenerator, Generator};
+/// let ramp = [1.0, 2.0, 3.0, 4.0].boundary(BoundaryMode::Mirror);
+/// assert_eq!(ramp.gen(2), 3.0);
+/// assert_eq!(ramp.gen(-1), 2.0);
+/// assert_eq!(ramp.gen(4), 3.0);
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Boundary<G> {
+    inner: G,
+    mode: BoundaryMode,
+}
+
+impl<G> Boundary<G> {
+    /// Synthesize elements outside of the given generator with the given mode.
+    pub fn new(gen: G, mode: BoundaryMode) -> Self {
+        Boundary { inner: gen, mode }
+    }
+    /// Returns the mode used to synthesize elements.
+    pub fn mode(&self) -> BoundaryMode {
+        self.mode
+    }
+}
+
+impl<G> Generator<isize> for Boundary<G>
+where
+    G: DiscreteGenerator,
+    G::Output: Default,
+{
+    type Output = G::Output;
+    /// # Panics
+    ///
+    /// Panics if the wrapped generator is empty and the index does not get synthesized by [`Zero`].
+    ///
+    /// [`Zero`]: BoundaryMode::Zero
+    fn gen(&self, input: isize) -> Self::Output {
+        let len = self.inner.len() as isize;
+        if 0 <= input && input < len {
+            return self.inner.gen(input as usize);
+        }
+        let index = match self.mode {
+            BoundaryMode::Clamp => input.clamp(0, len - 1),
+            BoundaryMode::Mirror if len == 1 => 0,
+            BoundaryMode::Mirror => {
+                let period = 2 * (len - 1);
+                let index = input.rem_euclid(period);
+                if index < len {
+                    index
+                } else {
+                    period - index
+                }
+            }
+            BoundaryMode::Wrap => input.rem_euclid(len),
+            BoundaryMode::Zero => return G::Output::default(),
+        };
+        self.inner.gen(index as usize)
+    }
+}
+
+/// Generator adaptor for interpolations whose elements are curves themselves.
+///
+/// Such an interpolation can be seen as a family of curves, or a surface.
+/// The adaptor takes a tuple `(outer, inner)` as input.
+/// Conceptually, the interpolation is evaluated at `outer`, which results in a curve,
+/// which then gets evaluated at `inner`.
+/// For example, this allows morphing between shapes over time.
+///
+/// Currently only [`Linear`] is supported as interpolation.
+///
+/// [`Linear`]: crate::linear::Linear
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Nested<G>(G);
+
+impl<G> Nested<G> {
+    /// Wrap an interpolation whose elements are curves.
+    pub fn new(gen: G) -> Self {
+        Nested(gen)
+    }
+    /// Returns a reference to the wrapped interpolation.
+    pub fn inner(&self) -> &G {
+        &self.0
+    }
+}
+
+/// Wrapper for generators which enables arithmetic operators on them.
+///
+/// Adding another generator with `+` creates their [`Sum`], subtracting another generator with `-`
+/// creates their [`Difference`], multiplying with a scalar with `*` creates a [`Scale`] and
+/// negating with `-` creates a [`Negate`] of the generator.
+/// The results are wrapped again, such that operators can be chained.
+///
+/// This struct is created by [`Generator::ops()`]. See its documentation for more.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Ops<G>(G);
+
+impl<G> Ops<G> {
+    /// Wrap the generator to enable arithmetic operators.
+    pub fn new(gen: G) -> Self {
+        Ops(gen)
+    }
+    /// Returns the wrapped generator.
+    pub fn inner(self) -> G {
+        self.0
+    }
+}
+
+impl<G, Input> Generator<Input> for Ops<G>
+where
+    G: Generator<Input>,
+{
+    type Output = G::Output;
+    fn gen(&self, input: Input) -> Self::Output {
+        self.0.gen(input)
+    }
+}
+
+impl<G> DiscreteGenerator for Ops<G>
+where
+    G: DiscreteGenerator,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<G, const N: usize> ConstDiscreteGenerator<N> for Ops<G> where G: ConstDiscreteGenerator<N> {}
+
+impl<G, R> Curve<R> for Ops<G>
+where
+    G: Curve<R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.0.domain()
+    }
+}
+
+impl<G, H> Add<H> for Ops<G> {
+    type Output = Ops<Sum<G, H>>;
+    fn add(self, other: H) -> Self::Output {
+        Ops(Sum(self.0, other))
+    }
+}
+
+impl<G, H> Sub<H> for Ops<G> {
+    type Output = Ops<Difference<G, H>>;
+    fn sub(self, other: H) -> Self::Output {
+        Ops(Difference(self.0, other))
+    }
+}
+
+impl<G, T> Mul<T> for Ops<G> {
+    type Output = Ops<Scale<G, T>>;
+    fn mul(self, factor: T) -> Self::Output {
+        Ops(Scale(self.0, factor))
+    }
+}
+
+impl<G> Neg for Ops<G> {
+    type Output = Ops<Negate<G>>;
+    fn neg(self) -> Self::Output {
+        Ops(Negate(self.0))
+    }
+}
+
+/// Generator adaptor which adds the outputs of two generators.
+///
+/// If both generators are curves, the domain is the intersection of both domains.
+/// This struct is created by adding generators wrapped in [`Ops`] or by [`Curve::add_curve()`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Sum<G, H>(G, H);
+
+impl<G, H> Sum<G, H> {
+    /// Creates a generator which adds the outputs of both generators.
+    pub fn new(first: G, second: H) -> Self {
+        Sum(first, second)
+    }
+}
+
+impl<G, H, Input> Generator<Input> for Sum<G, H>
+where
+    G: Generator<Input>,
+    H: Generator<Input>,
+    G::Output: Add<H::Output>,
+    Input: Copy,
+{
+    type Output = <G::Output as Add<H::Output>>::Output;
+    fn gen(&self, input: Input) -> Self::Output {
+        self.0.gen(input) + self.1.gen(input)
+    }
+}
+
+impl<G, H> DiscreteGenerator for Sum<G, H>
+where
+    G: DiscreteGenerator,
+    H: DiscreteGenerator,
+    G::Output: Add<H::Output>,
+{
+    fn len(&self) -> usize {
+        self.0.len().min(self.1.len())
+    }
+}
+
+impl<G, H, const N: usize> ConstDiscreteGenerator<N> for Sum<G, H>
+where
+    G: ConstDiscreteGenerator<N>,
+    H: ConstDiscreteGenerator<N>,
+    G::Output: Add<H::Output>,
+{
+}
+
+impl<G, H, R> Curve<R> for Sum<G, H>
+where
+    G: Curve<R>,
+    H: Curve<R>,
+    G::Output: Add<H::Output>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        intersection(self.0.domain(), self.1.domain())
+    }
+}
+
+/// Generator adaptor which subtracts the outputs of the second generator from the outputs of the first.
+///
+/// If both generators are curves, the domain is the intersection of both domains.
+/// This struct is created by subtracting generators wrapped in [`Ops`] or by [`Curve::sub_curve()`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Difference<G, H>(G, H);
+
+impl<G, H> Difference<G, H> {
+    /// Creates a generator which subtracts the outputs of the second generator from the outputs of the first.
+    pub fn new(first: G, second: H) -> Self {
+        Difference(first, second)
+    }
+}
+
+impl<G, H, Input> Generator<Input> for Difference<G, H>
+where
+    G: Generator<Input>,
+    H: Generator<Input>,
+    G::Output: Sub<H::Output>,
+    Input: Copy,
+{
+    type Output = <G::Output as Sub<H::Output>>::Output;
+    fn gen(&self, input: Input) -> Self::Output {
+        self.0.gen(input) - self.1.gen(input)
+    }
+}
+
+impl<G, H> DiscreteGenerator for Difference<G, H>
+where
+    G: DiscreteGenerator,
+    H: DiscreteGenerator,
+    G::Output: Sub<H::Output>,
+{
+    fn len(&self) -> usize {
+        self.0.len().min(self.1.len())
+    }
+}
+
+impl<G, H, const N: usize> ConstDiscreteGenerator<N> for Difference<G, H>
+where
+    G: ConstDiscreteGenerator<N>,
+    H: ConstDiscreteGenerator<N>,
+    G::Output: Sub<H::Output>,
+{
+}
+
+impl<G, H, R> Curve<R> for Difference<G, H>
+where
+    G: Curve<R>,
+    H: Curve<R>,
+    G::Output: Sub<H::Output>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        intersection(self.0.domain(), self.1.domain())
+    }
+}
+
+/// Generator adaptor which multiplies the output of a generator with a factor.
+///
+/// This struct is created by multiplying a generator wrapped in [`Ops`] or by [`Curve::scale()`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Scale<G, T>(G, T);
+
+impl<G, T> Scale<G, T> {
+    /// Creates a generator which multiplies the output of the generator with the given factor.
+    pub fn new(gen: G, factor: T) -> Self {
+        Scale(gen, factor)
+    }
+}
+
+impl<G, T, Input> Generator<Input> for Scale<G, T>
+where
+    G: Generator<Input>,
+    G::Output: Mul<T>,
+    T: Copy,
+{
+    type Output = <G::Output as Mul<T>>::Output;
+    fn gen(&self, input: Input) -> Self::Output {
+        self.0.gen(input) * self.1
+    }
+}
+
+impl<G, T> DiscreteGenerator for Scale<G, T>
+where
+    G: DiscreteGenerator,
+    G::Output: Mul<T>,
+    T: Copy,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<G, T, const N: usize> ConstDiscreteGenerator<N> for Scale<G, T>
+where
+    G: ConstDiscreteGenerator<N>,
+    G::Output: Mul<T>,
+    T: Copy,
+{
+}
+
+impl<G, T, R> Curve<R> for Scale<G, T>
+where
+    G: Curve<R>,
+    G::Output: Mul<T>,
+    T: Copy,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.0.domain()
+    }
+}
+
+/// Generator adaptor which negates the output of a generator.
+///
+/// This struct is created by negating a generator wrapped in [`Ops`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Negate<G>(G);
+
+impl<G> Negate<G> {
+    /// Creates a generator which negates the output of the generator.
+    pub fn new(gen: G) -> Self {
+        Negate(gen)
+    }
+}
+
+impl<G, Input> Generator<Input> for Negate<G>
+where
+    G: Generator<Input>,
+    G::Output: Neg,
+{
+    type Output = <G::Output as Neg>::Output;
+    fn gen(&self, input: Input) -> Self::Output {
+        -self.0.gen(input)
+    }
+}
+
+impl<G> DiscreteGenerator for Negate<G>
+where
+    G: DiscreteGenerator,
+    G::Output: Neg,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<G, const N: usize> ConstDiscreteGenerator<N> for Negate<G>
+where
+    G: ConstDiscreteGenerator<N>,
+    G::Output: Neg,
+{
+}
+
+impl<G, R> Curve<R> for Negate<G>
+where
+    G: Curve<R>,
+    G::Output: Neg,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.0.domain()
+    }
+}
+
+/// Generator adaptor which records the minimum and maximum of all generated values.
+///
+/// The range is tracked with interior mutability, such that it is updated by every call of [`gen()`]
+/// while the generator is only borrowed. Because of this, the adaptor can not be shared between threads.
+///
+/// This struct is created by [`Generator::observed()`]. See its documentation for more.
+///
+/// [`gen()`]: Generator::gen()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Observed<G, T> {
+    inner: G,
+    range: RefCell<Option<[T; 2]>>,
+}
+
+impl<G, T> Observed<G, T> {
+    /// Wrap the generator to record the range of its generated values.
+    pub fn new(gen: G) -> Self {
+        Observed {
+            inner: gen,
+            range: RefCell::new(None),
+        }
+    }
+    /// Returns the componentwise minimum and maximum of all values generated so far, in this order.
+    ///
+    /// Returns `None` if no value was generated yet.
+    pub fn observed_range(&self) -> Option<[T; 2]>
+    where
+        T: Clone,
+    {
+        self.range.borrow().clone()
+    }
+    /// Forget all values generated so far.
+    pub fn reset(&self) {
+        self.range.replace(None);
+    }
+    /// Returns the wrapped generator.
+    pub fn inner(self) -> G {
+        self.inner
+    }
+    /// Extend the recorded range by the given value.
+    fn record(&self, value: &T)
+    where
+        T: Bounded + Clone,
+    {
+        let mut range = self.range.borrow_mut();
+        *range = Some(match range.take() {
+            Some([min, max]) => [
+                min.component_min(value.clone()),
+                max.component_max(value.clone()),
+            ],
+            None => [value.clone(), value.clone()],
+        });
+    }
+}
+
+impl<G, Input> Generator<Input> for Observed<G, G::Output>
+where
+    G: Generator<Input>,
+    G::Output: Bounded + Clone,
+{
+    type Output = G::Output;
+    fn gen(&self, input: Input) -> Self::Output {
+        let value = self.inner.gen(input);
+        self.record(&value);
+        value
+    }
+}
+
+impl<G> DiscreteGenerator for Observed<G, G::Output>
+where
+    G: DiscreteGenerator,
+    G::Output: Bounded + Clone,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<G, R> Curve<R> for Observed<G, G::Output>
+where
+    G: Curve<R>,
+    G::Output: Bounded + Clone,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
+    }
+    fn gen_batch(&self, scalars: &[R], out: &mut [Self::Output]) {
+        self.inner.gen_batch(scalars, out);
+        for value in out.iter() {
+            self.record(value);
+        }
+    }
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        self.inner.knot_midpoints()
+    }
+}
+
+/// Returns the intersection of the domains of two curves.
+///
+/// If the domains are disjoint, the returned domain is empty, that is, it starts after it ends.
+fn intersection<R: Real>(first: [R; 2], second: [R; 2]) -> [R; 2] {
+    intersect_domains(first, second).unwrap_or([first[0].max(second[0]), first[1].min(second[1])])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::easing::Identity;
+    use crate::Stepper;
+
+    #[test]
+    fn ops() {
+        use crate::easing::FuncEase;
+        let a = FuncEase::new(|t: f64| t * t);
+        let b = FuncEase::new(|t: f64| 1.0 - 3.0 * t);
+        let sum = a.ops() + b;
+        let scaled = a.ops() * 2.5;
+        let negated = -b.ops();
+        let combined = -(a.ops() * 2.0 + b.ops() * -1.0) + a;
+        for t in Stepper::normalized(11) {
+            assert_f64_near!(sum.gen(t), a.gen(t) + b.gen(t));
+            assert_f64_near!(scaled.gen(t), a.gen(t) * 2.5);
+            assert_f64_near!(negated.gen(t), -b.gen(t));
+            assert_f64_near!(combined.gen(t), b.gen(t) - a.gen(t));
+        }
+        // the domain of a sum is the intersection of both domains
+        struct Shifted;
+        impl Generator<f64> for Shifted {
+            type Output = f64;
+            fn gen(&self, t: f64) -> f64 {
+                t
+            }
+        }
+        impl Curve<f64> for Shifted {
+            fn domain(&self) -> [f64; 2] {
+                [0.5, 1.5]
+            }
+        }
+        assert_eq!((a.ops() + Shifted).domain(), [0.5, 1.0]);
+        assert_eq!((-Shifted.ops() * 2.0).domain(), [0.5, 1.5]);
+        // discrete generators keep working
+        let elements = [1.0, 2.0, 3.0].ops() + [0.5, 0.5];
+        assert_eq!(elements.len(), 2);
+        assert_f64_near!(elements.gen(1), 2.5);
+        assert_eq!(([1.0, 2.0].ops() * 3.0).gen(1), 6.0);
+    }
+
+    #[test]
+    fn boundary() {
+        let ramp = [1.0, 2.0, 3.0, 4.0];
+        let expected = [
+            (BoundaryMode::Clamp, [1.0, 1.0, 4.0, 4.0]),
+            (BoundaryMode::Mirror, [3.0, 2.0, 3.0, 2.0]),
+            (BoundaryMode::Wrap, [3.0, 4.0, 1.0, 2.0]),
+            (BoundaryMode::Zero, [0.0, 0.0, 0.0, 0.0]),
+        ];
+        for (mode, values) in expected {
+            let extended = ramp.boundary(mode);
+            for (index, value) in IntoIterator::into_iter([-2, -1, 4, 5]).zip(values) {
+                assert_eq!(extended.gen(index), value, "{:?} at {}", mode, index);
+            }
+            for index in 0..4 {
+                assert_eq!(extended.gen(index), ramp[index as usize]);
+            }
+        }
+        // mirroring repeats itself with a period of twice the distance between the edges
+        let mirrored = ramp.boundary(BoundaryMode::Mirror);
+        assert_eq!(mirrored.gen(-7), 2.0);
+        assert_eq!(mirrored.gen(12), 1.0);
+        assert_eq!([5.0].boundary(BoundaryMode::Mirror).gen(-3), 5.0);
+    }
+
+    #[test]
+    #[cfg(feature = "linear")]
+    fn retime() {
+        use crate::easing::{Ease, EaseKind};
+        use crate::linear::Linear;
+        let linear = Linear::builder()
+            .elements([0.0, 10.0])
+            .knots([0.0, 2.0])
+            .build()
+            .unwrap();
+        let identity = Linear::builder()
+            .elements([0.0, 2.0])
+            .knots([0.0, 2.0])
+            .build()
+            .unwrap();
+        let ease_in = Ease::ease_in(EaseKind::Quadratic).apply(identity);
+        let retimed = linear
+            .compose_with_time_curve(ease_in, RetimeMode::Error { samples: 11 })
+            .unwrap();
+        assert_eq!(retimed.domain(), [0.0, 2.0]);
+        for t in Stepper::new(11, 0.0, 2.0) {
+            let normalized = t / 2.0;
+            assert_f64_near!(retimed.gen(t), 10.0 * normalized * normalized);
+        }
+        // back easing leaves the domain of the value curve
+        let back = Ease::ease_in(EaseKind::Back).apply(identity);
+        let error = linear
+            .compose_with_time_curve(back, RetimeMode::Error { samples: 11 })
+            .unwrap_err();
+        assert!(error.time() < 0.0);
+        assert!(error.input() > 0.0 && error.input() < 2.0);
+        assert_eq!(error.domain(), [0.0, 2.0]);
+        let clamped = linear
+            .compose_with_time_curve(back, RetimeMode::Clamp)
+            .unwrap();
+        assert_eq!(clamped.gen(0.3), 0.0);
+        assert_f64_near!(clamped.gen(2.0), 10.0);
+    }
+
+    #[test]
+    fn combine() {
+        use crate::easing::FuncEase;
+        let a = FuncEase::new(|t: f64| t * t);
+        let b = TransformInput::new(FuncEase::new(|t: f64| 1.0 - 3.0 * t), 0.0, 2.0);
+        assert_eq!(b.domain(), [0.0, 0.5]);
+        let sum = a.add_curve(&b);
+        let difference = a.sub_curve(&b);
+        let scaled = a.scale(-2.0);
+        let combined: Combine<_, _, _> = Combine::new(a, &b, |x: f64, y: f64| x * y);
+        // the domains are intersected
+        assert_eq!(sum.domain(), [0.0, 0.5]);
+        assert_eq!(difference.domain(), [0.0, 0.5]);
+        assert_eq!(combined.domain(), [0.0, 0.5]);
+        assert_eq!(scaled.domain(), [0.0, 1.0]);
+        for t in Stepper::new(11, 0.0, 0.5) {
+            assert_f64_near!(sum.gen(t), a.gen(t) + b.gen(t));
+            assert_f64_near!(difference.gen(t), a.gen(t) - b.gen(t));
+            assert_f64_near!(scaled.gen(t), -2.0 * a.gen(t));
+            assert_f64_near!(combined.gen(t), a.gen(t) * b.gen(t));
+            assert_f64_near!((a.ops() - &b).gen(t), difference.gen(t));
+        }
+        // a curve minus itself vanishes
+        assert!(a
+            .take(11)
+            .zip(a.sub_curve(a).take(11))
+            .all(|(_, zero)| zero == 0.0));
+        assert_eq!(([3.0, 2.0].ops() - [1.0, 1.0, 1.0]).gen(0), 2.0);
+    }
+
+    #[test]
+    #[cfg(feature = "linear")]
+    fn reflect() {
+        use crate::linear::Linear;
+        let x = Linear::builder()
+            .elements([0.0, 1.0, 3.0])
+            .knots([0.0, 1.0, 2.0])
+            .build()
+            .unwrap();
+        let y = Linear::builder()
+            .elements([1.0, -2.0, 0.5])
+            .knots([0.0, 1.0, 2.0])
+            .build()
+            .unwrap();
+        let curve = x.stack(y);
+        let reflected = curve.reflect([1.0, 1.0], [1.0, 2.0]);
+        let twice = reflected.reflect([1.0, 1.0], [1.0, 2.0]);
+        for input in Stepper::normalized(11).map(|t: f64| t * 2.0) {
+            let original = curve.gen(input);
+            let mirrored = reflected.gen(input);
+            let back = twice.gen(input);
+            assert_float_absolute_eq!(back.0, original.0, 1e-12);
+            assert_float_absolute_eq!(back.1, original.1, 1e-12);
+            // the axis is the perpendicular bisector of a point and its reflection
+            let mid = (
+                (original.0 + mirrored.0) / 2.0,
+                (original.1 + mirrored.1) / 2.0,
+            );
+            assert_float_absolute_eq!((mid.0 - 1.0) * 2.0, mid.1 - 1.0, 1e-12);
+        }
+        // reflection about the x-axis
+        let flipped = curve.reflect([0.0, 0.0], [1.0, 0.0]);
+        assert_eq!(flipped.gen(1.0), (1.0, 2.0));
+    }
+
+    #[test]
+    fn pow() {
+        let identity = Identity::new().pow(1.0);
+        for t in Stepper::normalized(11) {
+            assert_f64_near!(identity.gen(t), t);
+        }
+        let squared = Identity::new().pow(2.0);
+        assert_eq!(squared.domain(), [0.0, 1.0]);
+        assert_f64_near!(squared.gen(0.0), 0.0);
+        assert_f64_near!(squared.gen(1.0), 1.0);
+        // the curve starts slower and ends faster
+        for t in Stepper::normalized(11).skip(1).take(9) {
+            assert!(squared.gen(t) < t);
+        }
+        assert!(squared.gen(0.1) - squared.gen(0.0) < squared.gen(1.0) - squared.gen(0.9));
+    }
+
+    #[test]
+    #[cfg(feature = "linear")]
+    fn smooth() {
+        use crate::linear::Linear;
+        // zigzag between 0.0 and 2.0 with a period of 2.0
+        let zigzag = Linear::builder()
+            .elements([0.0, 2.0, 0.0, 2.0, 0.0, 2.0, 0.0, 2.0, 0.0, 2.0, 0.0])
+            .equidistant::<f64>()
+            .distance(0.0, 1.0)
+            .build()
+            .unwrap();
+        let smooth = zigzag.smooth(1.0, 9);
+        let inputs = || Stepper::new(41, 2.0, 8.0);
+        let (min, max, sum) = inputs().map(|input| smooth.gen(input)).fold(
+            (f64::INFINITY, f64::NEG_INFINITY, 0.0),
+            |(min, max, sum), value| (min.min(value), max.max(value), sum + value),
+        );
+        // the amplitude gets heavily reduced
+        assert!(max - min < 0.25);
+        // while the mean is preserved
+        let mean = sum / 41.0;
+        let original_mean = inputs().map(|input| zigzag.gen(input)).sum::<f64>() / 41.0;
+        assert_float_absolute_eq!(mean, original_mean, 0.05);
+    }
+
+    #[test]
+    #[cfg(feature = "linear")]
+    fn zip_with() {
+        use crate::linear::Linear;
+        let first = Linear::builder()
+            .elements([0.0, 2.0])
+            .knots([0.0, 2.0])
+            .build()
+            .unwrap();
+        let second = Linear::builder()
+            .elements([3.0, 1.0])
+            .knots([1.0, 3.0])
+            .build()
+            .unwrap();
+        let product = first.zip_with(second, |a, b| a * b);
+        assert_eq!(product.domain(), [1.0, 2.0]);
+        let expected = [3.0, 3.75, 4.0];
+        for (value, result) in product.take(expected.len()).zip(expected) {
+            assert_f64_near!(value, result);
+        }
+        assert_f64_near!(product.gen(0.0), 0.0);
+    }
+
+    #[test]
+    fn input_transform() {
+        let identity = Identity {};
+        let transformed = TransformInput::new(identity, 0.0, 2.0);
+        assert_f64_near!(transformed.gen(1.0), 2.0);
+        let results = [0.0, 1.0, 2.0];
+        // try to extract
+        let extractor = transformed.extract([0.0, 0.5, 1.0]);
+        for (val, res) in extractor.zip(results.iter()) {
+            assert_f64_near!(val, res);
+        }
+        // try to take - should be the same as before as the domain should have changed accordingly
+        let transformed = TransformInput::new(identity, 0.0, 2.0);
+        for (val, res) in transformed
+            .take(results.len())
+            .zip(<Identity as Curve<f64>>::take(identity, results.len()))
+        {
+            assert_f64_near!(val, res);
+        }
+    }
+
+    #[test]
+    fn slice() {
+        let identity = Identity {};
+        let slice = Slice::new(identity, 10.0..100.0);
+        let results = [10.0, 100.0];
+        assert_f64_near!(slice.gen(0.0), 10.0);
+        assert_f64_near!(slice.gen(1.0), 100.0);
+        for (val, res) in slice.take(results.len()).zip(results.iter()) {
+            assert_f64_near!(val, res);
+        }
+    }
+
+    #[test]
+    fn select() {
+        use crate::easing::FuncEase;
+        let idle: FuncEase<fn(f64) -> f64> = FuncEase::new(|_| 2.0);
+        let moving: FuncEase<fn(f64) -> f64> = FuncEase::new(|_| 7.0);
+        let state = FuncEase::new(|x: f64| if x < 0.4 { 0.0 } else { 1.0 });
+        let selected = state.select([idle, moving]);
+        assert_eq!(selected.domain(), [0.0, 1.0]);
+        assert_f64_near!(selected.gen(0.0), 2.0);
+        assert_f64_near!(selected.gen(0.39), 2.0);
+        assert_f64_near!(selected.gen(0.4), 7.0);
+        assert_f64_near!(selected.gen(1.0), 7.0);
+        // indices are rounded and clamped
+        let selector = FuncEase::new(|x: f64| x);
+        let selected = selector.select([idle, moving]);
+        assert_f64_near!(selected.gen(0.49), 2.0);
+        assert_f64_near!(selected.gen(0.51), 7.0);
+        assert_f64_near!(selected.gen(-3.0), 2.0);
+        assert_f64_near!(selected.gen(5.0), 7.0);
+        assert_f64_near!(selected.gen(f64::NAN), 2.0);
+    }
+
+    #[test]
+    fn speed() {
+        #[derive(Debug, Copy, Clone)]
+        struct Point(f64, f64);
+        impl Sub for Point {
+            type Output = Point;
+            fn sub(self, other: Point) -> Point {
+                Point(self.0 - other.0, self.1 - other.1)
+            }
+        }
+        impl Mul<f64> for Point {
+            type Output = Point;
+            fn mul(self, factor: f64) -> Point {
+                Point(self.0 * factor, self.1 * factor)
+            }
+        }
+        // planar curve defined by a function on [0,1]
+        struct Planar<F>(F);
+        impl<F: Fn(f64) -> Point> Generator<f64> for Planar<F> {
+            type Output = Point;
+            fn gen(&self, t: f64) -> Point {
+                (self.0)(t)
+            }
+        }
+        impl<F: Fn(f64) -> Point> Curve<f64> for Planar<F> {
+            fn domain(&self) -> [f64; 2] {
+                [0.0, 1.0]
+            }
+        }
+        let norm = |point: Point| point.0.hypot(point.1);
+        // arc of a circle with radius 2, parameterized by angle and thus with constant speed
+        let arc = Planar(|t: f64| Point(2.0 * t.cos(), 2.0 * t.sin())).finite_difference();
+        let speed = arc.speed(norm);
+        assert_eq!(speed.domain(), [0.0, 1.0]);
+        for t in Stepper::normalized(21) {
+            assert_float_absolute_eq!(speed.gen(t), 2.0, 1e-4);
+        }
+        // a parabola speeds up
+        let parabola = Planar(|t: f64| Point(t, t * t)).finite_difference();
+        let speed = parabola.speed(norm);
+        assert_float_absolute_eq!(speed.gen(0.5), 2.0f64.sqrt(), 1e-6);
+        assert!(speed.gen(0.2) < speed.gen(0.8));
+    }
+
+    #[test]
+    fn soft_min() {
+        use crate::easing::FuncEase;
+        let rising = FuncEase::new(|x: f64| x);
+        let falling = FuncEase::new(|x: f64| 1.0 - x);
+        let hard = |x: f64| x.min(1.0 - x);
+        // large sharpness approaches the hard minimum
+        let sharp = rising.soft_min(falling, 1000.0);
+        assert_eq!(sharp.domain(), [0.0, 1.0]);
+        for x in [0.0, 0.1, 0.3, 0.5, 0.7, 0.9, 1.0] {
+            assert_float_absolute_eq!(sharp.gen(x), hard(x), 1e-3);
+        }
+        // no overflow even far away from the kink
+        assert_f64_near!(rising.soft_min(falling, 1e6).gen(0.0), 0.0);
+        // small sharpness rounds the corner, staying below the hard minimum
+        let soft = rising.soft_min(falling, 4.0);
+        assert_f64_near!(soft.gen(0.5), 0.5 - 2.0f64.ln() / 4.0);
+        for x in [0.1, 0.3, 0.5, 0.7, 0.9] {
+            assert!(soft.gen(x) < hard(x));
+        }
+        // negative sharpness gives the soft maximum
+        let max = rising.soft_min(falling, -1000.0);
+        assert_float_absolute_eq!(max.gen(0.2), 0.8, 1e-3);
+        // zero sharpness gives the average
+        let average = rising.soft_min(falling, 0.0);
+        assert_f64_near!(average.gen(0.2), 0.5);
+        assert!(!rising.soft_min(falling, -0.0).gen(0.9).is_nan());
+    }
+
+    #[test]
+    fn observed() {
+        use crate::easing::FuncEase;
+        let wave = FuncEase::new(|x: f64| (x * 7.0).sin()).observed();
+        assert_eq!(wave.observed_range(), None);
+        let (min, max) = wave
+            .by_ref()
+            .take(50)
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        assert_eq!(wave.observed_range(), Some([min, max]));
+        // batches are recorded as well
+        let mut batch = [0.0; 2];
+        wave.gen_batch(&[0.0, 0.0], &mut batch);
+        assert_eq!(wave.observed_range(), Some([min, max]));
+        wave.reset();
+        wave.gen_batch(&[0.5, 0.25], &mut batch);
+        let [first, second] = batch;
+        assert_eq!(
+            wave.observed_range(),
+            Some([first.min(second), first.max(second)])
+        );
     }
 }
@@ -1,11 +1,31 @@
 use num_traits::real::Real;
 use num_traits::FromPrimitive;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use core::cmp::Ordering;
+#[cfg(feature = "alloc")]
+use core::fmt::{Display, Write};
 use core::iter::FusedIterator;
+use core::marker::PhantomData;
 use core::ops::RangeBounds;
+use core::ops::{Add, Mul, Sub};
 
-use super::Equidistant;
-use super::{Clamp, Composite, Repeat, Slice, Stack};
+#[cfg(feature = "alloc")]
+use super::Lut;
+use super::{
+    Boundary, BoundaryMode, Clamp, Composite, Difference, FiniteDifference, Observed, Ops,
+    OutOfDomain, Pow, Reflect, Repeat, Retime, RetimeMode, Scale, Select, Slice, Smooth, SoftMin,
+    Speed, Stack, Sum, ZipWith,
+};
+use super::{Geometric, Linspace, NotPositive};
+#[cfg(all(feature = "alloc", feature = "linear"))]
+use crate::{linear::Linear, Equidistant, Identity};
+#[cfg(feature = "alloc")]
+use topology_traits::Merge;
+use topology_traits::QuasiMetric;
 
 /// Trait which symbolises the generation or copying of an element.
 ///
@@ -86,6 +106,32 @@ pub trait Generator<Input> {
     {
         Stack::new(self, gen)
     }
+    /// Combine the outputs of two generators with a closure.
+    ///
+    /// This is the same as [`stack()`] followed by mapping the tuple,
+    /// without creating the intermediate tuple.
+    /// If both generators are curves, the domain of the created curve is the intersection of both domains.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::Generator;
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// let elements = [1.0,5.0,3.0];
+    /// let scale = [2.0,1.0,0.5];
+    /// let scaled = elements.zip_with(scale, |element, factor| element * factor);
+    /// assert_f64_near!(scaled.gen(1), 5.0);
+    /// assert_f64_near!(scaled.gen(2), 1.5);
+    /// ```
+    ///
+    /// [`stack()`]: Self::stack()
+    fn zip_with<G, F>(self, gen: G, func: F) -> ZipWith<Self, G, F>
+    where
+        Self: Sized,
+    {
+        ZipWith::new(self, gen, func)
+    }
     /// Takes two generators and creates a new generator pipelining both generators.
     ///
     /// [`composite()`] will return a new generator which will first generate values from the original input
@@ -129,9 +175,97 @@ pub trait Generator<Input> {
     /// Get a reference of the generator.
     ///
     /// This is useful if one wants to add an adaptor without consuming the original.
-    fn by_ref(&self) -> &Self {
+    /// As references of generators are generators themselves, the same generator can be used
+    /// in multiple compositions, similar to [`Iterator::by_ref()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::Generator;
+    /// let elements = [1.0,5.0,3.0];
+    /// let sums = elements.by_ref().zip_with([1.0,1.0,1.0], |a, b| a + b);
+    /// let products = elements.by_ref().zip_with([2.0,2.0,2.0], |a, b| a * b);
+    /// assert_eq!(sums.gen(1), 6.0);
+    /// assert_eq!(products.gen(1), 10.0);
+    /// // elements was not moved
+    /// assert_eq!(elements.gen(1), 5.0);
+    /// ```
+    fn by_ref(&self) -> &Self
+    where
+        Self: Sized,
+    {
         self
     }
+    /// Wrap the generator to combine it with others by arithmetic operators.
+    ///
+    /// The wrapped generator can be added to other generators with `+`, subtracted from with `-`,
+    /// multiplied with a scalar with `*` and negated with `-`. Every operator returns a wrapped generator again,
+    /// such that they can be chained. If curves are added or subtracted, the domain of the result
+    /// is the intersection of both domains.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// let carrier = FuncEase::new(|t: f64| (t * 20.0).sin());
+    /// let offset = FuncEase::new(|t: f64| t);
+    /// let signal = -(carrier.ops() * 0.5 + offset);
+    /// assert_eq!(signal.domain(), [0.0, 1.0]);
+    /// assert_f64_near!(signal.gen(0.3), -(6.0f64.sin() * 0.5 + 0.3));
+    /// ```
+    fn ops(self) -> Ops<Self>
+    where
+        Self: Sized,
+    {
+        Ops::new(self)
+    }
+    /// Wrap the generator to record the range of all values it generates.
+    ///
+    /// The wrapped generator behaves like the original one, but keeps track of the componentwise
+    /// minimum and maximum of every value generated, which can be read with [`observed_range()`].
+    /// This is useful to find the range of a curve which gets evaluated anyway, for example
+    /// to fit the axes of a plot, without sampling the curve beforehand.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Generator, Curve};
+    /// let parabola = FuncEase::new(|x: f64| 4.0 * x * (1.0 - x)).observed();
+    /// let plot: Vec<f64> = parabola.by_ref().take(5).collect();
+    /// assert_eq!(plot, [0.0, 0.75, 1.0, 0.75, 0.0]);
+    /// assert_eq!(parabola.observed_range(), Some([0.0, 1.0]));
+    /// ```
+    ///
+    /// [`observed_range()`]: Observed::observed_range()
+    fn observed(self) -> Observed<Self, Self::Output>
+    where
+        Self: Sized,
+        Self::Output: Bounded + Clone,
+    {
+        Observed::new(self)
+    }
+    /// Get a closure which generates values with this generator.
+    ///
+    /// The closure borrows the generator. This allows to pass generators to functions expecting closures,
+    /// for example of plotting libraries, without them knowing about the traits of this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Generator};
+    /// fn plot(function: impl Fn(f64) -> f64) -> Vec<f64> {
+    ///     (0..=4).map(|i| function(i as f64 / 4.0)).collect()
+    /// }
+    /// let square = FuncEase::new(|x: f64| x * x);
+    /// assert_eq!(plot(square.as_fn()), [0.0, 0.0625, 0.25, 0.5625, 1.0]);
+    /// ```
+    fn as_fn(&self) -> impl Fn(Input) -> Self::Output + '_
+    where
+        Self: Sized,
+    {
+        move |input| self.gen(input)
+    }
     /// Helper function if one wants to sample values from the interpolation.
     ///
     /// It takes an iterator of items which are inputed into the [`gen()`] method
@@ -181,6 +315,14 @@ impl<G: Generator<I> + ?Sized, I> Generator<I> for &G {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<G: Generator<I> + ?Sized, I> Generator<I> for Box<G> {
+    type Output = G::Output;
+    fn gen(&self, input: I) -> Self::Output {
+        (**self).gen(input)
+    }
+}
+
 /// Specialized [`Generator`] which takes a real number as input.
 ///
 /// [`Generator`]: Generator
@@ -192,6 +334,102 @@ where
     ///
     /// Not all Curves may extrapolate in a safe way.
     fn domain(&self) -> [R; 2];
+    /// Returns the outputs of the curve at the start and at the end of its domain.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Curve};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// assert_eq!(linear.endpoints(), (0.0, 3.0));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn endpoints(&self) -> (Self::Output, Self::Output) {
+        let [start, end] = self.domain();
+        (self.gen(start), self.gen(end))
+    }
+    /// Approximate the axis-aligned bounding box of the curve by sampling it.
+    ///
+    /// Returns the componentwise minimum and maximum of `samples` equidistant samples, in this order.
+    /// As only samples are considered, the box is approximate and may be slightly too small
+    /// if the curve reaches its extremes between samples. Curves like [`Bezier`] also provide
+    /// a conservative box, which always contains the curve, with their `control_bounds()` method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Curve};
+    /// let parabola = FuncEase::new(|x: f64| 4.0 * x * (1.0 - x));
+    /// assert_eq!(parabola.sample_bounds(5), [0.0, 1.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given size of samples is 0 or if `samples - 1` can not be converted to the type `R`.
+    ///
+    /// [`Bezier`]: crate::bezier::Bezier
+    fn sample_bounds(&self, samples: usize) -> [Self::Output; 2]
+    where
+        Self::Output: Bounded + Clone,
+        R: FromPrimitive,
+    {
+        let [start, end] = self.domain();
+        bounds(Stepper::new(samples, start, end).map(|input| self.gen(input)))
+            .expect("at least one sample has to be taken")
+    }
+    /// Checks if the curve is strictly monotone by comparing `samples` equidistant samples.
+    ///
+    /// Returns `Some(Ordering::Less)` if every sample is less than the next one, that is, the curve is
+    /// strictly increasing, and `Some(Ordering::Greater)` if the curve is strictly decreasing.
+    /// Otherwise, or if less than two samples are taken, `None` is returned.
+    ///
+    /// As only samples are compared, a curve which changes its direction between two samples
+    /// is not detected. Enough samples should be taken to resolve every extremum, which may exist,
+    /// before relying on the result, for example to invert the curve.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Curve};
+    /// # use core::cmp::Ordering;
+    /// let increasing = FuncEase::new(|x: f64| x * x);
+    /// assert_eq!(increasing.is_monotone(10), Some(Ordering::Less));
+    /// let decreasing = FuncEase::new(|x: f64| 1.0 - x);
+    /// assert_eq!(decreasing.is_monotone(10), Some(Ordering::Greater));
+    /// let parabola = FuncEase::new(|x: f64| 4.0 * x * (1.0 - x));
+    /// assert_eq!(parabola.is_monotone(10), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given size of samples is 0 or if `samples - 1` can not be converted to the type `R`.
+    fn is_monotone(&self, samples: usize) -> Option<Ordering>
+    where
+        Self::Output: PartialOrd,
+        R: FromPrimitive,
+    {
+        let [start, end] = self.domain();
+        let mut values = Stepper::new(samples, start, end).map(|input| self.gen(input));
+        let mut last = values.next()?;
+        let mut direction = None;
+        for value in values {
+            let order = last.partial_cmp(&value)?;
+            if order == Ordering::Equal || direction.is_some_and(|direction| direction != order) {
+                return None;
+            }
+            direction = Some(order);
+            last = value;
+        }
+        direction
+    }
     /// Takes equidistant samples of the curve.
     ///
     /// # Examples
@@ -226,6 +464,43 @@ where
         let [start, end] = self.domain();
         Take(self.extract(Stepper::new(samples, start, end)))
     }
+    /// Generate the outputs of the curve at the given inputs, paired with their inputs.
+    ///
+    /// Inputs outside of the domain of the curve are skipped.
+    /// Together with [`Stepper::from_generator()`] this allows sampling the curve non-uniformly,
+    /// for example more densely where it bends.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Curve, Stepper};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let knots = [0.0, 1.0, 3.0];
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0, 5.0, 3.0])
+    ///                 .knots(knots)
+    ///                 .build()?;
+    /// let pairs: Vec<_> = linear.sample_pairs(Stepper::from_generator(knots)).collect();
+    /// assert_eq!(pairs, [(0.0, 0.0), (1.0, 5.0), (3.0, 3.0)]);
+    /// // inputs outside of the domain are skipped
+    /// assert_eq!(linear.sample_pairs([-1.0, 2.0, 4.0]).count(), 1);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn sample_pairs<I>(&self, inputs: I) -> SamplePairs<&Self, I::IntoIter, R>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = R>,
+    {
+        SamplePairs {
+            curve: self,
+            iterator: inputs.into_iter(),
+            domain: self.domain(),
+        }
+    }
     /// Take a slice of a curve.
     ///
     /// A slice of a curve maps its domain onto the given range.
@@ -258,6 +533,181 @@ where
     {
         Slice::new(self, bounds)
     }
+    /// Apply the curve to all parameters of a discrete generator.
+    ///
+    /// The returned generator is discrete with the same length as `params` and generates the value
+    /// of the curve at the corresponding parameter. It can for example be given to a builder as elements.
+    /// This is the same as calling [`composite()`] on `params` with the curve.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, DiscreteGenerator, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,4.0])
+    ///                 .knots([0.0,1.0])
+    ///                 .build()?;
+    /// let values = linear.over([0.25,0.5,1.0]);
+    /// assert_eq!(values.len(), 3);
+    /// assert_eq!(values.gen(1), 2.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`composite()`]: Generator::composite()
+    fn over<G>(self, params: G) -> Composite<G, Self>
+    where
+        Self: Sized,
+        G: DiscreteGenerator<Output = R>,
+    {
+        Composite::new(params, self)
+    }
+    /// Remap the time of the curve by another curve.
+    ///
+    /// The created curve evaluates the time curve at its input and the curve at the result,
+    /// that is `curve.gen(time.gen(t))`. This is the same as [`composite()`], however the generated
+    /// times are made sure to be inside of the domain of the curve, as configured by the given mode:
+    /// - [`RetimeMode::Clamp`] clamps the generated times to the domain of the curve.
+    /// - [`RetimeMode::Error`] checks samples of the time curve and returns an error if one of them is
+    ///   outside of the domain of the curve. Times between these samples are not checked.
+    ///
+    /// The domain of the created curve is the domain of the time curve.
+    /// This is useful for effects like slow-motion or to ease a curve.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::Linear, easing::{Ease, EaseKind}, Curve, Generator, RetimeMode};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,10.0])
+    ///                 .knots([0.0,1.0])
+    ///                 .build()
+    ///                 .unwrap();
+    /// let eased = linear
+    ///     .compose_with_time_curve(
+    ///         Ease::ease_in(EaseKind::Quadratic),
+    ///         RetimeMode::Error { samples: 101 },
+    ///     )
+    ///     .unwrap();
+    /// assert_f64_near!(eased.gen(0.5), 2.5);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OutOfDomain`] if the mode is [`RetimeMode::Error`] and a sample of the time curve
+    /// is outside of the domain of the curve.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mode is [`RetimeMode::Error`] with 0 samples
+    /// or if `samples - 1` can not be converted to the type `R`.
+    ///
+    /// [`composite()`]: Generator::composite()
+    fn compose_with_time_curve<T>(
+        self,
+        time: T,
+        mode: RetimeMode,
+    ) -> Result<Retime<Self, T>, OutOfDomain<R>>
+    where
+        Self: Sized,
+        T: Curve<R, Output = R>,
+        R: FromPrimitive,
+    {
+        Retime::new(self, time, mode)
+    }
+    /// Add the outputs of another curve to the outputs of this curve.
+    ///
+    /// The domain of the created curve is the intersection of both domains.
+    /// To combine curves with any other closure, use [`zip_with()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Curve, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// let ramp = FuncEase::new(|t: f64| t);
+    /// let wave = FuncEase::new(|t: f64| (t * 10.0).sin());
+    /// let signal = ramp.add_curve(wave);
+    /// assert_f64_near!(signal.gen(0.5), 0.5 + 5.0f64.sin());
+    /// ```
+    ///
+    /// [`zip_with()`]: Generator::zip_with()
+    fn add_curve<C>(self, other: C) -> Sum<Self, C>
+    where
+        Self: Sized,
+        C: Curve<R>,
+        Self::Output: Add<C::Output>,
+    {
+        Sum::new(self, other)
+    }
+    /// Subtract the outputs of another curve from the outputs of this curve.
+    ///
+    /// The domain of the created curve is the intersection of both domains.
+    /// This is useful to compare curves, for example to get the offset of an animation
+    /// to the one it gets blended with.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Curve, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let first = Linear::builder()
+    ///                 .elements([0.0,4.0])
+    ///                 .knots([0.0,2.0])
+    ///                 .build()?;
+    /// let second = Linear::builder()
+    ///                 .elements([1.0,2.0])
+    ///                 .knots([1.0,3.0])
+    ///                 .build()?;
+    /// let difference = first.sub_curve(second);
+    /// assert_eq!(difference.domain(), [1.0,2.0]);
+    /// assert_f64_near!(difference.gen(1.0), 1.0);
+    /// assert_f64_near!(difference.gen(2.0), 2.5);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn sub_curve<C>(self, other: C) -> Difference<Self, C>
+    where
+        Self: Sized,
+        C: Curve<R>,
+        Self::Output: Sub<C::Output>,
+    {
+        Difference::new(self, other)
+    }
+    /// Multiply the outputs of the curve with the given factor.
+    ///
+    /// The domain of the created curve is the domain of this curve.
+    /// To chain several arithmetic operations, use [`ops()`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Curve, Generator};
+    /// let doubled = FuncEase::new(|t: f64| t * t).scale(2.0);
+    /// assert_eq!(doubled.domain(), [0.0, 1.0]);
+    /// assert_eq!(doubled.gen(0.5), 0.5);
+    /// ```
+    ///
+    /// [`ops()`]: Generator::ops()
+    fn scale<T>(self, factor: T) -> Scale<Self, T>
+    where
+        Self: Sized,
+        Self::Output: Mul<T>,
+        T: Copy,
+    {
+        Scale::new(self, factor)
+    }
     /// Clamp the input of a curve to its domain.
     ///
     /// # Examples
@@ -283,18 +733,1176 @@ where
     /// ```
     fn clamp(self) -> Clamp<Self>
     where
-        Self: Sized,
+        Self: Sized,
+    {
+        Clamp::new(self)
+    }
+    /// Generate the output of the curve as if it repeats itself, together with the number of repetitions.
+    ///
+    /// The input is wrapped into the domain of the curve, such that the curve loops with a period of the
+    /// length of its domain. Returns the output at the wrapped input and how many periods the input is away
+    /// from the domain, which is 0 inside of the domain, positive after and negative before it.
+    /// The count saturates at the bounds of `i64`.
+    ///
+    /// If the domain is empty or reversed, the input is not wrapped and the count is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Curve};
+    /// let frame = FuncEase::new(|t: f64| (t * 4.0).floor());
+    /// assert_eq!(frame.gen_with_cycle(0.5), (2.0, 0));
+    /// assert_eq!(frame.gen_with_cycle(2.25), (1.0, 2));
+    /// assert_eq!(frame.gen_with_cycle(-0.25), (3.0, -1));
+    /// ```
+    fn gen_with_cycle(&self, input: R) -> (Self::Output, i64) {
+        let [start, end] = self.domain();
+        let length = end - start;
+        // also catches NaN
+        if length.partial_cmp(&R::zero()) != Some(Ordering::Greater) {
+            return (self.gen(input), 0);
+        }
+        let cycles = ((input - start) / length).floor();
+        let wrapped = input - cycles * length;
+        // rounding may push the wrapped input slightly out of the domain
+        let wrapped = wrapped.max(start).min(end);
+        let count = cycles.to_i64().unwrap_or(if cycles > R::zero() {
+            i64::MAX
+        } else {
+            i64::MIN
+        });
+        (self.gen(wrapped), count)
+    }
+    /// Smooth the curve by a moving average.
+    ///
+    /// The created curve averages the original curve over `[t - window, t + window]` for each input `t`,
+    /// with the given number of equidistant subsamples. This reduces high-frequency content,
+    /// like noise, without rebuilding the curve. With less than two subsamples, the curve is not changed.
+    ///
+    /// Near the borders of the domain, the original curve gets evaluated outside of its domain.
+    /// Use [`clamp()`] beforehand if the curve should not extrapolate.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let spike = Linear::builder()
+    ///                 .elements([0.0,0.0,4.0,0.0,0.0])
+    ///                 .knots([0.0,1.0,2.0,3.0,4.0])
+    ///                 .build()?;
+    /// let smooth = spike.smooth(1.0, 3);
+    /// assert_f64_near!(smooth.gen(2.0), 4.0 / 3.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`clamp()`]: Curve::clamp()
+    fn smooth(self, window: R, subsamples: usize) -> Smooth<Self, R>
+    where
+        Self: Sized,
+    {
+        Smooth::new(self, window, subsamples)
+    }
+    /// Raise the output of the curve to the power of `exponent`.
+    ///
+    /// Applied to easing curves, this tunes their feel without the need to write a new easing function.
+    /// An exponent of 1.0 does not change the curve, bigger exponents result in a slower start and
+    /// a faster end, smaller exponents in a faster start and a slower end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::Identity, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// let ease_in = Identity::new().pow(2.0);
+    /// assert_f64_near!(ease_in.gen(0.5), 0.25);
+    /// assert_f64_near!(ease_in.gen(1.0), 1.0);
+    /// ```
+    fn pow(self, exponent: Self::Output) -> Pow<Self, Self::Output>
+    where
+        Self: Sized,
+        Self::Output: Real,
+    {
+        Pow::new(self, exponent)
+    }
+    /// Reflect the planar outputs of the curve about the axis going through `point` in `direction`.
+    ///
+    /// The reflection is applied to the outputs of the curve, such that this works for all curves whose
+    /// output can be converted from and into an array of two coordinates, like arrays and tuples.
+    /// The concrete type of the curve gets wrapped. As linear interpolations, bezier curves and bsplines
+    /// are affine invariant, the result is the same as reflecting their elements.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let x = Linear::builder().elements([0.0,2.0]).knots([0.0,1.0]).build()?;
+    /// let y = Linear::builder().elements([1.0,3.0]).knots([0.0,1.0]).build()?;
+    /// // mirror at the vertical line x = 1.0
+    /// let mirrored = x.stack(y).reflect([1.0,0.0], [0.0,1.0]);
+    /// assert_eq!(mirrored.gen(0.0), (2.0,1.0));
+    /// assert_eq!(mirrored.gen(1.0), (0.0,3.0));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn reflect(self, point: [R; 2], direction: [R; 2]) -> Reflect<Self, R>
+    where
+        Self: Sized,
+        Self::Output: Into<[R; 2]> + From<[R; 2]>,
+    {
+        Reflect::new(self, point, direction)
+    }
+    /// Approximate the derivative of the curve with finite differences.
+    ///
+    /// The returned curve generates the same values as this curve and implements [`Derivative`],
+    /// such that curves without an analytic derivative can be used wherever a derivative is needed.
+    /// Linear, bezier and bspline curves implement [`Derivative`] themselves, which is more precise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Derivative, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// let square = FuncEase::new(|x: f64| x * x).finite_difference();
+    /// assert_f64_near!(square.gen(0.5), 0.25);
+    /// assert!((square.derivative(0.5) - 1.0).abs() < 1e-6);
+    /// ```
+    fn finite_difference(self) -> FiniteDifference<Self>
+    where
+        Self: Sized,
+    {
+        FiniteDifference::new(self)
+    }
+    /// Create a curve of the speed of this curve, the norm of its derivative.
+    ///
+    /// The created curve generates `norm(self.derivative(t))`, such that it can be sampled or searched
+    /// for extrema like any other curve. It has the same domain as this curve.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Generator, Curve};
+    /// let square = FuncEase::new(|x: f64| x * x).finite_difference();
+    /// let speed = square.speed(f64::abs);
+    /// assert!((speed.gen(0.5) - 1.0).abs() < 1e-6);
+    /// assert!(speed.gen(0.2) < speed.gen(0.8));
+    /// ```
+    fn speed<F>(self, norm: F) -> Speed<Self, F>
+    where
+        Self: Sized + Derivative<R>,
+        F: Fn(Self::Output) -> R,
+    {
+        Speed::new(self, norm)
+    }
+    /// Smoothly blend this curve with another curve to their soft minimum.
+    ///
+    /// The created curve generates `-ln(e^(-k*a) + e^(-k*b))/k` for the outputs `a` and `b` of both curves,
+    /// where `k` is the given `sharpness`. The bigger `sharpness` is, the closer the result gets to the
+    /// hard minimum of both curves. Smaller values round the corner where both curves cross.
+    /// The result never exceeds the hard minimum. A negative `sharpness` results in the soft maximum instead.
+    /// As the soft minimum diverges for a `sharpness` approaching zero, a `sharpness` of zero
+    /// instead results in the average of both outputs.
+    /// The domain of the created curve is the intersection of both domains.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// let rising = FuncEase::new(|x: f64| x);
+    /// let falling = FuncEase::new(|x: f64| 1.0 - x);
+    /// let union = rising.soft_min(falling, 100.0);
+    /// assert!((union.gen(0.2) - 0.2).abs() < 1e-6);
+    /// assert!(union.gen(0.5) < 0.5);
+    /// ```
+    fn soft_min<C>(self, other: C, sharpness: Self::Output) -> SoftMin<Self, C, Self::Output>
+    where
+        Self: Sized,
+        Self::Output: Real,
+        C: Curve<R, Output = Self::Output>,
+    {
+        SoftMin::new(self, other, sharpness)
+    }
+    /// Use this curve as selector to switch between the given curves.
+    ///
+    /// At each input, the output of this curve is rounded to the nearest integer and used as index
+    /// into `curves`. The selected curve is then evaluated at the same input.
+    /// Indices which are out of bounds are clamped to the first or last curve.
+    /// The domain of the created curve is the domain of the selector.
+    ///
+    /// This allows switching between curves depending on some discrete state, like the state of a
+    /// state machine over time. A step function, like a linear interpolation with
+    /// a [`Dwell`] easing, is a natural selector.
+    ///
+    /// # Panics
+    ///
+    /// Generating values panics if `curves` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// let state = FuncEase::new(|x: f64| if x < 0.5 { 0.0 } else { 1.0 });
+    /// let curves: [FuncEase<fn(f64) -> f64>; 2] = [FuncEase::new(|x| x), FuncEase::new(|x| 1.0 - x)];
+    /// let selected = state.select(curves);
+    /// assert_f64_near!(selected.gen(0.25), 0.25);
+    /// assert_f64_near!(selected.gen(0.75), 0.25);
+    /// ```
+    ///
+    /// [`Dwell`]: crate::easing::Dwell
+    fn select<C>(self, curves: C) -> Select<Self, C>
+    where
+        Self: Sized,
+        Self::Output: Real,
+        C: DiscreteGenerator,
+        C::Output: Generator<R>,
+    {
+        Select::new(self, curves)
+    }
+    /// Numerically integrate the curve over its domain.
+    ///
+    /// The integral is approximated by the trapezoidal rule with the given number of equidistant samples.
+    /// The result is a linear interpolation of the accumulated values over the same domain,
+    /// starting with zero at the start of the domain.
+    /// More samples result in a more accurate approximation.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let rate = Linear::builder()
+    ///                 .elements([1.0,3.0])
+    ///                 .knots([0.0,2.0])
+    ///                 .build()?;
+    /// let total = rate.running_integral(5);
+    /// assert_f64_near!(total.gen(1.0), 1.5);
+    /// assert_f64_near!(total.gen(2.0), 4.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given size of samples is less than 2 or if it can not be converted to the type `R`.
+    #[cfg(all(feature = "alloc", feature = "linear"))]
+    fn running_integral(self, samples: usize) -> Linear<Equidistant<R>, Vec<Self::Output>, Identity>
+    where
+        Self: Sized,
+        Self::Output: Add<Output = Self::Output> + Mul<R, Output = Self::Output> + Copy,
+        R: FromPrimitive,
+    {
+        assert!(samples >= 2, "at least two samples are needed to integrate");
+        let [start, end] = self.domain();
+        Linear::new_unchecked(
+            trapezoidal(self, samples),
+            Equidistant::new(samples, start, end),
+            Identity::new(),
+        )
+    }
+    /// Numerically integrate the curve over its domain and estimate the error of the approximation.
+    ///
+    /// The integral is calculated like in [`running_integral()`]. Additionally, the total integral
+    /// is also calculated with only every second sample. As the error of the trapezoidal rule
+    /// shrinks quadratically with the step size for smooth curves, Richardson extrapolation gives
+    /// an estimate of the error of the total integral, which is returned as second value.
+    /// The estimate is signed, that is, adding it to the total integral gives a better approximation.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "bezier", doc = "```rust")]
+    #[cfg_attr(not(feature = "bezier"), doc = "```ignore")]
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let rate = Bezier::builder()
+    ///                 .elements([0.0,4.0,1.0])
+    ///                 .normalized::<f64>()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// let (total, error) = rate.running_integral_with_error(11);
+    /// // the exact integral is 5/3
+    /// assert!((total.gen(1.0) - 5.0 / 3.0).abs() <= 1.1 * error.abs());
+    /// assert_f64_near!(total.gen(1.0) + error, 5.0 / 3.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given size of samples is not odd and at least 3 or if it can not be converted to the type `R`.
+    ///
+    /// [`running_integral()`]: Curve::running_integral()
+    #[cfg(all(feature = "alloc", feature = "linear"))]
+    #[allow(clippy::type_complexity)]
+    fn running_integral_with_error(
+        self,
+        samples: usize,
+    ) -> (
+        Linear<Equidistant<R>, Vec<Self::Output>, Identity>,
+        Self::Output,
+    )
+    where
+        Self: Sized,
+        Self::Output: Add<Output = Self::Output> + Mul<R, Output = Self::Output> + Copy,
+        R: FromPrimitive,
+    {
+        assert!(
+            samples >= 3 && samples % 2 == 1,
+            "an odd number of at least three samples is needed to estimate the error"
+        );
+        let [start, end] = self.domain();
+        let fine = trapezoidal(&self, samples);
+        let coarse = trapezoidal(&self, samples / 2 + 1);
+        let third = R::from_usize(3).unwrap().recip();
+        let error = (fine[samples - 1] + coarse[coarse.len() - 1] * -R::one()) * third;
+        let integral =
+            Linear::new_unchecked(fine, Equidistant::new(samples, start, end), Identity::new());
+        (integral, error)
+    }
+    /// Generate the values at all given inputs and write them into `out`.
+    ///
+    /// This is the same as calling [`gen()`] for each input, however curves which need
+    /// a workspace to generate their values may reuse it for all inputs.
+    /// For example, a [`BSpline`] with a [`DynSpace`] allocates its workspace only once,
+    /// instead of once for each input, which makes a difference when sampling many inputs per frame.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let mut buffer = [0.0; 3];
+    /// linear.gen_batch(&[0.5,1.0,1.5], &mut buffer);
+    /// let results = [2.5,5.0,4.0];
+    /// for (value,result) in buffer.iter().zip(results){
+    ///     assert_f64_near!(*value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalars` and `out` differ in length.
+    ///
+    /// [`gen()`]: Generator::gen()
+    /// [`BSpline`]: crate::bspline::BSpline
+    /// [`DynSpace`]: crate::DynSpace
+    fn gen_batch(&self, scalars: &[R], out: &mut [Self::Output]) {
+        assert_eq!(
+            scalars.len(),
+            out.len(),
+            "the number of inputs and outputs have to be equal"
+        );
+        for (scalar, slot) in scalars.iter().zip(out.iter_mut()) {
+            *slot = self.gen(*scalar);
+        }
+    }
+    /// Fill the given slice with equidistant samples of the curve in parallel.
+    ///
+    /// The samples are the same as the ones given by [`take()`] with as many samples as the slice is long.
+    /// If the slice contains only one element, the start of the domain is sampled.
+    /// This allows one to render into an existing buffer without allocating.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let mut buffer = [0.0; 5];
+    /// linear.par_sample_into(&mut buffer);
+    /// let results = [0.0,2.5,5.0,4.0,3.0];
+    /// for (value,result) in buffer.iter().zip(results){
+    ///     assert_f64_near!(*value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the slice minus one can not be converted to the type `R`.
+    ///
+    /// [`take()`]: Curve::take()
+    #[cfg(feature = "rayon")]
+    fn par_sample_into(&self, out: &mut [Self::Output])
+    where
+        Self: Sync,
+        Self::Output: Send,
+        R: FromPrimitive + Send + Sync,
+    {
+        let [start, end] = self.domain();
+        if out.len() == 1 {
+            out[0] = self.gen(start);
+            return;
+        }
+        let inputs = Linspace::new(out.len(), start, end);
+        out.par_iter_mut()
+            .enumerate()
+            .for_each(|(i, slot)| *slot = self.gen(inputs.gen(i)));
+    }
+    /// Bake the curve into a lookup table of `samples` equidistant samples.
+    ///
+    /// The returned [`Lut`] interpolates linearly between the stored samples, such that generating
+    /// a value is cheap no matter how expensive the original curve is to evaluate.
+    /// The more samples are taken, the better the approximation, at the cost of memory.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "bezier", doc = "```rust")]
+    #[cfg_attr(not(feature = "bezier"), doc = "```ignore")]
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///                 .elements([0.0,5.0,1.0])
+    ///                 .normalized::<f64>()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// let lut = bezier.bake_lut(1024);
+    /// assert!((lut.gen(0.3) - bezier.gen(0.3)).abs() < 1e-5);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is 0 or if it can not be converted to the type `R`.
+    #[cfg(feature = "alloc")]
+    fn bake_lut(&self, samples: usize) -> Lut<R, Self::Output>
+    where
+        R: FromPrimitive,
+    {
+        let [start, end] = self.domain();
+        Lut::new(self.take(samples).collect(), start, end)
+    }
+    /// Calculate the parameters at which the given fractions of the arc length of the curve are reached.
+    ///
+    /// The arc length is approximated by the length of the polygon going through the given number of
+    /// equidistant samples of the curve. The closure `norm` has to return the distance between two
+    /// outputs of the curve, that is, the norm of their difference.
+    /// The quantiles given are fractions of the total arc length and are clamped to `[0.0,1.0]`.
+    /// For example, the quantile `0.5` returns the parameter at which half of the curve was transversed.
+    ///
+    /// This is useful if one wants to place objects evenly along a curve.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// // the curve moves slower in its first half
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,1.0,4.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let params = linear.arc_length_quantiles(&[0.25,0.5,1.0], 101, |a: f64, b: f64| (a - b).abs());
+    /// assert_f64_near!(params[0], 1.0);
+    /// assert_f64_near!(params[1], 4.0/3.0);
+    /// assert_f64_near!(params[2], 2.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given size of samples is less than 2 or if it can not be converted to the type `R`.
+    #[cfg(feature = "alloc")]
+    fn arc_length_quantiles<F>(&self, quantiles: &[R], samples: usize, norm: F) -> Vec<R>
+    where
+        Self: Sized,
+        Self::Output: Copy,
+        F: Fn(Self::Output, Self::Output) -> R,
+        R: FromPrimitive,
+    {
+        let [start, end] = self.domain();
+        let lengths = cumulative_lengths(self, samples, norm);
+        let length = lengths[samples - 1];
+        let step = (end - start) / R::from_usize(samples - 1).unwrap();
+        quantiles
+            .iter()
+            .map(|&quantile| {
+                let target = quantile.max(R::zero()).min(R::one()) * length;
+                // first sample which reaches the target
+                let index = lengths
+                    .partition_point(|&len| len < target)
+                    .clamp(1, samples - 1);
+                let section = lengths[index] - lengths[index - 1];
+                let factor = if section > R::zero() {
+                    (target - lengths[index - 1]) / section
+                } else {
+                    R::zero()
+                };
+                start + (R::from_usize(index - 1).unwrap() + factor) * step
+            })
+            .collect()
+    }
+    /// Returns the curve mapping each input to the arc length of the curve up to this input.
+    ///
+    /// The arc length is approximated by the length of the polygon going through the given number of
+    /// equidistant samples of the curve, in the same way as [`arc_length_quantiles()`] does.
+    /// The closure `norm` has to return the distance between two outputs of the curve, that is,
+    /// the norm of their difference.
+    /// The returned curve has the same domain as this curve, starts with `0.0`, ends with the total arc length
+    /// and interpolates linearly between the samples. As such, it is monotonically increasing.
+    ///
+    /// This is useful to plot or query the distance travelled along a curve.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// // the curve moves slower in its first half
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,1.0,4.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let arc_length = linear.arc_length_curve(101, |a: f64, b: f64| (a - b).abs());
+    /// assert_eq!(arc_length.domain(), [0.0,2.0]);
+    /// assert_f64_near!(arc_length.gen(1.0), 1.0);
+    /// assert_f64_near!(arc_length.gen(1.5), 2.5);
+    /// assert_f64_near!(arc_length.gen(2.0), 4.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given size of samples is less than 2 or if it can not be converted to the type `R`.
+    ///
+    /// [`arc_length_quantiles()`]: Curve::arc_length_quantiles()
+    #[cfg(feature = "alloc")]
+    fn arc_length_curve<F>(&self, samples: usize, norm: F) -> Lut<R, R>
+    where
+        Self: Sized,
+        Self::Output: Copy,
+        F: Fn(Self::Output, Self::Output) -> R,
+        R: FromPrimitive,
+    {
+        let [start, end] = self.domain();
+        Lut::new(cumulative_lengths(self, samples, norm), start, end)
+    }
+    /// Returns the midpoints of all knot spans inside the domain of the curve.
+    ///
+    /// Only spans between successive distinct knots are considered, such that all midpoints lie
+    /// strictly between two knots and strictly inside of the domain.
+    /// The midpoints are in increasing order and, unlike knots, never coincide with a point where
+    /// the curve switches its segment. As such, they are stable evaluation points for tests and
+    /// a natural choice for placing sample markers.
+    ///
+    /// Curves without knots, like bezier curves, return the midpoint of their domain.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Curve};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,1.0,4.0,2.0])
+    ///                 .knots([0.0,1.0,3.0,4.0])
+    ///                 .build()?;
+    /// assert_eq!(linear.knot_midpoints(), vec![0.5,2.0,3.5]);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        let [start, end] = self.domain();
+        vec![(start + end) / (R::one() + R::one())]
+    }
+    /// Find the input for which the output of the curve is nearest to `target`.
+    ///
+    /// The distance between outputs is given by their [`QuasiMetric`] implementation.
+    /// The curve is sampled coarsely at equidistant inputs, after which the input of the nearest sample
+    /// is refined with Newton's method, minimizing the squared distance. The derivatives needed are
+    /// approximated with central differences, such that the curve does not have to provide them itself.
+    ///
+    /// The returned input is always within the domain. If several outputs are equally near,
+    /// the smallest input is preferred.
+    /// As only coarse samples are used as starting point, features of the curve smaller than the distance
+    /// between the samples may be missed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{Generator, Curve, QuasiMetric};
+    /// # use assert_float_eq::assert_float_absolute_eq;
+    /// #[derive(Debug, Copy, Clone)]
+    /// struct Point(f64, f64);
+    /// impl QuasiMetric<f64> for Point {
+    ///     fn distance(self, to: Self) -> f64 {
+    ///         (self.0 - to.0).hypot(self.1 - to.1)
+    ///     }
+    /// }
+    /// // the parabola y = x^2 for x in [-1, 1]
+    /// struct Parabola;
+    /// impl Generator<f64> for Parabola {
+    ///     type Output = Point;
+    ///     fn gen(&self, t: f64) -> Point {
+    ///         let x = 2.0 * t - 1.0;
+    ///         Point(x, x * x)
+    ///     }
+    /// }
+    /// impl Curve<f64> for Parabola {
+    ///     fn domain(&self) -> [f64; 2] {
+    ///         [0.0, 1.0]
+    ///     }
+    /// }
+    /// assert_float_absolute_eq!(Parabola.project(&Point(0.0, -1.0)), 0.5, 1e-9);
+    /// // both ends are equally near, the smaller input is chosen
+    /// assert_eq!(Parabola.project(&Point(0.0, 5.0)), 0.0);
+    /// ```
+    ///
+    /// [`QuasiMetric`]: crate::QuasiMetric
+    fn project(&self, target: &Self::Output) -> R
+    where
+        Self::Output: QuasiMetric<R> + Clone,
+        R: FromPrimitive,
+    {
+        const SEGMENTS: usize = 64;
+        const ITERATIONS: usize = 16;
+        let squared_distance = |input: R| {
+            let distance = self.gen(input).distance(target.clone());
+            distance * distance
+        };
+        let [start, end] = self.domain();
+        let step = (end - start) / R::from_usize(SEGMENTS).unwrap();
+        // coarse search, keeping the first of equally near samples
+        let (mut input, mut distance) = (start, squared_distance(start));
+        for sample in Stepper::new(SEGMENTS + 1, start, end).skip(1) {
+            let sample_distance = squared_distance(sample);
+            if sample_distance < distance {
+                input = sample;
+                distance = sample_distance;
+            }
+        }
+        // the minimum lies between the neighbours of the nearest sample
+        let low = (input - step).max(start);
+        let high = (input + step).min(end);
+        let h = step * R::epsilon().cbrt();
+        let two = R::one() + R::one();
+        for _ in 0..ITERATIONS {
+            let before = squared_distance(input - h);
+            let after = squared_distance(input + h);
+            let first = (after - before) / (two * h);
+            let second = (after - two * distance + before) / (h * h);
+            if second <= R::zero() {
+                break;
+            }
+            let next = (input - first / second).max(low).min(high);
+            let next_distance = squared_distance(next);
+            if next == input || next_distance.partial_cmp(&distance) != Some(Ordering::Less) {
+                break;
+            }
+            input = next;
+            distance = next_distance;
+        }
+        input
+    }
+    /// Sample the curve with increasing density until the polyline through the samples converges.
+    ///
+    /// Starting with three equidistant samples, the number of segments is doubled by sampling the middle
+    /// of each segment. Refinement stops as soon as none of these new samples is further away than `tolerance`
+    /// from the middle of the segment it was put in. The closure `norm` has to return the distance
+    /// between two outputs of the curve, that is, the norm of their difference.
+    ///
+    /// Returns the samples, which are equidistant over the domain, together with their number.
+    /// If doubling the density would result in more than `max_samples` samples, the current samples
+    /// are returned, even if they did not converge yet.
+    ///
+    /// As only the middles of the segments are checked, features of the curve smaller than
+    /// the distance between the initial samples may be missed.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,2.0,1.0,4.0,0.0])
+    ///                 .knots([0.0,1.0,2.0,3.0,4.0])
+    ///                 .build()?;
+    /// let (samples, density) = linear.sample_until_converged(1e-9, 1000, |a: f64, b: f64| (a - b).abs());
+    /// // all corners of the curve are sampled
+    /// assert_eq!(density, 9);
+    /// assert_eq!(samples.len(), density);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_samples` is less than 3 or if it can not be converted to the type `R`.
+    #[cfg(feature = "alloc")]
+    fn sample_until_converged<F>(
+        &self,
+        tolerance: R,
+        max_samples: usize,
+        norm: F,
+    ) -> (Vec<Self::Output>, usize)
+    where
+        Self: Sized,
+        Self::Output: Merge<R> + Copy,
+        F: Fn(Self::Output, Self::Output) -> R,
+        R: FromPrimitive,
+    {
+        assert!(
+            max_samples >= 3,
+            "at least three samples are needed to check for convergence"
+        );
+        let [start, end] = self.domain();
+        let half = R::from_usize(2).unwrap().recip();
+        let mut samples: Vec<Self::Output> = self.take(3).collect();
+        while 2 * samples.len() - 1 <= max_samples {
+            let segments = samples.len() - 1;
+            let step = (end - start) / R::from_usize(segments).unwrap();
+            let mut refined = Vec::with_capacity(2 * segments + 1);
+            let mut deviation = R::zero();
+            for i in 0..segments {
+                let middle = self.gen(start + step * (R::from_usize(i).unwrap() + half));
+                let chord = samples[i].merge(samples[i + 1], half);
+                deviation = deviation.max(norm(middle, chord));
+                refined.push(samples[i]);
+                refined.push(middle);
+            }
+            refined.push(samples[segments]);
+            samples = refined;
+            if deviation <= tolerance {
+                break;
+            }
+        }
+        let len = samples.len();
+        (samples, len)
+    }
+    /// Create SVG path data of the curve, as used in the `d` attribute of a `path` element.
+    ///
+    /// The curve is sampled at the given number of equidistant points, which are connected by straight lines.
+    /// The closure `accessor` maps an output of the curve to its `x` and `y` coordinate.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,2.0,1.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let path = linear.to_svg_path(3, |y: f64| [0.0, y]);
+    /// assert_eq!(path, "M0 0 L0 2 L0 1");
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given size of samples is 0 or if it can not be converted to the type `R`.
+    #[cfg(feature = "alloc")]
+    fn to_svg_path<F, T>(&self, samples: usize, accessor: F) -> String
+    where
+        Self: Sized,
+        F: Fn(Self::Output) -> [T; 2],
+        T: Display,
+        R: FromPrimitive,
+    {
+        let mut path = String::new();
+        for (i, point) in self.take(samples).enumerate() {
+            let [x, y] = accessor(point);
+            let command = if i == 0 { "M" } else { " L" };
+            // writing into a string does not fail
+            write!(path, "{}{} {}", command, x, y).unwrap();
+        }
+        path
+    }
+}
+
+/// Accumulate the integral of the curve with the trapezoidal rule over equidistant samples.
+#[cfg(all(feature = "alloc", feature = "linear"))]
+fn trapezoidal<C, R>(curve: C, samples: usize) -> Vec<C::Output>
+where
+    C: Curve<R>,
+    C::Output: Add<Output = C::Output> + Mul<R, Output = C::Output> + Copy,
+    R: Real + FromPrimitive,
+{
+    let [start, end] = curve.domain();
+    let half_step = (end - start) / R::from_usize(2 * (samples - 1)).unwrap();
+    let mut values = curve.take(samples);
+    let mut last = values.next().unwrap();
+    let mut sum = last * R::zero();
+    let mut elements = Vec::with_capacity(samples);
+    elements.push(sum);
+    for value in values {
+        sum = sum + (last + value) * half_step;
+        elements.push(sum);
+        last = value;
+    }
+    elements
+}
+
+/// Specialized [`Generator`] which takes two real numbers `(u, v)` as input.
+///
+/// This is the two-dimensional analogue of [`Curve`].
+pub trait Surface<R>: Generator<(R, R)>
+where
+    R: Real,
+{
+    /// The domain in which the surface uses interpolation.
+    ///
+    /// The first entry contains the bounds for `u`, the second entry the bounds for `v`.
+    /// Not all Surfaces may extrapolate in a safe way.
+    fn domain(&self) -> [[R; 2]; 2];
+}
+
+//Make references of curves also curves
+impl<C: Curve<R> + ?Sized, R> Curve<R> for &C
+where
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        (**self).domain()
+    }
+    fn gen_batch(&self, scalars: &[R], out: &mut [Self::Output]) {
+        (**self).gen_batch(scalars, out)
+    }
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        (**self).knot_midpoints()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Curve<R> + ?Sized, R> Curve<R> for Box<C>
+where
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        (**self).domain()
+    }
+    fn gen_batch(&self, scalars: &[R], out: &mut [Self::Output]) {
+        (**self).gen_batch(scalars, out)
+    }
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        (**self).knot_midpoints()
+    }
+}
+
+/// Specialized [`Curve`] which is able to calculate its derivative.
+///
+/// Linear, bezier and bspline curves calculate their derivative analytically.
+/// Any other curve can be wrapped with [`Curve::finite_difference()`] to approximate its derivative.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "bezier", doc = "```rust")]
+#[cfg_attr(not(feature = "bezier"), doc = "```ignore")]
+/// # use enterpolation::{bezier::Bezier, Derivative, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// let position = Bezier::builder()
+///     .elements([0.0, 1.0, 4.0])
+///     .normalized::<f64>()
+///     .constant::<3>()
+///     .build()
+///     .unwrap();
+/// assert_f64_near!(position.derivative(0.0), 2.0);
+/// assert_f64_near!(position.derivative(1.0), 6.0);
+/// ```
+pub trait Derivative<R>: Curve<R>
+where
+    R: Real,
+{
+    /// Calculate the derivative of the curve at the given input.
+    ///
+    /// Piecewise defined curves return the derivative of the piece after the input
+    /// if the input is exactly at the border of two pieces.
+    fn derivative(&self, scalar: R) -> Self::Output;
+
+    /// Calculate the curvature of a 3D curve at the given input.
+    ///
+    /// The curvature is the reciprocal of the radius of the circle which fits the curve best at the input.
+    /// It is calculated from the derivative of the curve, while the second derivative is approximated
+    /// by central differences of the derivative.
+    ///
+    /// Straight parts of the curve have a curvature of 0. If the derivative vanishes, the curvature is not
+    /// defined and NaN is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{Curve, Derivative, Generator};
+    /// # use assert_float_eq::assert_float_absolute_eq;
+    /// #
+    /// // a circle with radius 2 in the xy-plane
+    /// struct Circle;
+    /// impl Generator<f64> for Circle {
+    ///     type Output = [f64; 3];
+    ///     fn gen(&self, t: f64) -> [f64; 3] {
+    ///         [2.0 * t.cos(), 2.0 * t.sin(), 0.0]
+    ///     }
+    /// }
+    /// impl Curve<f64> for Circle {
+    ///     fn domain(&self) -> [f64; 2] {
+    ///         [0.0, 6.0]
+    ///     }
+    /// }
+    /// impl Derivative<f64> for Circle {
+    ///     fn derivative(&self, t: f64) -> [f64; 3] {
+    ///         [-2.0 * t.sin(), 2.0 * t.cos(), 0.0]
+    ///     }
+    /// }
+    /// assert_float_absolute_eq!(Circle.curvature(1.0), 0.5, 1e-6);
+    /// ```
+    fn curvature(&self, scalar: R) -> R
+    where
+        Self: Sized + Derivative<R, Output = [R; 3]>,
+    {
+        let (first, second) = crate::utils::analytic_derivatives(self, scalar);
+        crate::utils::curvature_of(first, second)
+    }
+
+    /// Calculate the Frenet frame of a 3D curve at the given input.
+    ///
+    /// Returns the tangent, normal and binormal of the curve, all normalized.
+    /// The frame is calculated from the derivative of the curve, while the second derivative is approximated
+    /// by central differences of the derivative.
+    ///
+    /// If the normal is not defined, as the curve is (locally) a straight line, the normal falls back to
+    /// the coordinate axis least aligned with the tangent, made orthogonal to the tangent.
+    /// To get a normal which stays consistent along straight parts of a curve, use [`frenet_frames()`].
+    ///
+    /// [`frenet_frames()`]: Derivative::frenet_frames()
+    fn frenet_frame(&self, scalar: R) -> ([R; 3], [R; 3], [R; 3])
+    where
+        Self: Sized + Derivative<R, Output = [R; 3]>,
+    {
+        let (first, second) = crate::utils::analytic_derivatives(self, scalar);
+        crate::utils::frame_of(first, second, self.domain(), None)
+    }
+
+    /// Calculate the Frenet frames of a 3D curve at `samples` equidistant inputs over its whole domain.
+    ///
+    /// Each frame is calculated as with [`frenet_frame()`]. However, if the normal is not defined,
+    /// as the curve is (locally) a straight line, the normal of the previous frame is transported
+    /// along the curve instead, such that the frames do not jump on straight parts of the curve.
+    ///
+    /// [`frenet_frame()`]: Derivative::frenet_frame()
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples - 1` can not be converted to the type `R`.
+    #[cfg(feature = "alloc")]
+    fn frenet_frames(&self, samples: usize) -> Vec<([R; 3], [R; 3], [R; 3])>
+    where
+        Self: Sized + Derivative<R, Output = [R; 3]>,
+        R: FromPrimitive,
     {
-        Clamp::new(self)
+        let [start, end] = self.domain();
+        let mut previous = None;
+        Stepper::new(samples, start, end)
+            .map(|input| {
+                let (first, second) = crate::utils::analytic_derivatives(self, input);
+                let frame = crate::utils::frame_of(first, second, [start, end], previous);
+                previous = Some(frame.1);
+                frame
+            })
+            .collect()
     }
 }
 
-//Make references of curves also curves
-impl<C: Curve<R> + ?Sized, R> Curve<R> for &C
+impl<D: Derivative<R> + ?Sized, R> Derivative<R> for &D
 where
     R: Real,
 {
-    fn domain(&self) -> [R; 2] {
+    fn derivative(&self, scalar: R) -> Self::Output {
+        (**self).derivative(scalar)
+    }
+}
+
+/// Trait for elements which can be bounded by an axis-aligned box.
+///
+/// This trait is used to calculate bounding boxes of curves, as with [`Curve::sample_bounds()`].
+/// It is implemented for floats and for arrays of bounded elements,
+/// for other points it has to be implemented componentwise.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::Bounded;
+/// #[derive(Debug, Copy, Clone, PartialEq)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+/// impl Bounded for Point {
+///     fn component_min(self, other: Self) -> Self {
+///         Point { x: self.x.min(other.x), y: self.y.min(other.y) }
+///     }
+///     fn component_max(self, other: Self) -> Self {
+///         Point { x: self.x.max(other.x), y: self.y.max(other.y) }
+///     }
+/// }
+/// let min = Point { x: 0.0, y: 2.0 }.component_min(Point { x: 1.0, y: -1.0 });
+/// assert_eq!(min, Point { x: 0.0, y: -1.0 });
+/// ```
+pub trait Bounded {
+    /// Returns the componentwise minimum of both elements.
+    fn component_min(self, other: Self) -> Self;
+    /// Returns the componentwise maximum of both elements.
+    fn component_max(self, other: Self) -> Self;
+}
+
+impl Bounded for f32 {
+    fn component_min(self, other: Self) -> Self {
+        self.min(other)
+    }
+    fn component_max(self, other: Self) -> Self {
+        self.max(other)
+    }
+}
+
+impl Bounded for f64 {
+    fn component_min(self, other: Self) -> Self {
+        self.min(other)
+    }
+    fn component_max(self, other: Self) -> Self {
+        self.max(other)
+    }
+}
+
+impl<T, const N: usize> Bounded for [T; N]
+where
+    T: Bounded + Copy,
+{
+    fn component_min(self, other: Self) -> Self {
+        core::array::from_fn(|i| self[i].component_min(other[i]))
+    }
+    fn component_max(self, other: Self) -> Self {
+        core::array::from_fn(|i| self[i].component_max(other[i]))
+    }
+}
+
+/// The real and imaginary part are bounded independently, such that the bounds describe
+/// a rectangle in the complex plane.
+#[cfg(feature = "num-complex")]
+impl<T> Bounded for num_complex::Complex<T>
+where
+    T: Bounded,
+{
+    fn component_min(self, other: Self) -> Self {
+        num_complex::Complex::new(
+            self.re.component_min(other.re),
+            self.im.component_min(other.im),
+        )
+    }
+    fn component_max(self, other: Self) -> Self {
+        num_complex::Complex::new(
+            self.re.component_max(other.re),
+            self.im.component_max(other.im),
+        )
+    }
+}
+
+/// Returns the accumulated length of the polygon through the given number of equidistant samples of the curve
+/// at each sample.
+///
+/// # Panics
+///
+/// Panics if given size of samples is less than 2 or if it can not be converted to the type `R`.
+#[cfg(feature = "alloc")]
+fn cumulative_lengths<C, F, R>(curve: &C, samples: usize, norm: F) -> Vec<R>
+where
+    C: Curve<R> + ?Sized,
+    C::Output: Copy,
+    F: Fn(C::Output, C::Output) -> R,
+    R: Real + FromPrimitive,
+{
+    assert!(
+        samples >= 2,
+        "at least two samples are needed to measure the arc length"
+    );
+    let mut lengths = Vec::with_capacity(samples);
+    let mut points = curve.take(samples);
+    let mut last = points.next().unwrap();
+    let mut length = R::zero();
+    lengths.push(length);
+    for point in points {
+        length = length + norm(last, point);
+        lengths.push(length);
+        last = point;
+    }
+    lengths
+}
+
+/// Returns the componentwise minimum and maximum of all given values or `None` if there are none.
+pub(crate) fn bounds<T, I>(mut values: I) -> Option<[T; 2]>
+where
+    I: Iterator<Item = T>,
+    T: Bounded + Clone,
+{
+    let first = values.next()?;
+    Some(values.fold([first.clone(), first], |[min, max], value| {
+        [min.component_min(value.clone()), max.component_max(value)]
+    }))
+}
+
+//Make references of surfaces also surfaces
+impl<S: Surface<R> + ?Sized, R> Surface<R> for &S
+where
+    R: Real,
+{
+    fn domain(&self) -> [[R; 2]; 2] {
         (**self).domain()
     }
 }
@@ -348,6 +1956,15 @@ pub trait DiscreteGenerator: Generator<usize> {
     {
         Repeat::new(self)
     }
+    /// Transform generator to one which also generates elements for indices outside of it.
+    ///
+    /// See [`BoundaryMode`] for how these elements are synthesized.
+    fn boundary(self, mode: BoundaryMode) -> Boundary<Self>
+    where
+        Self: Sized,
+    {
+        Boundary::new(self, mode)
+    }
 }
 
 // Make references of DiscreteGenerator also DiscreteGenerator
@@ -357,6 +1974,13 @@ impl<G: DiscreteGenerator + ?Sized> DiscreteGenerator for &G {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<G: DiscreteGenerator + ?Sized> DiscreteGenerator for Box<G> {
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
 /// Trait for [`DiscreteGenerator`] where its length is knwon at compile-time.
 ///
 /// [`DiscreteGenerator`]: DiscreteGenerator
@@ -403,6 +2027,23 @@ where
             gen,
         }
     }
+    /// Create an iterator which skips the first `offset` elements.
+    pub(crate) fn with_offset(gen: G, offset: usize) -> Self {
+        let back = gen.len();
+        IntoIter {
+            front: offset.min(back),
+            back,
+            gen,
+        }
+    }
+    /// Returns the index of the element which is generated next.
+    pub(crate) fn front(&self) -> usize {
+        self.front
+    }
+    /// Returns the length of the underlying generator.
+    pub(crate) fn total(&self) -> usize {
+        self.gen.len()
+    }
 }
 
 impl<G> Iterator for IntoIter<G>
@@ -444,9 +2085,8 @@ where
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.front < self.back {
-            let res = self.gen.gen(self.back);
             self.back -= 1;
-            return Some(res);
+            return Some(self.gen.gen(self.back));
         }
         None
     }
@@ -578,11 +2218,17 @@ where
 /// Stepper is an iterator which increments its number.
 ///
 /// Stepper can be seen as a [`Range`] with variable step size.
+/// Instead of equal steps, a stepper may also walk through the values of any [`DiscreteGenerator`],
+/// see [`from_generator()`].
 ///
 /// [`Range`]: core::ops::Range
+/// [`from_generator()`]: Stepper::from_generator()
 #[derive(Debug, Clone)] // Iterators shouldn't be Copy -- see #27186
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-pub struct Stepper<R: Real = f64>(IntoIter<Equidistant<R>>);
+pub struct Stepper<R: Real = f64, G = Linspace<R>>(IntoIter<G>, PhantomData<R>);
+
+/// Stepper which steps geometrically, created by [`Stepper::logarithmic()`].
+pub type LogStepper<R = f64> = Stepper<R, Geometric<R>>;
 
 impl<R> Stepper<R>
 where
@@ -595,23 +2241,125 @@ where
     ///
     /// Panics if the given steps are 0 and if `steps -1` can not be transformed into R.
     pub fn normalized(steps: usize) -> Self {
-        Stepper(Equidistant::normalized(steps).into_iter())
+        Stepper::from_generator(Linspace::new(steps, R::zero(), R::one()))
     }
 
     /// Creates a new Stepper stepping from `start` to `end`
     /// Also the given steps are not allowed to be less than 1
     ///
+    /// The given steps are the number of values yielded, which include both `start` and `end` exactly.
+    /// Every value is calculated directly from its index by linear interpolation between `start` and `end`,
+    /// such that no rounding errors accumulate.
+    ///
     /// #Panics
     ///
     /// Panics if the given steps are 0 and if `steps -1` can not be transformed into R.
     pub fn new(steps: usize, start: R, end: R) -> Self {
-        Stepper(Equidistant::new(steps, start, end).into_iter())
+        Stepper::from_generator(Linspace::new(steps, start, end))
+    }
+
+    /// Creates a new Stepper stepping from `start` to `end`, beginning at the step with the given `index`.
+    ///
+    /// The stepper yields the same values as a stepper created with [`new()`] after it yielded `index` values.
+    /// This allows one to resume sampling, for example for progressive rendering.
+    /// If `index` is bigger than the number of steps, the stepper is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::Stepper;
+    /// let fresh = Stepper::new(5, 0.0, 4.0);
+    /// let resumed = Stepper::with_offset(5, 0.0, 4.0, 2);
+    /// assert_eq!(resumed.index(), 2);
+    /// assert!(fresh.skip(2).eq(resumed));
+    /// ```
+    ///
+    /// #Panics
+    ///
+    /// Panics if the given steps are 0 and if `steps -1` can not be transformed into R.
+    ///
+    /// [`new()`]: Stepper::new()
+    pub fn with_offset(steps: usize, start: R, end: R, index: usize) -> Self {
+        Stepper(
+            IntoIter::with_offset(Linspace::new(steps, start, end), index),
+            PhantomData,
+        )
     }
 }
 
-impl<R> Iterator for Stepper<R>
+impl<R> Stepper<R, Geometric<R>>
 where
     R: Real + FromPrimitive,
+{
+    /// Creates a new Stepper stepping geometrically from `start` to `end`.
+    ///
+    /// The steps are spaced evenly on a logarithmic scale, such that the ratio between
+    /// consecutive values is constant. See [`Geometric`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::Stepper;
+    /// let octaves: Vec<f64> = Stepper::logarithmic(4, 1.0, 8.0).unwrap().collect();
+    /// assert_eq!(octaves[0], 1.0);
+    /// assert_eq!(octaves[3], 8.0);
+    /// assert!((octaves[1] - 2.0).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotPositive`] if `start` or `end` is not positive.
+    ///
+    /// #Panics
+    ///
+    /// Panics if `steps - 1` can not be transformed into R.
+    pub fn logarithmic(steps: usize, start: R, end: R) -> Result<Self, NotPositive> {
+        Ok(Stepper::from_generator(Geometric::new(steps, start, end)?))
+    }
+}
+
+impl<R, G> Stepper<R, G>
+where
+    G: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+    /// Creates a new Stepper stepping through the values of the given generator.
+    ///
+    /// This allows for non-uniform steps, like the knots of a curve or
+    /// steps which are denser where the curve bends more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::{easing::Identity, Curve, Stepper};
+    /// let stepper = Stepper::from_generator([0.0, 0.5, 0.75, 0.875, 1.0]);
+    /// let pairs: Vec<_> = Identity::new().sample_pairs(stepper).collect();
+    /// assert_eq!(pairs[2], (0.75, 0.75));
+    /// ```
+    pub fn from_generator(gen: G) -> Self {
+        Stepper(IntoIter::new(gen), PhantomData)
+    }
+
+    /// Returns the index of the step which is yielded next.
+    ///
+    /// Together with [`steps()`] and [`with_offset()`] this allows to resume a stepper.
+    ///
+    /// [`steps()`]: Stepper::steps()
+    /// [`with_offset()`]: Stepper::with_offset()
+    pub fn index(&self) -> usize {
+        self.0.front()
+    }
+
+    /// Returns the total number of steps, including the ones already yielded.
+    pub fn steps(&self) -> usize {
+        self.0.total()
+    }
+}
+
+impl<R, G> Iterator for Stepper<R, G>
+where
+    G: DiscreteGenerator<Output = R>,
+    R: Real,
 {
     type Item = R;
     fn next(&mut self) -> Option<Self::Item> {
@@ -628,13 +2376,24 @@ where
     }
 }
 
-impl<R> FusedIterator for Stepper<R> where R: Real + FromPrimitive {}
+impl<R, G> FusedIterator for Stepper<R, G>
+where
+    G: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+}
 
-impl<R> ExactSizeIterator for Stepper<R> where R: Real + FromPrimitive {}
+impl<R, G> ExactSizeIterator for Stepper<R, G>
+where
+    G: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+}
 
-impl<R> DoubleEndedIterator for Stepper<R>
+impl<R, G> DoubleEndedIterator for Stepper<R, G>
 where
-    R: Real + FromPrimitive,
+    G: DiscreteGenerator<Output = R>,
+    R: Real,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.0.next_back()
@@ -644,24 +2403,675 @@ where
     }
 }
 
+/// Iterator which generates the outputs of a curve together with their inputs.
+///
+/// This struct is created by [`Curve::sample_pairs()`]. See its documentation for more.
+#[derive(Debug, Clone)] // Iterators shouldn't be Copy -- see #27186
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SamplePairs<C, I, R> {
+    curve: C,
+    iterator: I,
+    domain: [R; 2],
+}
+
+impl<C, I, R> Iterator for SamplePairs<C, I, R>
+where
+    C: Curve<R>,
+    I: Iterator<Item = R>,
+    R: Real,
+{
+    type Item = (R, C::Output);
+    fn next(&mut self) -> Option<Self::Item> {
+        let [start, end] = self.domain;
+        let input = self
+            .iterator
+            .by_ref()
+            .find(|input| start <= *input && *input <= end)?;
+        Some((input, self.curve.gen(input)))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iterator.size_hint().1)
+    }
+}
+
+impl<C, I, R> FusedIterator for SamplePairs<C, I, R>
+where
+    C: Curve<R>,
+    I: FusedIterator<Item = R>,
+    R: Real,
+{
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn is_monotone() {
+        use crate::easing::FuncEase;
+        let increasing = FuncEase::new(|x: f64| x * x * x);
+        assert_eq!(increasing.is_monotone(50), Some(Ordering::Less));
+        assert_eq!(increasing.is_monotone(1), None);
+        let decreasing = FuncEase::new(|x: f64| -x.sqrt());
+        assert_eq!(decreasing.is_monotone(50), Some(Ordering::Greater));
+        // a small dip in the middle of the domain
+        let wiggle = FuncEase::new(|x: f64| x - 0.1 * (x * core::f64::consts::PI * 8.0).sin());
+        assert_eq!(wiggle.is_monotone(3), Some(Ordering::Less));
+        assert_eq!(wiggle.is_monotone(100), None);
+        let constant = FuncEase::new(|_: f64| 1.0);
+        assert_eq!(constant.is_monotone(10), None);
+        let nan = FuncEase::new(|x: f64| if x > 0.5 { f64::NAN } else { x });
+        assert_eq!(nan.is_monotone(10), None);
+    }
+
+    #[test]
+    fn sample_bounds() {
+        struct Path;
+        impl Generator<f64> for Path {
+            type Output = [f64; 2];
+            fn gen(&self, t: f64) -> [f64; 2] {
+                [t * t, 1.0 - t * 2.0]
+            }
+        }
+        impl Curve<f64> for Path {
+            fn domain(&self) -> [f64; 2] {
+                [0.0, 1.0]
+            }
+        }
+        assert_eq!(
+            bounds(
+                [[1.0, 2.0], [-1.0, 3.0], [0.5, -2.0]]
+                    .as_slice()
+                    .iter()
+                    .copied()
+            ),
+            Some([[-1.0, -2.0], [1.0, 3.0]])
+        );
+        assert_eq!(bounds(core::iter::empty::<f64>()), None);
+        let [min, max] = Path.sample_bounds(11);
+        assert_f64_near!(min[0], 0.0);
+        assert_f64_near!(max[0], 1.0);
+        assert_f64_near!(min[1], -1.0);
+        assert_f64_near!(max[1], 1.0);
+    }
+
     #[test]
     fn stepper() {
         let mut stepper = Stepper::normalized(11);
         let res = [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0];
-        for i in 0..=10 {
+        for expect in res {
             let val = stepper.next().unwrap();
-            assert_f64_near!(val, res[i]);
+            assert_f64_near!(val, expect);
         }
 
         let mut stepper = Stepper::new(5, 3.0, 5.0);
         let res = [3.0, 3.5, 4.0, 4.5, 5.0];
-        for i in 0..5 {
+        for expect in res {
             let val = stepper.next().unwrap();
-            assert_f64_near!(val, res[i]);
+            assert_f64_near!(val, expect);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "linear")]
+    fn stepper_endpoints() {
+        use crate::linear::Linear;
+        let linear = Linear::builder()
+            .elements([2.0, -1.0, 5.0])
+            .equidistant::<f64>()
+            .domain(0.1, 0.7)
+            .build()
+            .unwrap();
+        let [start, end] = linear.domain();
+        // for some of these, calculating the last step from the step size is off by rounding
+        for steps in 2..200 {
+            let mut stepper = Stepper::new(steps, start, end);
+            assert_eq!(stepper.len(), steps);
+            assert_eq!(stepper.next(), Some(start));
+            assert_eq!(stepper.next_back(), Some(end));
+            let last = linear.by_ref().take(steps).next_back().unwrap();
+            assert_eq!(last.to_bits(), linear.gen(end).to_bits());
+        }
+        assert_eq!(Stepper::<f64>::normalized(49).next_back(), Some(1.0));
+        assert!(Stepper::new(1, 0.5, 2.0).eq([0.5]));
+    }
+
+    #[test]
+    fn gen_with_cycle() {
+        use crate::easing::FuncEase;
+        use crate::TransformInput;
+        // domain [1.0, 3.0]
+        let sawtooth = TransformInput::new(FuncEase::new(|t: f64| t), -0.5, 0.5);
+        assert_eq!(sawtooth.domain(), [1.0, 3.0]);
+        let expected = [
+            (1.0, 0.0, 0),
+            (2.0, 0.5, 0),
+            (3.0, 0.0, 1),
+            (4.5, 0.75, 1),
+            (5.0, 0.0, 2),
+            (7.5, 0.25, 3),
+            (0.5, 0.75, -1),
+            (-1.0, 0.0, -1),
+            (-1.5, 0.75, -2),
+            (-6.0, 0.5, -4),
+        ];
+        for (input, output, cycle) in expected {
+            let (value, count) = sawtooth.gen_with_cycle(input);
+            assert_f64_near!(value, output);
+            assert_eq!(count, cycle, "at {}", input);
+        }
+        // the count increments once per period
+        let mut counts = Stepper::new(41, -4.0, 6.0).map(|input| sawtooth.gen_with_cycle(input).1);
+        let mut previous = counts.next().unwrap();
+        assert_eq!(previous, -3);
+        for count in counts {
+            assert!(count - previous <= 1);
+            previous = count;
+        }
+        assert_eq!(previous, 2);
+        // saturating count
+        assert_eq!(sawtooth.gen_with_cycle(1e300).1, i64::MAX);
+        assert_eq!(sawtooth.gen_with_cycle(-1e300).1, i64::MIN);
+    }
+
+    #[test]
+    fn non_uniform_stepper() {
+        let inputs = [0.0, 0.1, 0.15, 0.175, 0.5, 1.0];
+        let stepper = Stepper::from_generator(inputs);
+        assert_eq!(stepper.steps(), 6);
+        assert!(stepper.clone().eq(inputs));
+        assert!(stepper.rev().eq(inputs.as_slice().iter().rev().copied()));
+        // the stepper can be resumed like equidistant ones
+        let mut stepper = Stepper::from_generator(inputs);
+        stepper.nth(2);
+        assert_eq!(stepper.index(), 3);
+        assert_eq!(stepper.len(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn sample_pairs() {
+        use crate::easing::FuncEase;
+        let square = FuncEase::new(|x: f64| x * x);
+        let stepper = Stepper::from_generator([-0.5, 0.0, 0.25, 0.5, 1.0, 1.5]);
+        let pairs: Vec<_> = square.sample_pairs(stepper).collect();
+        assert_eq!(pairs, [(0.0, 0.0), (0.25, 0.0625), (0.5, 0.25), (1.0, 1.0)]);
+        let mut pairs = square.sample_pairs(Stepper::normalized(3));
+        assert_eq!(pairs.next(), Some((0.0, 0.0)));
+        assert_eq!(pairs.next(), Some((0.5, 0.25)));
+        assert_eq!(pairs.next(), Some((1.0, 1.0)));
+        assert_eq!(pairs.next(), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "linear", feature = "bezier"))]
+    fn running_integral() {
+        use crate::bezier::Bezier;
+        let rate = Bezier::builder()
+            .elements([0.0, 4.0, 1.0])
+            .normalized::<f64>()
+            .dynamic()
+            .build()
+            .unwrap();
+        let integral = rate.integrate();
+        let mut last_error = f64::INFINITY;
+        for samples in [3, 5, 9, 17, 33] {
+            let total = rate.running_integral(samples);
+            let error = Stepper::normalized(11)
+                .map(|input| (total.gen(input) - integral.gen(input)).abs())
+                .fold(0.0, f64::max);
+            assert!(error < last_error);
+            last_error = error;
+        }
+        assert!(last_error < 1e-2);
+        let total = rate.running_integral(1001);
+        assert_float_absolute_eq!(total.gen(1.0), integral.gen(1.0), 1e-5);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "linear", feature = "bezier"))]
+    fn running_integral_error() {
+        use crate::bezier::Bezier;
+        let rate = Bezier::builder()
+            .elements([1.0, -3.0, 4.0, 2.0])
+            .normalized::<f64>()
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let exact = rate.integrate().gen(1.0);
+        let mut last_error = f64::INFINITY;
+        for samples in [3, 5, 9, 17, 33] {
+            let (total, error) = rate.running_integral_with_error(samples);
+            assert!(error.abs() < last_error);
+            last_error = error.abs();
+            // the estimate is close to the real error
+            let real_error = exact - total.gen(1.0);
+            assert!((real_error - error).abs() < 0.1 * real_error.abs());
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "bezier", feature = "linear"))]
+    fn over() {
+        use crate::bezier::Bezier;
+        use crate::linear::Linear;
+        let bezier = Bezier::builder()
+            .elements([0.0, 3.0, 1.0])
+            .normalized::<f64>()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let params = Equidistant::<f64>::normalized(5);
+        let elements = bezier.over(params);
+        assert_eq!(elements.len(), 5);
+        // the new curve goes through the bezier curve at the parameters
+        let linear = Linear::builder()
+            .elements(elements)
+            .knots(params)
+            .build()
+            .unwrap();
+        for input in params.into_iter() {
+            assert_f64_near!(linear.gen(input), bezier.gen(input));
+        }
+        assert_f64_near!(
+            linear.gen(0.125),
+            (bezier.gen(0.0) + bezier.gen(0.25)) / 2.0
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bezier")]
+    fn endpoints() {
+        use crate::bezier::Bezier;
+        let elements = [1.5, -2.0, 4.0, 3.0];
+        let bezier = Bezier::builder()
+            .elements(elements)
+            .domain(-1.0, 3.0)
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let (start, end) = bezier.endpoints();
+        assert_eq!(start, elements[0]);
+        assert_eq!(end, elements[3]);
+    }
+
+    #[test]
+    #[cfg(feature = "bezier")]
+    fn project() {
+        use crate::bezier::Bezier;
+        use core::ops::{Add, Mul};
+        #[derive(Debug, Default, Copy, Clone, PartialEq)]
+        struct Point(f64, f64);
+        impl Add for Point {
+            type Output = Point;
+            fn add(self, other: Point) -> Point {
+                Point(self.0 + other.0, self.1 + other.1)
+            }
+        }
+        impl Mul<f64> for Point {
+            type Output = Point;
+            fn mul(self, factor: f64) -> Point {
+                Point(self.0 * factor, self.1 * factor)
+            }
+        }
+        impl QuasiMetric<f64> for Point {
+            fn distance(self, to: Self) -> f64 {
+                (self.0 - to.0).hypot(self.1 - to.1)
+            }
+        }
+        // the parabola y = x^2 for x in [-3, 3]
+        let parabola = Bezier::builder()
+            .elements([Point(-3.0, 9.0), Point(0.0, -9.0), Point(3.0, 9.0)])
+            .normalized::<f64>()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let dense = |target: Point| {
+            Stepper::normalized(100_001)
+                .map(|t| parabola.gen(t).distance(target))
+                .fold(f64::INFINITY, f64::min)
+        };
+        for target in [
+            Point(0.0, -1.0),
+            Point(1.0, 0.5),
+            Point(-2.5, 3.0),
+            Point(7.0, 2.0),
+        ] {
+            let input = parabola.project(&target);
+            assert!((0.0..=1.0).contains(&input));
+            assert!(parabola.gen(input).distance(target) <= dense(target) + 1e-9);
+        }
+        assert_float_absolute_eq!(parabola.project(&Point(0.0, -1.0)), 0.5, 1e-9);
+        // outside of the domain the nearest end is chosen
+        assert_eq!(parabola.project(&Point(-5.0, 12.0)), 0.0);
+        assert_eq!(parabola.project(&Point(5.0, 12.0)), 1.0);
+        // two equally near points at x = -sqrt(4.5) and x = sqrt(4.5), the smaller input is preferred
+        let expected = (3.0 - 4.5f64.sqrt()) / 6.0;
+        assert_float_absolute_eq!(parabola.project(&Point(0.0, 5.0)), expected, 1e-9);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn sample_until_converged() {
+        use crate::easing::FuncEase;
+        let norm = |a: f64, b: f64| (a - b).abs();
+        let straight = FuncEase::new(|t: f64| t + 0.01 * t * t);
+        let (samples, straight_density) = straight.sample_until_converged(1e-4, 10_000, norm);
+        assert_eq!(samples.len(), straight_density);
+        assert!(straight_density <= 17);
+        for (input, sample) in Stepper::normalized(straight_density).zip(samples) {
+            assert_f64_near!(straight.gen(input), sample);
+        }
+        let wiggly = FuncEase::new(|t: f64| (t * 40.0).sin());
+        let (_, wiggly_density) = wiggly.sample_until_converged(1e-4, 10_000, norm);
+        assert!(wiggly_density > 16 * straight_density);
+        assert!(wiggly_density <= 10_000);
+        // stops at the maximum
+        let (_, density) = wiggly.sample_until_converged(1e-4, 100, norm);
+        assert_eq!(density, 65);
+    }
+
+    #[test]
+    #[cfg(feature = "linear")]
+    fn as_fn() {
+        use crate::linear::Linear;
+        fn integrate(function: impl Fn(f64) -> f64, samples: usize) -> f64 {
+            Stepper::normalized(samples).map(function).sum::<f64>() / samples as f64
+        }
+        let curve = Linear::builder()
+            .elements([0.0, 2.0, 1.0])
+            .knots([0.0, 0.5, 1.0])
+            .build()
+            .unwrap();
+        let function = curve.as_fn();
+        assert_f64_near!(function(0.25), 1.0);
+        assert_f64_near!(function(0.75), curve.gen(0.75));
+        assert_float_absolute_eq!(integrate(curve.as_fn(), 10_001), 1.25, 1e-3);
+        // the closure only borrows the curve
+        assert_f64_near!(curve.gen(0.5), 2.0);
+    }
+
+    #[test]
+    #[cfg(feature = "linear")]
+    fn by_ref() {
+        use crate::linear::Linear;
+        let curve = Linear::builder()
+            .elements([0.0, 2.0, 1.0])
+            .knots([0.0, 1.0, 2.0])
+            .build()
+            .unwrap();
+        let offset = Linear::builder()
+            .elements([1.0, 1.0])
+            .knots([0.0, 2.0])
+            .build()
+            .unwrap();
+        let shifted = curve.by_ref().zip_with(offset, |a, b| a + b);
+        let squared = curve.by_ref().zip_with(curve.by_ref(), |a, b| a * b);
+        assert_eq!(shifted.domain(), [0.0, 2.0]);
+        assert_eq!(squared.domain(), [0.0, 2.0]);
+        for input in [0.0, 0.5, 1.0, 1.5, 2.0] {
+            let value = curve.gen(input);
+            assert_f64_near!(shifted.gen(input), value + 1.0);
+            assert_f64_near!(squared.gen(input), value * value);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "linear", feature = "bspline"))]
+    fn knot_midpoints() {
+        use crate::bspline::BSpline;
+        use crate::linear::Linear;
+
+        fn assert_inside<C: Curve<f64>>(curve: &C) {
+            let [start, end] = curve.domain();
+            let midpoints = curve.knot_midpoints();
+            assert!(!midpoints.is_empty());
+            for window in midpoints.windows(2) {
+                assert!(window[0] < window[1]);
+            }
+            for midpoint in midpoints {
+                assert!(start < midpoint && midpoint < end);
+            }
+        }
+
+        // the repeated knot does not create a midpoint
+        let linear = Linear::builder()
+            .elements([0.0, 1.0, 1.0, 3.0, 2.0])
+            .knots([0.0, 1.0, 1.0, 2.5, 4.0])
+            .build()
+            .unwrap();
+        assert_inside(&linear);
+        assert_eq!(linear.knot_midpoints(), vec![0.5, 1.75, 3.25]);
+
+        // adaptors keeping the input forward the midpoints, others map them back
+        assert_eq!(linear.clamp().knot_midpoints(), vec![0.5, 1.75, 3.25]);
+        assert_eq!(linear.observed().knot_midpoints(), vec![0.5, 1.75, 3.25]);
+        assert_eq!(
+            crate::TransformInput::new(linear, 1.0, 2.0).knot_midpoints(),
+            vec![-0.25, 0.375, 1.125]
+        );
+        let slice = linear.slice(1.0..3.0);
+        assert_inside(&slice);
+        assert_eq!(slice.knot_midpoints(), vec![1.5]);
+
+        // clamped knots are repeated outside of the domain
+        let bspline = BSpline::through_polygon::<_, f64>([0.0, 1.0, 3.0, 2.0, 4.0], 2).unwrap();
+        assert_inside(&bspline);
+        let midpoints = bspline.knot_midpoints();
+        assert_eq!(midpoints.len(), 3);
+        for (midpoint, expected) in midpoints.iter().zip([1.0 / 6.0, 0.5, 5.0 / 6.0]) {
+            assert_f64_near!(midpoint, expected);
+        }
+
+        // weighted curves forward the midpoints of their knots
+        let nurbs = BSpline::builder()
+            .clamped()
+            .elements_with_weights([(1.0, 1.0), (-2.0, 0.5), (4.0, 2.0), (3.0, 1.0)])
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        assert_inside(&nurbs);
+        assert_eq!(nurbs.knot_midpoints(), vec![0.25, 0.75]);
+        assert_eq!(nurbs.knot_midpoints(), nurbs.inner().knot_midpoints());
+
+        // curves without knots fall back to the middle of their domain
+        let ease = crate::easing::FuncEase::new(|x: f64| x * x);
+        assert_inside(&ease);
+        assert_eq!(ease.knot_midpoints(), vec![0.5]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "bezier"))]
+    fn to_svg_path() {
+        use crate::bezier::Bezier;
+        let x = Bezier::builder()
+            .elements([0.0, 1.0, 2.5, 4.0])
+            .normalized::<f64>()
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let y = Bezier::builder()
+            .elements([0.0, 3.0, -1.0, 0.5])
+            .normalized::<f64>()
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let bezier = x.stack(y);
+        let path = bezier.to_svg_path(20, |(x, y)| [x, y]);
+        // parse the path data: pairs of numbers, each preceded by a command
+        let mut tokens = path.split_whitespace();
+        let mut points = Vec::new();
+        while let Some(first) = tokens.next() {
+            let (command, x) = first.split_at(1);
+            assert_eq!(command, if points.is_empty() { "M" } else { "L" });
+            let x: f64 = x.parse().unwrap();
+            let y: f64 = tokens.next().unwrap().parse().unwrap();
+            points.push((x, y));
+        }
+        assert_eq!(points.len(), 20);
+        assert_eq!(points[0], bezier.gen(0.0));
+        assert_eq!(points[19], bezier.gen(1.0));
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "linear"))]
+    fn arc_length_quantiles() {
+        use crate::linear::Linear;
+        let x = Linear::builder()
+            .elements([1.0, 4.0])
+            .knots([0.0, 2.0])
+            .build()
+            .unwrap();
+        let y = Linear::builder()
+            .elements([1.0, 5.0])
+            .knots([0.0, 2.0])
+            .build()
+            .unwrap();
+        let line = x.stack(y);
+        let distance = |a: (f64, f64), b: (f64, f64)| (a.0 - b.0).hypot(a.1 - b.1);
+        let quantiles = [0.0, 0.25, 0.5, 0.75, 1.0];
+        let params = line.arc_length_quantiles(&quantiles, 10, distance);
+        for (param, quantile) in params.as_slice().iter().zip(quantiles) {
+            assert_f64_near!(*param, 2.0 * quantile);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "bezier"))]
+    fn arc_length_curve() {
+        use crate::bezier::Bezier;
+        use core::cmp::Ordering;
+        let bezier = Bezier::builder()
+            .elements([1.0, -3.0, 4.0, 2.0])
+            .normalized::<f64>()
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let distance = |a: f64, b: f64| (a - b).abs();
+        let arc_length = bezier.arc_length_curve(200, distance);
+        let [start, end] = bezier.domain();
+        assert_eq!(arc_length.domain(), [start, end]);
+        assert_eq!(arc_length.gen(start), 0.0);
+        assert_eq!(arc_length.is_monotone(1000), Some(Ordering::Less));
+        // the total length is reached at the end of the domain
+        let total = (&bezier)
+            .take(200)
+            .collect::<Vec<_>>()
+            .windows(2)
+            .map(|pair| distance(pair[0], pair[1]))
+            .sum::<f64>();
+        assert_f64_near!(arc_length.gen(end), total);
+        // and matches the quantiles
+        let params = bezier.arc_length_quantiles(&[0.25, 0.5, 0.75], 200, distance);
+        for (param, quantile) in params.as_slice().iter().zip([0.25, 0.5, 0.75]) {
+            assert_float_absolute_eq!(arc_length.gen(*param), total * quantile, 1e-9);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "rayon", feature = "bezier"))]
+    fn par_sample_into() {
+        use crate::bezier::Bezier;
+        let curve = Bezier::builder()
+            .elements([1.0, -3.0, 4.0, 2.0])
+            .domain(-1.0, 3.0)
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let mut buffer = vec![0.0; 1000];
+        curve.par_sample_into(&mut buffer);
+        for (parallel, serial) in buffer.as_slice().iter().zip(curve.take(1000)) {
+            assert_eq!(*parallel, serial);
+        }
+    }
+
+    #[test]
+    fn resumed_stepper() {
+        let mut fresh = Stepper::new(7, -1.0, 2.0);
+        for _ in 0..3 {
+            fresh.next();
+        }
+        let resumed = Stepper::with_offset(fresh.steps(), -1.0, 2.0, fresh.index());
+        assert_eq!(resumed.index(), 3);
+        assert_eq!(resumed.len(), 4);
+        for (a, b) in fresh.zip(resumed) {
+            assert_f64_near!(a, b);
+        }
+        // out of bounds offsets result in an empty stepper
+        assert_eq!(Stepper::with_offset(7, -1.0, 2.0, 10).count(), 0);
+    }
+
+    #[test]
+    fn reversed_stepper() {
+        let res = [3.0, 3.5, 4.0, 4.5, 5.0];
+        let stepper = Stepper::new(5, 3.0, 5.0);
+        for (val, expect) in stepper.rev().zip(res.iter().rev()) {
+            assert_f64_near!(val, expect);
+        }
+    }
+
+    #[cfg(all(feature = "alloc", feature = "bezier"))]
+    #[test]
+    fn boxed() {
+        let bezier = crate::bezier::Bezier::builder()
+            .elements(vec![20.0, 100.0, 0.0, 200.0])
+            .normalized::<f64>()
+            .dynamic()
+            .build()
+            .unwrap();
+        let boxed = Box::new(bezier);
+        assert_eq!(boxed.domain(), [0.0, 1.0]);
+        let expected = [20.0, 53.75, 65.0, 98.75, 200.0];
+        for (val, exp) in boxed.take(5).zip(expected) {
+            assert_f64_near!(val, exp);
+        }
+        let boxed = Box::new([1.0, 2.0, 3.0]);
+        assert_eq!(DiscreteGenerator::len(&boxed), 3);
+        assert_f64_near!(boxed.gen(1), 2.0);
+    }
+
+    #[cfg(all(feature = "linear", feature = "bezier", feature = "bspline"))]
+    #[test]
+    fn derivative() {
+        use crate::{bezier::Bezier, bspline::BSpline, easing::FuncEase, linear::Linear};
+        // generic code only needs the trait
+        fn check<C: Derivative<f64, Output = f64>>(curve: C) {
+            let approximation = (&curve).finite_difference();
+            let [start, end] = curve.domain();
+            // avoid the knots, where the derivative jumps
+            for t in Stepper::new(10, start + 0.01, end - 0.01) {
+                assert!((curve.derivative(t) - approximation.derivative(t)).abs() < 1e-4);
+            }
         }
+        let linear = Linear::builder()
+            .elements([0.0, 5.0, 3.0])
+            .knots([0.0, 1.0, 3.0])
+            .build()
+            .unwrap();
+        assert_f64_near!(linear.derivative(0.5), 5.0);
+        assert_f64_near!(linear.derivative(2.0), -1.0);
+        check(linear);
+        let bezier = Bezier::builder()
+            .elements([20.0, 100.0, 0.0, 200.0])
+            .normalized::<f64>()
+            .constant::<4>()
+            .build()
+            .unwrap();
+        assert_f64_near!(bezier.derivative(0.0), 240.0);
+        check(bezier);
+        let bspline = BSpline::builder()
+            .elements([1.0, -2.0, 4.0, 3.0, 0.5, 2.0, -1.0])
+            .knots([0.0, 0.0, 0.0, 1.0, 2.5, 4.5, 6.0, 6.0, 6.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        check(bspline);
+        // the fallback steps into the domain at its borders
+        let square = FuncEase::new(|x: f64| x * x).finite_difference();
+        assert!((square.derivative(0.0)).abs() < 1e-4);
+        assert!((square.derivative(1.0) - 2.0).abs() < 1e-4);
     }
 }
@@ -1,10 +1,22 @@
 use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
 use core::ops::{Div, Index, Sub};
+#[cfg(all(
+    feature = "alloc",
+    any(
+        feature = "linear",
+        feature = "bspline",
+        feature = "catmullrom",
+        feature = "hermite",
+        feature = "kochanek"
+    )
+))]
+use num_traits::identities::One;
 use num_traits::identities::Zero;
 use num_traits::real::Real;
-use num_traits::FromPrimitive;
+use num_traits::{FromPrimitive, ToPrimitive};
 
 #[cfg(feature = "std")]
 use std::error::Error;
@@ -13,6 +25,8 @@ use std::error::Error;
 use core::fmt::Debug;
 
 use super::{DiscreteGenerator, Generator};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 // REMARK: It may be valuable to create traits SortedNonEmpty and SortedNonSingular
 // REMARK: These would be Sorted + NonEmpty and Sorted + MinSize<2>.
@@ -70,19 +84,7 @@ pub trait SortedGenerator: DiscreteGenerator {
     where
         Self::Output: PartialOrd + Copy,
     {
-        let mut pointer = min;
-        let mut dist = max - min;
-        while dist > 0 {
-            let step = dist / 2;
-            let sample = pointer + step;
-            if element >= self.gen(sample) {
-                pointer = sample + 1;
-                dist -= step + 1;
-            } else {
-                dist = step;
-            }
-        }
-        pointer
+        binary_search(self, element, min, max)
     }
     /// Returns the smallest index for which the corresponding element is bigger then the input.
     /// If all elements are bigger, this function will return self.len().
@@ -246,13 +248,163 @@ pub trait SortedGenerator: DiscreteGenerator {
         }
         (element - min) / div
     }
+
+    /// Returns all distinct elements together with their multiplicity, that is, how often they appear.
+    ///
+    /// As the elements are sorted, equal elements are adjacent and get grouped together.
+    /// The groups are returned in increasing order.
+    ///
+    /// For knots of a curve, the multiplicity of a knot determines how smooth the curve is at that knot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::{SortedGenerator, Sorted};
+    /// let knots = Sorted::new([0.0,1.0,1.0,1.0,2.0]).unwrap();
+    /// assert_eq!(knots.multiplicities(), vec![(0.0,1),(1.0,3),(2.0,1)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn multiplicities(&self) -> Vec<(Self::Output, usize)>
+    where
+        Self::Output: PartialEq,
+    {
+        let mut groups: Vec<(Self::Output, usize)> = Vec::new();
+        for i in 0..self.len() {
+            let element = self.gen(i);
+            match groups.last_mut() {
+                Some((last, count)) if *last == element => *count += 1,
+                _ => groups.push((element, 1)),
+            }
+        }
+        groups
+    }
     // If you want to add a default implementation: The wrapper `Sorted` should forward to the implementation!
 }
 
+/// Binary search for the smallest index between `min` and `max`
+/// for which the corresponding element is bigger then the input.
+fn binary_search<G>(gen: &G, element: G::Output, min: usize, max: usize) -> usize
+where
+    G: DiscreteGenerator + ?Sized,
+    G::Output: PartialOrd + Copy,
+{
+    let mut pointer = min;
+    let mut dist = max - min;
+    while dist > 0 {
+        let step = dist / 2;
+        let sample = pointer + step;
+        if element >= gen.gen(sample) {
+            pointer = sample + 1;
+            dist -= step + 1;
+        } else {
+            dist = step;
+        }
+    }
+    pointer
+}
+
+/// Interpolation search for the smallest index between `min` and `max`
+/// for which the corresponding element is bigger then the input.
+///
+/// Falls back to bisection if the elements can not be represented as `f64`.
+fn interpolation_search<G>(gen: &G, element: G::Output, min: usize, max: usize) -> usize
+where
+    G: DiscreteGenerator + ?Sized,
+    G::Output: PartialOrd + Copy + ToPrimitive,
+{
+    let mut low = min;
+    let mut high = max;
+    while low < high {
+        let first = gen.gen(low);
+        if element < first {
+            return low;
+        }
+        let last = gen.gen(high - 1);
+        if element >= last {
+            return high;
+        }
+        // now first <= element < last, such the result is in (low, high)
+        let guess = match (element.to_f64(), first.to_f64(), last.to_f64()) {
+            (Some(element), Some(first), Some(last)) => {
+                let factor = (element - first) / (last - first);
+                low + (factor * (high - 1 - low) as f64) as usize
+            }
+            _ => low + (high - low) / 2,
+        };
+        let guess = guess.max(low).min(high - 1);
+        if element >= gen.gen(guess) {
+            low = guess + 1;
+        } else {
+            high = guess;
+        }
+    }
+    low
+}
+
+/// The algorithm used to search through sorted knots.
+///
+/// Searching for the relevant knots is necessary for every generated value of curves with non-equidistant knots.
+/// The strategy is part of the type of [`Sorted`], such that only strategies which are able to search
+/// through the given knots can be chosen.
+pub trait SearchStrategy<T> {
+    /// Returns the smallest index between `min` and `max`
+    /// for which the corresponding element of `gen` is bigger then the input.
+    /// If all elements are smaller, this function will return the given maximum.
+    fn strict_upper_bound_clamped<G>(&self, gen: &G, element: T, min: usize, max: usize) -> usize
+    where
+        G: DiscreteGenerator<Output = T> + ?Sized,
+        T: PartialOrd + Copy;
+}
+
+/// Binary search, which needs `O(log n)` steps.
+///
+/// This is the default and the best choice if nothing is known about the distribution of the knots.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct BinarySearch;
+
+impl<T> SearchStrategy<T> for BinarySearch {
+    fn strict_upper_bound_clamped<G>(&self, gen: &G, element: T, min: usize, max: usize) -> usize
+    where
+        G: DiscreteGenerator<Output = T> + ?Sized,
+        T: PartialOrd + Copy,
+    {
+        binary_search(gen, element, min, max)
+    }
+}
+
+/// Interpolation search, which needs `O(log log n)` steps for uniformly distributed knots.
+///
+/// This is a good choice for huge amounts of knots which are near equidistant.
+/// However, for badly distributed knots it may need up to `O(n)` steps.
+/// The knots have to be convertible to `f64` to estimate the position of the searched element.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InterpolationSearch;
+
+impl<T> SearchStrategy<T> for InterpolationSearch
+where
+    T: ToPrimitive,
+{
+    fn strict_upper_bound_clamped<G>(&self, gen: &G, element: T, min: usize, max: usize) -> usize
+    where
+        G: DiscreteGenerator<Output = T> + ?Sized,
+        T: PartialOrd + Copy,
+    {
+        interpolation_search(gen, element, min, max)
+    }
+}
+
 /// Struct to represent a sorted collection/generator.
-#[derive(Debug, Copy, Clone)]
+///
+/// By default, [`BinarySearch`] is used to search through the collection.
+/// This can be changed with [`search()`].
+///
+/// [`search()`]: Sorted::search()
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-pub struct Sorted<C>(C);
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Sorted<C, S = BinarySearch>(C, #[cfg_attr(feature = "serde", serde(skip))] S);
 
 impl<C> Sorted<C>
 where
@@ -262,7 +414,7 @@ where
     /// Returns Some(Sorted) if collection is sorted, otherwise returns `NotSorted` Error.
     pub fn new(col: C) -> Result<Self, NotSorted> {
         if col.is_empty() {
-            return Ok(Sorted(col));
+            return Ok(Sorted::new_unchecked(col));
         }
         let mut last = col.gen(0);
         for i in 1..col.len() {
@@ -274,7 +426,7 @@ where
                 }
             }
         }
-        Ok(Sorted(col))
+        Ok(Sorted::new_unchecked(col))
     }
 }
 
@@ -284,11 +436,88 @@ impl<C> Sorted<C> {
     /// As unsorted collection will not create UB but will probably panic at some point,
     /// such this function is still safe, even if an unsorted collection is given.
     pub const fn new_unchecked(col: C) -> Self {
-        Sorted(col)
+        Sorted(col, BinarySearch)
+    }
+}
+
+impl<C, S> Sorted<C, S> {
+    /// Set the strategy used to search through the collection.
+    pub fn search<T>(self, strategy: T) -> Sorted<C, T> {
+        Sorted(self.0, strategy)
+    }
+
+    /// Merge elements which are at most `epsilon` bigger than a previous element.
+    ///
+    /// Elements are merged into the smallest element of their group, such that
+    /// near-equal elements become exactly equal and the collection stays sorted.
+    /// Elements are compared with the first element of their group, not their direct predecessor,
+    /// such that many small steps do not get merged together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::{Sorted, Generator};
+    /// let knots = Sorted::new([0.0, 1.0, 1.0000001, 2.0]).unwrap().snap(1e-6);
+    /// assert_eq!(knots.gen(2), 1.0);
+    /// ```
+    pub fn snap<R>(mut self, epsilon: R) -> Self
+    where
+        C: AsMut<[R]>,
+        R: Real,
+    {
+        if let Some((first, rest)) = self.0.as_mut().split_first_mut() {
+            let mut group = *first;
+            for element in rest {
+                if *element - group <= epsilon {
+                    *element = group;
+                } else {
+                    group = *element;
+                }
+            }
+        }
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, S> Sorted<Vec<T>, S>
+where
+    T: PartialOrd,
+{
+    /// Append an element, which must not be smaller than the last element.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotSorted`] if the element is smaller than the last element or not comparable to it.
+    /// In this case the collection is left unchanged.
+    pub fn push(&mut self, element: T) -> Result<(), NotSorted> {
+        if let Some(last) = self.0.as_slice().last() {
+            if let None | Some(Ordering::Greater) = last.partial_cmp(&element) {
+                return Err(NotSorted::new(self.0.len() - 1));
+            }
+        }
+        self.0.push(element);
+        Ok(())
+    }
+
+    /// Insert an element at the front, which must not be bigger than the first element.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotSorted`] if the element is bigger than the first element or not comparable to it.
+    /// In this case the collection is left unchanged.
+    pub fn push_front(&mut self, element: T) -> Result<(), NotSorted> {
+        if let Some(first) = self.0.as_slice().first() {
+            if let None | Some(Ordering::Greater) = element.partial_cmp(first) {
+                return Err(NotSorted::new(0));
+            }
+        }
+        self.0.insert(0, element);
+        Ok(())
     }
 }
 
-impl<C> Generator<usize> for Sorted<C>
+impl<C, S> Generator<usize> for Sorted<C, S>
 where
     C: Generator<usize>,
 {
@@ -298,7 +527,7 @@ where
     }
 }
 
-impl<C> DiscreteGenerator for Sorted<C>
+impl<C, S> DiscreteGenerator for Sorted<C, S>
 where
     C: DiscreteGenerator,
 {
@@ -307,9 +536,42 @@ where
     }
 }
 
-impl<C: DiscreteGenerator> SortedGenerator for Sorted<C> {}
+impl<C, S> SortedGenerator for Sorted<C, S>
+where
+    C: DiscreteGenerator,
+    S: SearchStrategy<C::Output>,
+{
+    /// Returns the smallest index between `min` and `max`
+    /// for which the corresponding element is bigger then the input.
+    /// If all elements are smaller, this function will return the given maximum.
+    ///
+    /// The search is done with the [`SearchStrategy`] of the collection.
+    ///
+    /// #Panic
+    ///
+    /// Panics if `min` or `max` are not within [0,self.len()].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::{SortedGenerator, Sorted, InterpolationSearch};
+    /// let arr = Sorted::new_unchecked([0.0,0.1,0.2,0.7,0.7,0.7,0.8,1.0])
+    ///     .search(InterpolationSearch);
+    /// assert_eq!(arr.strict_upper_bound_clamped(-1.0,1,5),1);
+    /// assert_eq!(arr.strict_upper_bound_clamped(0.15,1,5),2);
+    /// assert_eq!(arr.strict_upper_bound_clamped(0.7,1,5),5);
+    /// assert_eq!(arr.strict_upper_bound_clamped(20.0,1,5),5);
+    /// ```
+    fn strict_upper_bound_clamped(&self, element: Self::Output, min: usize, max: usize) -> usize
+    where
+        Self::Output: PartialOrd + Copy,
+    {
+        self.1
+            .strict_upper_bound_clamped(&self.0, element, min, max)
+    }
+}
 
-impl<C, Idx> Index<Idx> for Sorted<C>
+impl<C, S, Idx> Index<Idx> for Sorted<C, S>
 where
     C: Index<Idx>,
 {
@@ -349,7 +611,12 @@ impl Error for NotSorted {}
 
 /// Struct used as a generator for equidistant elements.
 /// Acts like an array of knots.
-#[derive(Debug, Copy, Clone)]
+///
+/// Every element is calculated directly from its index, such that no rounding errors accumulate.
+/// The first element is exactly the given start. The last element is calculated from the step like
+/// every other element, such that it may differ from a given end by rounding.
+/// Use [`Linspace`] if the last element has to be exactly the given end.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Equidistant<R = f64> {
     len: usize,
@@ -406,6 +673,17 @@ where
             offset: start,
         }
     }
+
+    /// Add one more step after the last element.
+    pub fn extend_back(&mut self) {
+        self.len += 1;
+    }
+
+    /// Add one more step before the first element.
+    pub fn extend_front(&mut self) {
+        self.len += 1;
+        self.offset = self.offset - self.step;
+    }
 }
 
 impl<R> Generator<usize> for Equidistant<R>
@@ -551,6 +829,237 @@ where
     }
 }
 
+/// Struct used as a generator for equidistant elements from a start to an end.
+/// Acts like an array of knots.
+///
+/// Like [`Equidistant`], every element is calculated directly from its index, such that no rounding
+/// errors accumulate. In contrast to it, the last element is exactly the given end.
+/// This is used by [`Stepper`] to sample a domain including both of its borders.
+///
+/// [`Stepper`]: crate::Stepper
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Linspace<R = f64> {
+    len: usize,
+    start: R,
+    end: R,
+}
+
+impl<R> Linspace<R> {
+    /// Create a generator for `len` equidistant elements from `start` to `end`.
+    pub fn new(len: usize, start: R, end: R) -> Self {
+        Linspace { len, start, end }
+    }
+}
+
+impl<R> Generator<usize> for Linspace<R>
+where
+    R: Real + FromPrimitive,
+{
+    type Output = R;
+    fn gen(&self, input: usize) -> R {
+        if input == 0 {
+            return self.start;
+        }
+        // the last element is returned as given, as calculating it from the step may be off by rounding
+        if input + 1 == self.len {
+            return self.end;
+        }
+        let step = (self.end - self.start) / R::from_usize(self.len - 1).unwrap();
+        step * R::from_usize(input).unwrap() + self.start
+    }
+}
+
+impl<R> DiscreteGenerator for Linspace<R>
+where
+    R: Real + FromPrimitive,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Struct used as a generator for Chebyshev nodes.
+/// Acts like an array of knots.
+///
+/// The nodes are the roots of the Chebyshev polynomial of degree `len`, mapped onto the interval
+/// `[start, end]` and given in increasing order. They cluster towards both ends of the interval,
+/// which mitigates Runge's phenomenon for interpolations of high degree. As the nodes are roots,
+/// the ends of the interval are not nodes themselves.
+///
+/// # Examples
+///
+/// ```
+/// # use enterpolation::{ChebyshevNodes, DiscreteGenerator, Generator};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// let nodes = ChebyshevNodes::new(3, -1.0, 1.0);
+/// assert_eq!(nodes.len(), 3);
+/// assert_f64_near!(nodes.gen(0), -(0.75_f64).sqrt());
+/// assert_f64_near!(nodes.gen(2), (0.75_f64).sqrt());
+/// ```
+#[derive(Debug, Copy, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ChebyshevNodes<R = f64> {
+    len: usize,
+    center: R,
+    radius: R,
+}
+
+impl<R> ChebyshevNodes<R>
+where
+    R: Real + FromPrimitive,
+{
+    /// Create a generator for `len` Chebyshev nodes inside of the interval from 0.0 to 1.0.
+    pub fn normalized(len: usize) -> Self {
+        Self::new(len, R::zero(), R::one())
+    }
+
+    /// Create a generator for `len` Chebyshev nodes inside of the interval from `start` to `end`.
+    ///
+    /// #Panics
+    ///
+    /// Panics if the number 2 can not be transformed into R.
+    pub fn new(len: usize, start: R, end: R) -> Self {
+        let two = R::from_usize(2).unwrap();
+        ChebyshevNodes {
+            len,
+            center: (start + end) / two,
+            radius: (end - start) / two,
+        }
+    }
+}
+
+impl<R> Generator<usize> for ChebyshevNodes<R>
+where
+    R: Real + FromPrimitive,
+{
+    type Output = R;
+    fn gen(&self, input: usize) -> R {
+        let angle = R::from_usize(2 * input + 1).unwrap() / R::from_usize(2 * self.len).unwrap()
+            * R::from_f64(core::f64::consts::PI).unwrap();
+        self.center - self.radius * angle.cos()
+    }
+}
+
+impl<R> DiscreteGenerator for ChebyshevNodes<R>
+where
+    R: Real + FromPrimitive,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<R> SortedGenerator for ChebyshevNodes<R> where R: Real + FromPrimitive {}
+
+/// Struct used as a generator for geometrically spaced elements.
+/// Acts like an array of knots.
+///
+/// The elements are spaced evenly on a logarithmic scale, such that the ratio of
+/// consecutive elements is constant. Both ends of the interval are elements themselves.
+/// This is useful to sample curves over frequencies or other quantities which span several orders of magnitude.
+///
+/// # Examples
+///
+/// ```
+/// # use enterpolation::{DiscreteGenerator, Generator, Geometric};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// let frequencies = Geometric::new(4, 10.0, 10_000.0).unwrap();
+/// assert_eq!(frequencies.len(), 4);
+/// assert_eq!(frequencies.gen(0), 10.0);
+/// assert_f64_near!(frequencies.gen(1), 100.0);
+/// assert_f64_near!(frequencies.gen(2), 1000.0);
+/// assert_eq!(frequencies.gen(3), 10_000.0);
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Geometric<R = f64> {
+    len: usize,
+    start: R,
+    end: R,
+    /// logarithm of the ratio of consecutive elements
+    step: R,
+}
+
+impl<R> Geometric<R>
+where
+    R: Real + FromPrimitive,
+{
+    /// Create a generator for `len` geometrically spaced elements from `start` to `end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotPositive`] if `start` or `end` is not positive.
+    ///
+    /// #Panics
+    ///
+    /// Panics if `len - 1` can not be transformed into R.
+    pub fn new(len: usize, start: R, end: R) -> Result<Self, NotPositive> {
+        // also rejects NaN
+        if !(start > R::zero() && end > R::zero()) {
+            return Err(NotPositive::new());
+        }
+        let step = if len > 1 {
+            (end / start).ln() / R::from_usize(len - 1).unwrap()
+        } else {
+            R::zero()
+        };
+        Ok(Geometric {
+            len,
+            start,
+            end,
+            step,
+        })
+    }
+}
+
+impl<R> Generator<usize> for Geometric<R>
+where
+    R: Real + FromPrimitive,
+{
+    type Output = R;
+    fn gen(&self, input: usize) -> R {
+        // return the end exactly, which the exponential may miss by rounding
+        if input > 0 && input == self.len - 1 {
+            return self.end;
+        }
+        self.start * (self.step * R::from_usize(input).unwrap()).exp()
+    }
+}
+
+impl<R> DiscreteGenerator for Geometric<R>
+where
+    R: Real + FromPrimitive,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Error returned if the given interval of a logarithmic spacing contains zero or negative values.
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NotPositive {}
+
+impl NotPositive {
+    /// Create a new error.
+    pub fn new() -> Self {
+        NotPositive {}
+    }
+}
+
+impl fmt::Display for NotPositive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Logarithmic spacing needs an interval with only positive values."
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NotPositive {}
+
 /// Struct used as a generator for equidistant elements in constant context.
 /// Acts like an array of knots.
 ///
@@ -561,6 +1070,9 @@ where
 /// In comparison to `Equidistant`, this struct is slower (as it has to do more calculations) and
 /// only represents knots in [0.0,1.0]. However as knot base for interpolations, it is more performant,
 /// as we have the knowledge of the domain.
+///
+/// Every element is calculated directly from its index, such that the first and the last element
+/// are exactly 0.0 and 1.0.
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ConstEquidistant<R /* = f64*/, const N: usize>(PhantomData<*const R>);
@@ -573,6 +1085,25 @@ impl<R, const N: usize> ConstEquidistant<R, N> {
     }
 }
 
+impl<R, const N: usize> Default for ConstEquidistant<R, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// implemented by hand, as deriving would require `R: Hash`
+impl<R, const N: usize> Hash for ConstEquidistant<R, N> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl<R, const N: usize> PartialEq for ConstEquidistant<R, N> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<R, const N: usize> Eq for ConstEquidistant<R, N> {}
+
 impl<R, const N: usize> Generator<usize> for ConstEquidistant<R, N>
 where
     R: Real + FromPrimitive,
@@ -713,3 +1244,180 @@ where
         (min_index, max_index, factor)
     }
 }
+
+/// Returns the midpoints of all spans between successive distinct knots within the given indices.
+///
+/// Spans of width zero, created by repeated knots, are skipped.
+#[cfg(all(
+    feature = "alloc",
+    any(
+        feature = "linear",
+        feature = "bspline",
+        feature = "catmullrom",
+        feature = "hermite",
+        feature = "kochanek"
+    )
+))]
+pub(crate) fn knot_midpoints<K>(knots: &K, first: usize, last: usize) -> Vec<K::Output>
+where
+    K: SortedGenerator,
+    K::Output: Real,
+{
+    let two = K::Output::one() + K::Output::one();
+    (first..last)
+        .filter_map(|index| {
+            let start = knots.gen(index);
+            let end = knots.gen(index + 1);
+            (start < end).then(|| (start + end) / two)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn equidistant_ends() {
+        for len in 2..100 {
+            let equi = Equidistant::new(len, 0.1, 0.7);
+            assert_eq!(equi.first(), Some(0.1));
+            assert_f64_near!(equi.last().unwrap(), 0.7);
+            // the last element always agrees with the step, also after extending
+            let mut extended = Equidistant::step(len - 1, 0.1, 0.3);
+            extended.extend_back();
+            assert_eq!(extended, Equidistant::step(len, 0.1, 0.3));
+            assert_eq!(extended.last(), Some(0.3 * (len - 1) as f64 + 0.1));
+        }
+        for len in 2..100 {
+            let linspace = Linspace::new(len, 0.1, 0.7);
+            let equi = Equidistant::new(len, 0.1, 0.7);
+            assert_eq!(linspace.first(), Some(0.1));
+            assert_eq!(linspace.last(), Some(0.7));
+            for i in 0..len - 1 {
+                assert_eq!(linspace.gen(i), equi.gen(i));
+            }
+        }
+        let normalized = Equidistant::<f64>::normalized(50);
+        assert_eq!(normalized.first(), Some(0.0));
+        assert_f64_near!(normalized.last().unwrap(), 1.0);
+        let constant = ConstEquidistant::<f64, 50>::new();
+        assert_eq!(constant.first(), Some(0.0));
+        assert_eq!(constant.last(), Some(1.0));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn multiplicities() {
+        let knots = Sorted::new([0.0, 0.0, 1.0, 2.5, 2.5, 2.5, 3.0, 4.0, 4.0]).unwrap();
+        assert_eq!(
+            knots.multiplicities(),
+            vec![(0.0, 2), (1.0, 1), (2.5, 3), (3.0, 1), (4.0, 2)]
+        );
+        let equidistant = Equidistant::<f64>::normalized(3);
+        assert_eq!(
+            equidistant.multiplicities(),
+            vec![(0.0, 1), (0.5, 1), (1.0, 1)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn geometric() {
+        let frequencies = Geometric::new(25, 20.0, 20_000.0).unwrap();
+        let values: Vec<f64> = frequencies.into_iter().collect();
+        assert_eq!(values.len(), 25);
+        assert_eq!(values[0], 20.0);
+        assert_eq!(values[24], 20_000.0);
+        let ratio = values[1] / values[0];
+        for pair in values.windows(2) {
+            assert_float_relative_eq!(pair[1] / pair[0], ratio, 1e-12);
+        }
+        // decreasing intervals and single elements
+        let reversed = Geometric::new(3, 4.0, 1.0).unwrap();
+        assert_f64_near!(reversed.gen(1), 2.0);
+        assert_eq!(Geometric::new(1, 3.0, 5.0).unwrap().gen(0), 3.0);
+        for (start, end) in [(0.0, 1.0), (-1.0, 1.0), (1.0, -2.0), (f64::NAN, 1.0)] {
+            assert!(Geometric::new(5, start, end).is_err());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn chebyshev_nodes() {
+        let len = 10;
+        let nodes = ChebyshevNodes::new(len, 2.0, 6.0);
+        // closed form on [-1,1], mapped onto [2,6]
+        for k in 0..len {
+            let angle = (2 * k + 1) as f64 / (2 * len) as f64 * core::f64::consts::PI;
+            assert_f64_near!(nodes.gen(k), 4.0 - 2.0 * angle.cos());
+        }
+        let nodes: Vec<f64> = nodes.into_iter().collect();
+        assert!(nodes.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(nodes[0] > 2.0 && nodes[len - 1] < 6.0);
+        // gaps are smallest at the ends and largest in the middle
+        let gaps: Vec<f64> = nodes.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        assert!(gaps[..len / 2 - 1].windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(gaps[len / 2..].windows(2).all(|pair| pair[0] > pair[1]));
+        // symmetric around the center
+        for k in 0..len {
+            assert_f64_near!(nodes[k] - 2.0, 6.0 - nodes[len - 1 - k]);
+        }
+    }
+
+    #[test]
+    fn search_partial_ord() {
+        // knots which can not be converted to numbers are still searchable with binary search
+        #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+        struct Meter(f64);
+        let knots = Sorted::new([Meter(0.0), Meter(1.0), Meter(1.0), Meter(3.0)]).unwrap();
+        assert_eq!(knots.strict_upper_bound(Meter(1.0)), 3);
+        assert_eq!(knots.strict_upper_bound(Meter(2.0)), 3);
+        assert_eq!(knots.strict_upper_bound(Meter(-1.0)), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_sorted() {
+        // the search strategy is part of the type and not serialized
+        let json = "[0.0,1.0,2.5]";
+        let binary: Sorted<[f64; 3]> = serde_json::from_str(json).unwrap();
+        assert_eq!(binary, Sorted::new([0.0, 1.0, 2.5]).unwrap());
+        assert_eq!(serde_json::to_string(&binary).unwrap(), json);
+        let interpolation: Sorted<[f64; 3], InterpolationSearch> =
+            serde_json::from_str(json).unwrap();
+        assert_eq!(interpolation.strict_upper_bound(1.5), 2);
+        assert_eq!(serde_json::to_string(&interpolation).unwrap(), json);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn search_strategies() {
+        let uniform: Vec<f64> = (0..1000).map(|i| i as f64).collect();
+        let quadratic: Vec<f64> = (0..1000).map(|i| (i * i) as f64).collect();
+        let duplicates = vec![0.0, 0.0, 0.0, 1.0, 2.0, 2.0, 2.0, 5.0, 7.0, 7.0, 10.0, 10.0];
+        for knots in [uniform, quadratic, duplicates] {
+            let binary = Sorted::new(&knots).unwrap();
+            let interpolation = binary.search(InterpolationSearch);
+            let first = knots[0];
+            let last = knots[knots.len() - 1];
+            let queries = (0..=300)
+                .map(|i| first - 1.0 + (last - first + 2.0) * i as f64 / 300.0)
+                .chain(knots.as_slice().iter().copied());
+            for query in queries {
+                assert_eq!(
+                    binary.strict_upper_bound(query),
+                    interpolation.strict_upper_bound(query)
+                );
+                for (min, max) in [(0, knots.len()), (1, knots.len() - 1), (3, 7)] {
+                    assert_eq!(
+                        binary.strict_upper_bound_clamped(query, min, max),
+                        interpolation.strict_upper_bound_clamped(query, min, max)
+                    );
+                }
+            }
+        }
+    }
+}
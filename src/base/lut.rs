@@ -0,0 +1,124 @@
+use super::{Curve, Generator};
+use alloc::vec::Vec;
+use num_traits::real::Real;
+use topology_traits::Merge;
+
+/// A curve backed by a lookup table of equidistant samples.
+///
+/// Generating a value only needs a single multiplication to find the two adjacent samples
+/// and a linear interpolation between them, no matter how expensive the original curve was.
+/// This makes it a good fit for hot paths in which a curve is evaluated very often
+/// and a small approximation error is acceptable.
+///
+/// Inputs outside of the domain are extrapolated linearly with the first or last two samples.
+///
+/// This struct is created by [`Curve::bake_lut()`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Lut<R, T> {
+    samples: Vec<T>,
+    start: R,
+    end: R,
+    // number of samples per unit of input
+    scale: R,
+}
+
+impl<R, T> Lut<R, T>
+where
+    R: Real,
+{
+    /// Create a lookup table from equidistant samples spanning the domain `[start, end]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is empty or if its length can not be converted to the type `R`.
+    pub fn new(samples: Vec<T>, start: R, end: R) -> Self {
+        assert!(
+            !samples.is_empty(),
+            "a lookup table needs at least one sample"
+        );
+        let scale = if samples.len() > 1 {
+            R::from(samples.len() - 1).unwrap() / (end - start)
+        } else {
+            R::zero()
+        };
+        Lut {
+            samples,
+            start,
+            end,
+            scale,
+        }
+    }
+    /// Returns the samples stored in the lookup table.
+    pub fn samples(&self) -> &[T] {
+        &self.samples
+    }
+}
+
+impl<R, T> Generator<R> for Lut<R, T>
+where
+    R: Real,
+    T: Merge<R> + Copy,
+{
+    type Output = T;
+    fn gen(&self, input: R) -> Self::Output {
+        let last = self.samples.len() - 1;
+        if last == 0 {
+            return self.samples[0];
+        }
+        let position = (input - self.start) * self.scale;
+        let index = position.floor().to_usize().unwrap_or(0).min(last - 1);
+        let factor = position - R::from(index).unwrap();
+        self.samples[index].merge(self.samples[index + 1], factor)
+    }
+}
+
+impl<R, T> Curve<R> for Lut<R, T>
+where
+    R: Real,
+    T: Merge<R> + Copy,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.start, self.end]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn exact_at_samples() {
+        let lut = Lut::new(vec![0.0, 2.0, 1.0], 1.0, 3.0);
+        assert_eq!(lut.domain(), [1.0, 3.0]);
+        assert_f64_near!(lut.gen(1.0), 0.0);
+        assert_f64_near!(lut.gen(1.5), 1.0);
+        assert_f64_near!(lut.gen(2.0), 2.0);
+        assert_f64_near!(lut.gen(2.5), 1.5);
+        assert_f64_near!(lut.gen(3.0), 1.0);
+        // extrapolation
+        assert_f64_near!(lut.gen(0.0), -2.0);
+        assert_f64_near!(lut.gen(4.0), 0.0);
+        // single sample
+        let lut = Lut::new(vec![5.0], 0.0, 1.0);
+        assert_f64_near!(lut.gen(0.3), 5.0);
+    }
+
+    #[cfg(feature = "bezier")]
+    #[test]
+    fn bake() {
+        let bezier = crate::bezier::Bezier::builder()
+            .elements([0.0, 5.0, 1.0])
+            .normalized::<f64>()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let lut = bezier.bake_lut(1001);
+        assert_eq!(lut.domain(), bezier.domain());
+        assert_eq!(lut.samples().len(), 1001);
+        for t in crate::Stepper::normalized(37) {
+            assert!((lut.gen(t) - bezier.gen(t)).abs() < 1e-5);
+        }
+    }
+}
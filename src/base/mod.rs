@@ -1,32 +1,61 @@
 mod adaptors;
 mod generator;
 mod list;
+#[cfg(feature = "alloc")]
+mod lut;
 mod space;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 // These get re-exported at the library level.
 #[allow(unreachable_pub)]
-pub use adaptors::{Clamp, Composite, Repeat, Slice, Stack, TransformInput, Wrap};
+pub use adaptors::{
+    Boundary, BoundaryMode, Clamp, Combine, Composite, Difference, FiniteDifference, Negate,
+    Nested, Observed, Ops, OutOfDomain, Pow, Reflect, Repeat, Retime, RetimeMode, Scale, Select,
+    Slice, Smooth, SoftMin, Speed, Stack, Sum, TransformInput, Wrap, ZipWith,
+};
+#[cfg(any(feature = "bezier", feature = "bspline"))]
+pub(crate) use generator::bounds;
 #[allow(unreachable_pub)]
 pub use generator::{
-    ConstDiscreteGenerator, Curve, DiscreteGenerator, Extract, Generator, Stepper, Take,
+    Bounded, ConstDiscreteGenerator, Curve, Derivative, DiscreteGenerator, Extract, Generator,
+    LogStepper, SamplePairs, Stepper, Surface, Take,
 };
+#[cfg(all(
+    feature = "alloc",
+    any(
+        feature = "linear",
+        feature = "bspline",
+        feature = "catmullrom",
+        feature = "hermite",
+        feature = "kochanek"
+    )
+))]
+pub(crate) use list::knot_midpoints;
 #[allow(unreachable_pub)]
-pub use list::{ConstEquidistant, Equidistant, NotSorted, Sorted, SortedGenerator};
+pub use list::{
+    BinarySearch, ChebyshevNodes, ConstEquidistant, Equidistant, Geometric, InterpolationSearch,
+    Linspace, NotPositive, NotSorted, SearchStrategy, Sorted, SortedGenerator,
+};
 #[allow(unreachable_pub)]
-#[cfg(feature = "std")]
-pub use space::DynSpace;
+#[cfg(feature = "alloc")]
+pub use lut::Lut;
 #[allow(unreachable_pub)]
 pub use space::{ConstSpace, Space};
+#[allow(unreachable_pub)]
+#[cfg(feature = "alloc")]
+pub use space::{DynSpace, Pooled, PooledSpace};
 
-#[cfg(feature = "std")]
-impl<T: Copy> Generator<usize> for Vec<T> {
+#[cfg(feature = "alloc")]
+impl<T: Clone> Generator<usize> for Vec<T> {
     type Output = T;
     fn gen(&self, input: usize) -> Self::Output {
-        self[input]
+        self[input].clone()
     }
 }
-#[cfg(feature = "std")]
-impl<T: Copy> DiscreteGenerator for Vec<T> {
+#[cfg(feature = "alloc")]
+impl<T: Clone> DiscreteGenerator for Vec<T> {
     fn len(&self) -> usize {
         self.len()
     }
@@ -43,20 +72,20 @@ impl<T: Copy> DiscreteGenerator for Vec<T> {
 //     }
 // }
 
-impl<T: Copy, const N: usize> Generator<usize> for [T; N] {
+impl<T: Clone, const N: usize> Generator<usize> for [T; N] {
     type Output = T;
     fn gen(&self, input: usize) -> Self::Output {
-        self[input]
+        self[input].clone()
     }
 }
 
-impl<T: Copy, const N: usize> DiscreteGenerator for [T; N] {
+impl<T: Clone, const N: usize> DiscreteGenerator for [T; N] {
     fn len(&self) -> usize {
         N
     }
 }
 
-impl<T: Copy, const N: usize> ConstDiscreteGenerator<N> for [T; N] {}
+impl<T: Clone, const N: usize> ConstDiscreteGenerator<N> for [T; N] {}
 
 // /// A stack of values or generators
 // impl<G,I, const N: usize> Generator<(usize, I)> for [G;N]
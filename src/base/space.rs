@@ -1,3 +1,14 @@
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::{Allocator, Global};
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cell::RefCell;
+use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
 
 /// Trait for constant or dynamic workspace handling.
@@ -45,7 +56,7 @@ where
 
 impl<T, const N: usize> ConstSpace<T, N> {
     /// Create a constant worksprace at compile-time.
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         ConstSpace {
             _phantom: PhantomData,
         }
@@ -58,13 +69,27 @@ impl<T, const N: usize> Default for ConstSpace<T, N> {
     }
 }
 
+// implemented by hand, as deriving would require `T: Hash`
+impl<T, const N: usize> Hash for ConstSpace<T, N> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl<T, const N: usize> PartialEq for ConstSpace<T, N> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T, const N: usize> Eq for ConstSpace<T, N> {}
+
 /// Struct which handles workspace at run-time.
 ///
 /// A new `Vec` is created every time [`workspace()`] is called.
 /// This may impact performance as we always allocate memory. However this allows safe concurrency.
+/// If the curve is only used within one thread, consider using [`PooledSpace`] instead.
 ///
 /// [`workspace()`]: DynSpace::workspace()
-#[cfg(feature = "std")]
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct DynSpace<T> {
@@ -72,10 +97,10 @@ pub struct DynSpace<T> {
     _phantom: PhantomData<fn() -> T>,
 }
 
-#[cfg(feature = "std")]
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
 impl<T> Space<T> for DynSpace<T>
 where
-    T: Default + Copy,
+    T: Default + Clone,
 {
     type Output = Vec<T>;
     fn len(&self) -> usize {
@@ -86,13 +111,281 @@ where
     }
 }
 
-#[cfg(feature = "std")]
+/// Struct which handles workspace at run-time.
+///
+/// A new `Vec` is created with the allocator `A` every time [`workspace()`] is called.
+/// This may impact performance as we always allocate memory. However this allows safe concurrency.
+/// Using a pool or bump allocator may reduce the cost of these allocations.
+///
+/// [`workspace()`]: DynSpace::workspace()
+#[cfg(feature = "allocator_api")]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DynSpace<T, A: Allocator = Global> {
+    len: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    alloc: A,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T, A> Space<T> for DynSpace<T, A>
+where
+    T: Default + Clone,
+    A: Allocator + Clone,
+{
+    type Output = Vec<T, A>;
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn workspace(&self) -> Self::Output {
+        let mut workspace = Vec::with_capacity_in(self.len, self.alloc.clone());
+        workspace.resize(self.len, Default::default());
+        workspace
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T, A: Allocator> DynSpace<T, A> {
+    /// Create a workspace with given length at run-time, which allocates with the given allocator.
+    pub fn new_in(len: usize, alloc: A) -> Self {
+        DynSpace {
+            len,
+            alloc,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+impl<T> Hash for DynSpace<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T, A: Allocator> Hash for DynSpace<T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+    }
+}
+
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+impl<T> PartialEq for DynSpace<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+    }
+}
+
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+impl<T> Eq for DynSpace<T> {}
+
+#[cfg(feature = "allocator_api")]
+impl<T, A: Allocator> PartialEq for DynSpace<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T, A: Allocator> Eq for DynSpace<T, A> {}
+
+#[cfg(feature = "alloc")]
 impl<T> DynSpace<T> {
     /// Create a workspace with given length at run-time.
     pub fn new(len: usize) -> Self {
         DynSpace {
             len,
+            #[cfg(feature = "allocator_api")]
+            alloc: Global,
             _phantom: PhantomData,
         }
     }
 }
+
+/// Struct which handles workspace at run-time and reuses it between calls.
+///
+/// Every time [`workspace()`] is called, a `Vec` is taken out of a pool and handed out as [`Pooled`].
+/// When it is dropped, the `Vec` is put back into the pool, such that memory is only allocated
+/// if the pool is empty. This happens for the first call or if multiple workspaces are in use at the same time.
+///
+/// The pool is shared by all clones of the space, as such this struct is neither `Send` nor `Sync`.
+/// That is, curves using this workspace can not be shared between threads. Use [`DynSpace`] instead
+/// or create a curve for each thread.
+///
+/// [`workspace()`]: PooledSpace::workspace()
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PooledSpace<T> {
+    len: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pool: Rc<RefCell<Vec<Vec<T>>>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> PooledSpace<T> {
+    /// Create a pooled workspace with given length at run-time.
+    ///
+    /// No memory is allocated until the workspace is used.
+    pub fn new(len: usize) -> Self {
+        PooledSpace {
+            len,
+            pool: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Space<T> for PooledSpace<T>
+where
+    T: Default + Clone,
+{
+    type Output = Pooled<T>;
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn workspace(&self) -> Self::Output {
+        let mut workspace = self.pool.borrow_mut().pop().unwrap_or_default();
+        // reset the content, as the workspace may be in any state after being used
+        workspace.clear();
+        workspace.resize(self.len, Default::default());
+        Pooled {
+            workspace,
+            pool: Rc::clone(&self.pool),
+        }
+    }
+}
+
+// the pool is only a cache of allocations, such that spaces of the same length are equal
+#[cfg(feature = "alloc")]
+impl<T> PartialEq for PooledSpace<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Eq for PooledSpace<T> {}
+
+#[cfg(feature = "alloc")]
+impl<T> Hash for PooledSpace<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+    }
+}
+
+/// Workspace handed out by [`PooledSpace`], which is put back into the pool when dropped.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct Pooled<T> {
+    workspace: Vec<T>,
+    pool: Rc<RefCell<Vec<Vec<T>>>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsMut<[T]> for Pooled<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.workspace
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Drop for Pooled<T> {
+    fn drop(&mut self) {
+        let workspace = core::mem::take(&mut self.workspace);
+        self.pool.borrow_mut().push(workspace);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod test {
+    use super::*;
+    #[cfg(all(feature = "allocator_api", feature = "bezier"))]
+    use core::alloc::{AllocError, Layout};
+    #[cfg(all(feature = "allocator_api", feature = "bezier"))]
+    use core::cell::{Cell, UnsafeCell};
+    #[cfg(all(feature = "allocator_api", feature = "bezier"))]
+    use core::ptr::NonNull;
+
+    #[test]
+    fn pooled() {
+        let space = PooledSpace::<f64>::new(3);
+        assert_eq!(space.len(), 3);
+        {
+            let mut first = space.workspace();
+            first.as_mut()[1] = 5.0;
+            // the first workspace is still in use
+            let mut second = space.workspace();
+            assert_eq!(second.as_mut(), &[0.0; 3]);
+        }
+        assert_eq!(space.pool.borrow().len(), 2);
+        // workspaces are reused and reset
+        for _ in 0..10 {
+            let mut workspace = space.workspace();
+            assert_eq!(workspace.as_mut(), &[0.0; 3]);
+            workspace.as_mut()[0] = 1.0;
+        }
+        assert_eq!(space.pool.borrow().len(), 2);
+        // clones share the pool
+        let clone = space.clone();
+        drop(clone.workspace());
+        assert_eq!(space.pool.borrow().len(), 2);
+        // equality only depends on the length
+        assert_eq!(space, PooledSpace::new(3));
+        assert_ne!(space, PooledSpace::new(4));
+    }
+
+    /// Allocator handing out consecutive parts of a fixed buffer, never freeing anything.
+    #[cfg(all(feature = "allocator_api", feature = "bezier"))]
+    #[repr(align(16))]
+    struct Bump {
+        memory: UnsafeCell<[u8; 1024]>,
+        offset: Cell<usize>,
+        allocations: Cell<usize>,
+    }
+
+    #[cfg(all(feature = "allocator_api", feature = "bezier"))]
+    unsafe impl Allocator for Bump {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            let start = (self.offset.get() + layout.align() - 1) & !(layout.align() - 1);
+            let end = start + layout.size();
+            if end > 1024 || layout.align() > 16 {
+                return Err(AllocError);
+            }
+            self.offset.set(end);
+            self.allocations.set(self.allocations.get() + 1);
+            // SAFETY: start and end are inside of the buffer
+            let ptr = unsafe { (self.memory.get() as *mut u8).add(start) };
+            Ok(NonNull::slice_from_raw_parts(
+                NonNull::new(ptr).ok_or(AllocError)?,
+                layout.size(),
+            ))
+        }
+        unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+    }
+
+    #[test]
+    #[cfg(all(feature = "allocator_api", feature = "bezier"))]
+    fn custom_allocator() {
+        use crate::{bezier::Bezier, Curve};
+        let bump = Bump {
+            memory: UnsafeCell::new([0; 1024]),
+            offset: Cell::new(0),
+            allocations: Cell::new(0),
+        };
+        let bez = Bezier::builder()
+            .elements([20.0, 100.0, 0.0, 200.0])
+            .normalized::<f64>()
+            .workspace(DynSpace::new_in(4, &bump))
+            .build()
+            .unwrap();
+        let expected = [20.0, 53.75, 65.0, 98.75, 200.0];
+        for (val, exp) in bez.take(5).zip(expected) {
+            assert_f64_near!(val, exp);
+        }
+        assert_eq!(bump.allocations.get(), 5);
+    }
+}
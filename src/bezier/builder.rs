@@ -6,7 +6,7 @@ use super::error::{BezierError, Empty};
 use super::{Bezier, TooSmallWorkspace};
 use crate::builder::{InputDomain, NormalizedInput, Unknown, WithWeight, WithoutWeight};
 use crate::weights::{Homogeneous, IntoWeight, Weighted, Weights};
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 use crate::DynSpace;
 use crate::{
     ConstDiscreteGenerator, ConstSpace, DiscreteGenerator, Generator, Space, TransformInput,
@@ -315,7 +315,7 @@ where
     /// Tells the builder to use a vector as workspace,
     /// such you don't need to know the degree of the bezier curve at compile-time,
     /// but every generation of a value an allocation of memory will be necessary.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub fn dynamic(self) -> BezierDirector<I, E, DynSpace<E::Output>, W> {
         BezierDirector {
             input: self.input,
@@ -378,7 +378,7 @@ where
     /// Tells the builder to use a vector as workspace,
     /// such you don't need to know the degree of the bezier curve at compile-time,
     /// but every generation of a value an allocation of memory will be necessary.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub fn dynamic(self) -> BezierBuilder<I, E, DynSpace<E::Output>, W> {
         BezierBuilder {
             inner: self.inner.map(|director| director.dynamic()),
@@ -25,11 +25,24 @@
 //! Bezier curves are polynomial curves with their degree given by the number of elements they consist of.
 //!
 //! [`BezierBuilder`]: BezierBuilder
+use crate::base::bounds;
 use crate::builder::Unknown;
-use crate::{Curve, DiscreteGenerator, Generator, Space};
+use crate::weights::{Homogeneous, Weighted};
+#[cfg(feature = "alloc")]
+use crate::DynSpace;
+use crate::{
+    Bounded, ConstDiscreteGenerator, ConstSpace, Curve, Derivative, DiscreteGenerator, Generator,
+    Space,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use core::ops::Add;
 use core::ops::{Mul, Sub};
 use num_traits::cast::FromPrimitive;
+use num_traits::identities::One;
 use num_traits::real::Real;
 use topology_traits::Merge;
 
@@ -156,13 +169,17 @@ where
 ///
 /// See [bezier module] for more information.
 ///
+/// The curve implements [`Hash`] and [`Eq`] if its elements and workspace do, such that it can be used as key for caching.
+/// As floats do not implement [`Hash`], they have to be wrapped in a hashable type,
+/// for example `OrderedFloat` of the crate `ordered-float`.
+///
 /// [bezier module]: self
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Bezier<R, E, S> {
     elements: E,
     space: S,
-    _input: PhantomData<*const R>,
+    _input: PhantomData<fn() -> R>,
 }
 
 impl Bezier<Unknown, Unknown, Unknown> {
@@ -207,6 +224,50 @@ impl Bezier<Unknown, Unknown, Unknown> {
     pub fn builder() -> BezierBuilder<Unknown, Unknown, Unknown, Unknown> {
         BezierBuilder::new()
     }
+
+    /// Create the rational quadratic bezier curve of the given points and the weight of the middle point.
+    ///
+    /// Such curves represent conic sections exactly: a weight less than 1 results in an ellipse,
+    /// a weight of 1 in a parabola and a weight bigger than 1 in a hyperbola.
+    /// A weight of 0 results in the line between the first and last point.
+    ///
+    /// A circular arc with an angle `a` less than 180° is given by the two points on the circle and
+    /// the intersection of their tangents as middle point, with a weight of `cos(a/2)`.
+    /// For bigger arcs, see [`BSpline::circular_arc()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::Bezier, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// // quarter of the unit circle, with each coordinate as its own curve
+    /// let arc = Bezier::rational_conic(1.0, 1.0, 0.0, core::f64::consts::FRAC_1_SQRT_2);
+    /// let other = Bezier::rational_conic(0.0, 1.0, 1.0, core::f64::consts::FRAC_1_SQRT_2);
+    /// for t in [0.0, 0.3, 0.5, 0.8, 1.0] {
+    ///     let (x, y): (f64, f64) = (arc.gen(t), other.gen(t));
+    ///     assert_f64_near!(x * x + y * y, 1.0);
+    /// }
+    /// ```
+    ///
+    /// [`BSpline::circular_arc()`]: crate::bspline::BSpline::circular_arc()
+    #[allow(clippy::type_complexity)]
+    pub fn rational_conic<P, R>(
+        first: P,
+        middle: P,
+        last: P,
+        weight: R,
+    ) -> Weighted<Bezier<R, [Homogeneous<P, R>; 3], ConstSpace<Homogeneous<P, R>, 3>>>
+    where
+        P: Mul<R, Output = P> + Default + Copy,
+        R: One + Default + Copy,
+    {
+        let elements = [
+            Homogeneous::new(first),
+            Homogeneous::weighted_unchecked(middle, weight),
+            Homogeneous::new(last),
+        ];
+        Weighted::new(Bezier::new_unchecked(elements, ConstSpace::new()))
+    }
 }
 
 impl<R, E, S> Bezier<R, E, S>
@@ -285,6 +346,209 @@ where
     }
 }
 
+impl<R, E, S> Bezier<R, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Bounded + Clone,
+{
+    /// Returns the axis-aligned bounding box of the elements, as minimum and maximum in this order.
+    ///
+    /// As a bezier curve lies within the convex hull of its elements, the box is conservative,
+    /// that is, it always contains the whole curve but may be bigger than necessary.
+    /// For a tighter but approximate box, see [`Curve::sample_bounds()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Curve};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///     .elements([0.0, 4.0, 2.0])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// assert_eq!(bezier.control_bounds(), [0.0, 4.0]);
+    /// // the curve itself does not get near the middle element
+    /// let [_, max] = bezier.sample_bounds(101);
+    /// assert!(max < 3.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Curve::sample_bounds()`]: crate::Curve::sample_bounds()
+    pub fn control_bounds(&self) -> [E::Output; 2] {
+        // bezier curves always have at least one element
+        bounds(self.elements.iter()).unwrap()
+    }
+}
+
+impl<R, E, S> Derivative<R> for Bezier<R, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Mul<R, Output = E::Output> + Sub<Output = E::Output> + Copy,
+    S: Space<E::Output>,
+    R: Real + FromPrimitive,
+{
+    fn derivative(&self, scalar: R) -> Self::Output {
+        let [_, tangent] = self.gen_with_tangent(scalar);
+        tangent
+    }
+}
+
+/// Split the bezier curve given by `points` at `scalar` with the de Casteljau algorithm.
+///
+/// The control points of both parts are written into `left` and `right`. `points` is used as workspace.
+fn split<R, T>(points: &mut [T], left: &mut [T], right: &mut [T], scalar: R)
+where
+    T: Merge<R> + Copy,
+    R: Real,
+{
+    let last = points.len() - 1;
+    left[0] = points[0];
+    right[last] = points[last];
+    for level in 1..=last {
+        for i in 0..=(last - level) {
+            points[i] = points[i].merge(points[i + 1], scalar);
+        }
+        left[level] = points[0];
+        right[last - level] = points[last - level];
+    }
+}
+
+impl<R, E, S> Bezier<R, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Default + Copy,
+    R: Real,
+{
+    /// Split the curve at `scalar` into two bezier curves of the same degree.
+    ///
+    /// The first curve maps its domain onto `[0.0, scalar]` of this curve, the second one onto `[scalar, 1.0]`,
+    /// such that together they reproduce this curve. The control points of both are calculated
+    /// with the de Casteljau algorithm and stored in arrays, such that no allocation is needed.
+    /// Use [`split_dynamic()`] for curves whose number of elements is not known at compile-time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///     .elements([0.0, 4.0, 2.0])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// let (left, right) = bezier.split(0.25);
+    /// assert_f64_near!(left.gen(1.0), bezier.gen(0.25));
+    /// assert_f64_near!(right.gen(0.5), bezier.gen(0.625));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`split_dynamic()`]: Bezier::split_dynamic()
+    #[allow(clippy::type_complexity)]
+    pub fn split<const N: usize>(
+        &self,
+        scalar: R,
+    ) -> (
+        Bezier<R, [E::Output; N], ConstSpace<E::Output, N>>,
+        Bezier<R, [E::Output; N], ConstSpace<E::Output, N>>,
+    )
+    where
+        E: ConstDiscreteGenerator<N>,
+    {
+        let mut points = self.elements.to_array();
+        let mut left = points;
+        let mut right = points;
+        split(&mut points, &mut left, &mut right, scalar);
+        (
+            Bezier::new_unchecked(left, ConstSpace::new()),
+            Bezier::new_unchecked(right, ConstSpace::new()),
+        )
+    }
+
+    /// Split the curve at `scalar` into two bezier curves of the same degree.
+    ///
+    /// Works the same as [`split()`], but stores the control points of the new curves in vectors.
+    ///
+    /// [`split()`]: Bezier::split()
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    pub fn split_dynamic(
+        &self,
+        scalar: R,
+    ) -> (
+        Bezier<R, Vec<E::Output>, DynSpace<E::Output>>,
+        Bezier<R, Vec<E::Output>, DynSpace<E::Output>>,
+    ) {
+        let mut points: Vec<E::Output> = (0..self.elements.len())
+            .map(|i| self.elements.gen(i))
+            .collect();
+        let mut left = points.clone();
+        let mut right = points.clone();
+        split(&mut points, &mut left, &mut right, scalar);
+        let len = points.len();
+        (
+            Bezier::new_unchecked(left, DynSpace::new(len)),
+            Bezier::new_unchecked(right, DynSpace::new(len)),
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R, E, S> Bezier<R, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Add<Output = E::Output> + Mul<R, Output = E::Output> + Default + Copy,
+    S: Space<E::Output>,
+    R: Real + FromPrimitive,
+{
+    /// Returns the antiderivative of this curve.
+    ///
+    /// The returned curve has one degree more than this curve and its derivative is this curve.
+    /// The constant of integration is chosen such that the returned curve starts at zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// // constant velocity of 2.0
+    /// let velocity = Bezier::builder()
+    ///     .elements([2.0, 2.0])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// let position = velocity.integrate();
+    /// assert_f64_near!(position.gen(0.0), 0.0);
+    /// assert_f64_near!(position.gen(0.5), 1.0);
+    /// assert_f64_near!(position.gen(1.0), 2.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn integrate(&self) -> Bezier<R, Vec<E::Output>, DynSpace<E::Output>> {
+        let len = self.elements.len();
+        let factor = R::from_usize(len).unwrap().recip();
+        let mut elements = Vec::with_capacity(len + 1);
+        // take a zero out vector as the start of the integral
+        let mut sum = self.elements.gen(0) * R::zero();
+        elements.push(sum);
+        for i in 0..len {
+            sum = sum + self.elements.gen(i) * factor;
+            elements.push(sum);
+        }
+        Bezier::new_unchecked(elements, DynSpace::new(len + 1))
+    }
+}
+
 impl<R, E, S> Bezier<R, E, S>
 where
     E: DiscreteGenerator,
@@ -324,10 +588,172 @@ where
     }
 }
 
+impl<R, E, S> Bezier<R, E, S> {
+    /// Returns the elements (control points) of the curve.
+    ///
+    /// As every collection of elements is a [`DiscreteGenerator`], they can be read back with its
+    /// [`iter()`] method, for example to export the curve into another format.
+    ///
+    /// [`iter()`]: DiscreteGenerator::iter()
+    pub fn elements(&self) -> &E {
+        &self.elements
+    }
+
+    /// Collect the elements (control points) of the curve into a vector.
+    ///
+    /// In contrast to [`elements()`], the returned vector is an owned and editable snapshot of the elements,
+    /// no matter if they are stored in an array, borrowed or generated by a closure.
+    ///
+    /// [`elements()`]: Bezier::elements()
+    #[cfg(feature = "alloc")]
+    pub fn control_points_vec(&self) -> Vec<E::Output>
+    where
+        E: DiscreteGenerator,
+    {
+        self.elements.iter().collect()
+    }
+}
+
+// implemented by hand, as deriving would require `R: Hash`
+impl<R, E, S> Hash for Bezier<R, E, S>
+where
+    E: Hash,
+    S: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.elements.hash(state);
+        self.space.hash(state);
+    }
+}
+
+// implemented by hand, as deriving would require `R: PartialEq`
+impl<R, E, S> PartialEq for Bezier<R, E, S>
+where
+    E: PartialEq,
+    S: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.elements == other.elements && self.space == other.space
+    }
+}
+
+impl<R, E, S> Eq for Bezier<R, E, S>
+where
+    E: Eq,
+    S: Eq,
+{
+}
+
+impl<R, T, const N: usize> Bezier<R, [T; N], ConstSpace<T, N>> {
+    /// Evaluated at compile-time to reject curves without any element.
+    const NON_EMPTY: () = assert!(N > 0, "a bezier curve needs at least one element");
+
+    /// Create a bezier curve from an array of elements at compile-time.
+    ///
+    /// The domain of the curve is [0.0,1.0] and the workspace is allocated on the stack.
+    /// As this constructor is `const`, it can be used to initialize `const` and `static` items.
+    /// Giving an empty array results in a compile error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::Bezier, ConstSpace, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// static EASE: Bezier<f64, [f64; 4], ConstSpace<f64, 4>> =
+    ///     Bezier::new_const([0.0, 0.0, 1.0, 1.0]);
+    ///
+    /// assert_f64_near!(EASE.gen(0.5), 0.5);
+    /// ```
+    pub const fn new_const(elements: [T; N]) -> Self {
+        let () = Self::NON_EMPTY;
+        Bezier {
+            elements,
+            space: ConstSpace::new(),
+            _input: PhantomData,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::ConstSpace;
+    use crate::Stepper;
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn control_points_vec() {
+        use crate::easing::FuncEase;
+        use crate::Equidistant;
+        let array = Bezier::builder()
+            .elements([1.0, 4.0, 2.0])
+            .normalized::<f64>()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        assert_eq!(array.control_points_vec(), vec![1.0, 4.0, 2.0]);
+        // elements generated by a closure
+        let squares = Equidistant::<f64>::new(4, 0.0, 3.0).composite(FuncEase::new(|x: f64| x * x));
+        let generated = Bezier::builder()
+            .elements(squares)
+            .normalized::<f64>()
+            .dynamic()
+            .build()
+            .unwrap();
+        let mut points = generated.control_points_vec();
+        assert_eq!(points, vec![0.0, 1.0, 4.0, 9.0]);
+        // the snapshot can be edited and used to build a new curve
+        points[3] = 1.0;
+        let edited = Bezier::builder()
+            .elements(points)
+            .normalized::<f64>()
+            .dynamic()
+            .build()
+            .unwrap();
+        assert_f64_near!(edited.gen(1.0), 1.0);
+    }
+
+    #[test]
+    fn rational_conic() {
+        // 120 degree arc of the unit circle, each coordinate as its own curve
+        let half = 60.0f64.to_radians();
+        let x = Bezier::rational_conic(half.cos(), 1.0 / half.cos(), half.cos(), half.cos());
+        let y = Bezier::rational_conic(-half.sin(), 0.0, half.sin(), half.cos());
+        for t in Stepper::normalized(50) {
+            assert_f64_near!(x.gen(t).hypot(y.gen(t)), 1.0);
+        }
+        assert_f64_near!(x.gen(0.5), 1.0);
+        // a weight of one is the usual quadratic bezier curve
+        let parabola = Bezier::rational_conic(0.0, 4.0, 1.0, 1.0);
+        let bezier = Bezier::builder()
+            .elements([0.0, 4.0, 1.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        for t in Stepper::normalized(10) {
+            assert_f64_near!(parabola.gen(t), bezier.gen(t));
+        }
+    }
+
+    #[test]
+    fn bounds() {
+        let bez = Bezier::builder()
+            .elements([0.0, 3.0, -1.0, 2.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        assert_eq!(bez.control_bounds(), [-1.0, 3.0]);
+        let [min, max] = bez.sample_bounds(50);
+        assert_f64_near!(min, 0.0);
+        assert_f64_near!(max, 2.0);
+        for val in Stepper::new(100, 0.0, 1.0).map(|t| bez.gen(t)) {
+            assert!((-1.0..=3.0).contains(&val));
+        }
+    }
 
     #[test]
     fn extrapolation() {
@@ -341,6 +767,68 @@ mod test {
         assert_f64_near!(bez.gen(-1.0), 280.0);
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn split() {
+        let bezier = Bezier::builder()
+            .elements([1.0, -2.0, 4.0, 3.0, 0.5])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        for at in [0.0, 0.3, 0.5, 0.9, 1.0] {
+            let (left, right) = bezier.split(at);
+            let (dynamic_left, dynamic_right) = bezier.split_dynamic(at);
+            for t in Stepper::normalized(21) {
+                let expected = bezier.gen(at * t);
+                assert_float_absolute_eq!(left.gen(t), expected, 1e-12);
+                assert_float_absolute_eq!(dynamic_left.gen(t), expected, 1e-12);
+                let expected = bezier.gen(at + t * (1.0 - at));
+                assert_float_absolute_eq!(right.gen(t), expected, 1e-12);
+                assert_float_absolute_eq!(dynamic_right.gen(t), expected, 1e-12);
+            }
+        }
+        // both parts meet at the split
+        let (left, right) = bezier.split(0.4);
+        assert_f64_near!(left.gen(1.0), right.gen(0.0));
+        // a single element can be split as well
+        let point: Bezier<f64, _, _> = Bezier::new_const([2.0]);
+        let (left, right) = point.split(0.5);
+        assert_f64_near!(left.gen(0.3), 2.0);
+        assert_f64_near!(right.gen(0.7), 2.0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash() {
+        use std::collections::hash_map::RandomState;
+        use std::collections::HashMap;
+        use std::hash::BuildHasher;
+        let first: Bezier<f64, _, _> = Bezier::new_const([1, 5, 3]);
+        let second: Bezier<f64, _, _> = Bezier::new([1, 5, 3], ConstSpace::<_, 3>::new()).unwrap();
+        let other: Bezier<f64, _, _> = Bezier::new_const([1, 5, 4]);
+        let state = RandomState::new();
+        assert_eq!(state.hash_one(first), state.hash_one(second));
+        assert_ne!(state.hash_one(first), state.hash_one(other));
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        // curves can be used as keys
+        let mut cache = HashMap::new();
+        cache.insert(first, "first");
+        assert_eq!(cache.get(&second), Some(&"first"));
+        assert_eq!(cache.get(&other), None);
+    }
+
+    #[test]
+    fn const_creation() {
+        static BEZ: Bezier<f64, [f64; 4], ConstSpace<f64, 4>> =
+            Bezier::new_const([20.0, 100.0, 0.0, 200.0]);
+        let expected = [20.0, 53.75, 65.0, 98.75, 200.0];
+        for (val, exp) in BEZ.take(5).zip(expected) {
+            assert_f64_near!(val, exp);
+        }
+    }
+
     #[test]
     fn bigger_workspace() {
         let bez = Bezier::new([5.0], ConstSpace::<_, 3>::new()).unwrap();
@@ -388,4 +876,37 @@ mod test {
         assert_f64_near!(res[3], 0.0);
         assert_f64_near!(res[4], 0.0);
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn integrate() {
+        let bez = Bezier::builder()
+            .elements([1.0, -2.0, 5.0, 3.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let integral = bez.integrate();
+        assert_f64_near!(integral.gen(0.0), 0.0);
+        for (input, value) in Stepper::normalized(11).zip(bez.take(11)) {
+            assert_f64_near!(integral.gen_with_tangent(input)[1], value);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let bez = Bezier::builder()
+            .elements([20.0, 0.0, 200.0])
+            .normalized::<f64>()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let json = serde_json::to_string(&bez).unwrap();
+        let restored: Bezier<f64, [f64; 3], ConstSpace<f64, 3>> =
+            serde_json::from_str(&json).unwrap();
+        for t in Stepper::normalized(11) {
+            assert_eq!(restored.gen(t), bez.gen(t));
+        }
+    }
 }
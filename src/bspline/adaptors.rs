@@ -1,5 +1,6 @@
 use crate::builder::TooFewElements;
 use crate::{DiscreteGenerator, Generator, SortedGenerator};
+use core::ops::{Add, Sub};
 
 /// DiscreteGenerator Adaptor which repeats its first and last element `n` more times.
 #[derive(Debug, Copy, Clone)]
@@ -39,6 +40,19 @@ where
     }
 }
 
+impl<G> BorderBuffer<G> {
+    /// Transforms the inner generator.
+    pub(crate) fn map_inner<H, F>(self, func: F) -> BorderBuffer<H>
+    where
+        F: FnOnce(G) -> H,
+    {
+        BorderBuffer {
+            inner: func(self.inner),
+            n: self.n,
+        }
+    }
+}
+
 impl<G> Generator<usize> for BorderBuffer<G>
 where
     G: DiscreteGenerator,
@@ -108,6 +122,18 @@ where
     }
 }
 
+impl<G> BorderDeletion<G> {
+    /// Transforms the inner generator, which must not change the number of elements.
+    pub(crate) fn map_inner<H, F>(self, func: F) -> BorderDeletion<H>
+    where
+        F: FnOnce(G) -> H,
+    {
+        BorderDeletion {
+            inner: func(self.inner),
+        }
+    }
+}
+
 impl<G> Generator<usize> for BorderDeletion<G>
 where
     G: DiscreteGenerator,
@@ -145,10 +171,73 @@ where
     }
 }
 
+/// DiscreteGenerator Adaptor which continues the knots of one period to both sides.
+///
+/// The underlying generator contains the knots of one period, that is, its first and last knot
+/// mark the start and end of the period. To both sides `n` knots are added, which are copies
+/// of the knots at the other side, shifted by the length of the period.
+///
+/// # Panics
+///
+/// Using this Generator may panic if the underlying generator has not more than `n` elements.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PeriodicKnots<G> {
+    inner: G,
+    n: usize,
+}
+
+impl<G> PeriodicKnots<G>
+where
+    G: DiscreteGenerator,
+{
+    /// Creates a generator which continues the knots of the given period `n` times to both sides.
+    pub fn new(inner: G, n: usize) -> Self {
+        PeriodicKnots { inner, n }
+    }
+}
+
+impl<G> Generator<usize> for PeriodicKnots<G>
+where
+    G: DiscreteGenerator,
+    G::Output: Add<Output = G::Output> + Sub<Output = G::Output> + Copy,
+{
+    type Output = G::Output;
+    fn gen(&self, input: usize) -> Self::Output {
+        let last = self.inner.len() - 1;
+        let period = self.inner.gen(last) - self.inner.gen(0);
+        if input < self.n {
+            return self.inner.gen(last - self.n + input) - period;
+        }
+        let index = input - self.n;
+        if index > last {
+            return self.inner.gen(index - last) + period;
+        }
+        self.inner.gen(index)
+    }
+}
+
+impl<G> DiscreteGenerator for PeriodicKnots<G>
+where
+    G: DiscreteGenerator,
+    G::Output: Add<Output = G::Output> + Sub<Output = G::Output> + Copy,
+{
+    fn len(&self) -> usize {
+        self.inner.len() + 2 * self.n
+    }
+}
+
+impl<G> SortedGenerator for PeriodicKnots<G>
+where
+    G: SortedGenerator,
+    G::Output: Add<Output = G::Output> + Sub<Output = G::Output> + Copy,
+{
+}
+
 #[cfg(test)]
 mod test {
-    use super::{BorderBuffer, BorderDeletion};
-    use crate::{DiscreteGenerator, Equidistant, SortedGenerator};
+    use super::{BorderBuffer, BorderDeletion, PeriodicKnots};
+    use crate::{DiscreteGenerator, Equidistant, Generator, Sorted, SortedGenerator};
 
     #[test]
     fn borderdeletion() {
@@ -177,4 +266,17 @@ mod test {
         assert_eq!(buf.strict_upper_bound_clamped(0.8, 1, 5), 5);
         assert_eq!(buf.strict_upper_bound_clamped(0.45, 3, 9), 8);
     }
+
+    #[test]
+    fn periodicknots() {
+        let knots = PeriodicKnots::new(Sorted::new([0.0, 1.0, 3.0, 4.0]).unwrap(), 2);
+        assert_eq!(knots.len(), 8);
+        let expected = [-3.0, -1.0, 0.0, 1.0, 3.0, 4.0, 5.0, 7.0];
+        for (i, knot) in expected.as_slice().iter().enumerate() {
+            assert_eq!(knots.gen(i), *knot);
+        }
+        assert_eq!(knots.strict_upper_bound(-2.0), 1);
+        assert_eq!(knots.strict_upper_bound(3.5), 5);
+        assert_eq!(knots.strict_upper_bound(6.0), 7);
+    }
 }
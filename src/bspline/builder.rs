@@ -2,20 +2,24 @@
 //!
 //! Each interpolation has it's own builder module, which accumalates all methods to create their interpolation.
 
-use super::adaptors::{BorderBuffer, BorderDeletion};
+use super::adaptors::{BorderBuffer, BorderDeletion, PeriodicKnots};
 use super::error::{
     BSplineError, IncongruousElementsDegree, IncongruousElementsKnots, InvalidDegree, TooFewKnots,
 };
+#[cfg(feature = "alloc")]
+use super::BSplineDiagnosis;
 use super::{BSpline, TooFewElements, TooSmallWorkspace};
 use crate::builder::{Type, Unknown, WithWeight, WithoutWeight};
-use crate::weights::{Homogeneous, IntoWeight, Weighted, Weights};
-#[cfg(feature = "std")]
-use crate::DynSpace;
+use crate::weights::{IntoWeight, Project, Rescaled, Weighted, Weights};
 use crate::{
-    ConstSpace, DiscreteGenerator, Equidistant, Generator, Sorted, SortedGenerator, Space,
+    BinarySearch, ConstSpace, DiscreteGenerator, Equidistant, Sorted, SortedGenerator, Space, Wrap,
 };
+#[cfg(feature = "alloc")]
+use crate::{DynSpace, PooledSpace};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::marker::PhantomData;
-use core::ops::{Div, Mul};
+use core::ops::Mul;
 use num_traits::identities::Zero;
 use num_traits::real::Real;
 use num_traits::FromPrimitive;
@@ -34,9 +38,10 @@ pub struct Open;
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Legacy;
-// #[derive(Debug, Clone, Copy)]
-// #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-// pub struct Closed;
+/// Marker struct to signify the building of a closed curve, whose start and end join smoothly.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Periodic;
 
 /// Marker Struct which saves data for equidistant.
 ///
@@ -67,6 +72,13 @@ impl<R> UnknownDomain<R> {
     }
 }
 
+/// Marker Struct which saves the knots of a periodic curve until its degree is known.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct UnknownDegree<K> {
+    knots: K,
+}
+
 /// Builder for bspline interpolation.
 ///
 /// This struct helps create bspline interpolations. The difference between this struct and [`BSplineBuilder`]
@@ -74,15 +86,15 @@ impl<R> UnknownDomain<R> {
 ///
 /// Before building, one has to give information for:
 /// - The elements the interpolation should use. Methods like [`elements()`] and [`elements_with_weights`()]
-///     exist for that cause.
+///   exist for that cause.
 /// - The knots the interpolation uses. Either by giving them directly with [`knots()`] or by using
-///     equidistant knots with [`equidistant()`].
+///   equidistant knots with [`equidistant()`].
 /// - A workspace to use, that is, a mutable slice-like object to do operations on.
-///     Usually this is done by calling [`constant()`] or [`dynamic()`].
-///     [`workspace()`] is also posbbile for a custom workspace.
+///   Usually this is done by calling [`constant()`] or [`dynamic()`].
+///   [`workspace()`] is also posbbile for a custom workspace.
 ///
-/// Furthermore one may want to use different modes, toggled by the methods [`open()`],[`clamped()`]
-/// and [`legacy()`], where [`open()`] is the default one.
+/// Furthermore one may want to use different modes, toggled by the methods [`open()`],[`clamped()`],
+/// [`legacy()`] and [`periodic()`], where [`open()`] is the default one.
 ///
 /// [`build()`]: BSplineDirector::build()
 /// [`BSplineBuilder`]: BSplineBuilder
@@ -96,6 +108,7 @@ impl<R> UnknownDomain<R> {
 /// [`open()`]: BSplineDirector::open()
 /// [`clamped()`]: BSplineDirector::clamped()
 /// [`legacy()`]: BSplineDirector::legacy()
+/// [`periodic()`]: BSplineDirector::periodic()
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct BSplineDirector<K, E, S, W, M> {
@@ -113,15 +126,15 @@ pub struct BSplineDirector<K, E, S, W, M> {
 ///
 /// Before building, one has to give information for:
 /// - The elements the interpolation should use. Methods like [`elements()`] and [`elements_with_weights`()]
-///     exist for that cause.
+///   exist for that cause.
 /// - The knots the interpolation uses. Either by giving them directly with [`knots()`] or by using
-///     equidistant knots with [`equidistant()`].
+///   equidistant knots with [`equidistant()`].
 /// - A workspace to use, that is, a mutable slice-like object to do operations on.
-///     Usually this is done by calling [`constant()`] or [`dynamic()`].
-///     [`workspace()`] is also posbbile for a custom workspace.
+///   Usually this is done by calling [`constant()`] or [`dynamic()`].
+///   [`workspace()`] is also posbbile for a custom workspace.
 ///
-/// Furthermore one may want to use different modes, toggled by the methods [`open()`],[`clamped()`]
-/// and [`legacy()`], where [`open()`] is the default one.
+/// Furthermore one may want to use different modes, toggled by the methods [`open()`],[`clamped()`],
+/// [`legacy()`] and [`periodic()`], where [`open()`] is the default one.
 ///
 /// [`build()`]: BSplineBuilder::build()
 /// [`builder()`]: super::BSpline::builder()
@@ -135,6 +148,7 @@ pub struct BSplineDirector<K, E, S, W, M> {
 /// [`open()`]: BSplineBuilder::open()
 /// [`clamped()`]: BSplineBuilder::clamped()
 /// [`legacy()`]: BSplineBuilder::legacy()
+/// [`periodic()`]: BSplineBuilder::periodic()
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct BSplineBuilder<K, E, S, W, M> {
@@ -210,12 +224,26 @@ impl<M> BSplineDirector<Unknown, Unknown, Unknown, Unknown, M> {
         }
     }
 
-    // /// Ensure the curve to be a loop, that is, its start and end point are equal and have a smooth transition.
-    // ///
-    // /// This method changes the underlying knot and element generator, by repeating some.
-    // pub fn loop(self) -> BSplineDirector<K,E, Unknown, W>{
-    //
-    // }
+    /// Change the mode to a periodic curve.
+    ///
+    /// The curve becomes a loop, that is, its start and end point are equal
+    /// and the curve is as smooth at its start and end as everywhere else.
+    /// To achieve this, the first `degree` elements are repeated at the end and
+    /// the knots are continued periodically to both sides.
+    ///
+    /// After setting the elements, either the knots of one period are given with [`knots()`]
+    /// followed by the degree of the curve or [`equidistant()`] knots are used.
+    ///
+    /// [`knots()`]: BSplineDirector::knots()
+    /// [`equidistant()`]: BSplineDirector::equidistant()
+    pub fn periodic(self) -> BSplineDirector<Unknown, Unknown, Unknown, Unknown, Periodic> {
+        BSplineDirector {
+            knots: self.knots,
+            space: self.space,
+            elements: self.elements,
+            _phantoms: (self._phantoms.0, PhantomData),
+        }
+    }
 
     /// Set the elements of the bspline interpolation.
     ///
@@ -242,6 +270,29 @@ impl<M> BSplineDirector<Unknown, Unknown, Unknown, Unknown, M> {
         })
     }
 
+    /// Set the elements of the bspline interpolation by collecting them into a vector.
+    ///
+    /// This is useful if the elements are only available as an iterator,
+    /// for example as the result of mapping another collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 elements are given.
+    ///
+    /// [`TooFewElements`]: super::error::BSplineError
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    pub fn elements_from_iter<I>(
+        self,
+        iter: I,
+    ) -> Result<BSplineDirector<Unknown, Vec<I::Item>, Unknown, WithoutWeight, M>, TooFewElements>
+    where
+        I: IntoIterator,
+        I::Item: Clone,
+    {
+        self.elements(iter.into_iter().collect())
+    }
+
     /// Set the elements and their weights for this interpolation.
     ///
     /// Weights of `Zero` can achieve unwanted results as their corresponding elements are considered
@@ -307,12 +358,23 @@ impl<M> BSplineBuilder<Unknown, Unknown, Unknown, Unknown, M> {
         }
     }
 
-    // /// Ensure the curve to be a loop, that is, its start and end point are equal and have a smooth transition.
-    // ///
-    // /// This method changes the underlying knot and element generator, by repeating some.
-    // pub fn loop(self) -> BSplineDirector<K,E, Unknown, W>{
-    //
-    // }
+    /// Change the mode to a periodic curve.
+    ///
+    /// The curve becomes a loop, that is, its start and end point are equal
+    /// and the curve is as smooth at its start and end as everywhere else.
+    /// To achieve this, the first `degree` elements are repeated at the end and
+    /// the knots are continued periodically to both sides.
+    ///
+    /// After setting the elements, either the knots of one period are given with [`knots()`]
+    /// followed by the degree of the curve or [`equidistant()`] knots are used.
+    ///
+    /// [`knots()`]: BSplineBuilder::knots()
+    /// [`equidistant()`]: BSplineBuilder::equidistant()
+    pub fn periodic(self) -> BSplineBuilder<Unknown, Unknown, Unknown, Unknown, Periodic> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.periodic()),
+        }
+    }
 
     /// Set the elements of the bspline interpolation.
     pub fn elements<E>(self, elements: E) -> BSplineBuilder<Unknown, E, Unknown, WithoutWeight, M>
@@ -326,6 +388,26 @@ impl<M> BSplineBuilder<Unknown, Unknown, Unknown, Unknown, M> {
         }
     }
 
+    /// Set the elements of the bspline interpolation by collecting them into a vector.
+    ///
+    /// This is useful if the elements are only available as an iterator,
+    /// for example as the result of mapping another collection.
+    #[cfg(feature = "alloc")]
+    pub fn elements_from_iter<I>(
+        self,
+        iter: I,
+    ) -> BSplineBuilder<Unknown, Vec<I::Item>, Unknown, WithoutWeight, M>
+    where
+        I: IntoIterator,
+        I::Item: Clone,
+    {
+        BSplineBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements_from_iter(iter).map_err(|err| err.into())),
+        }
+    }
+
     /// Set the elements and their weights for this interpolation.
     ///
     /// Weights of `Zero` can achieve unwanted results as their corresponding elements are considered
@@ -356,6 +438,49 @@ impl<M> BSplineBuilder<Unknown, Unknown, Unknown, Unknown, M> {
     }
 }
 
+/// Collect elements into a builder of a clamped bspline with equidistant knots.
+///
+/// The curve starts at the first and ends at the last element.
+/// Afterwards, only the degree, the domain and the workspace have to be given.
+/// Errors like too few elements are returned by [`build()`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{bspline::{BSpline, BSplineBuilder, BSplineError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), BSplineError> {
+/// let points = vec![0.0, 5.0, 3.0];
+/// let bspline = points
+///     .into_iter()
+///     .collect::<BSplineBuilder<_, _, _, _, _>>()
+///     .degree(2)
+///     .normalized()
+///     .dynamic()
+///     .build()?;
+/// assert_f64_near!(bspline.gen(0.0), 0.0);
+/// assert_f64_near!(bspline.gen(0.5), 3.25);
+/// assert_f64_near!(bspline.gen(1.0), 3.0);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: BSplineBuilder::build()
+#[cfg(feature = "alloc")]
+impl<T, R> FromIterator<T> for BSplineBuilder<Type<R>, Vec<T>, Unknown, WithoutWeight, Clamped>
+where
+    T: Clone,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        BSplineBuilder::new()
+            .clamped()
+            .elements_from_iter(iter)
+            .equidistant()
+    }
+}
+
 impl<E, W> BSplineDirector<Unknown, E, Unknown, W, Open> {
     /// Set the knots of the interpolation.
     ///
@@ -365,7 +490,7 @@ impl<E, W> BSplineDirector<Unknown, E, Unknown, W, Open> {
     ///
     /// Returns [`NotSorted`] if a knot is not greater or equal then the knot before him.
     /// Returns [`TooFewKnots`] if not at least 2 knots are given.
-    /// Returns [`IncongruousElementsKnots`] if less knots than elements or more knots than twice as many elements are given.
+    /// Returns [`IncongruousElementsKnots`] if less knots than elements - 1 or more knots than twice as many elements are given.
     ///
     /// # Performance
     ///
@@ -388,10 +513,10 @@ impl<E, W> BSplineDirector<Unknown, E, Unknown, W, Open> {
         if knots.len() < 2 {
             return Err(TooFewKnots::new(knots.len()).into());
         }
-        if knots.len() < self.elements.len() {
+        if knots.len() + 1 < self.elements.len() {
             return Err(IncongruousElementsKnots::open(self.elements.len(), knots.len()).into());
         }
-        if self.elements.len() <= knots.len() - self.elements.len() + 1 {
+        if self.elements.len() <= knots.len() + 1 - self.elements.len() {
             return Err(IncongruousElementsKnots::open(self.elements.len(), knots.len()).into());
         }
         Ok(BSplineDirector {
@@ -559,6 +684,321 @@ impl<E, W> BSplineBuilder<Unknown, E, Unknown, W, Legacy> {
     }
 }
 
+impl<E, W> BSplineDirector<Unknown, E, Unknown, W, Periodic> {
+    /// Set the knots of one period of the interpolation.
+    ///
+    /// The first and last knot are the start and end of the domain of the curve.
+    /// As the curve is closed, there has to be exactly one knot more than there are elements.
+    /// Afterwards the degree of the curve has to be set with [`degree()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotSorted`] if a knot is not greater or equal then the knot before him.
+    /// Returns [`IncongruousElementsKnots`] if the number of knots is not the number of elements + 1.
+    ///
+    /// # Performance
+    ///
+    /// If you have equidistant knots, near equidistant knots are you do not really care about
+    /// knots, consider using [`equidistant()`] instead.
+    ///
+    /// [`degree()`]: BSplineDirector::degree()
+    /// [`NotSorted`]: super::error::BSplineError
+    /// [`IncongruousElementsKnots`]: super::error::BSplineError
+    /// [`equidistant()`]: BSplineDirector::equidistant()
+    pub fn knots<K>(self, knots: K) -> Result<UnknownDegreeBSplineDirector<K, E, W>, BSplineError>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        if knots.len() != self.elements.len() + 1 {
+            return Err(
+                IncongruousElementsKnots::periodic(self.elements.len(), knots.len()).into(),
+            );
+        }
+        Ok(BSplineDirector {
+            knots: UnknownDegree {
+                knots: Sorted::new(knots)?,
+            },
+            elements: self.elements,
+            space: self.space,
+            _phantoms: self._phantoms,
+        })
+    }
+}
+
+impl<E, W> BSplineBuilder<Unknown, E, Unknown, W, Periodic> {
+    /// Set the knots of one period of the interpolation.
+    ///
+    /// The first and last knot are the start and end of the domain of the curve.
+    /// As the curve is closed, there has to be exactly one knot more than there are elements.
+    /// Afterwards the degree of the curve has to be set with [`degree()`].
+    ///
+    /// # Performance
+    ///
+    /// If you have equidistant knots, near equidistant knots are you do not really care about
+    /// knots, consider using [`equidistant()`] instead.
+    ///
+    /// [`degree()`]: BSplineBuilder::degree()
+    /// [`equidistant()`]: BSplineBuilder::equidistant()
+    pub fn knots<K>(
+        self,
+        knots: K,
+    ) -> BSplineBuilder<UnknownDegree<Sorted<K>>, E, Unknown, W, Periodic>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        BSplineBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+}
+
+impl<K, E, W> BSplineDirector<UnknownDegree<K>, E, Unknown, W, Periodic>
+where
+    E: DiscreteGenerator,
+    K: DiscreteGenerator,
+{
+    /// Set the degree of the curve.
+    ///
+    /// The degree of the curve has to be at least 1 and less than the number of elements.
+    /// The curve is continuous up to its `degree-1`th derivative everywhere, including where its
+    /// start and end join, as long as no knots are repeated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDegree`] if the degree is 0.
+    /// Returns [`IncongruousElementsDegree`] if given degree is not less than the amount of elements.
+    ///
+    /// [`InvalidDegree`]: super::error::BSplineError
+    /// [`IncongruousElementsDegree`]: super::error::BSplineError
+    pub fn degree(self, degree: usize) -> Result<PeriodicBSplineDirector<K, E, W>, BSplineError> {
+        if degree == 0 {
+            return Err(InvalidDegree::new(degree).into());
+        }
+        if self.elements.len() <= degree {
+            return Err(IncongruousElementsDegree::periodic(self.elements.len(), degree).into());
+        }
+        Ok(BSplineDirector {
+            knots: PeriodicKnots::new(self.knots.knots, degree - 1),
+            elements: Wrap::new(self.elements, degree),
+            space: self.space,
+            _phantoms: self._phantoms,
+        })
+    }
+}
+
+impl<K, E, W> BSplineBuilder<UnknownDegree<K>, E, Unknown, W, Periodic>
+where
+    E: DiscreteGenerator,
+    K: DiscreteGenerator,
+{
+    /// Set the degree of the curve.
+    ///
+    /// The degree of the curve has to be at least 1 and less than the number of elements.
+    /// The curve is continuous up to its `degree-1`th derivative everywhere, including where its
+    /// start and end join, as long as no knots are repeated.
+    pub fn degree(self, degree: usize) -> PeriodicBSplineBuilder<K, E, W> {
+        BSplineBuilder {
+            inner: self.inner.and_then(|director| director.degree(degree)),
+        }
+    }
+}
+
+impl<K, A, E, W> BSplineDirector<Sorted<K, A>, E, Unknown, W, Open> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<T>(self, strategy: T) -> BSplineDirector<Sorted<K, T>, E, Unknown, W, Open> {
+        BSplineDirector {
+            knots: self.knots.search(strategy),
+            elements: self.elements,
+            space: self.space,
+            _phantoms: self._phantoms,
+        }
+    }
+
+    /// Merge knots which are at most `epsilon` apart into a single knot with higher multiplicity.
+    ///
+    /// Knots coming from imported data are often only nearly equal, like `1.0` and `1.0000001`.
+    /// Such knots create tiny spans which are numerically ill-conditioned.
+    /// After snapping, knots of such a group are all equal to the smallest knot of the group.
+    pub fn snap_knots<R>(self, epsilon: R) -> Self
+    where
+        K: AsMut<[R]>,
+        R: Real,
+    {
+        BSplineDirector {
+            knots: self.knots.snap(epsilon),
+            elements: self.elements,
+            space: self.space,
+            _phantoms: self._phantoms,
+        }
+    }
+}
+
+impl<K, A, E, W> BSplineBuilder<Sorted<K, A>, E, Unknown, W, Open> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<T>(self, strategy: T) -> BSplineBuilder<Sorted<K, T>, E, Unknown, W, Open> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.search(strategy)),
+        }
+    }
+
+    /// Merge knots which are at most `epsilon` apart into a single knot with higher multiplicity.
+    ///
+    /// Knots coming from imported data are often only nearly equal, like `1.0` and `1.0000001`.
+    /// Such knots create tiny spans which are numerically ill-conditioned.
+    /// After snapping, knots of such a group are all equal to the smallest knot of the group.
+    pub fn snap_knots<R>(self, epsilon: R) -> Self
+    where
+        K: AsMut<[R]>,
+        R: Real,
+    {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.snap_knots(epsilon)),
+        }
+    }
+}
+
+impl<K, A, E, W> ClampedBSplineDirector<K, E, W, A> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<T>(self, strategy: T) -> ClampedBSplineDirector<K, E, W, T> {
+        BSplineDirector {
+            knots: self.knots.map_inner(|knots| knots.search(strategy)),
+            elements: self.elements,
+            space: self.space,
+            _phantoms: self._phantoms,
+        }
+    }
+
+    /// Merge knots which are at most `epsilon` apart into a single knot with higher multiplicity.
+    ///
+    /// Knots coming from imported data are often only nearly equal, like `1.0` and `1.0000001`.
+    /// Such knots create tiny spans which are numerically ill-conditioned.
+    /// After snapping, knots of such a group are all equal to the smallest knot of the group.
+    pub fn snap_knots<R>(self, epsilon: R) -> Self
+    where
+        K: AsMut<[R]>,
+        R: Real,
+    {
+        BSplineDirector {
+            knots: self.knots.map_inner(|knots| knots.snap(epsilon)),
+            elements: self.elements,
+            space: self.space,
+            _phantoms: self._phantoms,
+        }
+    }
+}
+
+impl<K, A, E, W> ClampedBSplineBuilder<K, E, W, A> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<T>(self, strategy: T) -> ClampedBSplineBuilder<K, E, W, T> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.search(strategy)),
+        }
+    }
+
+    /// Merge knots which are at most `epsilon` apart into a single knot with higher multiplicity.
+    ///
+    /// Knots coming from imported data are often only nearly equal, like `1.0` and `1.0000001`.
+    /// Such knots create tiny spans which are numerically ill-conditioned.
+    /// After snapping, knots of such a group are all equal to the smallest knot of the group.
+    pub fn snap_knots<R>(self, epsilon: R) -> Self
+    where
+        K: AsMut<[R]>,
+        R: Real,
+    {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.snap_knots(epsilon)),
+        }
+    }
+}
+
+impl<K, A, E, W> LegacyBSplineDirector<K, E, W, A> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<T>(self, strategy: T) -> LegacyBSplineDirector<K, E, W, T> {
+        BSplineDirector {
+            knots: self.knots.map_inner(|knots| knots.search(strategy)),
+            elements: self.elements,
+            space: self.space,
+            _phantoms: self._phantoms,
+        }
+    }
+
+    /// Merge knots which are at most `epsilon` apart into a single knot with higher multiplicity.
+    ///
+    /// Knots coming from imported data are often only nearly equal, like `1.0` and `1.0000001`.
+    /// Such knots create tiny spans which are numerically ill-conditioned.
+    /// After snapping, knots of such a group are all equal to the smallest knot of the group.
+    pub fn snap_knots<R>(self, epsilon: R) -> Self
+    where
+        K: AsMut<[R]>,
+        R: Real,
+    {
+        BSplineDirector {
+            knots: self.knots.map_inner(|knots| knots.snap(epsilon)),
+            elements: self.elements,
+            space: self.space,
+            _phantoms: self._phantoms,
+        }
+    }
+}
+
+impl<K, A, E, W> LegacyBSplineBuilder<K, E, W, A> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<T>(self, strategy: T) -> LegacyBSplineBuilder<K, E, W, T> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.search(strategy)),
+        }
+    }
+
+    /// Merge knots which are at most `epsilon` apart into a single knot with higher multiplicity.
+    ///
+    /// Knots coming from imported data are often only nearly equal, like `1.0` and `1.0000001`.
+    /// Such knots create tiny spans which are numerically ill-conditioned.
+    /// After snapping, knots of such a group are all equal to the smallest knot of the group.
+    pub fn snap_knots<R>(self, epsilon: R) -> Self
+    where
+        K: AsMut<[R]>,
+        R: Real,
+    {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.snap_knots(epsilon)),
+        }
+    }
+}
+
 impl<E, W, M> BSplineDirector<Unknown, E, Unknown, W, M> {
     /// Build an interpolation with equidistant knots.
     ///
@@ -614,7 +1054,9 @@ where
 {
     /// Set the degree of the curve.
     ///
-    /// The degree of the curve has to be at least 1 and be less than the number of elements.
+    /// The degree of the curve has to be less than the number of elements.
+    /// A curve of degree 0 is a step function, which needs at least 3 elements,
+    /// as its knots are the positions of the jumps between its elements.
     ///
     /// After this call, you also have to call either of
     /// - [`domain()`],
@@ -625,10 +1067,11 @@ where
     ///
     /// # Errors
     ///
-    /// Returns [`InvalidDegree`] if given degree is not at least 1.
     /// Returns [`IncongruousElementsDegree`] if given degree is not less than the amount of elements.
+    /// Returns [`TooFewKnots`] if the degree is 0 and less than 3 elements are given.
     ///
-    /// [`InvalidDegree`]: super::error::BSplineError
+    /// [`IncongruousElementsDegree`]: super::error::BSplineError
+    /// [`TooFewKnots`]: super::error::BSplineError
     /// [`domain()`]: BSplineDirector::domain()
     /// [`normalized()`]: BSplineDirector::normalized()
     /// [`distance()`]: BSplineDirector::distance()
@@ -636,12 +1079,12 @@ where
         self,
         degree: usize,
     ) -> Result<BSplineDirector<UnknownDomain<R>, E, Unknown, W, Open>, BSplineError> {
-        if degree < 1 {
-            return Err(InvalidDegree::new(degree).into());
-        }
         if self.elements.len() <= degree {
             return Err(IncongruousElementsDegree::open(self.elements.len(), degree).into());
         }
+        if self.elements.len() - 1 + degree < 2 {
+            return Err(TooFewKnots::new(self.elements.len() - 1 + degree).into());
+        }
         Ok(BSplineDirector {
             knots: UnknownDomain::new(self.elements.len() - 1 + degree, degree),
             elements: self.elements,
@@ -652,9 +1095,14 @@ where
 
     /// Set the number of knots.
     ///
-    /// For open curves, the number of knots has to be bigger then the number of elements.
+    /// For open curves, the number of knots has to be at least the number of elements minus one,
+    /// which results in a curve of degree 0. Every additional knot raises the degree by one.
     /// For closed curves, the number of knots has to be at most as big as the number of elements.
     ///
+    /// A curve of degree 0 is a step function, which jumps to the next element at every knot.
+    /// Its first element is only taken before the first knot, such that the domain does not include
+    /// the step of the first element and the last element is only reached at the end of the domain.
+    ///
     /// After this call, you also have to call either of
     /// - [`domain()`],
     /// - [`normalized()`] or
@@ -665,7 +1113,9 @@ where
     /// # Errors
     ///
     /// Returns [`TooFewKnots`] if not at least 2 knots are given.
-    /// Returns [`IncongruousElementsKnots`] if less knots than elements or more knots than double the amount of elements are given.
+    /// Returns [`IncongruousElementsKnots`] if less knots than the number of elements minus one are given
+    /// or so many that the degree would not be smaller than the number of elements,
+    /// that is, at least double the amount of elements minus one.
     ///
     /// [`TooFewKnots`]: super::error::BSplineError
     /// [`IncongruousElementsKnots`]: super::error::BSplineError
@@ -679,14 +1129,13 @@ where
         if quantity < 2 {
             return Err(TooFewKnots::new(quantity).into());
         }
-        if quantity < self.elements.len() {
-            return Err(IncongruousElementsKnots::legacy(self.elements.len(), quantity).into());
-        }
-        if self.elements.len() <= quantity - self.elements.len() + 1 {
-            return Err(IncongruousElementsKnots::open(self.elements.len(), quantity).into());
-        }
+        // the degree is `quantity + 1 - elements`, written such that it neither over- nor underflows
+        let degree = match quantity.checked_sub(self.elements.len().saturating_sub(1)) {
+            Some(degree) if degree < self.elements.len() => degree,
+            _ => return Err(IncongruousElementsKnots::open(self.elements.len(), quantity).into()),
+        };
         Ok(BSplineDirector {
-            knots: UnknownDomain::new(quantity, quantity - self.elements.len() + 1),
+            knots: UnknownDomain::new(quantity, degree),
             elements: self.elements,
             space: self.space,
             _phantoms: self._phantoms,
@@ -700,7 +1149,9 @@ where
 {
     /// Set the degree of the curve.
     ///
-    /// The degree of the curve has to be at least 1 and be less than the number of elements.
+    /// The degree of the curve has to be less than the number of elements.
+    /// A curve of degree 0 is a step function, which needs at least 3 elements,
+    /// as its knots are the positions of the jumps between its elements.
     ///
     /// After this call, you also have to call either of
     /// - [`domain()`],
@@ -709,10 +1160,11 @@ where
     ///
     /// which all define the domain of the interpolation and the spacing of the knots.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the number of elements is zero.
+    /// Any error of [`BSplineDirector::degree()`] is stored and returned by [`build()`].
     ///
+    /// [`build()`]: BSplineBuilder::build()
     /// [`domain()`]: BSplineBuilder::domain()
     /// [`normalized()`]: BSplineBuilder::normalized()
     /// [`distance()`]: BSplineBuilder::distance()
@@ -724,7 +1176,8 @@ where
 
     /// Set the number of knots.
     ///
-    /// For open curves, the number of knots has to be bigger then the number of elements.
+    /// For open curves, the number of knots has to be at least the number of elements minus one,
+    /// which results in a curve of degree 0. Every additional knot raises the degree by one.
     /// For closed curves, the number of knots has to be at most as big as the number of elements.
     ///
     /// After this call, you also have to call either of
@@ -734,11 +1187,11 @@ where
     ///
     /// which all define the domain of the interpolation and the spacing of the knots.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if given quantity is less than the number of elements.
-    /// May also panic if the number of elements is zero.
+    /// Any error of [`BSplineDirector::quantity()`] is stored and returned by [`build()`].
     ///
+    /// [`build()`]: BSplineBuilder::build()
     /// [`domain()`]: BSplineBuilder::domain()
     /// [`normalized()`]: BSplineBuilder::normalized()
     /// [`distance()`]: BSplineBuilder::distance()
@@ -853,10 +1306,11 @@ where
     ///
     /// which all define the domain of the interpolation and the spacing of the knots.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the number of elements is zero.
+    /// Any error of [`BSplineDirector::degree()`] is stored and returned by [`build()`].
     ///
+    /// [`build()`]: BSplineBuilder::build()
     /// [`domain()`]: BSplineBuilder::domain()
     /// [`normalized()`]: BSplineBuilder::normalized()
     /// [`distance()`]: BSplineBuilder::distance()
@@ -878,11 +1332,11 @@ where
     ///
     /// which all define the domain of the interpolation and the spacing of the knots.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if given quantity is less than the number of elements.
-    /// May also panic if the number of elements is zero.
+    /// Any error of [`BSplineDirector::quantity()`] is stored and returned by [`build()`].
     ///
+    /// [`build()`]: BSplineBuilder::build()
     /// [`domain()`]: BSplineBuilder::domain()
     /// [`normalized()`]: BSplineBuilder::normalized()
     /// [`distance()`]: BSplineBuilder::distance()
@@ -896,6 +1350,78 @@ where
     }
 }
 
+impl<R, E, W> BSplineDirector<Type<R>, E, Unknown, W, Periodic>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the degree of the curve.
+    ///
+    /// The degree of the curve has to be at least 1 and less than the number of elements.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDegree`] if the degree is 0.
+    /// Returns [`IncongruousElementsDegree`] if given degree is not less than the amount of elements.
+    ///
+    /// [`InvalidDegree`]: super::error::BSplineError
+    /// [`IncongruousElementsDegree`]: super::error::BSplineError
+    /// [`domain()`]: BSplineDirector::domain()
+    /// [`normalized()`]: BSplineDirector::normalized()
+    /// [`distance()`]: BSplineDirector::distance()
+    pub fn degree(
+        self,
+        degree: usize,
+    ) -> Result<UnknownDomainBSplineDirector<R, E, W>, BSplineError> {
+        if degree == 0 {
+            return Err(InvalidDegree::new(degree).into());
+        }
+        if self.elements.len() <= degree {
+            return Err(IncongruousElementsDegree::periodic(self.elements.len(), degree).into());
+        }
+        Ok(BSplineDirector {
+            knots: UnknownDomain::new(self.elements.len() + 2 * degree - 1, degree),
+            elements: Wrap::new(self.elements, degree),
+            space: self.space,
+            _phantoms: self._phantoms,
+        })
+    }
+}
+
+impl<R, E, W> BSplineBuilder<Type<R>, E, Unknown, W, Periodic>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the degree of the curve.
+    ///
+    /// The degree of the curve has to be at least 1 and less than the number of elements.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// [`domain()`]: BSplineBuilder::domain()
+    /// [`normalized()`]: BSplineBuilder::normalized()
+    /// [`distance()`]: BSplineBuilder::distance()
+    pub fn degree(
+        self,
+        degree: usize,
+    ) -> BSplineBuilder<UnknownDomain<R>, Wrap<E>, Unknown, W, Periodic> {
+        BSplineBuilder {
+            inner: self.inner.and_then(|director| director.degree(degree)),
+        }
+    }
+}
+
 impl<R, E, W> BSplineDirector<UnknownDomain<R>, E, Unknown, W, Open>
 where
     E: DiscreteGenerator,
@@ -1055,6 +1581,83 @@ where
     }
 }
 
+impl<R, E, W> BSplineDirector<UnknownDomain<R>, E, Unknown, W, Periodic>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation, which is one period of the curve.
+    pub fn domain(
+        self,
+        start: R,
+        end: R,
+    ) -> BSplineDirector<Equidistant<R>, E, Unknown, W, Periodic> {
+        // the number of knots in one period, without the knot at its end
+        let period = self.knots.len() + 1 - 2 * self.knots.deg();
+        let step = (end - start) / R::from_usize(period).unwrap();
+        self.distance(start, step)
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> BSplineDirector<Equidistant<R>, E, Unknown, W, Periodic> {
+        self.domain(R::zero(), R::one())
+    }
+
+    /// Set the domain of the interpolation by defining the distance between the knots.
+    ///
+    /// The domain of the curve starts at `start`.
+    pub fn distance(
+        self,
+        start: R,
+        step: R,
+    ) -> BSplineDirector<Equidistant<R>, E, Unknown, W, Periodic> {
+        let offset = step * R::from_usize(self.knots.deg() - 1).unwrap();
+        BSplineDirector {
+            knots: Equidistant::step(self.knots.len(), start - offset, step),
+            elements: self.elements,
+            space: self.space,
+            _phantoms: self._phantoms,
+        }
+    }
+}
+
+impl<R, E, W> BSplineBuilder<UnknownDomain<R>, E, Unknown, W, Periodic>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation, which is one period of the curve.
+    pub fn domain(
+        self,
+        start: R,
+        end: R,
+    ) -> BSplineBuilder<Equidistant<R>, E, Unknown, W, Periodic> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.domain(start, end)),
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> BSplineBuilder<Equidistant<R>, E, Unknown, W, Periodic> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+
+    /// Set the domain of the interpolation by defining the distance between the knots.
+    ///
+    /// The domain of the curve starts at `start`.
+    pub fn distance(
+        self,
+        start: R,
+        step: R,
+    ) -> BSplineBuilder<Equidistant<R>, E, Unknown, W, Periodic> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.distance(start, step)),
+        }
+    }
+}
+
 impl<K, E, W, M> BSplineDirector<K, E, Unknown, W, M>
 where
     E: DiscreteGenerator,
@@ -1069,7 +1672,7 @@ where
     /// If the degree of the bezier curve is known at compile-time, consider using [`constant()`] instead.
     ///
     /// [`constant()`]: BSplineDirector::constant()
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub fn dynamic(self) -> BSplineDirector<K, E, DynSpace<E::Output>, W, M> {
         BSplineDirector {
             space: DynSpace::new(self.knots.len() - self.elements.len() + 2),
@@ -1079,6 +1682,28 @@ where
         }
     }
 
+    /// Set the workspace which the interpolation uses.
+    ///
+    /// Tells the builder to use a pool of vectors as workspace,
+    /// such you don't need to know the degree of the bspline at compile-time
+    /// and memory is only allocated for the first generation of a value, as the workspace is reused afterwards.
+    ///
+    /// The curve built is neither `Send` nor `Sync`. If it has to be shared between threads,
+    /// consider using [`dynamic()`] instead. If the degree of the bspline is known at compile-time,
+    /// consider using [`constant()`] instead.
+    ///
+    /// [`dynamic()`]: BSplineDirector::dynamic()
+    /// [`constant()`]: BSplineDirector::constant()
+    #[cfg(feature = "alloc")]
+    pub fn pooled(self) -> BSplineDirector<K, E, PooledSpace<E::Output>, W, M> {
+        BSplineDirector {
+            space: PooledSpace::new(self.knots.len() - self.elements.len() + 2),
+            knots: self.knots,
+            elements: self.elements,
+            _phantoms: self._phantoms,
+        }
+    }
+
     /// Set the workspace which the interpolation uses.
     ///
     /// Tells the builder the size of the workspace needed such that no memory allocations are necessary
@@ -1090,10 +1715,10 @@ where
         self,
     ) -> Result<BSplineDirector<K, E, ConstSpace<E::Output, N>, W, M>, TooSmallWorkspace> {
         // This calculation won't panic as we checked before if the degree is not strictly positive.
-        if N <= self.knots.len() - self.elements.len() + 1 {
+        if N <= self.knots.len() + 1 - self.elements.len() {
             return Err(TooSmallWorkspace::new(
                 N,
-                self.knots.len() - self.elements.len() + 1,
+                self.knots.len() + 1 - self.elements.len(),
             ));
         }
         Ok(BSplineDirector {
@@ -1117,10 +1742,10 @@ where
     where
         S: Space<E::Output>,
     {
-        if space.len() <= self.knots.len() - self.elements.len() + 1 {
+        if space.len() <= self.knots.len() + 1 - self.elements.len() {
             return Err(TooSmallWorkspace::new(
                 space.len(),
-                self.knots.len() - self.elements.len() + 1,
+                self.knots.len() + 1 - self.elements.len(),
             ));
         }
         Ok(BSplineDirector {
@@ -1146,13 +1771,55 @@ where
     /// If the degree of the bezier curve is known at compile-time, consider using [`constant()`] instead.
     ///
     /// [`constant()`]: BSplineBuilder::constant()
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub fn dynamic(self) -> BSplineBuilder<K, E, DynSpace<E::Output>, W, M> {
         BSplineBuilder {
             inner: self.inner.map(|director| director.dynamic()),
         }
     }
 
+    /// Set the workspace which the interpolation uses.
+    ///
+    /// Tells the builder to use a pool of vectors as workspace,
+    /// such you don't need to know the degree of the bspline at compile-time
+    /// and memory is only allocated for the first generation of a value, as the workspace is reused afterwards.
+    ///
+    /// The curve built is neither `Send` nor `Sync`. If it has to be shared between threads,
+    /// consider using [`dynamic()`] instead. If the degree of the bspline is known at compile-time,
+    /// consider using [`constant()`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///     .clamped()
+    ///     .elements([0.0, 5.0, 3.0])
+    ///     .equidistant::<f64>()
+    ///     .degree(2)
+    ///     .normalized()
+    ///     .pooled()
+    ///     .build()?;
+    /// // only the first value allocates a workspace
+    /// let values: Vec<f64> = bspline.take(5).collect();
+    /// assert_f64_near!(values[2], 3.25);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`dynamic()`]: BSplineBuilder::dynamic()
+    /// [`constant()`]: BSplineBuilder::constant()
+    #[cfg(feature = "alloc")]
+    pub fn pooled(self) -> BSplineBuilder<K, E, PooledSpace<E::Output>, W, M> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.pooled()),
+        }
+    }
+
     /// Set the workspace which the interpolation uses.
     ///
     /// Tells the builder the size of the workspace needed such that no memory allocations are necessary
@@ -1192,7 +1859,7 @@ impl<K, E, S, M> BSplineDirector<K, E, S, WithoutWeight, M>
 where
     K: SortedGenerator,
     E: DiscreteGenerator,
-    E::Output: Merge<K::Output> + Copy,
+    E::Output: Merge<K::Output> + Clone,
     S: Space<E::Output>,
 {
     /// Build a bezier interpolation.
@@ -1215,7 +1882,7 @@ impl<K, E, S, M> BSplineBuilder<K, E, S, WithoutWeight, M>
 where
     K: SortedGenerator,
     E: DiscreteGenerator,
-    E::Output: Merge<K::Output> + Copy,
+    E::Output: Merge<K::Output> + Clone,
     S: Space<E::Output>,
 {
     /// Build a bezier interpolation.
@@ -1240,15 +1907,12 @@ where
     }
 }
 
-impl<K, G, S, M> BSplineDirector<K, Weights<G>, S, WithWeight, M>
+impl<K, E, S, M> BSplineDirector<K, E, S, WithWeight, M>
 where
-    G: DiscreteGenerator,
-    G::Output: IntoWeight,
     K: SortedGenerator,
-    S: Space<Homogeneous<<G::Output as IntoWeight>::Element, <G::Output as IntoWeight>::Weight>>,
-    <Weights<G> as Generator<usize>>::Output: Merge<K::Output> + Copy,
-    <G::Output as IntoWeight>::Element:
-        Div<<G::Output as IntoWeight>::Weight, Output = <G::Output as IntoWeight>::Element>,
+    E: DiscreteGenerator,
+    E::Output: Project + Merge<K::Output> + Copy,
+    S: Space<E::Output>,
 {
     /// Build a bezier interpolation.
     ///
@@ -1261,7 +1925,7 @@ where
     /// [`TooFewElements`]: super::BSplineError
     /// [`InvalidDegree`]: super::BSplineError
     /// [`TooSmallWorkspace`]: super::BSplineError
-    pub fn build(self) -> WeightedBSpline<K, G, S> {
+    pub fn build(self) -> Weighted<BSpline<K, E, S>> {
         Weighted::new(BSpline::new_unchecked(
             self.elements,
             self.knots,
@@ -1270,15 +1934,12 @@ where
     }
 }
 
-impl<K, G, S, M> BSplineBuilder<K, Weights<G>, S, WithWeight, M>
+impl<K, E, S, M> BSplineBuilder<K, E, S, WithWeight, M>
 where
-    G: DiscreteGenerator,
-    G::Output: IntoWeight,
     K: SortedGenerator,
-    S: Space<Homogeneous<<G::Output as IntoWeight>::Element, <G::Output as IntoWeight>::Weight>>,
-    <Weights<G> as Generator<usize>>::Output: Merge<K::Output> + Copy,
-    <G::Output as IntoWeight>::Element:
-        Div<<G::Output as IntoWeight>::Weight, Output = <G::Output as IntoWeight>::Element>,
+    E: DiscreteGenerator,
+    E::Output: Project + Merge<K::Output> + Copy,
+    S: Space<E::Output>,
 {
     /// Build a bezier interpolation.
     ///
@@ -1294,7 +1955,7 @@ where
     /// [`TooSmallWorkspace`]: super::BSplineError
     /// [`NotSorted`]: super::BSplineError
     /// [`knots()`]: BSplineBuilder::knots()
-    pub fn build(self) -> Result<WeightedBSpline<K, G, S>, BSplineError> {
+    pub fn build(self) -> Result<Weighted<BSpline<K, E, S>>, BSplineError> {
         match self.inner {
             Err(err) => Err(err),
             Ok(director) => Ok(director.build()),
@@ -1302,26 +1963,213 @@ where
     }
 }
 
-/// Type alias for weighted bsplines.
-type WeightedBSpline<K, G, S> = Weighted<BSpline<K, Weights<G>, S>>;
+impl<K, G, S, M> BSplineDirector<K, Weights<G>, S, WithWeight, M>
+where
+    G: DiscreteGenerator,
+    G::Output: IntoWeight,
+    <G::Output as IntoWeight>::Weight: Real,
+{
+    /// Scale all weights such that the largest weight in absolute value is one.
+    ///
+    /// Scaling all weights by the same factor leaves a rational curve invariant,
+    /// as only the ratios between the weights matter.
+    /// Normalizing them avoids numerical problems with very large or very small weights.
+    /// See [`BSplineBuilder::normalized_weights()`] for more information.
+    #[allow(clippy::type_complexity)]
+    pub fn normalized_weights(
+        self,
+    ) -> BSplineDirector<K, Weights<Rescaled<G, <G::Output as IntoWeight>::Weight>>, S, WithWeight, M>
+    {
+        BSplineDirector {
+            elements: Weights::new(Rescaled::normalized(self.elements.inner())),
+            knots: self.knots,
+            space: self.space,
+            _phantoms: self._phantoms,
+        }
+    }
+}
+
+impl<K, G, S, M> BSplineBuilder<K, Weights<G>, S, WithWeight, M>
+where
+    G: DiscreteGenerator,
+    G::Output: IntoWeight,
+    <G::Output as IntoWeight>::Weight: Real,
+{
+    /// Scale all weights such that the largest weight in absolute value is one.
+    ///
+    /// Scaling all weights by the same factor leaves a rational curve invariant,
+    /// as only the ratios between the weights matter.
+    /// Normalizing them avoids numerical problems with very large or very small weights,
+    /// which often occur in NURBS created by other tools.
+    /// If all weights are zero, they are not scaled at all.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let nurbs = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements_with_weights([(1.0,1e-9),(3.0,4e-9),(2.0,1e-9)])
+    ///                 .normalized_weights()
+    ///                 .equidistant::<f64>()
+    ///                 .degree(2)
+    ///                 .normalized()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// assert_f64_near!(nurbs.gen(0.5), 2.7);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn normalized_weights(
+        self,
+    ) -> BSplineBuilder<K, Weights<Rescaled<G, <G::Output as IntoWeight>::Weight>>, S, WithWeight, M>
+    {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.normalized_weights()),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, E, S, M, R> BSplineDirector<K, E, S, WithoutWeight, M>
+where
+    K: SortedGenerator<Output = R>,
+    R: Real + FromPrimitive,
+{
+    /// Report issues which lead to a degenerate or numerically unstable curve.
+    ///
+    /// The knots are checked for near-duplicates and suspiciously small spans.
+    /// See [`BSplineBuilder::diagnose()`] for more information.
+    pub fn diagnose(&self) -> BSplineDiagnosis<R> {
+        BSplineDiagnosis::new(&self.knots, None)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, E, S, M, R> BSplineDirector<K, E, S, WithWeight, M>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Project<Weight = R>,
+    R: Real + FromPrimitive,
+{
+    /// Report issues which lead to a degenerate or numerically unstable curve.
+    ///
+    /// The weights are checked to be positive and the knots are checked for near-duplicates
+    /// and suspiciously small spans.
+    /// See [`BSplineBuilder::diagnose()`] for more information.
+    pub fn diagnose(&self) -> BSplineDiagnosis<R> {
+        let weights = (0..self.elements.len()).map(|i| self.elements.gen(i).weight());
+        BSplineDiagnosis::new(&self.knots, weights)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, E, S, M, R> BSplineBuilder<K, E, S, WithoutWeight, M>
+where
+    K: SortedGenerator<Output = R>,
+    R: Real + FromPrimitive,
+{
+    /// Report issues which lead to a degenerate or numerically unstable curve.
+    ///
+    /// If the builder failed, for example as the degree does not match the number of elements,
+    /// the report contains only the error [`build()`] would return.
+    /// Otherwise knots which are almost, but not exactly, equal and knot spans which are much
+    /// smaller than the average span are reported.
+    /// The diagnosis does not change the builder, such that it can still be built afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::bspline::{BSpline, BSplineIssue};
+    /// let builder = BSpline::builder()
+    ///     .elements([0.0, 5.0, 3.0, 10.0])
+    ///     .knots([0.0, 1.0, 1.0000001, 2.0, 3.0]);
+    /// let diagnosis = builder.diagnose();
+    /// assert!(matches!(diagnosis.issues(), [BSplineIssue::SmallKnotSpan { index: 1, .. }]));
+    /// assert!(builder.dynamic().build().is_ok());
+    /// ```
+    ///
+    /// [`build()`]: BSplineBuilder::build()
+    pub fn diagnose(&self) -> BSplineDiagnosis<R> {
+        match &self.inner {
+            Ok(director) => director.diagnose(),
+            Err(err) => BSplineDiagnosis::invalid(*err),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, E, S, M, R> BSplineBuilder<K, E, S, WithWeight, M>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Project<Weight = R>,
+    R: Real + FromPrimitive,
+{
+    /// Report issues which lead to a degenerate or numerically unstable curve.
+    ///
+    /// If the builder failed, for example as the degree does not match the number of elements,
+    /// the report contains only the error [`build()`] would return.
+    /// Otherwise weights which are zero or negative, knots which are almost, but not exactly, equal
+    /// and knot spans which are much smaller than the average span are reported.
+    /// The diagnosis does not change the builder, such that it can still be built afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::bspline::{BSpline, BSplineIssue};
+    /// let builder = BSpline::builder()
+    ///     .elements_with_weights([(0.0, 1.0), (5.0, 0.0), (3.0, 2.0)])
+    ///     .knots([0.0, 1.0, 2.0, 3.0]);
+    /// let diagnosis = builder.diagnose();
+    /// assert!(matches!(diagnosis.issues(), [BSplineIssue::NonPositiveWeight { index: 1, .. }]));
+    /// ```
+    ///
+    /// [`build()`]: BSplineBuilder::build()
+    pub fn diagnose(&self) -> BSplineDiagnosis<R> {
+        match &self.inner {
+            Ok(director) => director.diagnose(),
+            Err(err) => BSplineDiagnosis::invalid(*err),
+        }
+    }
+}
+
 /// Type alias for ClampedBuilder
-type ClampedBSplineBuilder<K, E, W> =
-    BSplineBuilder<BorderBuffer<Sorted<K>>, E, Unknown, W, Clamped>;
+type ClampedBSplineBuilder<K, E, W, A = BinarySearch> =
+    BSplineBuilder<BorderBuffer<Sorted<K, A>>, E, Unknown, W, Clamped>;
 /// Type alias for ClampedDirector
-type ClampedBSplineDirector<K, E, W> =
-    BSplineDirector<BorderBuffer<Sorted<K>>, E, Unknown, W, Clamped>;
+type ClampedBSplineDirector<K, E, W, A = BinarySearch> =
+    BSplineDirector<BorderBuffer<Sorted<K, A>>, E, Unknown, W, Clamped>;
 ///Type alias for LegacyBuilder
-type LegacyBSplineBuilder<K, E, W> =
-    BSplineBuilder<BorderDeletion<Sorted<K>>, E, Unknown, W, Legacy>;
+type LegacyBSplineBuilder<K, E, W, A = BinarySearch> =
+    BSplineBuilder<BorderDeletion<Sorted<K, A>>, E, Unknown, W, Legacy>;
 ///Type alias for LegacyDirector
-type LegacyBSplineDirector<K, E, W> =
-    BSplineDirector<BorderDeletion<Sorted<K>>, E, Unknown, W, Legacy>;
+type LegacyBSplineDirector<K, E, W, A = BinarySearch> =
+    BSplineDirector<BorderDeletion<Sorted<K, A>>, E, Unknown, W, Legacy>;
+///Type alias for PeriodicBuilder
+type PeriodicBSplineBuilder<K, E, W> =
+    BSplineBuilder<PeriodicKnots<K>, Wrap<E>, Unknown, W, Periodic>;
+///Type alias for PeriodicDirector
+type PeriodicBSplineDirector<K, E, W> =
+    BSplineDirector<PeriodicKnots<K>, Wrap<E>, Unknown, W, Periodic>;
+///Type alias for PeriodicDirector with knots but without degree
+type UnknownDegreeBSplineDirector<K, E, W> =
+    BSplineDirector<UnknownDegree<Sorted<K>>, E, Unknown, W, Periodic>;
+///Type alias for PeriodicDirector with equidistant knots but without domain
+type UnknownDomainBSplineDirector<R, E, W> =
+    BSplineDirector<UnknownDomain<R>, Wrap<E>, Unknown, W, Periodic>;
 
 #[cfg(test)]
 mod test {
     use super::BSplineBuilder;
     // Homogeneous for creating Homogeneous, Generator for using .stack()
-    use crate::{bspline::BSplineDirector, weights::Homogeneous, Curve, Generator};
+    use crate::{bspline::BSplineDirector, weights::Homogeneous, Curve, Derivative, Generator};
 
     #[test]
     fn degenerate_creations() {
@@ -1340,6 +2188,68 @@ mod test {
             .is_err());
     }
 
+    #[test]
+    fn invalid_degree_and_quantity() {
+        let empty: [f64; 0] = [];
+        assert!(BSplineBuilder::new()
+            .elements(empty)
+            .equidistant::<f64>()
+            .degree(1)
+            .normalized()
+            .constant::<2>()
+            .build()
+            .is_err());
+        assert!(BSplineBuilder::new()
+            .clamped()
+            .elements(empty)
+            .equidistant::<f64>()
+            .quantity(2)
+            .normalized()
+            .constant::<2>()
+            .build()
+            .is_err());
+        // the errors are stored by the builder and surface at build
+        let elements = [1.0, 3.0, 7.0];
+        for quantity in [0, 1, 5, usize::MAX] {
+            assert!(BSplineBuilder::new()
+                .elements(elements)
+                .equidistant::<f64>()
+                .quantity(quantity)
+                .normalized()
+                .constant::<3>()
+                .build()
+                .is_err());
+            assert!(BSplineBuilder::new()
+                .clamped()
+                .elements(elements)
+                .equidistant::<f64>()
+                .quantity(quantity)
+                .normalized()
+                .constant::<3>()
+                .build()
+                .is_err());
+        }
+        for degree in [3, usize::MAX] {
+            assert!(BSplineBuilder::new()
+                .elements(elements)
+                .equidistant::<f64>()
+                .degree(degree)
+                .normalized()
+                .constant::<3>()
+                .build()
+                .is_err());
+        }
+        assert!(BSplineBuilder::new()
+            .clamped()
+            .elements(elements)
+            .equidistant::<f64>()
+            .degree(0)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .is_err());
+    }
+
     #[test]
     fn mode_equality() {
         let elements = [1.0, 3.0, 7.0];
@@ -1374,6 +2284,95 @@ mod test {
         }
     }
 
+    #[test]
+    fn snap_knots() {
+        let elements = [1.0, 3.0, 7.0, 2.0, 5.0];
+        let exact = BSplineBuilder::new()
+            .elements(elements)
+            .knots([0.0, 1.0, 1.0, 2.5, 3.0, 4.5])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let snapped = BSplineBuilder::new()
+            .elements(elements)
+            .knots([0.0, 1.0, 1.0000001, 2.5, 3.0, 4.5])
+            .snap_knots(1e-6)
+            .constant::<3>()
+            .build()
+            .unwrap();
+        for (a, b) in exact.take(20).zip(snapped.take(20)) {
+            assert_eq!(a, b);
+        }
+        let exact = BSplineBuilder::new()
+            .clamped()
+            .elements(elements)
+            .knots([0.0, 1.0, 1.0, 2.5])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let snapped = BSplineBuilder::new()
+            .clamped()
+            .elements(elements)
+            .knots([0.0, 1.0, 1.0000001, 2.5])
+            .snap_knots(1e-6)
+            .constant::<3>()
+            .build()
+            .unwrap();
+        for (a, b) in exact.take(20).zip(snapped.take(20)) {
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn search_strategy() {
+        use crate::InterpolationSearch;
+        let elements = [1.0, 3.0, 7.0, 2.0, 5.0];
+        let knots = [0.0, 0.5, 2.0, 2.5, 3.0, 4.5];
+        let binary = BSplineBuilder::new()
+            .elements(elements)
+            .knots(knots)
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let open = BSplineBuilder::new()
+            .elements(elements)
+            .knots(knots)
+            .search(InterpolationSearch)
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let clamped = BSplineBuilder::new()
+            .clamped()
+            .elements(elements)
+            .knots([0.0, 0.5, 2.0, 2.5])
+            .search(InterpolationSearch)
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let clamped_binary = BSplineBuilder::new()
+            .clamped()
+            .elements(elements)
+            .knots([0.0, 0.5, 2.0, 2.5])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let legacy = BSplineBuilder::new()
+            .legacy()
+            .elements(elements)
+            .knots([-1.0, 0.0, 0.5, 2.0, 2.5, 3.0, 4.5, 5.0])
+            .search(InterpolationSearch)
+            .constant::<3>()
+            .build()
+            .unwrap();
+        for ((a, b), c) in binary.take(20).zip(open.take(20)).zip(legacy.take(20)) {
+            assert_f64_near!(a, b);
+            assert_f64_near!(b, c);
+        }
+        for (a, b) in clamped_binary.take(20).zip(clamped.take(20)) {
+            assert_f64_near!(a, b);
+        }
+    }
+
     #[test]
     fn elements_with_weights() {
         BSplineBuilder::new()
@@ -1509,13 +2508,21 @@ mod test {
             .quantity(1)
             .is_err());
 
-        // invalid degree
+        // degree 0 is a step function, which needs at least two knots
         assert!(BSplineDirector::new()
             .open()
             .elements([0.0, 1.0, 2.0, 3.0])
             .unwrap()
             .equidistant::<f32>()
             .degree(0)
+            .is_ok());
+
+        assert!(BSplineDirector::new()
+            .open()
+            .elements([0.0, 1.0])
+            .unwrap()
+            .equidistant::<f32>()
+            .degree(0)
             .is_err());
 
         // too small of a workspace
@@ -1533,12 +2540,20 @@ mod test {
         // incongruous knots
         assert!(BSplineDirector::new()
             .open()
-            .elements([0.0, 1.0, 2.0])
+            .elements([0.0, 1.0, 2.0, 3.0])
             .unwrap()
             .equidistant::<f32>()
             .quantity(2)
             .is_err());
 
+        assert!(BSplineDirector::new()
+            .open()
+            .elements([0.0, 1.0, 2.0])
+            .unwrap()
+            .equidistant::<f32>()
+            .quantity(2)
+            .is_ok());
+
         assert!(BSplineDirector::new()
             .open()
             .elements([0.0, 1.0, 2.0])
@@ -1633,4 +2648,188 @@ mod test {
             .knots([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
             .is_err());
     }
+
+    #[test]
+    fn periodic() {
+        let elements = [0.0, 5.0, 2.0, -3.0, 1.0, 4.0];
+        let equidistant = BSplineBuilder::new()
+            .periodic()
+            .elements(elements)
+            .equidistant::<f64>()
+            .degree(3)
+            .domain(1.0, 4.0)
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let knots = BSplineBuilder::new()
+            .periodic()
+            .elements(elements)
+            .knots([0.0, 0.5, 1.0, 2.5, 3.0, 3.5, 5.0])
+            .degree(3)
+            .constant::<4>()
+            .build()
+            .unwrap();
+        assert_eq!(equidistant.domain(), [1.0, 4.0]);
+        assert_eq!(knots.domain(), [0.0, 5.0]);
+        fn assert_closed<C>(curve: C)
+        where
+            C: Curve<f64, Output = f64> + Derivative<f64>,
+        {
+            let [start, end] = curve.domain();
+            assert_float_absolute_eq!(curve.gen(start), curve.gen(end), 1e-10);
+            assert_float_absolute_eq!(curve.derivative(start), curve.derivative(end), 1e-10);
+        }
+        assert_closed(equidistant);
+        assert_closed(knots);
+        let weighted = BSplineBuilder::new()
+            .periodic()
+            .elements_with_weights([(1.0, 1.0), (2.0, 2.0), (3.0, 0.5), (-1.0, 1.0)])
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        assert_float_absolute_eq!(weighted.gen(0.0), weighted.gen(1.0), 1e-10);
+    }
+
+    #[test]
+    fn periodic_errors() {
+        let elements = [0.0, 5.0, 2.0];
+        // degree 0
+        assert!(BSplineBuilder::new()
+            .periodic()
+            .elements(elements)
+            .equidistant::<f64>()
+            .degree(0)
+            .normalized()
+            .constant::<1>()
+            .build()
+            .is_err());
+        // degree too big
+        assert!(BSplineBuilder::new()
+            .periodic()
+            .elements(elements)
+            .knots([0.0, 1.0, 2.0, 3.0])
+            .degree(3)
+            .constant::<4>()
+            .build()
+            .is_err());
+        // wrong number of knots
+        assert!(BSplineBuilder::new()
+            .periodic()
+            .elements(elements)
+            .knots([0.0, 1.0, 2.0])
+            .degree(2)
+            .constant::<3>()
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn from_iter() {
+        let points = [[1.0, 2.0], [3.0, -2.0], [5.0, 0.0], [2.0, 1.0]];
+        let xs = points.as_slice().iter().map(|[x, _]| *x);
+        let collected = BSplineBuilder::new()
+            .elements_from_iter(xs.clone())
+            .knots([0.0, 0.0, 1.0, 2.0, 2.0])
+            .dynamic()
+            .build()
+            .unwrap();
+        let array = BSplineBuilder::new()
+            .elements([1.0, 3.0, 5.0, 2.0])
+            .knots([0.0, 0.0, 1.0, 2.0, 2.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        for x in [0.0, 0.3, 1.0, 1.7, 2.0] {
+            assert_f64_near!(collected.gen(x), array.gen(x));
+        }
+        let clamped = xs
+            .collect::<BSplineBuilder<_, _, _, _, _>>()
+            .degree(2)
+            .domain(0.0, 2.0)
+            .constant::<3>()
+            .build()
+            .unwrap();
+        for x in [0.0, 0.3, 1.0, 1.7, 2.0] {
+            assert_f64_near!(clamped.gen(x), array.gen(x));
+        }
+        assert!(core::iter::empty::<f64>()
+            .collect::<BSplineBuilder<crate::builder::Type<f64>, _, _, _, _>>()
+            .degree(2)
+            .normalized()
+            .dynamic()
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn normalized_weights() {
+        let elements = [(1.0, 250.0), (3.0, 1000.0), (7.0, 4000.0), (2.0, 500.0)];
+        let raw = BSplineBuilder::new()
+            .clamped()
+            .elements_with_weights(elements)
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let normalized = BSplineBuilder::new()
+            .clamped()
+            .elements_with_weights(elements)
+            .normalized_weights()
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        for x in crate::Stepper::normalized(11) {
+            assert_f64_near!(raw.gen(x), normalized.gen(x));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn diagnose() {
+        use crate::bspline::BSplineIssue;
+        let builder = BSplineBuilder::new()
+            .elements_with_weights([(1.0, 1.0), (3.0, 0.0), (7.0, 2.0), (2.0, 1.0)])
+            .knots([0.0, 1.0, 1.00001, 2.0, 3.0]);
+        let diagnosis = builder.diagnose();
+        assert!(!diagnosis.is_ok());
+        assert!(diagnosis
+            .issues()
+            .iter()
+            .any(|issue| matches!(issue, BSplineIssue::NonPositiveWeight { index: 1, .. })));
+        assert!(diagnosis
+            .issues()
+            .iter()
+            .any(|issue| matches!(issue, BSplineIssue::SmallKnotSpan { index: 1, .. })));
+        assert_eq!(diagnosis.issues().len(), 2);
+        // near-duplicate knots
+        let diagnosis = BSplineBuilder::new()
+            .elements([1.0, 3.0, 7.0, 2.0])
+            .knots([0.0, 1.0, 1.0 + 1e-12, 2.0, 3.0])
+            .diagnose();
+        assert!(matches!(
+            diagnosis.issues(),
+            [BSplineIssue::NearDuplicateKnots { index: 1, .. }]
+        ));
+        // a fine spline has no issues
+        let diagnosis = BSplineBuilder::new()
+            .elements([1.0, 3.0, 7.0, 2.0])
+            .knots([0.0, 0.0, 1.0, 2.0, 2.0])
+            .diagnose();
+        assert!(diagnosis.is_ok());
+        // failed builder
+        let diagnosis = BSplineBuilder::new()
+            .elements([1.0, 3.0, 7.0, 2.0])
+            .knots([0.0, 1.0])
+            .diagnose();
+        assert!(matches!(diagnosis.issues(), [BSplineIssue::Invalid(_)]));
+    }
 }
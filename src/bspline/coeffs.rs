@@ -0,0 +1,288 @@
+//! Conversion of bsplines to and from plain buffers of coefficients.
+
+use super::{BSpline, BSplineError, IncongruousCoefficients};
+use crate::{DiscreteGenerator, DynSpace, Sorted, SortedGenerator};
+use alloc::vec::Vec;
+
+/// Elements which can be flattened into plain coefficients and restored from them.
+///
+/// This is used by [`BSpline::to_coeffs()`] and [`BSpline::from_coeffs()`] and is implemented
+/// for `f32`, `f64` and arrays of elements implementing it.
+/// To use your own type of elements, implement this trait for it:
+///
+/// ```rust
+/// # use enterpolation::bspline::Coefficients;
+/// #[derive(Debug, PartialEq)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// impl Coefficients<f64> for Point {
+///     fn extend_coeffs(&self, coeffs: &mut Vec<f64>) {
+///         coeffs.extend_from_slice(&[self.x, self.y]);
+///     }
+///     fn from_coeffs(coeffs: &[f64]) -> Option<Self> {
+///         match coeffs {
+///             [x, y] => Some(Point { x: *x, y: *y }),
+///             _ => None,
+///         }
+///     }
+/// }
+/// let mut coeffs = Vec::new();
+/// Point { x: 1.0, y: 2.0 }.extend_coeffs(&mut coeffs);
+/// assert_eq!(coeffs, vec![1.0, 2.0]);
+/// assert_eq!(Point::from_coeffs(&coeffs), Some(Point { x: 1.0, y: 2.0 }));
+/// ```
+pub trait Coefficients<R>: Sized {
+    /// Append all coefficients of the element to `coeffs`.
+    fn extend_coeffs(&self, coeffs: &mut Vec<R>);
+    /// Restore an element from its coefficients.
+    ///
+    /// Returns `None` if the number of coefficients does not fit the element.
+    fn from_coeffs(coeffs: &[R]) -> Option<Self>;
+}
+
+impl Coefficients<f32> for f32 {
+    fn extend_coeffs(&self, coeffs: &mut Vec<f32>) {
+        coeffs.push(*self);
+    }
+    fn from_coeffs(coeffs: &[f32]) -> Option<Self> {
+        match coeffs {
+            [value] => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl Coefficients<f64> for f64 {
+    fn extend_coeffs(&self, coeffs: &mut Vec<f64>) {
+        coeffs.push(*self);
+    }
+    fn from_coeffs(coeffs: &[f64]) -> Option<Self> {
+        match coeffs {
+            [value] => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl<T, R, const N: usize> Coefficients<R> for [T; N]
+where
+    T: Coefficients<R>,
+{
+    fn extend_coeffs(&self, coeffs: &mut Vec<R>) {
+        for element in self {
+            element.extend_coeffs(coeffs);
+        }
+    }
+    fn from_coeffs(coeffs: &[R]) -> Option<Self> {
+        if N == 0 || !coeffs.len().is_multiple_of(N) {
+            return None;
+        }
+        // all entries have the same number of coefficients
+        let mut chunks = coeffs.chunks(coeffs.len() / N);
+        let entries: Option<Vec<T>> = (0..N)
+            .map(|_| chunks.next().and_then(T::from_coeffs))
+            .collect();
+        entries?.try_into().ok()
+    }
+}
+
+/// The real part comes before the imaginary part.
+#[cfg(feature = "num-complex")]
+impl<T, R> Coefficients<R> for num_complex::Complex<T>
+where
+    T: Coefficients<R>,
+{
+    fn extend_coeffs(&self, coeffs: &mut Vec<R>) {
+        self.re.extend_coeffs(coeffs);
+        self.im.extend_coeffs(coeffs);
+    }
+    fn from_coeffs(coeffs: &[R]) -> Option<Self> {
+        if !coeffs.len().is_multiple_of(2) {
+            return None;
+        }
+        let (re, im) = coeffs.split_at(coeffs.len() / 2);
+        Some(num_complex::Complex::new(
+            T::from_coeffs(re)?,
+            T::from_coeffs(im)?,
+        ))
+    }
+}
+
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Coefficients<R>,
+    K: SortedGenerator<Output = R>,
+{
+    /// Returns the degree, the knots and the flattened elements of the bspline, in this order.
+    ///
+    /// This is meant as plain interchange format, for example to upload the curve to a GPU,
+    /// and can be reversed with [`from_coeffs()`].
+    ///
+    /// The knots are returned as they are stored in the bspline, that is, without the
+    /// additional knot at each border some other libraries expect. As such, there are
+    /// `elements + degree - 1` knots.
+    /// The elements are stored one after another, each with all its coefficients next to each other:
+    /// for elements of dimension 2, the coefficients are `[x0, y0, x1, y1, ...]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///     .clamped()
+    ///     .elements([0.0, 5.0, 3.0])
+    ///     .equidistant::<f64>()
+    ///     .degree(2)
+    ///     .normalized()
+    ///     .constant::<3>()
+    ///     .build()?;
+    /// let (degree, knots, coeffs) = bspline.to_coeffs();
+    /// assert_eq!(degree, 2);
+    /// assert_eq!(knots, vec![0.0, 0.0, 1.0, 1.0]);
+    /// assert_eq!(coeffs, vec![0.0, 5.0, 3.0]);
+    /// let copy = BSpline::<_, Vec<f64>, _>::from_coeffs(degree, 1, knots, coeffs)?;
+    /// assert_f64_near!(copy.gen(0.3), bspline.gen(0.3));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`from_coeffs()`]: BSpline::from_coeffs()
+    pub fn to_coeffs(&self) -> (usize, Vec<R>, Vec<R>) {
+        let knots = (0..self.knots.len()).map(|i| self.knots.gen(i)).collect();
+        let mut coeffs = Vec::new();
+        for element in self.elements.iter() {
+            element.extend_coeffs(&mut coeffs);
+        }
+        (self.degree, knots, coeffs)
+    }
+}
+
+impl<R, T> BSpline<Sorted<Vec<R>>, Vec<T>, DynSpace<T>>
+where
+    R: PartialOrd + Clone,
+    T: Coefficients<R> + Default + Clone,
+{
+    /// Creates a bspline from its degree, knots and flattened elements, as returned by [`to_coeffs()`].
+    ///
+    /// Every `dims` consecutive coefficients are converted into one element.
+    /// The layout of knots and coefficients is described in [`to_coeffs()`].
+    ///
+    /// # Errors
+    ///
+    /// [`IncongruousCoefficients`] if the coefficients can not be split into elements of `dims` coefficients
+    /// or if the number of knots does not match the number of elements and the degree.
+    /// [`TooFewElements`] if there are less than two elements.
+    /// [`NotSorted`] if the knots are not sorted.
+    ///
+    /// [`to_coeffs()`]: BSpline::to_coeffs()
+    /// [`IncongruousCoefficients`]: BSplineError
+    /// [`TooFewElements`]: BSplineError
+    /// [`NotSorted`]: BSplineError
+    pub fn from_coeffs(
+        degree: usize,
+        dims: usize,
+        knots: Vec<R>,
+        coeffs: Vec<R>,
+    ) -> Result<Self, BSplineError> {
+        let error = IncongruousCoefficients::new(knots.len(), coeffs.len(), degree, dims);
+        if dims == 0
+            || !coeffs.len().is_multiple_of(dims)
+            || knots.len() + 1 != coeffs.len() / dims + degree
+        {
+            return Err(error.into());
+        }
+        let elements = coeffs
+            .chunks(dims)
+            .map(T::from_coeffs)
+            .collect::<Option<Vec<T>>>()
+            .ok_or(error)?;
+        BSpline::try_from_parts(elements, knots, DynSpace::new(degree + 1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ConstSpace, Curve, Generator, Stepper};
+    use alloc::vec;
+
+    #[test]
+    fn round_trip() {
+        let bspline = BSpline::builder()
+            .elements([1.0, -2.0, 4.0, 3.0, 0.5])
+            .knots([0.0, 1.0, 2.5, 3.0, 4.5, 6.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let (degree, knots, coeffs) = bspline.to_coeffs();
+        assert_eq!(degree, 2);
+        assert_eq!(knots, vec![0.0, 1.0, 2.5, 3.0, 4.5, 6.0]);
+        let copy = BSpline::<_, Vec<f64>, _>::from_coeffs(degree, 1, knots, coeffs).unwrap();
+        assert_eq!(copy.domain(), bspline.domain());
+        let [start, end] = bspline.domain();
+        for scalar in Stepper::new(20, start, end) {
+            assert_eq!(copy.gen(scalar), bspline.gen(scalar));
+        }
+        // elements with multiple coefficients
+        let elements = [[1.0, -2.0, 0.5], [4.0, 3.0, 0.0], [2.0, -1.0, 1.5]];
+        let bspline = BSpline::new(
+            elements,
+            Sorted::new([0.0, 1.0, 2.0, 3.0]).unwrap(),
+            ConstSpace::<[f64; 3], 3>::new(),
+        )
+        .unwrap();
+        let (degree, knots, coeffs) = bspline.to_coeffs();
+        assert_eq!(coeffs, vec![1.0, -2.0, 0.5, 4.0, 3.0, 0.0, 2.0, -1.0, 1.5]);
+        let copy = BSpline::<_, Vec<[f64; 3]>, _>::from_coeffs(degree, 3, knots, coeffs).unwrap();
+        assert_eq!(copy.elements, elements.to_vec());
+        assert_eq!(copy.degree, 2);
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    fn complex() {
+        use num_complex::Complex;
+        let elements = vec![
+            Complex::new(1.0, -2.0),
+            Complex::new(4.0, 3.0),
+            Complex::new(2.0, -1.0),
+        ];
+        let bspline = BSpline::builder()
+            .elements(elements.clone())
+            .knots([0.0, 1.0, 2.0, 3.0])
+            .dynamic()
+            .build()
+            .unwrap();
+        let (degree, knots, coeffs) = bspline.to_coeffs();
+        assert_eq!(coeffs, vec![1.0, -2.0, 4.0, 3.0, 2.0, -1.0]);
+        let copy =
+            BSpline::<_, Vec<Complex<f64>>, _>::from_coeffs(degree, 2, knots, coeffs).unwrap();
+        assert_eq!(copy.elements, elements);
+    }
+
+    #[test]
+    fn errors() {
+        let from_coeffs = BSpline::<_, Vec<[f64; 2]>, _>::from_coeffs;
+        let knots = vec![0.0, 1.0, 2.0, 3.0];
+        let coeffs = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        assert!(from_coeffs(2, 2, knots.clone(), coeffs.clone()).is_ok());
+        for (degree, dims) in [(2, 0), (2, 4), (3, 2), (2, 3), (1, 2)] {
+            assert!(matches!(
+                from_coeffs(degree, dims, knots.clone(), coeffs.clone()),
+                Err(BSplineError::IncongruousCoefficients(_))
+            ));
+        }
+        assert!(matches!(
+            from_coeffs(2, 2, vec![0.0, 2.0, 1.0, 3.0], coeffs),
+            Err(BSplineError::NotSorted(_))
+        ));
+    }
+}
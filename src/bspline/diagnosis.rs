@@ -0,0 +1,164 @@
+//! Diagnosis of bspline builders, which reports configurations that produce degenerate curves.
+
+use super::BSplineError;
+use crate::SortedGenerator;
+use alloc::{vec, vec::Vec};
+use core::fmt;
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Knots closer together than this fraction of the range of all knots are considered near-duplicates.
+const NEAR_DUPLICATE: f64 = 1e-9;
+/// Knot spans smaller than this fraction of the average span are considered suspiciously small.
+const SMALL_SPAN: f64 = 1e-3;
+
+/// An issue found while diagnosing a bspline builder.
+#[derive(Debug, Copy, Clone)]
+pub enum BSplineIssue<R> {
+    /// The builder failed, for example because the degree does not match the number of elements.
+    /// Building the curve returns this error.
+    Invalid(BSplineError),
+    /// The weight of the element at `index` is zero or negative.
+    ///
+    /// Elements with zero weights lie at infinity, such that the curve generates NaN or infinite values
+    /// wherever only they contribute. Negative weights may cancel out positive ones.
+    NonPositiveWeight {
+        /// The index of the element.
+        index: usize,
+        /// The weight of the element.
+        weight: R,
+    },
+    /// The knots at `index` and `index + 1` are almost, but not exactly, equal.
+    ///
+    /// Such knots usually come from rounding errors and create a numerically ill-conditioned span.
+    /// They can be merged with `snap_knots()`.
+    NearDuplicateKnots {
+        /// The index of the first knot.
+        index: usize,
+        /// The distance between both knots.
+        distance: R,
+    },
+    /// The span between the knots at `index` and `index + 1` is much smaller than the average span.
+    SmallKnotSpan {
+        /// The index of the first knot.
+        index: usize,
+        /// The length of the span.
+        span: R,
+    },
+}
+
+impl<R> fmt::Display for BSplineIssue<R>
+where
+    R: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BSplineIssue::Invalid(err) => err.fmt(f),
+            BSplineIssue::NonPositiveWeight { index, weight } => write!(
+                f,
+                "The element at index {} has the weight {}, which is not positive.",
+                index, weight
+            ),
+            BSplineIssue::NearDuplicateKnots { index, distance } => write!(
+                f,
+                "The knots at index {} and {} are only {} apart. Consider snapping them together.",
+                index,
+                index + 1,
+                distance
+            ),
+            BSplineIssue::SmallKnotSpan { index, span } => write!(
+                f,
+                "The span between the knots at index {} and {} is only {} long, which is much smaller than the average span.",
+                index,
+                index + 1,
+                span
+            ),
+        }
+    }
+}
+
+/// Report of all issues found while diagnosing a bspline builder.
+///
+/// This struct is created by the `diagnose()` method of the bspline builders.
+/// Please look there for more information.
+#[derive(Debug, Clone)]
+pub struct BSplineDiagnosis<R> {
+    issues: Vec<BSplineIssue<R>>,
+}
+
+impl<R> BSplineDiagnosis<R> {
+    /// Create a report with the single issue of the builder having failed.
+    pub(crate) fn invalid(err: BSplineError) -> Self {
+        BSplineDiagnosis {
+            issues: vec![BSplineIssue::Invalid(err)],
+        }
+    }
+    /// Returns all issues found.
+    pub fn issues(&self) -> &[BSplineIssue<R>] {
+        &self.issues
+    }
+    /// Returns `true` if no issues were found.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl<R> BSplineDiagnosis<R>
+where
+    R: Real + FromPrimitive,
+{
+    /// Create a report of the issues of the given knots and weights.
+    pub(crate) fn new<K, W>(knots: &K, weights: W) -> Self
+    where
+        K: SortedGenerator<Output = R>,
+        W: IntoIterator<Item = R>,
+    {
+        let mut issues: Vec<_> = weights
+            .into_iter()
+            .enumerate()
+            .filter(|(_, weight)| *weight <= R::zero())
+            .map(|(index, weight)| BSplineIssue::NonPositiveWeight { index, weight })
+            .collect();
+        let len = knots.len();
+        if len >= 2 {
+            let range = knots.gen(len - 1) - knots.gen(0);
+            let spans = (0..len - 1).map(|i| knots.gen(i + 1) - knots.gen(i));
+            let nonzero = spans.clone().filter(|span| *span > R::zero()).count();
+            let near = range * R::from_f64(NEAR_DUPLICATE).unwrap();
+            let small =
+                range / R::from_usize(nonzero.max(1)).unwrap() * R::from_f64(SMALL_SPAN).unwrap();
+            for (index, span) in spans.enumerate() {
+                if span <= R::zero() {
+                    continue;
+                }
+                if span <= near {
+                    issues.push(BSplineIssue::NearDuplicateKnots {
+                        index,
+                        distance: span,
+                    });
+                } else if span < small {
+                    issues.push(BSplineIssue::SmallKnotSpan { index, span });
+                }
+            }
+        }
+        BSplineDiagnosis { issues }
+    }
+}
+
+impl<R> fmt::Display for BSplineDiagnosis<R>
+where
+    R: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.issues.is_empty() {
+            return write!(f, "No issues found.");
+        }
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "- {}", issue)?;
+        }
+        Ok(())
+    }
+}
@@ -28,6 +28,8 @@ pub enum BSplineError {
     IncongruousElementsKnots(IncongruousElementsKnots),
     /// Error returned when elements and degree are ill-matched.
     IncongruousElementsDegree(IncongruousElementsDegree),
+    /// Error returned when flat coefficients do not match the degree and dimension given.
+    IncongruousCoefficients(IncongruousCoefficients),
 }
 
 impl fmt::Display for BSplineError {
@@ -40,6 +42,7 @@ impl fmt::Display for BSplineError {
             BSplineError::TooFewKnots(inner) => inner.fmt(f),
             BSplineError::IncongruousElementsKnots(inner) => inner.fmt(f),
             BSplineError::IncongruousElementsDegree(inner) => inner.fmt(f),
+            BSplineError::IncongruousCoefficients(inner) => inner.fmt(f),
         }
     }
 }
@@ -86,6 +89,12 @@ impl From<IncongruousElementsDegree> for BSplineError {
     }
 }
 
+impl From<IncongruousCoefficients> for BSplineError {
+    fn from(from: IncongruousCoefficients) -> Self {
+        BSplineError::IncongruousCoefficients(from)
+    }
+}
+
 #[cfg(feature = "std")]
 impl Error for BSplineError {}
 
@@ -124,6 +133,7 @@ enum BSplineBuildMode {
     Open,
     Clamped,
     Legacy,
+    Periodic,
 }
 
 /// Error returned when the number of elements and knots are ill-matched.
@@ -160,6 +170,14 @@ impl IncongruousElementsKnots {
             mode: BSplineBuildMode::Legacy,
         }
     }
+    /// Invalid values for a periodic bspline
+    pub fn periodic(elements: usize, knots: usize) -> Self {
+        IncongruousElementsKnots {
+            elements,
+            knots,
+            mode: BSplineBuildMode::Periodic,
+        }
+    }
 }
 
 impl fmt::Display for IncongruousElementsKnots {
@@ -189,6 +207,14 @@ impl fmt::Display for IncongruousElementsKnots {
                     self.elements, self.knots
                 )
             }
+            BSplineBuildMode::Periodic => {
+                write!(
+                    f,
+                    "Found {} elements and {} knots, but for a periodic bspline 
+                    there must be exactly one knot more than there are elements.",
+                    self.elements, self.knots
+                )
+            }
         }
     }
 }
@@ -230,6 +256,14 @@ impl IncongruousElementsDegree {
             mode: BSplineBuildMode::Legacy,
         }
     }
+    /// Invalid values for a periodic bspline
+    pub fn periodic(elements: usize, degree: usize) -> Self {
+        IncongruousElementsDegree {
+            elements,
+            degree,
+            mode: BSplineBuildMode::Periodic,
+        }
+    }
 }
 
 impl fmt::Display for IncongruousElementsDegree {
@@ -260,9 +294,85 @@ impl fmt::Display for IncongruousElementsDegree {
                     self.elements, self.degree
                 )
             }
+            BSplineBuildMode::Periodic => {
+                write!(
+                    f,
+                    "Found {} elements and degree of {}, but for a periodic bspline 
+                    there must be more elements than the degree of the spline.",
+                    self.elements, self.degree
+                )
+            }
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl Error for IncongruousElementsDegree {}
+
+/// Error returned when flat coefficients and knots do not describe a bspline of the given degree and dimension.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct IncongruousCoefficients {
+    knots: usize,
+    coefficients: usize,
+    degree: usize,
+    dims: usize,
+}
+
+impl IncongruousCoefficients {
+    /// Create a new error with the number of knots and coefficients found and the degree and dimension given.
+    pub fn new(knots: usize, coefficients: usize, degree: usize, dims: usize) -> Self {
+        IncongruousCoefficients {
+            knots,
+            coefficients,
+            degree,
+            dims,
+        }
+    }
+}
+
+impl fmt::Display for IncongruousCoefficients {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Found {} knots (#k) and {} coefficients, which do not describe a bspline of degree {}
+            with elements of dimension {}. There have to be #k + 1 - degree elements,
+            each consisting of exactly dimension many coefficients.",
+            self.knots, self.coefficients, self.degree, self.dims
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for IncongruousCoefficients {}
+
+/// Error returned if a bspline can not be converted to a bezier curve.
+///
+/// This is the case if the bspline consists of more than one bezier segment.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NotBezier {
+    elements: usize,
+    degree: usize,
+}
+
+impl NotBezier {
+    /// Create a new error with the number of elements and the degree of the bspline.
+    pub fn new(elements: usize, degree: usize) -> Self {
+        NotBezier { elements, degree }
+    }
+}
+
+impl fmt::Display for NotBezier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The bspline with {} elements and degree {} is not a single bezier segment.
+            This needs exactly degree + 1 elements, with the first and the last degree knots being equal.",
+            self.elements, self.degree
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NotBezier {}
@@ -35,29 +35,64 @@
 //! [`BSplineBuilder`]: BSplineBuilder
 mod adaptors;
 mod builder;
+#[cfg(feature = "alloc")]
+mod coeffs;
+#[cfg(feature = "alloc")]
+mod diagnosis;
 mod error;
 
-pub use adaptors::{BorderBuffer, BorderDeletion};
+pub use adaptors::{BorderBuffer, BorderDeletion, PeriodicKnots};
 pub use builder::{BSplineBuilder, BSplineDirector};
+#[cfg(feature = "alloc")]
+pub use coeffs::Coefficients;
+#[cfg(feature = "alloc")]
+pub use diagnosis::{BSplineDiagnosis, BSplineIssue};
 pub use error::{
-    BSplineError, IncongruousElementsDegree, IncongruousElementsKnots, InvalidDegree, NotSorted,
-    TooFewElements, TooSmallWorkspace,
+    BSplineError, IncongruousCoefficients, IncongruousElementsDegree, IncongruousElementsKnots,
+    InvalidDegree, NotBezier, NotSorted, TooFewElements, TooSmallWorkspace,
 };
 
+use crate::base::bounds;
+#[cfg(feature = "alloc")]
+use crate::base::knot_midpoints;
+#[cfg(feature = "bezier")]
+use crate::bezier::Bezier;
 use crate::builder::Unknown;
-use crate::{Curve, DiscreteGenerator, Generator, SortedGenerator, Space};
+#[cfg(feature = "alloc")]
+use crate::weights::{Homogeneous, Weighted};
+#[cfg(feature = "alloc")]
+use crate::ConstSpace;
+#[cfg(feature = "alloc")]
+use crate::DynSpace;
+#[cfg(feature = "alloc")]
+use crate::Equidistant;
+use crate::{
+    Bounded, Curve, Derivative, DiscreteGenerator, Generator, Sorted, SortedGenerator, Space,
+};
 use builder::Open;
 use num_traits::real::Real;
+use num_traits::FromPrimitive;
 use topology_traits::Merge;
 
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(all(feature = "alloc", feature = "bezier"))]
+use core::cmp::Ordering;
 use core::fmt::Debug;
+#[cfg(feature = "alloc")]
+use core::ops::Add;
+use core::ops::{Mul, Sub};
 
 /// BSpline curve.
 ///
 /// See [bspline module] for more information.
 ///
+/// The curve implements [`Hash`] and [`Eq`] if its elements, knots and workspace do, such that it can be used as key for caching.
+/// As floats do not implement [`Hash`], they have to be wrapped in a hashable type,
+/// for example `OrderedFloat` of the crate `ordered-float`.
+///
 /// [bspline module]: self
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct BSpline<K, E, S> {
     elements: E,
@@ -115,34 +150,264 @@ impl BSpline<Unknown, Unknown, Unknown> {
     pub fn builder() -> BSplineBuilder<Unknown, Unknown, Unknown, Unknown, Open> {
         BSplineBuilder::new()
     }
+
+    /// Create a clamped bspline of the given degree with the points as its control polygon.
+    ///
+    /// The knots are spaced equidistantly over the domain `[0.0, 1.0]`, such that the curve
+    /// starts at the first point, ends at the last one and is `degree - 1` times continuously
+    /// differentiable everywhere in between.
+    ///
+    /// This is a shortcut for the builder chain
+    /// `clamped().elements(points).equidistant().degree(degree).normalized().dynamic().build()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if less than two points are given.
+    /// Returns [`InvalidDegree`] if the degree is 0.
+    /// Returns [`IncongruousElementsDegree`] if the degree is not less than the number of points.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::through_polygon::<_, f64>([20.0, 100.0, 0.0, 200.0], 3)?;
+    /// let expected = [20.0, 53.75, 65.0, 98.75, 200.0];
+    /// for (value, expect) in bspline.take(5).zip(expected) {
+    ///     assert_f64_near!(value, expect);
+    /// }
+    /// assert!(BSpline::through_polygon::<_, f64>([1.0, 2.0], 2).is_err());
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`TooFewElements`]: BSplineError::TooFewElements
+    /// [`InvalidDegree`]: BSplineError::InvalidDegree
+    /// [`IncongruousElementsDegree`]: BSplineError::IncongruousElementsDegree
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    pub fn through_polygon<E, R>(
+        points: E,
+        degree: usize,
+    ) -> Result<BSpline<BorderBuffer<Equidistant<R>>, E, DynSpace<E::Output>>, BSplineError>
+    where
+        E: DiscreteGenerator,
+        E::Output: Merge<R> + Clone + Default,
+        R: Real + FromPrimitive,
+    {
+        BSpline::builder()
+            .clamped()
+            .elements(points)
+            .equidistant::<R>()
+            .degree(degree)
+            .normalized()
+            .dynamic()
+            .build()
+    }
+
+    /// Create an exact circular arc as rational quadratic bspline.
+    ///
+    /// The arc lies around `center` with the given `radius` and goes from `start_angle` to `end_angle`,
+    /// both in radians and counterclockwise from the first axis. If the end angle is less than
+    /// the start angle, the arc goes clockwise. Arcs of more than a full circle are allowed.
+    ///
+    /// The arc is split into segments of at most 90°, each represented by three weighted elements
+    /// as given by [`Bezier::rational_conic()`]. The domain of the curve is `[0.0, 1.0]`,
+    /// however the curve is not parameterized by arc length, such that equidistant inputs
+    /// do not result in equidistant points on the arc.
+    ///
+    /// Points are created from their coordinates with `From<[R; 2]>`, which is implemented by
+    /// the point types of most math libraries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::BSpline, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// # use core::ops::{Add, Mul};
+    /// #[derive(Debug, Default, Clone, Copy)]
+    /// struct Point(f64, f64);
+    /// # impl Add for Point {
+    /// #     type Output = Point;
+    /// #     fn add(self, other: Point) -> Point { Point(self.0 + other.0, self.1 + other.1) }
+    /// # }
+    /// # impl Mul<f64> for Point {
+    /// #     type Output = Point;
+    /// #     fn mul(self, scalar: f64) -> Point { Point(self.0 * scalar, self.1 * scalar) }
+    /// # }
+    /// # impl core::ops::Div<f64> for Point {
+    /// #     type Output = Point;
+    /// #     fn div(self, scalar: f64) -> Point { Point(self.0 / scalar, self.1 / scalar) }
+    /// # }
+    /// impl From<[f64; 2]> for Point {
+    ///     fn from([x, y]: [f64; 2]) -> Self {
+    ///         Point(x, y)
+    ///     }
+    /// }
+    /// // three quarters of a circle around (1.0, 2.0)
+    /// let arc = BSpline::circular_arc(Point(1.0, 2.0), 3.0, 0.0, 1.5 * core::f64::consts::PI);
+    /// for point in arc.take(10) {
+    ///     assert_f64_near!((point.0 - 1.0).hypot(point.1 - 2.0), 3.0);
+    /// }
+    /// ```
+    ///
+    /// [`Bezier::rational_conic()`]: crate::bezier::Bezier::rational_conic()
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    pub fn circular_arc<P, R>(
+        center: P,
+        radius: R,
+        start_angle: R,
+        end_angle: R,
+    ) -> Weighted<BSpline<Sorted<Vec<R>>, Vec<Homogeneous<P, R>>, ConstSpace<Homogeneous<P, R>, 3>>>
+    where
+        P: From<[R; 2]> + Add<Output = P> + Mul<R, Output = P> + Default + Copy,
+        R: Real + FromPrimitive + Default,
+    {
+        let sweep = end_angle - start_angle;
+        let quarter = R::from_f64(core::f64::consts::FRAC_PI_2).unwrap();
+        let segments = (sweep.abs() / quarter)
+            .ceil()
+            .to_usize()
+            .unwrap_or(0)
+            .max(1);
+        let count = R::from_usize(segments).unwrap();
+        let step = sweep / count;
+        let weight = (step / (R::one() + R::one())).cos();
+        let point = |angle: R, distance: R| center + P::from([angle.cos(), angle.sin()]) * distance;
+        let mut elements = Vec::with_capacity(2 * segments + 1);
+        elements.push(Homogeneous::new(point(start_angle, radius)));
+        let mut knots = Vec::with_capacity(2 * segments + 2);
+        knots.extend([R::zero(), R::zero()]);
+        for i in 0..segments {
+            let angle = start_angle + step * R::from_usize(i).unwrap();
+            // the intersection of the tangents at both ends of the segment
+            let middle = point(angle + step / (R::one() + R::one()), radius / weight);
+            elements.push(Homogeneous::weighted_unchecked(middle, weight));
+            elements.push(Homogeneous::new(point(angle + step, radius)));
+            let knot = R::from_usize(i + 1).unwrap() / count;
+            knots.extend([knot, knot]);
+        }
+        Weighted::new(BSpline::new_unchecked(
+            elements,
+            Sorted::new_unchecked(knots),
+            ConstSpace::new(),
+        ))
+    }
+
+    /// Approximate a function by a bspline of the given degree and knots.
+    ///
+    /// The function is evaluated at the Greville abscissae of the knots, that is the averages of `degree`
+    /// consecutive knots. The bspline interpolates the function at these sites.
+    /// For the curve to interpolate the function at the borders of its domain, the knots should be clamped,
+    /// that is their first and last knot should be repeated `degree` times. Otherwise some sites lie
+    /// outside of the domain of the curve, where the function gets evaluated as well.
+    ///
+    /// The number of elements of the resulting curve is `knots.len() - degree + 1`. As for every bspline,
+    /// no knot should be repeated more than `degree` times.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDegree`] if the degree is 0 or not less than the resulting number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve, Sorted};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let knots = Sorted::new([0.0, 0.0, 1.0, 2.0, 2.0])?;
+    /// let parabola = BSpline::from_fn(knots, 2, |x: f64| x * x)?;
+    /// // polynomials up to the degree of the curve are reproduced exactly
+    /// assert_f64_near!(parabola.gen(0.5), 0.25);
+    /// assert_f64_near!(parabola.gen(1.5), 2.25);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`InvalidDegree`]: BSplineError::InvalidDegree
+    #[cfg(feature = "alloc")]
+    pub fn from_fn<K, R, T, F>(
+        knots: K,
+        degree: usize,
+        function: F,
+    ) -> Result<BSpline<K, Vec<T>, DynSpace<T>>, BSplineError>
+    where
+        K: SortedGenerator<Output = R>,
+        R: Real + FromPrimitive,
+        T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Default + Copy,
+        F: Fn(R) -> T,
+    {
+        if degree == 0 || knots.len() < 2 * degree {
+            return Err(InvalidDegree::new(degree).into());
+        }
+        let len = knots.len() + 1 - degree;
+        // only used to calculate the basis functions
+        let basis = BSpline {
+            elements: (),
+            knots,
+            space: (),
+            degree,
+        };
+        let scale = R::from_usize(degree).unwrap().recip();
+        let mut rows = Vec::with_capacity(len);
+        let mut values = Vec::with_capacity(len);
+        for i in 0..len {
+            let site = (i..i + degree).fold(R::zero(), |sum, j| sum + basis.knots.gen(j)) * scale;
+            let (first, mut row) = basis.basis_derivatives(site, 0);
+            rows.push((first, row.swap_remove(0)));
+            values.push(function(site));
+        }
+        let elements = solve_banded(&mut rows, values);
+        Ok(BSpline::new_unchecked(
+            elements,
+            basis.knots,
+            DynSpace::new(degree + 1),
+        ))
+    }
 }
 
-impl<K, E, S> BSpline<K, E, S>
+impl<K, E, S, R> Generator<R> for BSpline<K, E, S>
 where
     E: DiscreteGenerator,
     S: Space<E::Output>,
+    E::Output: Merge<R> + Clone,
+    R: Real + Debug,
+    K: SortedGenerator<Output = R>,
 {
-    /// Creates a workspace and copies degree+1 elements into it, starting from given index.
-    fn workspace(&self, index: usize) -> impl AsMut<[E::Output]> {
-        let mut workspace = self.space.workspace();
-        let mut_workspace = workspace.as_mut();
-        for (i, val) in mut_workspace.iter_mut().enumerate().take(self.degree + 1) {
-            *val = self.elements.gen(index - self.degree + i);
-        }
-        workspace
+    type Output = E::Output;
+    fn gen(&self, scalar: R) -> E::Output {
+        let (_, mut workspace) = self.de_boor(scalar, self.degree);
+        workspace.as_mut()[0].clone()
     }
 }
 
-impl<K, E, S, R> Generator<R> for BSpline<K, E, S>
+impl<K, E, S, R> BSpline<K, E, S>
 where
     E: DiscreteGenerator,
     S: Space<E::Output>,
-    E::Output: Merge<R> + Copy,
+    E::Output: Merge<R> + Clone,
     R: Real + Debug,
     K: SortedGenerator<Output = R>,
 {
-    type Output = E::Output;
-    fn gen(&self, scalar: R) -> E::Output {
+    /// Executes the first `levels` levels of the de Boor algorithm.
+    ///
+    /// Returns the index of the knot span and the workspace with the calculated elements at its start.
+    fn de_boor(&self, scalar: R, levels: usize) -> (usize, impl AsMut<[E::Output]>) {
+        let mut workspace = self.space.workspace();
+        let index = self.de_boor_in(scalar, levels, workspace.as_mut());
+        (index, workspace)
+    }
+
+    /// Executes the first `levels` levels of the de Boor algorithm inside the given workspace.
+    ///
+    /// Returns the index of the knot span. The calculated elements are at the start of the workspace.
+    fn de_boor_in(&self, scalar: R, levels: usize, elements: &mut [E::Output]) -> usize {
         // we do NOT calculaute a possible multiplicity of the scalar, as we assume
         // the chance of hitting a knot is almost zero.
         let lower_cut = self.degree;
@@ -152,20 +417,287 @@ where
         let index = self
             .knots
             .strict_upper_bound_clamped(scalar, lower_cut, upper_cut);
+        self.de_boor_span(scalar, index, levels, elements);
+        index
+    }
+
+    /// Returns the index of the knot span of `scalar` by stepping from the knot span `index`.
+    ///
+    /// `index` has to be the knot span of an input not greater than `scalar` if `descending` is false
+    /// or of an input not less than `scalar` if `descending` is true.
+    /// The cost is linear in the number of knots passed.
+    fn step_span(&self, scalar: R, mut index: usize, descending: bool) -> usize {
+        let lower_cut = self.degree;
+        let upper_cut = self.knots.len() - self.degree;
+        if descending {
+            while index > lower_cut && self.knots.gen(index - 1) > scalar {
+                index -= 1;
+            }
+        } else {
+            while index < upper_cut && self.knots.gen(index) <= scalar {
+                index += 1;
+            }
+        }
+        index
+    }
 
+    /// Executes the first `levels` levels of the de Boor algorithm inside the knot span `index`.
+    fn de_boor_span(&self, scalar: R, index: usize, levels: usize, elements: &mut [E::Output]) {
         //copy elements into workspace
-        let mut workspace = self.workspace(index);
-        let elements = workspace.as_mut();
+        for (i, val) in elements.iter_mut().enumerate().take(self.degree + 1) {
+            *val = self.elements.gen(index - self.degree + i);
+        }
 
-        for r in 1..=self.degree {
+        for r in 1..=levels {
             for j in 0..=(self.degree - r) {
                 let i = j + r + index - self.degree;
                 let factor = (scalar - self.knots.gen(i - 1))
                     / (self.knots.gen(i + self.degree - r) - self.knots.gen(i - 1));
-                elements[j] = elements[j].merge(elements[j + 1], factor);
+                elements[j] = elements[j].clone().merge(elements[j + 1].clone(), factor);
+            }
+        }
+    }
+}
+
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    S: Space<E::Output>,
+    E::Output: Merge<R> + Mul<R, Output = E::Output> + Sub<Output = E::Output> + Copy,
+    R: Real + FromPrimitive + Debug,
+    K: SortedGenerator<Output = R>,
+{
+    /// Generate the value and its tangent, in this order.
+    ///
+    /// Both are calculated at once, which is cheaper than calculating them separately.
+    pub fn gen_with_tangent(&self, scalar: R) -> [E::Output; 2] {
+        if self.degree == 0 {
+            // step functions are flat everywhere but at their jumps
+            let value = self.gen(scalar);
+            return [value, value * R::zero()];
+        }
+        let (index, mut workspace) = self.de_boor(scalar, self.degree - 1);
+        let elements = workspace.as_mut();
+        let start = self.knots.gen(index - 1);
+        let span = self.knots.gen(index) - start;
+        let value = elements[0].merge(elements[1], (scalar - start) / span);
+        let degree = R::from_usize(self.degree).unwrap();
+        let tangent = (elements[1] - elements[0]) * (degree / span);
+        [value, tangent]
+    }
+}
+
+impl<K, E, S> BSpline<K, E, S> {
+    /// Returns the elements (control points) of the curve.
+    ///
+    /// As every collection of elements is a [`DiscreteGenerator`], they can be read back with its
+    /// [`iter()`] method, for example to export the curve into another format.
+    ///
+    /// [`iter()`]: DiscreteGenerator::iter()
+    pub fn elements(&self) -> &E {
+        &self.elements
+    }
+
+    /// Returns the knots of the curve.
+    ///
+    /// This includes the knots outside of the domain of the curve.
+    /// Knots which are computed on demand, like [`Equidistant`], can be read back with the
+    /// [`iter()`] method of [`DiscreteGenerator`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, DiscreteGenerator};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .equidistant::<f64>()
+    ///                 .degree(1)
+    ///                 .normalized()
+    ///                 .constant::<2>()
+    ///                 .build()?;
+    /// let knots: Vec<f64> = bspline.knots().iter().collect();
+    /// assert_eq!(knots, vec![0.0,0.5,1.0]);
+    /// assert_eq!(bspline.elements(), &[0.0,5.0,3.0]);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Equidistant`]: crate::Equidistant
+    /// [`iter()`]: DiscreteGenerator::iter()
+    pub fn knots(&self) -> &K {
+        &self.knots
+    }
+
+    /// Returns the degree of the curve.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Collect the elements (control points) of the curve into a vector.
+    ///
+    /// In contrast to [`elements()`], the returned vector is an owned and editable snapshot of the elements,
+    /// no matter if they are stored in an array, borrowed or generated by a closure.
+    /// The elements of periodic curves include the elements which are repeated at their end.
+    ///
+    /// [`elements()`]: BSpline::elements()
+    #[cfg(feature = "alloc")]
+    pub fn control_points_vec(&self) -> Vec<E::Output>
+    where
+        E: DiscreteGenerator,
+    {
+        self.elements.iter().collect()
+    }
+}
+
+impl<K, E, S> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Bounded + Clone,
+{
+    /// Returns the axis-aligned bounding box of the elements, as minimum and maximum in this order.
+    ///
+    /// Within its domain, a bspline lies within the convex hull of its elements, such that the box
+    /// is conservative, that is, it always contains the whole curve but may be bigger than necessary.
+    /// For a tighter but approximate box, see [`Curve::sample_bounds()`].
+    /// The exact box of the curve is calculated by [`tight_bounds()`], which needs an accessor
+    /// mapping the elements to coordinates instead of [`Bounded`] elements.
+    ///
+    /// [`Curve::sample_bounds()`]: crate::Curve::sample_bounds()
+    /// [`tight_bounds()`]: BSpline::tight_bounds()
+    pub fn control_bounds(&self) -> [E::Output; 2] {
+        // bsplines always have at least one element
+        bounds(self.elements.iter()).unwrap()
+    }
+}
+
+impl<K, E, S, R> Derivative<R> for BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    S: Space<E::Output>,
+    E::Output: Merge<R> + Mul<R, Output = E::Output> + Sub<Output = E::Output> + Copy,
+    R: Real + FromPrimitive + Debug,
+    K: SortedGenerator<Output = R>,
+{
+    fn derivative(&self, scalar: R) -> Self::Output {
+        let [_, tangent] = self.gen_with_tangent(scalar);
+        tangent
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    R: Real + FromPrimitive,
+    K: SortedGenerator<Output = R>,
+{
+    /// Calculate the basis functions which are non-zero at the given scalar and their derivatives.
+    ///
+    /// Returns the index of the first element with a non-zero basis function together with the values
+    /// of the basis functions. The value at `[k][j]` is the `k`-th derivative of the basis function
+    /// of the element at index `first + j`, for all derivatives up to `order` and all `degree + 1` active
+    /// elements. The row `[0]` contains the values of the basis functions themselves.
+    /// Derivatives of an order higher than the degree are zero.
+    ///
+    /// The basis functions are the ones of the non-rational bspline, weights are not taken into account.
+    /// Their values only depend on the knots and the degree, not the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///                 .elements([0.0,4.0,2.0])
+    ///                 .knots([0.0,0.0,1.0,1.0])
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// let (first, basis) = bspline.basis_derivatives(0.5, 1);
+    /// assert_eq!(first, 0);
+    /// // the quadratic bernstein polynomials and their derivatives
+    /// let expected = [[0.25, 0.5, 0.25], [-1.0, 0.0, 1.0]];
+    /// for (row, expected) in basis.iter().zip(expected) {
+    ///     for (value, expected) in row.iter().zip(expected) {
+    ///         assert_f64_near!(*value, expected);
+    ///     }
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn basis_derivatives(&self, scalar: R, order: usize) -> (usize, Vec<Vec<R>>) {
+        let degree = self.degree;
+        // same knot span as used for the generation of values
+        let span = self
+            .knots
+            .strict_upper_bound_clamped(scalar, degree, self.knots.len() - degree);
+        // The knots are missing the first knot of the usual definition,
+        // such that `knot(m)` corresponds to the m-th knot of the usual definition.
+        let knot = |m: usize| self.knots.gen(m - 1);
+        // algorithm A2.3 of "The NURBS Book" by Piegl and Tiller
+        let mut ndu = vec![vec![R::zero(); degree + 1]; degree + 1];
+        let mut left = vec![R::zero(); degree + 1];
+        let mut right = vec![R::zero(); degree + 1];
+        ndu[0][0] = R::one();
+        for j in 1..=degree {
+            left[j] = scalar - knot(span + 1 - j);
+            right[j] = knot(span + j) - scalar;
+            let mut saved = R::zero();
+            for r in 0..j {
+                // lower triangle
+                ndu[j][r] = right[r + 1] + left[j - r];
+                let temp = ndu[r][j - 1] / ndu[j][r];
+                // upper triangle
+                ndu[r][j] = saved + right[r + 1] * temp;
+                saved = left[j - r] * temp;
+            }
+            ndu[j][j] = saved;
+        }
+        let mut ders = vec![vec![R::zero(); degree + 1]; order + 1];
+        for (j, value) in ders[0].iter_mut().enumerate() {
+            *value = ndu[j][degree];
+        }
+        let highest = order.min(degree);
+        let mut a = [vec![R::zero(); degree + 1], vec![R::zero(); degree + 1]];
+        for r in 0..=degree {
+            let (mut s1, mut s2) = (0, 1);
+            a[0][0] = R::one();
+            for k in 1..=highest {
+                let mut d = R::zero();
+                let pk = degree - k;
+                if r >= k {
+                    let rk = r - k;
+                    a[s2][0] = a[s1][0] / ndu[pk + 1][rk];
+                    d = a[s2][0] * ndu[rk][pk];
+                }
+                let j1 = if r >= k - 1 { 1 } else { k - r };
+                let j2 = if r <= pk + 1 { k - 1 } else { degree - r };
+                for j in j1..=j2 {
+                    // r + j >= k holds for all j
+                    let rkj = r + j - k;
+                    a[s2][j] = (a[s1][j] - a[s1][j - 1]) / ndu[pk + 1][rkj];
+                    d = d + a[s2][j] * ndu[rkj][pk];
+                }
+                if r <= pk {
+                    a[s2][k] = -a[s1][k - 1] / ndu[pk + 1][r];
+                    d = d + a[s2][k] * ndu[r][pk];
+                }
+                ders[k][r] = d;
+                core::mem::swap(&mut s1, &mut s2);
+            }
+        }
+        let mut factor = R::from_usize(degree).unwrap();
+        for (k, row) in ders.iter_mut().enumerate().take(highest + 1).skip(1) {
+            for value in row.iter_mut() {
+                *value = *value * factor;
             }
+            factor = factor * R::from_usize(degree - k).unwrap();
         }
-        elements[0]
+        (span - degree, ders)
     }
 }
 
@@ -173,16 +705,63 @@ impl<K, E, S, R> Curve<R> for BSpline<K, E, S>
 where
     E: DiscreteGenerator,
     S: Space<E::Output>,
-    E::Output: Merge<R> + Copy,
+    E::Output: Merge<R> + Clone,
     R: Real + Debug,
     K: SortedGenerator<Output = R>,
 {
     fn domain(&self) -> [R; 2] {
+        // the knots of a step function are its jumps, the outermost ones bound its domain
+        let border = self.degree.max(1);
         [
-            self.knots.gen(self.degree - 1),
-            self.knots.gen(self.knots.len() - self.degree),
+            self.knots.gen(border - 1),
+            self.knots.gen(self.knots.len() - border),
         ]
     }
+    /// Generate the values at all given inputs and write them into `out`.
+    ///
+    /// The workspace is created only once and reused for all inputs.
+    ///
+    /// Sweeping through the curve is cheap in both directions: whether the inputs ascend or descend
+    /// is detected from the first two of them. As long as the inputs keep this direction,
+    /// the knot span of each input is found by stepping from the knot span of the previous one,
+    /// which is O(1) amortized per input. Inputs against this direction fall back to a search.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalars` and `out` differ in length.
+    fn gen_batch(&self, scalars: &[R], out: &mut [E::Output]) {
+        assert_eq!(
+            scalars.len(),
+            out.len(),
+            "the number of inputs and outputs have to be equal"
+        );
+        let descending = match scalars {
+            [first, second, ..] => second < first,
+            _ => false,
+        };
+        let mut workspace = self.space.workspace();
+        let elements = workspace.as_mut();
+        let mut previous = None;
+        for (scalar, slot) in scalars.iter().copied().zip(out.iter_mut()) {
+            let index = match previous {
+                Some((last, index))
+                    if (descending && scalar <= last) || (!descending && scalar >= last) =>
+                {
+                    let index = self.step_span(scalar, index, descending);
+                    self.de_boor_span(scalar, index, self.degree, elements);
+                    index
+                }
+                _ => self.de_boor_in(scalar, self.degree, elements),
+            };
+            previous = Some((scalar, index));
+            *slot = elements[0].clone();
+        }
+    }
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        let border = self.degree.max(1);
+        knot_midpoints(&self.knots, border - 1, self.knots.len() - border)
+    }
 }
 
 impl<K, E, S> BSpline<K, E, S>
@@ -193,36 +772,36 @@ where
 {
     /// Creates a bspline curve of elements and knots given.
     ///
-    /// The resulting degree of the curve is `elements.len() - knots.len() +1`.
-    /// The domain for the curve with degree `p` is `knots[p-1]` and `knots[knots.len() - p -2]`.
+    /// The resulting degree of the curve is `knots.len() - elements.len() + 1`.
+    /// The domain for the curve with degree `p` is `knots[p-1]` and `knots[knots.len() - p]`.
+    /// A curve of degree 0 is a step function, whose domain is spanned by its first and last knot.
     ///
     /// The knots have to be sorted.
     ///
     /// # Errors
     ///
     /// [`TooFewElements`] if there are less than two elements.
-    /// [`InvalidDegree`] if degree is not at least 1 and at most the number of elements - 1.
     /// [`TooSmallWorkspace`] if the workspace is not bigger than the degree of the curve.
-    /// [`IncongruousElementsKnots`] either if the amount of knots is less than the amount of elements
+    /// [`IncongruousElementsKnots`] either if the amount of knots is less than the amount of elements - 1
     /// or if the anoumt of knots is more than double the amount of elements.
     ///
     /// [`TooFewElements`]: BSplineError
-    /// [`InvalidDegree`]: BSplineError
     /// [`TooSmallWorkspace`]: BSplineError
+    /// [`IncongruousElementsKnots`]: BSplineError
     pub fn new(elements: E, knots: K, space: S) -> Result<Self, BSplineError> {
         //Test if we have at least two elements
         if elements.len() < 2 {
             return Err(TooFewElements::new(elements.len()).into());
         }
-        // Test if degree is strict positive
-        if knots.len() < elements.len() {
+        // Test if degree is not negative
+        if knots.len() + 1 < elements.len() {
             return Err(IncongruousElementsKnots::open(elements.len(), knots.len()).into());
         }
         // Test if we have enough elements for the degree
-        if elements.len() <= knots.len() - elements.len() + 1 {
+        if elements.len() <= knots.len() + 1 - elements.len() {
             return Err(IncongruousElementsKnots::open(elements.len(), knots.len()).into());
         }
-        let degree = knots.len() - elements.len() + 1;
+        let degree = knots.len() + 1 - elements.len();
         if space.len() <= degree {
             return Err(TooSmallWorkspace::new(space.len(), degree).into());
         }
@@ -243,15 +822,16 @@ where
 {
     /// Creates a bspline curve of elements and knots given.
     ///
-    /// The resulting degree of the curve is `elements.len() - knots.len() + 1`.
-    /// The domain for the curve with degree `p` is `knots[p-1]` and `knots[knots.len() - p -2]`.
+    /// The resulting degree of the curve is `knots.len() - elements.len() + 1`.
+    /// The domain for the curve with degree `p` is `knots[p-1]` and `knots[knots.len() - p]`.
     /// The knots have to be sorted.
     ///
     /// # Panics
     ///
-    /// The degree has to be at least 1, otherwise the library may panic at any time.
+    /// The number of knots has to be at least the number of elements - 1,
+    /// otherwise the library may panic at any time.
     pub fn new_unchecked(elements: E, knots: K, space: S) -> Self {
-        let degree = knots.len() - elements.len() + 1;
+        let degree = knots.len() + 1 - elements.len();
         BSpline {
             elements,
             knots,
@@ -261,60 +841,841 @@ where
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn linear_bspline() {
-        let expect = [
-            (-1.0, -1.0),
-            (0.0, 0.0),
-            (0.2, 0.2),
-            (0.4, 0.4),
-            (0.6, 0.6),
-            (0.8, 0.8),
-            (1.0, 1.0),
-            (2.0, 2.0),
-        ];
-        let points = [0.0f32, 1.0];
-        let knots = [0.0f32, 1.0];
-        let spline = BSpline::builder()
-            .elements(points)
-            .knots(knots)
-            .constant::<2>()
-            .build()
-            .unwrap();
-        for i in 0..expect.len() {
-            assert_f32_near!(spline.gen(expect[i].0), expect[i].1);
+#[cfg(feature = "alloc")]
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Add<Output = E::Output>
+        + Sub<Output = E::Output>
+        + Mul<R, Output = E::Output>
+        + Default
+        + Copy,
+    S: Space<E::Output>,
+    R: Real + FromPrimitive + Debug,
+    K: SortedGenerator<Output = R>,
+{
+    /// Returns the antiderivative of this curve.
+    ///
+    /// The returned curve has one degree more than this curve and its derivative is this curve,
+    /// at least within the domain. The domain of the returned curve is the same as of this curve.
+    /// The constant of integration is chosen such that the returned curve starts at zero.
+    ///
+    /// For NURBS, the homogeneous elements are integrated, which is not the integral of the rational curve.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{assert_f64_near, assert_float_absolute_eq};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// // constant velocity of 2.0
+    /// let velocity = BSpline::builder()
+    ///     .elements([2.0, 2.0, 2.0])
+    ///     .knots([0.0, 1.0, 2.0, 3.0])
+    ///     .constant::<3>()
+    ///     .build()?;
+    /// let position = velocity.integrate();
+    /// assert_eq!(position.domain(), [1.0, 2.0]);
+    /// assert_float_absolute_eq!(position.gen(1.0), 0.0);
+    /// assert_f64_near!(position.gen(1.5), 1.0);
+    /// assert_f64_near!(position.gen(2.0), 2.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn integrate(&self) -> BSpline<Sorted<Vec<R>>, Vec<E::Output>, DynSpace<E::Output>> {
+        // The usual knot vector, which has one more knot at each border than our knots.
+        // These are chosen to be the same as their neighbors, as they do not change the curve
+        // within its domain.
+        let len = self.knots.len();
+        let full_knot = |index: usize| self.knots.gen(index.max(1).min(len) - 1);
+        let factor = R::from_usize(self.degree + 1).unwrap().recip();
+        let mut elements = Vec::with_capacity(self.elements.len() + 1);
+        // take a zero out vector as the start of the integral
+        let mut sum = self.elements.gen(0) * R::zero();
+        elements.push(sum);
+        for i in 0..self.elements.len() {
+            let span = full_knot(i + self.degree + 1) - full_knot(i);
+            sum = sum + self.elements.gen(i) * (span * factor);
+            elements.push(sum);
         }
-    }
-    #[test]
-    fn quadratic_bspline() {
-        let expect = [
-            (0.0, 0.0),
-            (0.5, 0.125),
-            (1.0, 0.5),
-            (1.4, 0.74),
-            (1.5, 0.75),
-            (1.6, 0.74),
-            (2.0, 0.5),
-            (2.5, 0.125),
-            (3.0, 0.0),
-        ];
-        let points = [0.0f32, 0.0, 1.0, 0.0, 0.0];
-        let knots = [0.0f32, 0.0, 1.0, 2.0, 3.0, 3.0];
-        let spline = BSpline::builder()
-            .elements(points)
-            .knots(knots)
-            .constant::<3>()
-            .build()
-            .unwrap();
-        for i in 0..expect.len() {
-            assert_f32_near!(spline.gen(expect[i].0), expect[i].1);
+        let knots = (0..len + 2).map(full_knot).collect();
+        let mut integral = BSpline {
+            elements,
+            knots: Sorted::new_unchecked(knots),
+            space: DynSpace::new(self.degree + 2),
+            degree: self.degree + 1,
+        };
+        // shift the integral such that it starts at zero
+        let start = integral.gen(integral.domain()[0]);
+        for element in integral.elements.iter_mut() {
+            *element = *element - start;
         }
+        integral
     }
-    #[test]
+}
+
+#[cfg(feature = "alloc")]
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Clone + Default,
+    R: Real,
+    K: SortedGenerator<Output = R>,
+{
+    /// Returns the same curve with the additional knot `scalar` and one more element.
+    ///
+    /// The new elements are calculated with Boehm's algorithm, such that the curve does not change.
+    /// As inserting a knot in general breaks any structure of the knots, like them being equidistant,
+    /// the knots and elements of the returned bspline are always stored in vectors.
+    /// Inserting the same knot multiple times is allowed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalar` is not within the domain of the bspline or equal to the end of the domain.
+    /// A knot at the end of the domain would result in an empty last knot span.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///     .clamped()
+    ///     .elements([0.0, 5.0, 3.0, 10.0])
+    ///     .equidistant::<f64>()
+    ///     .degree(2)
+    ///     .normalized()
+    ///     .constant::<3>()
+    ///     .build()?;
+    /// let refined = bspline.insert_knot(0.25);
+    /// assert_eq!(refined.domain(), bspline.domain());
+    /// assert_f64_near!(refined.gen(0.1), bspline.gen(0.1));
+    /// assert_f64_near!(refined.gen(0.6), bspline.gen(0.6));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn insert_knot(
+        &self,
+        scalar: R,
+    ) -> BSpline<Sorted<Vec<R>>, Vec<E::Output>, DynSpace<E::Output>> {
+        let border = self.degree.max(1);
+        let len = self.knots.len();
+        assert!(
+            self.knots.gen(border - 1) <= scalar && scalar < self.knots.gen(len - border),
+            "the inserted knot has to be within the domain, excluding its end"
+        );
+        // the knot span [knots(index - 1), knots(index)] contains the scalar
+        let index = self
+            .knots
+            .strict_upper_bound_clamped(scalar, self.degree, len - self.degree);
+        let mut elements = Vec::with_capacity(self.elements.len() + 1);
+        elements.extend((0..=index - self.degree).map(|i| self.elements.gen(i)));
+        for i in index - self.degree + 1..=index {
+            let factor = (scalar - self.knots.gen(i - 1))
+                / (self.knots.gen(i + self.degree - 1) - self.knots.gen(i - 1));
+            elements.push(self.elements.gen(i - 1).merge(self.elements.gen(i), factor));
+        }
+        elements.extend((index..self.elements.len()).map(|i| self.elements.gen(i)));
+        let mut knots = Vec::with_capacity(len + 1);
+        knots.extend((0..index).map(|i| self.knots.gen(i)));
+        knots.push(scalar);
+        knots.extend((index..len).map(|i| self.knots.gen(i)));
+        BSpline {
+            elements,
+            knots: Sorted::new_unchecked(knots),
+            space: DynSpace::new(self.degree + 1),
+            degree: self.degree,
+        }
+    }
+}
+
+impl<K, E, S> BSpline<Sorted<K>, E, S>
+where
+    E: DiscreteGenerator,
+    K: DiscreteGenerator,
+    K::Output: PartialOrd,
+    S: Space<E::Output>,
+{
+    /// Creates a bspline curve of elements and knots given, checking all invariants at runtime.
+    ///
+    /// This is meant for data whose length is only known at runtime, for example data coming
+    /// from deserialization, where the builder is not a good fit.
+    /// In contrast to [`new()`], the knots do not have to be wrapped in [`Sorted`] beforehand,
+    /// as their sortedness is checked here.
+    ///
+    /// # Errors
+    ///
+    /// [`TooFewElements`] if there are less than two elements.
+    /// [`IncongruousElementsKnots`] either if the amount of knots is less than the amount of elements - 1
+    /// or if the amount of knots is more than double the amount of elements.
+    /// [`NotSorted`] if the knots are not sorted.
+    /// [`TooSmallWorkspace`] if the workspace is not bigger than the degree of the curve.
+    ///
+    /// [`new()`]: BSpline::new()
+    /// [`Sorted`]: crate::Sorted
+    /// [`TooFewElements`]: BSplineError
+    /// [`IncongruousElementsKnots`]: BSplineError
+    /// [`NotSorted`]: BSplineError
+    /// [`TooSmallWorkspace`]: BSplineError
+    pub fn try_from_parts(elements: E, knots: K, space: S) -> Result<Self, BSplineError> {
+        // check the counts first, as this is cheaper than checking the sortedness
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()).into());
+        }
+        if knots.len() + 1 < elements.len() || elements.len() <= knots.len() + 1 - elements.len() {
+            return Err(IncongruousElementsKnots::open(elements.len(), knots.len()).into());
+        }
+        let knots = Sorted::new(knots)?;
+        BSpline::new(elements, knots, space)
+    }
+}
+
+#[cfg(feature = "bezier")]
+impl<R, K, E, S> TryFrom<BSpline<K, E, S>> for Bezier<R, E, S>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    S: Space<E::Output>,
+    R: PartialEq,
+{
+    type Error = NotBezier;
+    /// Convert a bspline consisting of a single segment into a bezier curve.
+    ///
+    /// This is the case if the bspline has exactly `degree + 1` elements
+    /// and its first and last `degree` knots are the same.
+    /// The domain of the bspline gets mapped onto [0.0,1.0], the domain of all bezier curves.
+    ///
+    /// # Errors
+    ///
+    /// [`NotBezier`] if the bspline is not a single bezier segment.
+    fn try_from(bspline: BSpline<K, E, S>) -> Result<Self, Self::Error> {
+        let degree = bspline.degree;
+        let knots = &bspline.knots;
+        let len = knots.len();
+        let is_bezier = bspline.elements.len() == degree + 1
+            && (1..degree).all(|i| knots.gen(i) == knots.gen(0))
+            && (1..degree).all(|i| knots.gen(len - 1 - i) == knots.gen(len - 1));
+        if !is_bezier {
+            return Err(NotBezier::new(bspline.elements.len(), degree));
+        }
+        Ok(Bezier::new_unchecked(bspline.elements, bspline.space))
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "bezier"))]
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Clone + Default,
+    R: Real,
+    K: SortedGenerator<Output = R>,
+{
+    /// Returns an iterator over the bezier curves the bspline consists of.
+    ///
+    /// Every non-empty knot span of the domain yields one bezier curve of the same degree as the bspline.
+    /// The domain of each bezier curve, `[0.0, 1.0]`, corresponds to its knot span.
+    /// Segments are only calculated when the iterator is advanced,
+    /// such that large bsplines can be tessellated without storing all segments at once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///     .elements([0.0, 6.0, 0.0, 6.0])
+    ///     .knots([0.0, 0.0, 1.0, 2.0, 2.0])
+    ///     .constant::<3>()
+    ///     .build()?;
+    /// let segments: Vec<_> = bspline.bezier_segments().collect();
+    /// assert_eq!(segments.len(), 2);
+    /// assert_f64_near!(segments[0].gen(1.0), bspline.gen(1.0));
+    /// assert_f64_near!(segments[1].gen(0.5), bspline.gen(1.5));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn bezier_segments(&self) -> BezierSegments<'_, K, E, S> {
+        let border = self.degree.max(1);
+        BezierSegments {
+            spline: self,
+            front: border,
+            back: self.knots.len() - border + 1,
+        }
+    }
+
+    /// Calculate the tightest axis-aligned bounding box of the bspline.
+    ///
+    /// The accessor maps the elements of the bspline to points, whose coordinates are bounded.
+    /// It has to be affine, like the projection to some coordinates, as it is applied to control points.
+    /// Returned are the corners with the smallest and biggest coordinates.
+    ///
+    /// Unlike the bounding box of the elements given by [`control_bounds()`], the box is not bigger
+    /// than the curve itself.
+    /// The bspline is split into its [bezier segments] and the extrema of every coordinate are
+    /// calculated analytically for segments up to cubic degree and numerically for higher degrees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all knot spans of the domain are empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///     .elements([0.0, 4.0, 0.0])
+    ///     .knots([0.0, 0.0, 1.0, 1.0])
+    ///     .constant::<3>()
+    ///     .build()?;
+    /// let [min, max] = bspline.tight_bounds(|x| [x]);
+    /// assert_f64_near!(min[0], 0.0);
+    /// assert_f64_near!(max[0], 2.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [bezier segments]: BSpline::bezier_segments()
+    /// [`control_bounds()`]: BSpline::control_bounds()
+    pub fn tight_bounds<F, const N: usize>(&self, accessor: F) -> [[R; N]; 2]
+    where
+        F: Fn(E::Output) -> [R; N],
+    {
+        let border = self.degree.max(1);
+        let mut bounds: Option<[[R; N]; 2]> = None;
+        for index in border..self.knots.len() - border + 1 {
+            if self.knots.gen(index - 1) >= self.knots.gen(index) {
+                continue;
+            }
+            let points: Vec<[R; N]> = self
+                .bezier_elements(index)
+                .as_slice()
+                .iter()
+                .cloned()
+                .map(&accessor)
+                .collect();
+            let [min, max] = bounds.get_or_insert([points[0]; 2]);
+            for axis in 0..N {
+                let coefficients: Vec<R> = points.iter().map(|point| point[axis]).collect();
+                let [low, high] = bernstein_extrema(&coefficients);
+                min[axis] = min[axis].min(low);
+                max[axis] = max[axis].max(high);
+            }
+        }
+        bounds.expect("the domain of the bspline has to contain a non-empty knot span")
+    }
+
+    /// Calculate the bezier control points of the knot span ending at the knot with the given index.
+    ///
+    /// Every control point is a blossom of the span, evaluated with the de Boor algorithm.
+    fn bezier_elements(&self, index: usize) -> Vec<E::Output> {
+        let start = self.knots.gen(index - 1);
+        let end = self.knots.gen(index);
+        (0..=self.degree)
+            .map(|point| {
+                let mut elements: Vec<E::Output> = (index - self.degree..=index)
+                    .map(|i| self.elements.gen(i))
+                    .collect();
+                for r in 1..=self.degree {
+                    let scalar = if r + point <= self.degree { start } else { end };
+                    for j in 0..=(self.degree - r) {
+                        let i = j + r + index - self.degree;
+                        let factor = (scalar - self.knots.gen(i - 1))
+                            / (self.knots.gen(i + self.degree - r) - self.knots.gen(i - 1));
+                        elements[j] = elements[j].clone().merge(elements[j + 1].clone(), factor);
+                    }
+                }
+                elements.swap_remove(0)
+            })
+            .collect()
+    }
+}
+
+/// Iterator over the bezier segments of a bspline.
+///
+/// This struct is created by [`BSpline::bezier_segments()`].
+#[cfg(all(feature = "alloc", feature = "bezier"))]
+#[derive(Debug, Clone)]
+pub struct BezierSegments<'a, K, E, S> {
+    spline: &'a BSpline<K, E, S>,
+    // knot index of the end of the next span
+    front: usize,
+    back: usize,
+}
+
+#[cfg(all(feature = "alloc", feature = "bezier"))]
+impl<K, E, S, R> Iterator for BezierSegments<'_, K, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Clone + Default,
+    R: Real,
+    K: SortedGenerator<Output = R>,
+{
+    type Item = Bezier<R, Vec<E::Output>, DynSpace<E::Output>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            let index = self.front;
+            self.front += 1;
+            let knots = &self.spline.knots;
+            if knots.gen(index - 1) < knots.gen(index) {
+                return Some(Bezier::new_unchecked(
+                    self.spline.bezier_elements(index),
+                    DynSpace::new(self.spline.degree + 1),
+                ));
+            }
+        }
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.back - self.front))
+    }
+}
+
+/// Evaluate the polynomial with the given coefficients in the bernstein basis.
+#[cfg(all(feature = "alloc", feature = "bezier"))]
+fn bernstein<R: Real>(coefficients: &[R], scalar: R) -> R {
+    let mut values = coefficients.to_vec();
+    for len in (1..values.len()).rev() {
+        for i in 0..len {
+            values[i] = values[i] + (values[i + 1] - values[i]) * scalar;
+        }
+    }
+    values[0]
+}
+
+/// Calculate the minimum and maximum of the polynomial in the bernstein basis over `[0.0, 1.0]`.
+#[cfg(all(feature = "alloc", feature = "bezier"))]
+fn bernstein_extrema<R: Real>(coefficients: &[R]) -> [R; 2] {
+    let first = coefficients[0];
+    let last = coefficients[coefficients.len() - 1];
+    let mut extrema = [first.min(last), first.max(last)];
+    // the roots of the derivative are the roots of the differences of the coefficients
+    let differences: Vec<R> = coefficients.windows(2).map(|w| w[1] - w[0]).collect();
+    for root in bernstein_roots(&differences) {
+        let value = bernstein(coefficients, root);
+        extrema = [extrema[0].min(value), extrema[1].max(value)];
+    }
+    extrema
+}
+
+/// Calculate the roots of the polynomial in the bernstein basis within `(0.0, 1.0)`.
+///
+/// Roots of polynomials up to quadratic degree are calculated analytically.
+/// For higher degrees, the polynomial is monotone between the roots of its derivative,
+/// such that every sign change between these is found by bisection.
+/// Some additional scalars which are no roots may be returned.
+#[cfg(all(feature = "alloc", feature = "bezier"))]
+fn bernstein_roots<R: Real>(coefficients: &[R]) -> Vec<R> {
+    let zero = R::zero();
+    let one = R::one();
+    let two = one + one;
+    let mut roots = match *coefficients {
+        [] | [_] => Vec::new(),
+        [a, b] => vec![a / (a - b)],
+        [a, b, c] => {
+            // a(1-t)^2 + 2bt(1-t) + ct^2 in the monomial basis
+            let quadratic = a - two * b + c;
+            let linear = two * (b - a);
+            if quadratic == zero {
+                vec![-a / linear]
+            } else {
+                let discriminant = linear * linear - two * two * quadratic * a;
+                if discriminant < zero {
+                    Vec::new()
+                } else {
+                    // avoid cancellation by choosing the sign of the linear coefficient
+                    let q = -(linear + linear.signum() * discriminant.sqrt()) / two;
+                    vec![q / quadratic, a / q]
+                }
+            }
+        }
+        _ => {
+            let differences: Vec<R> = coefficients.windows(2).map(|w| w[1] - w[0]).collect();
+            let mut critical = vec![zero];
+            critical.extend(bernstein_roots(&differences));
+            critical.push(one);
+            critical.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            critical
+                .windows(2)
+                .filter_map(|w| bisect(coefficients, w[0], w[1]))
+                .collect()
+        }
+    };
+    roots.retain(|root| zero < *root && *root < one);
+    roots
+}
+
+/// Find a root of the polynomial in the bernstein basis between `low` and `high` by bisection.
+///
+/// Returns `None` if the polynomial has the same sign at both scalars.
+#[cfg(all(feature = "alloc", feature = "bezier"))]
+fn bisect<R: Real>(coefficients: &[R], mut low: R, mut high: R) -> Option<R> {
+    let low_value = bernstein(coefficients, low);
+    if low_value * bernstein(coefficients, high) > R::zero() {
+        return None;
+    }
+    let negative = low_value < R::zero();
+    loop {
+        let mid = (low + high) / (R::one() + R::one());
+        if !(low < mid && mid < high) {
+            return Some(low);
+        }
+        if (bernstein(coefficients, mid) < R::zero()) == negative {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+}
+
+/// Solve a system of linear equations, whose matrix is banded like the collocation matrix of a bspline.
+///
+/// Every row is given by the index of its first non-zero column and its non-zero values, all of the same length.
+/// The first columns of consecutive rows have to be non-decreasing and have to be at most the index of the row,
+/// such that no pivoting is necessary and no fill-in occurs.
+#[cfg(feature = "alloc")]
+fn solve_banded<R, T>(rows: &mut [(usize, Vec<R>)], mut values: Vec<T>) -> Vec<T>
+where
+    R: Real,
+    T: Sub<Output = T> + Mul<R, Output = T> + Copy,
+{
+    let len = rows.len();
+    // forward elimination
+    for j in 0..len {
+        let (done, rest) = rows.split_at_mut(j + 1);
+        let (first, pivot_row) = &done[j];
+        let pivot = pivot_row[j - first];
+        for (offset, (start, row)) in rest.iter_mut().enumerate() {
+            if *start > j {
+                break;
+            }
+            let factor = row[j - *start] / pivot;
+            for (column, value) in (j..).zip(&pivot_row[j - first..]) {
+                row[column - *start] = row[column - *start] - *value * factor;
+            }
+            values[j + 1 + offset] = values[j + 1 + offset] - values[j] * factor;
+        }
+    }
+    // back substitution
+    for j in (0..len).rev() {
+        let (first, row) = &rows[j];
+        let mut value = values[j];
+        for (column, factor) in (j + 1..).zip(&row[j - first + 1..]) {
+            value = value - values[column] * *factor;
+        }
+        values[j] = value * row[j - first].recip();
+    }
+    values
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::ConstSpace;
+    use crate::Stepper;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn control_points_vec() {
+        use crate::easing::FuncEase;
+        let elements = [1.0, 4.0, 2.0, 0.5];
+        let array = BSpline::builder()
+            .elements(elements)
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        assert_eq!(array.control_points_vec(), elements.to_vec());
+        // borrowed elements
+        let borrowed = BSpline::builder()
+            .elements(&elements)
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        assert_eq!(borrowed.control_points_vec(), elements.to_vec());
+        // elements generated by a closure
+        let negated = Equidistant::<f64>::new(4, 0.0, 3.0).composite(FuncEase::new(|x: f64| -x));
+        let generated = BSpline::builder()
+            .elements(negated)
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        assert_eq!(generated.control_points_vec(), vec![0.0, -1.0, -2.0, -3.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn circular_arc() {
+        use core::f64::consts::PI;
+        use core::ops::Div;
+        #[derive(Debug, Default, Clone, Copy)]
+        struct Point(f64, f64);
+        impl Add for Point {
+            type Output = Point;
+            fn add(self, other: Point) -> Point {
+                Point(self.0 + other.0, self.1 + other.1)
+            }
+        }
+        impl Mul<f64> for Point {
+            type Output = Point;
+            fn mul(self, scalar: f64) -> Point {
+                Point(self.0 * scalar, self.1 * scalar)
+            }
+        }
+        impl Div<f64> for Point {
+            type Output = Point;
+            fn div(self, scalar: f64) -> Point {
+                Point(self.0 / scalar, self.1 / scalar)
+            }
+        }
+        impl From<[f64; 2]> for Point {
+            fn from([x, y]: [f64; 2]) -> Self {
+                Point(x, y)
+            }
+        }
+        let center = Point(-1.0, 0.5);
+        for (start, end) in [
+            (0.0, PI / 2.0),
+            (0.3, 2.0),
+            (PI, -PI / 3.0),
+            (0.0, 2.0 * PI),
+        ] {
+            let arc = BSpline::circular_arc(center, 2.0, start, end);
+            assert_eq!(arc.domain(), [0.0, 1.0]);
+            for point in Stepper::normalized(100).map(|t| arc.gen(t)) {
+                assert_float_absolute_eq!((point.0 + 1.0).hypot(point.1 - 0.5), 2.0, 1e-12);
+            }
+            let (first, last) = arc.endpoints();
+            assert_f64_near!(first.0, -1.0 + 2.0 * start.cos());
+            assert_f64_near!(first.1, 0.5 + 2.0 * start.sin());
+            assert_float_absolute_eq!(last.0, -1.0 + 2.0 * end.cos(), 1e-12);
+            assert_float_absolute_eq!(last.1, 0.5 + 2.0 * end.sin(), 1e-12);
+            // the arc moves monotonically from the start to the end angle
+            let angle = |t: f64| {
+                let point = arc.gen(t);
+                (point.1 - 0.5).atan2(point.0 + 1.0)
+            };
+            let direction = (end - start).signum();
+            assert!((angle(0.01) - start) * direction > 0.0);
+        }
+    }
+
+    #[test]
+    fn bounds() {
+        let bspline = BSpline::builder()
+            .clamped()
+            .elements([2.0, -4.0, 1.0, 5.0, 0.5])
+            .equidistant::<f64>()
+            .degree(3)
+            .normalized()
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let [min, max] = bspline.control_bounds();
+        assert_eq!([min, max], [-4.0, 5.0]);
+        let [sample_min, sample_max] = bspline.sample_bounds(100);
+        assert!(min <= sample_min && sample_max <= max);
+        assert!(sample_min > -4.0 && sample_max < 5.0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn basis_derivatives() {
+        let elements = [1.0, -2.0, 4.0, 3.0, 0.5, 2.0, -1.0];
+        let bspline = BSpline::builder()
+            .elements(elements)
+            .knots([0.0, 0.0, 0.0, 1.0, 2.5, 4.5, 6.0, 6.0, 6.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let step = 1e-6;
+        for input in [0.0, 0.7, 1.0, 2.2, 3.9, 5.5, 6.0] {
+            let (first, basis) = bspline.basis_derivatives(input, 5);
+            assert_eq!(basis.len(), 6);
+            // the basis is a partition of unity and reproduces the curve
+            assert_f64_near!(basis[0].iter().sum::<f64>(), 1.0, 8);
+            let value: f64 = (0..=3).map(|j| basis[0][j] * elements[first + j]).sum();
+            assert_float_absolute_eq!(value, bspline.gen(input), 1e-12);
+            let tangent: f64 = (0..=3).map(|j| basis[1][j] * elements[first + j]).sum();
+            assert_float_absolute_eq!(tangent, bspline.gen_with_tangent(input)[1], 1e-12);
+            // derivatives against finite differences within the same span
+            let (next_first, next) = bspline.basis_derivatives(input + step, 5);
+            if next_first == first {
+                for k in 0..3 {
+                    for j in 0..=3 {
+                        let difference = (next[k][j] - basis[k][j]) / step;
+                        assert_float_absolute_eq!(difference, basis[k + 1][j], 1e-4);
+                    }
+                }
+            }
+            // above the degree all derivatives vanish
+            assert!(basis[4..].iter().flatten().all(|value| *value == 0.0));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn borrowed_clone_elements() {
+        // big element which is only `Clone`, such that it has to be borrowed from its storage
+        #[derive(Debug, Clone, Default, PartialEq)]
+        struct Large([f64; 32]);
+        impl Merge<f64> for Large {
+            fn merge(mut self, other: Self, factor: f64) -> Self {
+                for (value, other) in self.0.iter_mut().zip(other.0) {
+                    *value = value.merge(other, factor);
+                }
+                self
+            }
+        }
+        let storage: Vec<Large> = [0.0, 5.0, 3.0, 10.0, 7.0]
+            .as_slice()
+            .iter()
+            .map(|&value| Large([value; 32]))
+            .collect();
+        let bspline = BSpline::builder()
+            .clamped()
+            .elements(&storage)
+            .equidistant::<f64>()
+            .degree(3)
+            .normalized()
+            .dynamic()
+            .build()
+            .unwrap();
+        let results = [
+            0.0, 2.346, 3.648, 4.302, 4.704, 5.25, 6.2, 7.27, 8.04, 8.09, 7.0,
+        ];
+        for (value, result) in bspline.take(results.len()).zip(results) {
+            for component in value.0 {
+                assert_f64_near!(component, result);
+            }
+        }
+        // the storage was not touched
+        assert_eq!(storage[1], Large([5.0; 32]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash() {
+        use std::collections::hash_map::RandomState;
+        use std::collections::HashMap;
+        use std::hash::BuildHasher;
+        let knots = Sorted::new([0, 0, 1, 2, 2]).unwrap();
+        let first = BSpline::new([1, 5, 3, 2], knots, ConstSpace::<i32, 3>::new()).unwrap();
+        let second = BSpline::new([1, 5, 3, 2], knots, ConstSpace::<i32, 3>::new()).unwrap();
+        let other = BSpline::new([1, 5, 3, 7], knots, ConstSpace::<i32, 3>::new()).unwrap();
+        let state = RandomState::new();
+        assert_eq!(state.hash_one(first), state.hash_one(second));
+        assert_ne!(state.hash_one(first), state.hash_one(other));
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        // curves can be used as keys
+        let mut cache = HashMap::new();
+        cache.insert(first, "first");
+        assert_eq!(cache.get(&second), Some(&"first"));
+        assert_eq!(cache.get(&other), None);
+    }
+
+    #[test]
+    #[cfg(feature = "bezier")]
+    fn into_bezier() {
+        let spline = BSpline::builder()
+            .elements([20.0, 100.0, 0.0, 200.0])
+            .knots([0.0, 0.0, 0.0, 1.0, 1.0, 1.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let bez = Bezier::try_from(spline).unwrap();
+        for (a, b) in bez.take(11).zip(spline.take(11)) {
+            assert_f64_near!(a, b);
+        }
+        let spline = BSpline::builder()
+            .elements([20.0, 100.0, 0.0, 200.0])
+            .knots([0.0, 0.0, 1.0, 2.0, 2.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        assert!(Bezier::try_from(spline).is_err());
+        let spline = BSpline::builder()
+            .elements([20.0, 100.0, 0.0, 200.0])
+            .knots([0.0, 0.0, 0.5, 1.0, 1.0, 1.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        assert!(Bezier::try_from(spline).is_err());
+    }
+
+    #[test]
+    fn linear_bspline() {
+        let expect = [
+            (-1.0, -1.0),
+            (0.0, 0.0),
+            (0.2, 0.2),
+            (0.4, 0.4),
+            (0.6, 0.6),
+            (0.8, 0.8),
+            (1.0, 1.0),
+            (2.0, 2.0),
+        ];
+        let points = [0.0f32, 1.0];
+        let knots = [0.0f32, 1.0];
+        let spline = BSpline::builder()
+            .elements(points)
+            .knots(knots)
+            .constant::<2>()
+            .build()
+            .unwrap();
+        for (input, output) in expect {
+            assert_f32_near!(spline.gen(input), output);
+        }
+    }
+    #[test]
+    fn quadratic_bspline() {
+        let expect = [
+            (0.0, 0.0),
+            (0.5, 0.125),
+            (1.0, 0.5),
+            (1.4, 0.74),
+            (1.5, 0.75),
+            (1.6, 0.74),
+            (2.0, 0.5),
+            (2.5, 0.125),
+            (3.0, 0.0),
+        ];
+        let points = [0.0f32, 0.0, 1.0, 0.0, 0.0];
+        let knots = [0.0f32, 0.0, 1.0, 2.0, 3.0, 3.0];
+        let spline = BSpline::builder()
+            .elements(points)
+            .knots(knots)
+            .constant::<3>()
+            .build()
+            .unwrap();
+        for (input, output) in expect {
+            assert_f32_near!(spline.gen(input), output);
+        }
+    }
+    #[test]
     fn cubic_bspline() {
         let expect = [
             (-2.0, 0.0),
@@ -334,8 +1695,8 @@ mod test {
             .constant::<4>()
             .build()
             .unwrap();
-        for i in 0..expect.len() {
-            assert_f32_near!(spline.gen(expect[i].0), expect[i].1);
+        for (input, output) in expect {
+            assert_f32_near!(spline.gen(input), output);
         }
     }
     #[test]
@@ -361,8 +1722,8 @@ mod test {
             .constant::<5>()
             .build()
             .unwrap();
-        for i in 0..expect.len() {
-            assert_f32_near!(spline.gen(expect[i].0), expect[i].1);
+        for (input, output) in expect {
+            assert_f32_near!(spline.gen(input), output);
         }
     }
     #[test]
@@ -388,8 +1749,492 @@ mod test {
             .constant::<5>()
             .build()
             .unwrap();
-        for i in 0..expect.len() {
-            assert_f64_near!(spline.gen(expect[i].0), expect[i].1);
+        for (input, output) in expect {
+            assert_f64_near!(spline.gen(input), output);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn try_from_parts() {
+        // too few elements
+        assert!(matches!(
+            BSpline::try_from_parts([0.0], [0.0, 1.0], ConstSpace::<f64, 3>::new()),
+            Err(BSplineError::TooFewElements(_))
+        ));
+        // too few knots
+        assert!(matches!(
+            BSpline::try_from_parts([0.0, 1.0, 2.0], [0.0], ConstSpace::<f64, 3>::new()),
+            Err(BSplineError::IncongruousElementsKnots(_))
+        ));
+        // unsorted knots
+        assert!(matches!(
+            BSpline::try_from_parts(
+                [0.0, 1.0, 2.0],
+                [0.0, 2.0, 1.0, 3.0],
+                ConstSpace::<f64, 3>::new()
+            ),
+            Err(BSplineError::NotSorted(_))
+        ));
+        // too small of a workspace
+        assert!(matches!(
+            BSpline::try_from_parts(
+                [0.0, 1.0, 2.0],
+                [0.0, 1.0, 2.0, 3.0],
+                ConstSpace::<f64, 2>::new()
+            ),
+            Err(BSplineError::TooSmallWorkspace(_))
+        ));
+        // success, should be the same as if created with the builder
+        let spline = BSpline::try_from_parts(
+            vec![0.0, 0.0, 1.0, 0.0, 0.0],
+            vec![0.0, 0.0, 1.0, 2.0, 3.0, 3.0],
+            ConstSpace::<f64, 3>::new(),
+        )
+        .unwrap();
+        let expect = BSpline::builder()
+            .elements([0.0, 0.0, 1.0, 0.0, 0.0])
+            .knots([0.0, 0.0, 1.0, 2.0, 3.0, 3.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        assert_eq!(spline.domain(), expect.domain());
+        for (val, res) in spline.take(10).zip(expect.take(10)) {
+            assert_f64_near!(val, res);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn integrate() {
+        let spline = BSpline::builder()
+            .elements([1.0, -2.0, 5.0, 3.0, 0.0, 2.0])
+            .knots([0.0, 0.5, 0.5, 2.0, 3.0, 3.5, 4.0, 6.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let integral = spline.integrate();
+        assert_eq!(integral.domain(), spline.domain());
+        let [start, end] = spline.domain();
+        assert_float_absolute_eq!(integral.gen(start), 0.0, 1e-12);
+        // differentiate the integral with central differences
+        let step = 1e-5;
+        for input in Stepper::new(20, start + step, end - step) {
+            let deriative =
+                (integral.gen(input + step) - integral.gen(input - step)) / (2.0 * step);
+            assert_float_absolute_eq!(deriative, spline.gen(input), 1e-6);
+        }
+    }
+
+    #[test]
+    fn tangent() {
+        let spline = BSpline::builder()
+            .elements([1.0, -2.0, 5.0, 3.0, 0.0, 2.0])
+            .knots([0.0, 0.5, 0.5, 2.0, 3.0, 3.5, 4.0, 6.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let [start, end] = spline.domain();
+        let step = 1e-6;
+        for input in Stepper::new(20, start + step, end - step) {
+            let [value, tangent] = spline.gen_with_tangent(input);
+            assert_f64_near!(value, spline.gen(input));
+            let expected = (spline.gen(input + step) - spline.gen(input - step)) / (2.0 * step);
+            assert_float_absolute_eq!(tangent, expected, 1e-6);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn through_polygon() {
+        let points = [0.0, 3.0, -1.0, 2.0];
+        // degree 1 interpolates the points
+        let linear = BSpline::through_polygon::<_, f64>(points, 1).unwrap();
+        assert_eq!(linear.domain(), [0.0, 1.0]);
+        for (value, point) in linear.take(4).zip(points) {
+            assert_f64_near!(value, point);
+        }
+        // degree 2 has the clamped knot vector [0,0,0,0.5,1,1,1]
+        let quadratic = BSpline::through_polygon::<_, f64>(points, 2).unwrap();
+        let expected = BSpline::builder()
+            .elements(points)
+            .knots([0.0, 0.0, 0.5, 1.0, 1.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        for (value, expect) in quadratic.take(11).zip(expected.take(11)) {
+            assert_f64_near!(value, expect);
+        }
+        // degree 3 with 4 points is a bezier curve
+        let cubic = BSpline::through_polygon::<_, f64>([20.0, 100.0, 0.0, 200.0], 3).unwrap();
+        let expected = [20.0, 53.75, 65.0, 98.75, 200.0];
+        for (value, expect) in cubic.take(5).zip(expected) {
+            assert_f64_near!(value, expect);
+        }
+        // too few points for the degree
+        assert!(matches!(
+            BSpline::through_polygon::<_, f64>(points, 4),
+            Err(BSplineError::IncongruousElementsDegree(_))
+        ));
+        assert!(matches!(
+            BSpline::through_polygon::<_, f64>([1.0], 1),
+            Err(BSplineError::TooFewElements(_))
+        ));
+        assert!(matches!(
+            BSpline::through_polygon::<_, f64>(points, 0),
+            Err(BSplineError::InvalidDegree(_))
+        ));
+    }
+
+    #[cfg(all(feature = "alloc", feature = "bezier"))]
+    #[test]
+    fn bezier_segments() {
+        let elements = [1.0, -2.0, 4.0, 3.0, 0.5, 2.0, -1.0];
+        // cubic with a double knot at 2.5, such that one span is empty
+        let bspline = BSpline::builder()
+            .elements(elements)
+            .knots([0.0, 0.0, 0.0, 1.0, 2.5, 2.5, 6.0, 6.0, 6.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let spans = [[0.0, 1.0], [1.0, 2.5], [2.5, 6.0]];
+        let mut segments = bspline.bezier_segments();
+        let first = segments.next().unwrap();
+        for t in Stepper::normalized(11) {
+            assert_float_absolute_eq!(first.gen(t), bspline.gen(t), 1e-12);
+        }
+        let segments: Vec<_> = bspline.bezier_segments().collect();
+        assert_eq!(segments.len(), spans.len());
+        for (segment, [start, end]) in segments.iter().zip(spans) {
+            for t in Stepper::normalized(11) {
+                let expected = bspline.gen(start + t * (end - start));
+                assert_float_absolute_eq!(segment.gen(t), expected, 1e-12);
+            }
+        }
+        // a single bezier segment is the bspline itself
+        let bspline = BSpline::builder()
+            .elements([20.0, 100.0, 0.0, 200.0])
+            .knots([0.0, 0.0, 0.0, 1.0, 1.0, 1.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let segments: Vec<_> = bspline.bezier_segments().collect();
+        assert_eq!(segments.len(), 1);
+        let bezier = Bezier::try_from(bspline).unwrap();
+        for t in Stepper::normalized(11) {
+            assert_f64_near!(segments[0].gen(t), bezier.gen(t));
+        }
+    }
+
+    #[cfg(all(feature = "alloc", feature = "bezier"))]
+    #[test]
+    fn tight_bounds() {
+        let accessor = |x: f64| [x, 1.0 - 2.0 * x];
+        let elements = [1.0, -2.0, 4.0, 3.0, 0.5, 2.0, -1.0];
+        let control = [
+            accessor(
+                elements
+                    .as_slice()
+                    .iter()
+                    .copied()
+                    .fold(f64::INFINITY, f64::min),
+            ),
+            accessor(
+                elements
+                    .as_slice()
+                    .iter()
+                    .copied()
+                    .fold(f64::NEG_INFINITY, f64::max),
+            ),
+        ];
+        // clamped knots for the degrees one to five
+        let knots = [
+            vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            vec![0.0, 0.0, 1.0, 2.0, 3.5, 4.5, 6.0, 6.0],
+            vec![0.0, 0.0, 0.0, 1.0, 2.5, 4.5, 6.0, 6.0, 6.0],
+            vec![0.0, 0.0, 0.0, 0.0, 1.0, 2.5, 6.0, 6.0, 6.0, 6.0],
+            vec![0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 6.0, 6.0, 6.0, 6.0, 6.0],
+        ];
+        for knots in knots {
+            let bspline = BSpline::builder()
+                .elements(elements)
+                .knots(knots)
+                .dynamic()
+                .build()
+                .unwrap();
+            let [min, max] = bspline.tight_bounds(accessor);
+            // no larger than the bounds of the control points
+            assert!(min[0] >= control[0][0] && max[0] <= control[1][0]);
+            assert!(min[1] >= control[1][1] && max[1] <= control[0][1]);
+            // but containing all samples, while being tight
+            let mut sampled = [[f64::INFINITY; 2], [f64::NEG_INFINITY; 2]];
+            for point in bspline.take(6001).map(accessor) {
+                for axis in 0..2 {
+                    assert!(min[axis] <= point[axis] + 1e-12);
+                    assert!(point[axis] <= max[axis] + 1e-12);
+                    sampled[0][axis] = sampled[0][axis].min(point[axis]);
+                    sampled[1][axis] = sampled[1][axis].max(point[axis]);
+                }
+            }
+            for axis in 0..2 {
+                assert_float_absolute_eq!(min[axis], sampled[0][axis], 1e-4);
+                assert_float_absolute_eq!(max[axis], sampled[1][axis], 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn insert_knot() {
+        let elements = [1.0, -2.0, 4.0, 3.0, 0.5, 2.0, -1.0];
+        let sorted = BSpline::builder()
+            .elements(elements)
+            .knots([0.0, 0.0, 0.0, 1.0, 2.5, 4.5, 6.0, 6.0, 6.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let equidistant = BSpline::builder()
+            .elements(elements)
+            .equidistant::<f64>()
+            .degree(2)
+            .domain(0.0, 4.0)
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let step = BSpline::builder()
+            .elements(elements)
+            .knots([0.0, 1.0, 2.0, 3.0, 4.0, 5.0])
+            .constant::<1>()
+            .build()
+            .unwrap();
+        for scalar in [0.0, 0.3, 1.0, 1.7, 3.2, 3.9] {
+            let refined = sorted.insert_knot(scalar);
+            assert_eq!(refined.elements.len(), elements.len() + 1);
+            assert_eq!(refined.knots.len(), sorted.knots.len() + 1);
+            assert!(refined.knots.iter().any(|knot| knot == scalar));
+            assert_eq!(refined.domain(), sorted.domain());
+            for t in Stepper::new(61, 0.0, 6.0) {
+                assert_float_absolute_eq!(refined.gen(t), sorted.gen(t), 1e-12);
+            }
+            // the domain of the equidistant spline is smaller than the range of its knots
+            let [start, end] = equidistant.domain();
+            let refined = equidistant.insert_knot(start + (end - start) * scalar / 4.0);
+            assert_eq!(refined.domain(), equidistant.domain());
+            for t in Stepper::new(41, start, end) {
+                assert_float_absolute_eq!(refined.gen(t), equidistant.gen(t), 1e-12);
+            }
+            let refined = step.insert_knot(scalar);
+            for t in Stepper::new(41, 0.0, 5.0) {
+                assert_f64_near!(refined.gen(t), step.gen(t));
+            }
+        }
+        // inserting repeatedly, also the same knot
+        let refined = sorted.insert_knot(3.0).insert_knot(3.0).insert_knot(5.0);
+        assert_eq!(refined.elements.len(), elements.len() + 3);
+        for t in Stepper::new(61, 0.0, 6.0) {
+            assert_float_absolute_eq!(refined.gen(t), sorted.gen(t), 1e-12);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use crate::weights::{Homogeneous, Weighted, Weights};
+        let bspline = BSpline::builder()
+            .elements(vec![1.0, -2.0, 4.0, 3.0, 0.5])
+            .knots(vec![0.0, 0.0, 1.0, 2.5, 4.0, 4.0])
+            .dynamic()
+            .build()
+            .unwrap();
+        let json = serde_json::to_string(&bspline).unwrap();
+        let restored: BSpline<Sorted<Vec<f64>>, Vec<f64>, DynSpace<f64>> =
+            serde_json::from_str(&json).unwrap();
+        for t in Stepper::new(41, 0.0, 4.0) {
+            assert_eq!(restored.gen(t), bspline.gen(t));
+        }
+        // equidistant knots and weighted elements
+        let nurbs = BSpline::builder()
+            .clamped()
+            .elements_with_weights([(1.0, 1.0), (-2.0, 0.5), (4.0, 2.0), (3.0, 1.0)])
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let json = serde_json::to_string(&nurbs).unwrap();
+        type Nurbs = Weighted<
+            BSpline<
+                BorderBuffer<Equidistant<f64>>,
+                Weights<[(f64, f64); 4]>,
+                ConstSpace<Homogeneous<f64, f64>, 3>,
+            >,
+        >;
+        let restored: Nurbs = serde_json::from_str(&json).unwrap();
+        for t in Stepper::normalized(41) {
+            assert_eq!(restored.gen(t), nurbs.gen(t));
         }
     }
+
+    #[test]
+    #[should_panic(expected = "within the domain")]
+    #[cfg(feature = "alloc")]
+    fn insert_knot_outside() {
+        let bspline = BSpline::builder()
+            .elements([1.0, -2.0, 4.0])
+            .knots([0.0, 0.0, 1.0, 1.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        bspline.insert_knot(1.0);
+    }
+
+    #[cfg(feature = "linear")]
+    #[test]
+    fn degree_zero() {
+        use crate::easing::Dwell;
+        use crate::linear::{Linear, StepLinear};
+        let elements = [1.0, 4.0, -2.0, 0.0];
+        let knots = [0.0, 1.0, 3.0, 4.0];
+        let step: StepLinear<_, _, f64> = Linear::builder()
+            .elements(elements)
+            .knots(knots)
+            .easing(Dwell::new(1.0))
+            .build()
+            .unwrap();
+        // the knots are the jumps, so the first element is only reached before the domain
+        let bspline = BSpline::builder()
+            .elements([1.0, 1.0, 4.0, -2.0, 0.0])
+            .knots(knots)
+            .constant::<1>()
+            .build()
+            .unwrap();
+        assert_eq!(bspline.domain(), step.domain());
+        for input in Stepper::<f64>::normalized(41).map(|t| t * 4.0) {
+            assert_f64_near!(bspline.gen(input), step.gen(input));
+            assert_f64_near!(bspline.gen_with_tangent(input)[1], 0.0);
+        }
+        assert_f64_near!(bspline.gen(-1.0), 1.0);
+        assert_f64_near!(bspline.gen(5.0), 0.0);
+        // equidistant knots
+        let bspline = BSpline::builder()
+            .elements([1.0, 4.0, -2.0, 0.0])
+            .equidistant::<f64>()
+            .degree(0)
+            .domain(0.0, 2.0)
+            .constant::<1>()
+            .build()
+            .unwrap();
+        assert_eq!(bspline.domain(), [0.0, 2.0]);
+        assert_f64_near!(bspline.gen(0.5), 4.0);
+        assert_f64_near!(bspline.gen(1.5), -2.0);
+        assert_f64_near!(bspline.gen(2.0), 0.0);
+        assert!(BSpline::builder()
+            .elements([1.0, 4.0])
+            .equidistant::<f64>()
+            .degree(0)
+            .normalized()
+            .constant::<1>()
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn from_fn() {
+        use core::f64::consts::PI;
+        let degree = 3;
+        let mut knots = vec![0.0; degree - 1];
+        knots.extend((0..=10).map(|i| i as f64 * PI / 10.0));
+        knots.extend([PI; 2]);
+        let knots = Sorted::new(knots).unwrap();
+        let sine = BSpline::from_fn(knots, degree, f64::sin).unwrap();
+        assert_eq!(sine.elements().len(), 13);
+        assert_eq!(sine.domain(), [0.0, PI]);
+        for input in Stepper::new(1000, 0.0, PI) {
+            assert!((sine.gen(input) - input.sin()).abs() < 1e-4);
+        }
+        // cubic polynomials are reproduced exactly, even with unclamped knots
+        let cubic = |x: f64| x * x * x - 2.0 * x;
+        let knots = Sorted::new([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let spline = BSpline::from_fn(knots, 3, cubic).unwrap();
+        for input in Stepper::new(20, 2.0, 4.0) {
+            assert_float_absolute_eq!(spline.gen(input), cubic(input), 1e-9);
+        }
+        let knots = Sorted::new([0.0, 1.0, 2.0]).unwrap();
+        assert!(BSpline::from_fn(knots, 2, cubic).is_err());
+        assert!(BSpline::from_fn(knots, 0, cubic).is_err());
+    }
+
+    #[test]
+    fn gen_batch() {
+        let knots = [0.0, 0.5, 0.5, 2.0, 3.0, 3.5, 4.0, 6.0];
+        let elements = [1.0, -2.0, 5.0, 3.0, 0.0, 2.0];
+        let constant = BSpline::builder()
+            .elements(elements)
+            .knots(knots)
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let inputs: [f64; 7] = [-1.0, 0.5, 0.7, 2.0, 2.25, 3.9, 7.0];
+        let mut batch = [0.0; 7];
+        constant.gen_batch(&inputs, &mut batch);
+        for (input, value) in inputs.as_slice().iter().zip(batch.as_slice()) {
+            assert_f64_near!(*value, constant.gen(*input));
+        }
+        #[cfg(feature = "alloc")]
+        {
+            let dynamic = BSpline::builder()
+                .elements(elements)
+                .knots(knots)
+                .dynamic()
+                .build()
+                .unwrap();
+            let mut batch = [0.0; 7];
+            dynamic.gen_batch(&inputs, &mut batch);
+            for (input, value) in inputs.as_slice().iter().zip(batch.as_slice()) {
+                assert_f64_near!(*value, dynamic.gen(*input));
+            }
+        }
+    }
+
+    #[test]
+    fn gen_batch_sweep() {
+        let knots = [0.0, 0.5, 0.5, 2.0, 3.0, 3.5, 4.0, 6.0];
+        let elements = [1.0, -2.0, 5.0, 3.0, 0.0, 2.0];
+        let spline = BSpline::builder()
+            .elements(elements)
+            .knots(knots)
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let mut ascending = [0.0; 41];
+        for (i, input) in ascending.iter_mut().enumerate() {
+            *input = -1.0 + i as f64 * 0.2;
+        }
+        let mut descending = ascending;
+        descending.reverse();
+        // a sweep which turns around
+        let turning = [3.9, 3.0, 0.5, 0.5, -1.0, 2.25, 7.0, 4.0];
+        for inputs in [&ascending[..], &descending[..], &turning[..]] {
+            let mut batch = [0.0; 41];
+            let batch = &mut batch[..inputs.len()];
+            spline.gen_batch(inputs, batch);
+            for (input, value) in inputs.iter().zip(batch.iter()) {
+                assert_f64_near!(*value, spline.gen(*input));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn gen_batch_length() {
+        let spline = BSpline::builder()
+            .elements([1.0, -2.0, 5.0, 3.0])
+            .knots([0.0, 1.0, 2.0, 3.0, 4.0, 5.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        spline.gen_batch(&[0.0, 1.0], &mut [0.0; 3]);
+    }
 }
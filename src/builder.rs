@@ -1,13 +1,34 @@
 //! Module with structures, utilities and errors used in many builders
 
-#[cfg(any(feature = "linear", feature = "bezier", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bezier",
+    feature = "bspline",
+    feature = "catmullrom",
+    feature = "hermite",
+    feature = "kochanek"
+))]
 use core::fmt;
-#[cfg(any(feature = "linear", feature = "bezier", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bezier",
+    feature = "bspline",
+    feature = "catmullrom",
+    feature = "hermite",
+    feature = "kochanek"
+))]
 use core::marker::PhantomData;
 
 #[cfg(all(
     feature = "std",
-    any(feature = "linear", feature = "bezier", feature = "bspline")
+    any(
+        feature = "linear",
+        feature = "bezier",
+        feature = "bspline",
+        feature = "catmullrom",
+        feature = "hermite",
+        feature = "kochanek"
+    )
 ))]
 use std::error::Error;
 
@@ -24,7 +45,15 @@ pub struct WithoutWeight;
 pub struct WithWeight;
 
 /// Struct indicator to mark information not yet given.
-#[cfg(any(feature = "linear", feature = "bezier", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bezier",
+    feature = "bspline",
+    feature = "chain",
+    feature = "catmullrom",
+    feature = "hermite",
+    feature = "kochanek"
+))]
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Unknown;
@@ -59,12 +88,24 @@ impl<R> InputDomain<R> {
 }
 
 /// Struct indicator to mark which type to use
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "catmullrom",
+    feature = "hermite",
+    feature = "kochanek"
+))]
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Type<R = f64>(PhantomData<*const R>);
 
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "catmullrom",
+    feature = "hermite",
+    feature = "kochanek"
+))]
 impl<R> Type<R> {
     pub const fn new() -> Self {
         Type(PhantomData)
@@ -85,6 +126,13 @@ impl Empty {
     }
 }
 
+#[cfg(feature = "bezier")]
+impl Default for Empty {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(feature = "bezier")]
 impl fmt::Display for Empty {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -96,7 +144,13 @@ impl fmt::Display for Empty {
 impl Error for Empty {}
 
 /// Error returned if the elements are to few for the specific interpolation.
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "catmullrom",
+    feature = "hermite",
+    feature = "kochanek"
+))]
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct TooFewElements {
@@ -104,17 +158,38 @@ pub struct TooFewElements {
     found: usize,
 }
 
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "catmullrom",
+    feature = "hermite",
+    feature = "kochanek"
+))]
 impl fmt::Display for TooFewElements {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "To few elements given for the interpolation. {} elements were given, but at least 2 are necessary.", self.found)
     }
 }
 
-#[cfg(all(feature = "std", any(feature = "linear", feature = "bspline")))]
+#[cfg(all(
+    feature = "std",
+    any(
+        feature = "linear",
+        feature = "bspline",
+        feature = "catmullrom",
+        feature = "hermite",
+        feature = "kochanek"
+    )
+))]
 impl Error for TooFewElements {}
 
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "catmullrom",
+    feature = "hermite",
+    feature = "kochanek"
+))]
 impl TooFewElements {
     /// Create a new error and document the number of elements found.
     pub fn new(found: usize) -> Self {
@@ -122,6 +197,66 @@ impl TooFewElements {
     }
 }
 
+/// Error returned if the number of elements and the number of knots are not matching.
+#[cfg(any(
+    feature = "linear",
+    feature = "cubic",
+    feature = "catmullrom",
+    feature = "hermite",
+    feature = "kochanek"
+))]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KnotElementInequality {
+    /// The number of elements found.
+    elements: usize,
+    /// The number of knots found.
+    knots: usize,
+}
+
+#[cfg(any(
+    feature = "linear",
+    feature = "cubic",
+    feature = "catmullrom",
+    feature = "hermite",
+    feature = "kochanek"
+))]
+impl fmt::Display for KnotElementInequality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "There has to be as many knots as elements, however we found {} elements and {} knots.",
+            self.elements, self.knots
+        )
+    }
+}
+
+#[cfg(all(
+    feature = "std",
+    any(
+        feature = "linear",
+        feature = "cubic",
+        feature = "catmullrom",
+        feature = "hermite",
+        feature = "kochanek"
+    )
+))]
+impl Error for KnotElementInequality {}
+
+#[cfg(any(
+    feature = "linear",
+    feature = "cubic",
+    feature = "catmullrom",
+    feature = "hermite",
+    feature = "kochanek"
+))]
+impl KnotElementInequality {
+    /// Create a new error with the number of elements and knots found.
+    pub fn new(elements: usize, knots: usize) -> Self {
+        KnotElementInequality { elements, knots }
+    }
+}
+
 /// Error returned when the number of knots are too few.
 #[cfg(feature = "bspline")]
 #[derive(Debug, Copy, Clone)]
@@ -0,0 +1,513 @@
+//! Builder module for Catmull-Rom splines.
+
+use super::error::CatmullRomError;
+use super::{CatmullRom, Endpoints, KnotElementInequality, TooFewElements};
+use crate::builder::{Type, Unknown};
+use crate::{DiscreteGenerator, Equidistant, Sorted, SortedGenerator};
+use num_traits::identities::Zero;
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Builder for Catmull-Rom splines.
+///
+/// This struct helps create Catmull-Rom splines. The differene between this struct and [`CatmullRomBuilder`]
+/// is that this struct may have other fallible methods and not only the [`build()`] method.
+///
+/// Before building, one has to give information for:
+/// - The elements the spline should use with [`elements()`].
+/// - The knots the spline uses. This can be seen as the spacing between those elements.
+///   Either by giving them directly with [`knots()`] or by using equidistant knots with [`equidistant()`].
+///
+/// Optionally, the handling of the first and last element can be changed with [`endpoints()`]
+/// before the elements are given, the neighbours of the first and last element can be given with
+/// [`start_handle()`] and [`end_handle()`] after the elements are given and a tension can be set with
+/// [`tension()`] after the knots are given.
+///
+/// ```rust
+/// # use enterpolation::{catmullrom::{CatmullRomDirector, CatmullRomError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), CatmullRomError> {
+/// let spline = CatmullRomDirector::new()
+///                 .elements([0.0,1.0,3.0,2.0])?
+///                 .equidistant::<f64>()
+///                 .distance(0.0,1.0)
+///                 .build();
+/// assert_f64_near!(spline.gen(2.0), 3.0);
+/// assert_f64_near!(spline.gen(1.5), 2.125);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: CatmullRomDirector::build()
+/// [`endpoints()`]: CatmullRomDirector::endpoints()
+/// [`elements()`]: CatmullRomDirector::elements()
+/// [`knots()`]: CatmullRomDirector::knots()
+/// [`equidistant()`]: CatmullRomDirector::equidistant()
+/// [`start_handle()`]: CatmullRomDirector::start_handle()
+/// [`end_handle()`]: CatmullRomDirector::end_handle()
+/// [`tension()`]: CatmullRomDirector::tension()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CatmullRomDirector<K, E, R, T> {
+    knots: K,
+    elements: E,
+    tension: R,
+    endpoints: Endpoints,
+    handles: [Option<T>; 2],
+}
+
+/// Builder for Catmull-Rom splines.
+///
+/// This struct helps create Catmull-Rom splines. Its only fallible method is [`build()`].
+/// Usually one creates an instance by using the [`builder()`] method on the spline itself.
+///
+/// Before building, one has to give information for:
+/// - The elements the spline should use with [`elements()`].
+/// - The knots the spline uses. This can be seen as the spacing between those elements.
+///   Either by giving them directly with [`knots()`] or by using equidistant knots with [`equidistant()`].
+///
+/// Optionally, the handling of the first and last element can be changed with [`endpoints()`]
+/// before the elements are given, the neighbours of the first and last element can be given with
+/// [`start_handle()`] and [`end_handle()`] after the elements are given and a tension can be set with
+/// [`tension()`] after the knots are given.
+///
+/// ```rust
+/// # use enterpolation::{catmullrom::{CatmullRom, CatmullRomError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), CatmullRomError> {
+/// let spline = CatmullRom::builder()
+///                 .elements([0.0,1.0,3.0,2.0])
+///                 .equidistant::<f64>()
+///                 .distance(0.0,1.0)
+///                 .build()?;
+/// assert_f64_near!(spline.gen(2.0), 3.0);
+/// assert_f64_near!(spline.gen(1.5), 2.125);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: CatmullRomBuilder::build()
+/// [`builder()`]: super::CatmullRom::builder()
+/// [`endpoints()`]: CatmullRomBuilder::endpoints()
+/// [`elements()`]: CatmullRomBuilder::elements()
+/// [`knots()`]: CatmullRomBuilder::knots()
+/// [`equidistant()`]: CatmullRomBuilder::equidistant()
+/// [`start_handle()`]: CatmullRomBuilder::start_handle()
+/// [`end_handle()`]: CatmullRomBuilder::end_handle()
+/// [`tension()`]: CatmullRomBuilder::tension()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CatmullRomBuilder<K, E, R, T> {
+    inner: Result<CatmullRomDirector<K, E, R, T>, CatmullRomError>,
+}
+
+impl Default for CatmullRomDirector<Unknown, Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        CatmullRomDirector::new()
+    }
+}
+
+impl Default for CatmullRomBuilder<Unknown, Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        CatmullRomBuilder::new()
+    }
+}
+
+impl CatmullRomDirector<Unknown, Unknown, Unknown, Unknown> {
+    /// Create a new Catmull-Rom spline builder.
+    pub const fn new() -> Self {
+        CatmullRomDirector {
+            knots: Unknown,
+            elements: Unknown,
+            tension: Unknown,
+            endpoints: Endpoints::Duplicate,
+            handles: [None, None],
+        }
+    }
+
+    /// Set how the first and last element are handled.
+    ///
+    /// Per default, they are duplicated such that the spline goes through all elements.
+    /// See [`Endpoints`] for all options.
+    ///
+    /// [`Endpoints`]: super::Endpoints
+    pub fn endpoints(self, endpoints: Endpoints) -> Self {
+        CatmullRomDirector { endpoints, ..self }
+    }
+
+    /// Set the elements of the spline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 elements are given,
+    /// or not at least 4 elements if the first and last element are phantom points.
+    ///
+    /// [`TooFewElements`]: super::error::CatmullRomError
+    pub fn elements<E>(
+        self,
+        elements: E,
+    ) -> Result<CatmullRomDirector<Unknown, E, Unknown, E::Output>, TooFewElements>
+    where
+        E: DiscreteGenerator,
+    {
+        if elements.len() < self.endpoints.min_elements() {
+            return Err(TooFewElements::new(elements.len()));
+        }
+        Ok(CatmullRomDirector {
+            knots: self.knots,
+            elements,
+            tension: self.tension,
+            endpoints: self.endpoints,
+            handles: [None, None],
+        })
+    }
+}
+
+impl CatmullRomBuilder<Unknown, Unknown, Unknown, Unknown> {
+    /// Create a new Catmull-Rom spline builder.
+    pub const fn new() -> Self {
+        CatmullRomBuilder {
+            inner: Ok(CatmullRomDirector::new()),
+        }
+    }
+
+    /// Set how the first and last element are handled.
+    ///
+    /// Per default, they are duplicated such that the spline goes through all elements.
+    /// See [`Endpoints`] for all options.
+    ///
+    /// [`Endpoints`]: super::Endpoints
+    pub fn endpoints(self, endpoints: Endpoints) -> Self {
+        CatmullRomBuilder {
+            inner: self.inner.map(|director| director.endpoints(endpoints)),
+        }
+    }
+
+    /// Set the elements of the spline.
+    pub fn elements<E>(self, elements: E) -> CatmullRomBuilder<Unknown, E, Unknown, E::Output>
+    where
+        E: DiscreteGenerator,
+    {
+        CatmullRomBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements(elements).map_err(|err| err.into())),
+        }
+    }
+}
+
+impl<E, T> CatmullRomDirector<Unknown, E, Unknown, T> {
+    /// Set the neighbour of the first element, which is used to calculate the tangent at the first element.
+    ///
+    /// The handle acts as a phantom point one knot span before the first knot.
+    /// Per default, the first element is duplicated, which is the same as giving the reflection of
+    /// the second element about the first element as handle.
+    /// Handles have no effect if the first and last element are phantom points already.
+    pub fn start_handle(self, handle: T) -> Self {
+        let [_, end] = self.handles;
+        CatmullRomDirector {
+            handles: [Some(handle), end],
+            ..self
+        }
+    }
+
+    /// Set the neighbour of the last element, which is used to calculate the tangent at the last element.
+    ///
+    /// The handle acts as a phantom point one knot span after the last knot.
+    /// Per default, the last element is duplicated, which is the same as giving the reflection of
+    /// the second to last element about the last element as handle.
+    /// Handles have no effect if the first and last element are phantom points already.
+    pub fn end_handle(self, handle: T) -> Self {
+        let [start, _] = self.handles;
+        CatmullRomDirector {
+            handles: [start, Some(handle)],
+            ..self
+        }
+    }
+
+    /// Set the knots of the spline.
+    ///
+    /// The amount of knots must be equal to the amount of elements, including phantom points.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    /// Returns [`NotSorted`] if the knots are not sorted such that they are increasing.
+    ///
+    /// [`KnotElementInequality`]: super::error::CatmullRomError
+    /// [`NotSorted`]:  super::error::CatmullRomError
+    #[allow(clippy::type_complexity)]
+    pub fn knots<K>(
+        self,
+        knots: K,
+    ) -> Result<CatmullRomDirector<Sorted<K>, E, K::Output, T>, CatmullRomError>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: Real,
+    {
+        if self.elements.len() != knots.len() {
+            return Err(KnotElementInequality::new(self.elements.len(), knots.len()).into());
+        }
+        Ok(CatmullRomDirector {
+            knots: Sorted::new(knots)?,
+            elements: self.elements,
+            tension: K::Output::zero(),
+            endpoints: self.endpoints,
+            handles: self.handles,
+        })
+    }
+
+    /// Build a spline with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the spline and the spacing of the knots.
+    /// Phantom points lie outside of the domain, one step before and after it.
+    ///
+    /// [`domain()`]: CatmullRomDirector::domain()
+    /// [`normalized()`]: CatmullRomDirector::normalized()
+    /// [`distance()`]: CatmullRomDirector::distance()
+    pub fn equidistant<R>(self) -> CatmullRomDirector<Type<R>, E, Unknown, T> {
+        CatmullRomDirector {
+            knots: Type::new(),
+            elements: self.elements,
+            tension: self.tension,
+            endpoints: self.endpoints,
+            handles: self.handles,
+        }
+    }
+}
+
+impl<E, T> CatmullRomBuilder<Unknown, E, Unknown, T> {
+    /// Set the neighbour of the first element, which is used to calculate the tangent at the first element.
+    ///
+    /// The handle acts as a phantom point one knot span before the first knot.
+    /// Per default, the first element is duplicated, which is the same as giving the reflection of
+    /// the second element about the first element as handle.
+    /// Handles have no effect if the first and last element are phantom points already.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{catmullrom::{CatmullRom, CatmullRomError}, Curve, Derivative};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), CatmullRomError> {
+    /// let spline = CatmullRom::builder()
+    ///                 .elements([0.0,1.0,3.0])
+    ///                 .start_handle(1.0)
+    ///                 .end_handle(3.0)
+    ///                 .equidistant::<f64>()
+    ///                 .distance(0.0,1.0)
+    ///                 .build()?;
+    /// // the curve leaves the first element with the tangent (1.0 - 1.0) / 2.0
+    /// assert_f64_near!(spline.derivative(0.0), 0.0);
+    /// // and arrives at the last element with the tangent (3.0 - 1.0) / 2.0
+    /// assert_f64_near!(spline.derivative(2.0), 1.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn start_handle(self, handle: T) -> Self {
+        CatmullRomBuilder {
+            inner: self.inner.map(|director| director.start_handle(handle)),
+        }
+    }
+
+    /// Set the neighbour of the last element, which is used to calculate the tangent at the last element.
+    ///
+    /// The handle acts as a phantom point one knot span after the last knot.
+    /// Per default, the last element is duplicated, which is the same as giving the reflection of
+    /// the second to last element about the last element as handle.
+    /// Handles have no effect if the first and last element are phantom points already.
+    pub fn end_handle(self, handle: T) -> Self {
+        CatmullRomBuilder {
+            inner: self.inner.map(|director| director.end_handle(handle)),
+        }
+    }
+
+    /// Set the knots of the spline.
+    ///
+    /// The amount of knots must be equal to the amount of elements, including phantom points.
+    pub fn knots<K>(self, knots: K) -> CatmullRomBuilder<Sorted<K>, E, K::Output, T>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: Real,
+    {
+        CatmullRomBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+
+    /// Build a spline with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the spline and the spacing of the knots.
+    /// Phantom points lie outside of the domain, one step before and after it.
+    ///
+    /// [`domain()`]: CatmullRomBuilder::domain()
+    /// [`normalized()`]: CatmullRomBuilder::normalized()
+    /// [`distance()`]: CatmullRomBuilder::distance()
+    pub fn equidistant<R>(self) -> CatmullRomBuilder<Type<R>, E, Unknown, T> {
+        CatmullRomBuilder {
+            inner: self.inner.map(|director| director.equidistant()),
+        }
+    }
+}
+
+impl<R, E, T> CatmullRomDirector<Type<R>, E, Unknown, T>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the spline.
+    pub fn domain(self, start: R, end: R) -> CatmullRomDirector<Equidistant<R>, E, R, T> {
+        let border = self.endpoints.border();
+        let segments = self.elements.len() - 1 - 2 * border;
+        let step = (end - start) / R::from_usize(segments).unwrap();
+        self.distance(start, step)
+    }
+
+    /// Set the domain of the spline to be [0.0,1.0].
+    pub fn normalized(self) -> CatmullRomDirector<Equidistant<R>, E, R, T> {
+        self.domain(R::zero(), R::one())
+    }
+
+    /// Set the domain of the spline by defining the distance between the knots
+    pub fn distance(self, start: R, step: R) -> CatmullRomDirector<Equidistant<R>, E, R, T> {
+        let border = R::from_usize(self.endpoints.border()).unwrap();
+        CatmullRomDirector {
+            knots: Equidistant::step(self.elements.len(), start - step * border, step),
+            elements: self.elements,
+            tension: R::zero(),
+            endpoints: self.endpoints,
+            handles: self.handles,
+        }
+    }
+}
+
+impl<R, E, T> CatmullRomBuilder<Type<R>, E, Unknown, T>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the spline.
+    pub fn domain(self, start: R, end: R) -> CatmullRomBuilder<Equidistant<R>, E, R, T> {
+        CatmullRomBuilder {
+            inner: self.inner.map(|director| director.domain(start, end)),
+        }
+    }
+
+    /// Set the domain of the spline to be [0.0,1.0].
+    pub fn normalized(self) -> CatmullRomBuilder<Equidistant<R>, E, R, T> {
+        CatmullRomBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+
+    /// Set the domain of the spline by defining the distance between the knots
+    pub fn distance(self, start: R, step: R) -> CatmullRomBuilder<Equidistant<R>, E, R, T> {
+        CatmullRomBuilder {
+            inner: self.inner.map(|director| director.distance(start, step)),
+        }
+    }
+}
+
+impl<K, A, E, R, T> CatmullRomDirector<Sorted<K, A>, E, R, T> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<S>(self, strategy: S) -> CatmullRomDirector<Sorted<K, S>, E, R, T> {
+        CatmullRomDirector {
+            knots: self.knots.search(strategy),
+            elements: self.elements,
+            tension: self.tension,
+            endpoints: self.endpoints,
+            handles: self.handles,
+        }
+    }
+}
+
+impl<K, A, E, R, T> CatmullRomBuilder<Sorted<K, A>, E, R, T> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<S>(self, strategy: S) -> CatmullRomBuilder<Sorted<K, S>, E, R, T> {
+        CatmullRomBuilder {
+            inner: self.inner.map(|director| director.search(strategy)),
+        }
+    }
+}
+
+impl<K, E, R> CatmullRomDirector<K, E, R, E::Output>
+where
+    E: DiscreteGenerator,
+    K: SortedGenerator<Output = R>,
+    R: Real,
+{
+    /// Set the tension of the spline.
+    ///
+    /// All tangents are scaled by `1 - tension`. The default tension of `0.0` results in a Catmull-Rom spline,
+    /// a tension of `1.0` results in tangents of length zero at all elements.
+    pub fn tension(self, tension: R) -> Self {
+        CatmullRomDirector { tension, ..self }
+    }
+
+    /// Build a Catmull-Rom spline.
+    pub fn build(self) -> CatmullRom<K, E, R, E::Output> {
+        CatmullRom {
+            handles: self.handles,
+            ..CatmullRom::new_unchecked(self.elements, self.knots, self.tension, self.endpoints)
+        }
+    }
+}
+
+impl<K, E, R> CatmullRomBuilder<K, E, R, E::Output>
+where
+    E: DiscreteGenerator,
+    K: SortedGenerator<Output = R>,
+    R: Real,
+{
+    /// Set the tension of the spline.
+    ///
+    /// All tangents are scaled by `1 - tension`. The default tension of `0.0` results in a Catmull-Rom spline,
+    /// a tension of `1.0` results in tangents of length zero at all elements.
+    pub fn tension(self, tension: R) -> Self {
+        CatmullRomBuilder {
+            inner: self.inner.map(|director| director.tension(tension)),
+        }
+    }
+
+    /// Build a Catmull-Rom spline.
+    pub fn build(self) -> Result<CatmullRom<K, E, R, E::Output>, CatmullRomError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
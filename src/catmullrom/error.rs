@@ -0,0 +1,51 @@
+//! All error types for Catmull-Rom splines.
+
+pub use crate::builder::{KnotElementInequality, TooFewElements};
+pub use crate::NotSorted;
+use core::{convert::From, fmt};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Errors which could occur when using or creating a Catmull-Rom spline.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum CatmullRomError {
+    /// Error returned if the elements are to few for a Catmull-Rom spline.
+    TooFewElements(TooFewElements),
+    /// Error returned if the number of knots and elements are not equal.
+    KnotElementInequality(KnotElementInequality),
+    /// Error returned if knots are not sorted.
+    NotSorted(NotSorted),
+}
+
+impl fmt::Display for CatmullRomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatmullRomError::TooFewElements(inner) => inner.fmt(f),
+            CatmullRomError::KnotElementInequality(inner) => inner.fmt(f),
+            CatmullRomError::NotSorted(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl From<TooFewElements> for CatmullRomError {
+    fn from(from: TooFewElements) -> Self {
+        CatmullRomError::TooFewElements(from)
+    }
+}
+
+impl From<KnotElementInequality> for CatmullRomError {
+    fn from(from: KnotElementInequality) -> Self {
+        CatmullRomError::KnotElementInequality(from)
+    }
+}
+
+impl From<NotSorted> for CatmullRomError {
+    fn from(from: NotSorted) -> Self {
+        CatmullRomError::NotSorted(from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for CatmullRomError {}
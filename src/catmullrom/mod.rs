@@ -0,0 +1,506 @@
+//! Catmull-Rom splines.
+//!
+//! Catmull-Rom splines are piecewise cubic curves which pass through all of their elements.
+//! The tangent at each element is given by the difference of its neighbours, such that the curve
+//! has a continuous derivative. The easiest way to create one is by using the builder pattern of
+//! [`CatmullRomBuilder`].
+//!
+//! ```rust
+//! # use enterpolation::{catmullrom::{CatmullRom, CatmullRomError}, Generator, Curve};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # fn main() -> Result<(), CatmullRomError> {
+//! let spline = CatmullRom::builder()
+//!                 .elements([0.0,1.0,3.0,2.0])
+//!                 .knots([0.0,1.0,2.0,3.0])
+//!                 .build()?;
+//! assert_eq!(spline.domain(), [0.0,3.0]);
+//! assert_f64_near!(spline.gen(1.0), 1.0);
+//! assert_f64_near!(spline.gen(1.5), 2.125);
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! The first and last element are missing a neighbour to calculate their tangent from.
+//! Per default, the missing neighbour is replaced by the element itself, which is the same as
+//! duplicating the first and last element. Alternatively, the first and last element
+//! can be given explicitly as phantom points, which are only used to calculate the tangents
+//! at the second and second to last element. The curve then starts at the second element and ends at
+//! the second to last element. See [`Endpoints`] for more information.
+//! To control how the curve leaves the first element and arrives at the last element without
+//! giving up on the curve passing through them, the missing neighbours can be given as handles with
+//! [`start_handle()`] and [`end_handle()`] instead.
+//!
+//! The tangents may be scaled down with a tension, in which case the curve is also called a cardinal spline.
+//!
+//! [`CatmullRomBuilder`]: CatmullRomBuilder
+//! [`start_handle()`]: CatmullRomBuilder::start_handle()
+//! [`end_handle()`]: CatmullRomBuilder::end_handle()
+
+#[cfg(feature = "alloc")]
+use crate::base::knot_midpoints;
+use crate::builder::Unknown;
+use crate::utils::{hermite, hermite_segment};
+use crate::{Curve, Derivative, DiscreteGenerator, Generator, SortedGenerator};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+
+mod builder;
+pub use builder::{CatmullRomBuilder, CatmullRomDirector};
+
+pub mod error;
+pub use error::{CatmullRomError, KnotElementInequality, NotSorted, TooFewElements};
+
+/// The way the tangents at the first and last element of a Catmull-Rom spline are calculated.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Endpoints {
+    /// The first and last element are duplicated, such that the curve goes through all elements.
+    ///
+    /// The tangent at the first and last element is the direction to their only neighbour.
+    #[default]
+    Duplicate,
+    /// The first and last element are phantom points.
+    ///
+    /// They are only used to calculate the tangents at the second and second to last element,
+    /// such that the curve only goes from the second element to the second to last element.
+    /// At least four elements are necessary.
+    Phantom,
+}
+
+/// Catmull-Rom spline.
+///
+/// See [catmullrom module] for more information.
+/// The type `T` is the type of the optional handles, which is the type of the elements.
+///
+/// [catmullrom module]: self
+#[derive(Debug, Copy, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CatmullRom<K, E, R, T> {
+    elements: E,
+    knots: K,
+    tension: R,
+    endpoints: Endpoints,
+    handles: [Option<T>; 2],
+}
+
+impl CatmullRom<Unknown, Unknown, Unknown, Unknown> {
+    /// Get the builder for a Catmull-Rom spline.
+    ///
+    /// The builder takes:
+    /// - optionally the handling of the endpoints with [`endpoints()`]
+    /// - elements with [`elements()`]
+    /// - optionally the neighbours of the first and last element with [`start_handle()`] and [`end_handle()`]
+    /// - knots with either [`knots()`] or [`equidistant()`]
+    /// - optionally a tension with [`tension()`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{catmullrom::{CatmullRom, CatmullRomError, Endpoints}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), CatmullRomError> {
+    /// let spline = CatmullRom::builder()
+    ///                 .endpoints(Endpoints::Phantom)
+    ///                 .elements([-1.0,0.0,1.0,2.0,3.0,4.0])
+    ///                 .equidistant::<f64>()
+    ///                 .normalized()
+    ///                 .build()?;
+    /// // the phantom points are on a line with the other elements, such that the curve is a line
+    /// let results = [0.0,0.75,1.5,2.25,3.0];
+    /// for (value,result) in spline.take(5).zip(results.iter().copied()){
+    ///     assert_f64_near!(value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`endpoints()`]: CatmullRomBuilder::endpoints()
+    /// [`elements()`]: CatmullRomBuilder::elements()
+    /// [`start_handle()`]: CatmullRomBuilder::start_handle()
+    /// [`end_handle()`]: CatmullRomBuilder::end_handle()
+    /// [`knots()`]: CatmullRomBuilder::knots()
+    /// [`equidistant()`]: CatmullRomBuilder::equidistant()
+    /// [`tension()`]: CatmullRomBuilder::tension()
+    pub fn builder() -> CatmullRomBuilder<Unknown, Unknown, Unknown, Unknown> {
+        CatmullRomBuilder::new()
+    }
+}
+
+impl<K, E, R> CatmullRom<K, E, R, E::Output>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    R: Real,
+{
+    /// Create a Catmull-Rom spline with slice-like collections of elements and knots.
+    ///
+    /// Knots have to be sorted and there should be as many knots as elements.
+    /// There has to be at least 2 elements, or 4 elements if the first and last element are phantom points.
+    pub fn new(
+        elements: E,
+        knots: K,
+        tension: R,
+        endpoints: Endpoints,
+    ) -> Result<Self, CatmullRomError> {
+        if elements.len() < endpoints.min_elements() {
+            return Err(TooFewElements::new(elements.len()).into());
+        }
+        if knots.len() != elements.len() {
+            return Err(KnotElementInequality::new(elements.len(), knots.len()).into());
+        }
+        Ok(CatmullRom {
+            elements,
+            knots,
+            tension,
+            endpoints,
+            handles: [None, None],
+        })
+    }
+
+    /// Create a Catmull-Rom spline with slice-like collections of elements and knots.
+    ///
+    /// # Panics
+    ///
+    /// Knots should be in increasing order, there should be as many knots as elements
+    /// and there has to be at least *two* elements, or *four* if the first and last element are phantom points.
+    /// If any of these requirements are not uphold, the library may panic at any time.
+    pub fn new_unchecked(elements: E, knots: K, tension: R, endpoints: Endpoints) -> Self {
+        CatmullRom {
+            elements,
+            knots,
+            tension,
+            endpoints,
+            handles: [None, None],
+        }
+    }
+
+    /// Returns the index of the segment in which the scalar lies, the position of the scalar
+    /// within the segment normalized to [0.0,1.0] and the width of the segment.
+    fn segment(&self, scalar: R) -> (usize, R, R) {
+        let border = self.endpoints.border();
+        hermite_segment(
+            &self.knots,
+            scalar,
+            border + 1,
+            self.knots.len() - 1 - border,
+        )
+    }
+}
+
+impl<K, E, R> CatmullRom<K, E, R, E::Output>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output:
+        Add<Output = E::Output> + Sub<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    R: Real,
+{
+    /// Returns the tangent at the element with the given index.
+    fn tangent(&self, index: usize) -> E::Output {
+        let last = self.elements.len() - 1;
+        let factor = R::one() - self.tension;
+        // handles are phantom points one knot span outside
+        if index == 0 {
+            if let Some(handle) = self.handles[0] {
+                let width = self.knots.gen(1) - self.knots.gen(0);
+                return (self.elements.gen(1) - handle) * (factor / (width + width));
+            }
+        }
+        if index == last {
+            if let Some(handle) = self.handles[1] {
+                let width = self.knots.gen(last) - self.knots.gen(last - 1);
+                return (handle - self.elements.gen(last - 1)) * (factor / (width + width));
+            }
+        }
+        let prev = index.saturating_sub(1);
+        let next = (index + 1).min(last);
+        let width = self.knots.gen(next) - self.knots.gen(prev);
+        (self.elements.gen(next) - self.elements.gen(prev)) * (factor / width)
+    }
+
+    /// Generate the value and its tangent, in this order.
+    ///
+    /// Both are calculated at once, which is cheaper than calculating them separately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalar` is NaN or similar.
+    pub fn gen_with_tangent(&self, scalar: R) -> [E::Output; 2] {
+        let (index, t, width) = self.segment(scalar);
+        let start = self.elements.gen(index);
+        let end = self.elements.gen(index + 1);
+        let start_tangent = self.tangent(index);
+        let end_tangent = self.tangent(index + 1);
+        hermite([start, end], [start_tangent, end_tangent], t, width)
+    }
+}
+
+impl<K, E, R> Generator<R> for CatmullRom<K, E, R, E::Output>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output:
+        Add<Output = E::Output> + Sub<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    R: Real,
+{
+    type Output = E::Output;
+    /// # Panics
+    ///
+    /// Panics if `scalar` is NaN or similar.
+    fn gen(&self, scalar: R) -> Self::Output {
+        let [value, _] = self.gen_with_tangent(scalar);
+        value
+    }
+}
+
+impl<K, E, R> Curve<R> for CatmullRom<K, E, R, E::Output>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output:
+        Add<Output = E::Output> + Sub<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        let border = self.endpoints.border();
+        [
+            self.knots.gen(border),
+            self.knots.gen(self.knots.len() - 1 - border),
+        ]
+    }
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        let border = self.endpoints.border();
+        knot_midpoints(&self.knots, border, self.knots.len() - 1 - border)
+    }
+}
+
+impl<K, E, R> Derivative<R> for CatmullRom<K, E, R, E::Output>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output:
+        Add<Output = E::Output> + Sub<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    R: Real,
+{
+    fn derivative(&self, scalar: R) -> Self::Output {
+        let [_, tangent] = self.gen_with_tangent(scalar);
+        tangent
+    }
+}
+
+impl Endpoints {
+    /// Returns the minimal number of elements necessary.
+    const fn min_elements(self) -> usize {
+        match self {
+            Endpoints::Duplicate => 2,
+            Endpoints::Phantom => 4,
+        }
+    }
+
+    /// Returns the number of elements at each end which are only used as phantom points.
+    const fn border(self) -> usize {
+        match self {
+            Endpoints::Duplicate => 0,
+            Endpoints::Phantom => 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Curve, Derivative, Generator, Sorted};
+
+    #[test]
+    fn interpolates_elements() {
+        let elements = [0.0, 2.0, -1.0, 4.0, 3.0];
+        let knots = [0.0, 1.0, 3.0, 4.0, 7.0];
+        let spline = CatmullRom::builder()
+            .elements(elements)
+            .knots(knots)
+            .build()
+            .unwrap();
+        assert_eq!(spline.domain(), [0.0, 7.0]);
+        for (knot, element) in knots.as_slice().iter().zip(elements.as_slice()) {
+            assert_f64_near!(spline.gen(*knot), *element);
+        }
+    }
+
+    #[test]
+    fn continuous_tangent() {
+        let spline = CatmullRom::builder()
+            .elements([0.0, 2.0, -1.0, 4.0, 3.0])
+            .knots([0.0, 1.0, 3.0, 4.0, 7.0])
+            .tension(0.3)
+            .build()
+            .unwrap();
+        let eps = 1e-9;
+        for knot in [1.0, 3.0, 4.0] {
+            let [before_value, before] = spline.gen_with_tangent(knot - eps);
+            let [after_value, after] = spline.gen_with_tangent(knot);
+            assert_float_absolute_eq!(before_value, after_value, 1e-6);
+            assert_float_absolute_eq!(before, after, 1e-6);
+        }
+        // tangent at an element is the scaled difference of its neighbours
+        assert_float_absolute_eq!(spline.derivative(3.0), 0.7 * 2.0 / 3.0, 1e-12);
+        // compare with finite differences
+        let finite = spline.finite_difference();
+        for x in [0.5, 2.0, 3.5, 5.0] {
+            assert_float_absolute_eq!(spline.derivative(x), finite.derivative(x), 1e-6);
+        }
+    }
+
+    #[test]
+    fn endpoints() {
+        let duplicate = CatmullRom::builder()
+            .elements([0.0, 1.0, 3.0, 2.0])
+            .equidistant::<f64>()
+            .distance(0.0, 1.0)
+            .build()
+            .unwrap();
+        assert_eq!(duplicate.domain(), [0.0, 3.0]);
+        // one-sided tangent at the first element
+        assert_f64_near!(duplicate.derivative(0.0), 1.0);
+        assert_f64_near!(duplicate.derivative(3.0), -1.0);
+
+        let phantom = CatmullRom::builder()
+            .endpoints(Endpoints::Phantom)
+            .elements([0.0, 1.0, 3.0, 2.0])
+            .equidistant::<f64>()
+            .distance(0.0, 1.0)
+            .build()
+            .unwrap();
+        assert_eq!(phantom.domain(), [0.0, 1.0]);
+        #[cfg(feature = "alloc")]
+        assert_eq!(phantom.knot_midpoints(), alloc::vec![0.5]);
+        assert_f64_near!(phantom.gen(0.0), 1.0);
+        assert_f64_near!(phantom.gen(1.0), 3.0);
+        assert_f64_near!(phantom.derivative(0.0), 1.5);
+        assert_f64_near!(phantom.derivative(1.0), 0.5);
+        // the same segment without phantom points
+        assert_f64_near!(phantom.gen(0.5), duplicate.gen(1.5));
+
+        let normalized = CatmullRom::builder()
+            .endpoints(Endpoints::Phantom)
+            .elements([0.0, 1.0, 3.0, 2.0, 5.0])
+            .equidistant::<f64>()
+            .normalized()
+            .build()
+            .unwrap();
+        assert_eq!(normalized.domain(), [0.0, 1.0]);
+        assert_f64_near!(normalized.gen(0.5), 3.0);
+    }
+
+    #[test]
+    fn handles() {
+        let elements = [0.0, 2.0, -1.0, 4.0, 3.0];
+        let knots = [0.0, 1.0, 3.0, 4.0, 7.0];
+        let plain = CatmullRom::builder()
+            .elements(elements)
+            .knots(knots)
+            .build()
+            .unwrap();
+        let handled = CatmullRom::builder()
+            .elements(elements)
+            .start_handle(5.0)
+            .end_handle(-2.0)
+            .knots(knots)
+            .tension(0.5)
+            .build()
+            .unwrap();
+        let tensed = CatmullRom::builder()
+            .elements(elements)
+            .knots(knots)
+            .tension(0.5)
+            .build()
+            .unwrap();
+        assert_eq!(handled.domain(), plain.domain());
+        // tangents at the ends point from and to the handles, one knot span outside
+        assert_f64_near!(handled.derivative(0.0), 0.5 * (2.0 - 5.0) / 2.0);
+        assert_f64_near!(handled.derivative(7.0), 0.5 * (-2.0 - 4.0) / 6.0);
+        // the curve still passes through all elements
+        for (knot, element) in knots.as_slice().iter().zip(elements.as_slice()) {
+            assert_f64_near!(handled.gen(*knot), *element);
+        }
+        // only the first and last segment change
+        for x in [1.5, 2.0, 3.0, 3.5, 4.0] {
+            assert_f64_near!(handled.gen(x), tensed.gen(x));
+            assert_f64_near!(handled.derivative(x), tensed.derivative(x));
+        }
+        assert!((handled.gen(0.5) - tensed.gen(0.5)).abs() > 1e-3);
+        assert!((handled.gen(5.5) - tensed.gen(5.5)).abs() > 1e-3);
+        // reflected handles are the same as duplicated endpoints
+        let reflected = CatmullRom::builder()
+            .elements(elements)
+            .start_handle(-2.0)
+            .end_handle(2.0)
+            .knots(knots)
+            .build()
+            .unwrap();
+        for x in [0.0, 0.5, 2.0, 5.5, 7.0] {
+            assert_f64_near!(reflected.gen(x), plain.gen(x));
+        }
+    }
+
+    #[test]
+    fn tension() {
+        let spline = CatmullRom::builder()
+            .elements([0.0, 1.0, 3.0, 2.0])
+            .equidistant::<f64>()
+            .normalized()
+            .tension(1.0)
+            .build()
+            .unwrap();
+        // with full tension, all tangents vanish at the elements
+        for knot in [0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0] {
+            assert_float_absolute_eq!(spline.derivative(knot), 0.0, 1e-12);
+        }
+        assert_float_absolute_eq!(spline.gen(0.5), 2.0, 1e-12);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            CatmullRom::builder().elements([1.0]).knots([0.0]).build(),
+            Err(CatmullRomError::TooFewElements(_))
+        ));
+        assert!(matches!(
+            CatmullRom::builder()
+                .endpoints(Endpoints::Phantom)
+                .elements([1.0, 2.0, 3.0])
+                .knots([0.0, 1.0, 2.0])
+                .build(),
+            Err(CatmullRomError::TooFewElements(_))
+        ));
+        assert!(matches!(
+            CatmullRom::builder()
+                .elements([1.0, 2.0, 3.0])
+                .knots([0.0, 1.0])
+                .build(),
+            Err(CatmullRomError::KnotElementInequality(_))
+        ));
+        assert!(matches!(
+            CatmullRom::builder()
+                .elements([1.0, 2.0, 3.0])
+                .knots([0.0, 2.0, 1.0])
+                .build(),
+            Err(CatmullRomError::NotSorted(_))
+        ));
+        assert!(matches!(
+            CatmullRom::new(
+                [1.0, 2.0],
+                Sorted::new_unchecked([0.0, 1.0, 2.0]),
+                0.0,
+                Endpoints::Duplicate
+            ),
+            Err(CatmullRomError::KnotElementInequality(_))
+        ));
+    }
+}
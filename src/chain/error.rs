@@ -0,0 +1,39 @@
+//! All error types for chains of curves.
+
+use core::fmt;
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Errors which could occur when creating a chain of curves.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ChainError {
+    /// Error returned if no segment was given.
+    Empty,
+    /// Error returned if the domain of the segment with the given index is not a finite interval.
+    InvalidDomain(usize),
+    /// Error returned if the segment with the given index does not start where the previous segment ends.
+    Discontinuous(usize),
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChainError::Empty => write!(f, "No segments given, a chain needs at least one curve."),
+            ChainError::InvalidDomain(index) => write!(
+                f,
+                "The domain of segment {} has to be finite and its start has to be less or equal to its end.",
+                index
+            ),
+            ChainError::Discontinuous(index) => write!(
+                f,
+                "Segment {} does not start where the previous segment ends.",
+                index
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ChainError {}
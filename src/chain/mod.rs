@@ -0,0 +1,281 @@
+//! Chains of curves.
+//!
+//! A [`CurveChain`] concatenates curves end to end, such that they form one curve with a continuous domain.
+//! Each segment keeps the length of its domain, but its domain gets shifted to start where the previous
+//! segment ends. The domain of the chain starts at the start of the domain of its first segment.
+//!
+//! Segments of different types can be chained by boxing them, as shown below.
+//!
+#![cfg_attr(all(feature = "bezier", feature = "linear"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "bezier", feature = "linear")), doc = "```ignore")]
+//! # use enterpolation::{bezier::Bezier, chain::CurveChain, linear::Linear, Generator, Curve};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let ease_in = Bezier::builder()
+//!                 .elements([0.0, 0.0, 1.0])
+//!                 .normalized::<f64>()
+//!                 .constant::<3>()
+//!                 .build()?;
+//! let ramp = Linear::builder()
+//!                 .elements([1.0, 3.0])
+//!                 .knots([0.0, 2.0])
+//!                 .build()?;
+//! let segments: Vec<Box<dyn Curve<f64, Output = f64>>> = vec![Box::new(ease_in), Box::new(ramp)];
+//! let chain = CurveChain::builder()
+//!                 .segments(segments)
+//!                 .build_continuous(1e-9, |a: f64, b: f64| (a - b).abs())?;
+//! assert_eq!(chain.domain(), [0.0, 3.0]);
+//! assert_f64_near!(chain.gen(0.5), 0.25);
+//! assert_f64_near!(chain.gen(2.0), 2.0);
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! Inputs outside of the domain of the chain are extrapolated by the first or last segment.
+//! At a join, the later segment is evaluated.
+
+use crate::builder::Unknown;
+use crate::{Curve, Generator};
+use alloc::vec::Vec;
+use num_traits::real::Real;
+
+mod error;
+pub use error::ChainError;
+
+/// Curve which concatenates curves end to end.
+///
+/// See [chain module] for more information.
+///
+/// [chain module]: self
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CurveChain<C, R> {
+    segments: Vec<C>,
+    /// start of every segment in the domain of the chain, followed by the end of the chain
+    joins: Vec<R>,
+    /// difference between the input of every segment and the input of the chain
+    shifts: Vec<R>,
+}
+
+impl CurveChain<Unknown, Unknown> {
+    /// Get a builder for chains of curves.
+    pub fn builder<C>() -> CurveChainBuilder<C> {
+        CurveChainBuilder::new()
+    }
+}
+
+impl<C, R> CurveChain<C, R> {
+    /// Returns the segments of the chain.
+    pub fn segments(&self) -> &[C] {
+        &self.segments
+    }
+
+    /// Returns the start of every segment in the domain of the chain, followed by the end of the chain.
+    pub fn joins(&self) -> &[R] {
+        &self.joins
+    }
+}
+
+impl<C, R> CurveChain<C, R>
+where
+    C: Curve<R>,
+    R: Real,
+{
+    /// Returns the index of the segment responsible for the given input.
+    fn segment_index(&self, scalar: R) -> usize {
+        // the first and last joins bound the chain, inputs outside are extrapolated
+        let inner = &self.joins[1..self.segments.len()];
+        inner.partition_point(|join| *join <= scalar)
+    }
+}
+
+impl<C, R> Generator<R> for CurveChain<C, R>
+where
+    C: Curve<R>,
+    R: Real,
+{
+    type Output = C::Output;
+    fn gen(&self, scalar: R) -> Self::Output {
+        let index = self.segment_index(scalar);
+        self.segments[index].gen(scalar + self.shifts[index])
+    }
+}
+
+impl<C, R> Curve<R> for CurveChain<C, R>
+where
+    C: Curve<R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.joins[0], self.joins[self.joins.len() - 1]]
+    }
+}
+
+/// Builder for chains of curves.
+///
+/// Segments are added in order with [`segment()`] or [`segments()`].
+/// The chain is created with [`build()`] or, if the segments should be checked for continuity at their joins,
+/// with [`build_continuous()`].
+///
+/// [`segment()`]: CurveChainBuilder::segment()
+/// [`segments()`]: CurveChainBuilder::segments()
+/// [`build()`]: CurveChainBuilder::build()
+/// [`build_continuous()`]: CurveChainBuilder::build_continuous()
+#[derive(Debug, Clone)]
+pub struct CurveChainBuilder<C> {
+    segments: Vec<C>,
+}
+
+impl<C> Default for CurveChainBuilder<C> {
+    fn default() -> Self {
+        CurveChainBuilder::new()
+    }
+}
+
+impl<C> CurveChainBuilder<C> {
+    /// Create a new builder without any segments.
+    pub const fn new() -> Self {
+        CurveChainBuilder {
+            segments: Vec::new(),
+        }
+    }
+
+    /// Append a segment to the end of the chain.
+    pub fn segment(mut self, segment: C) -> Self {
+        self.segments.push(segment);
+        self
+    }
+
+    /// Append all given segments to the end of the chain.
+    pub fn segments<I>(mut self, segments: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+    {
+        self.segments.extend(segments);
+        self
+    }
+
+    /// Build the chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Empty`] if no segment was given.
+    /// Returns [`InvalidDomain`] if the domain of a segment is not finite or its start is greater than its end.
+    ///
+    /// [`Empty`]: ChainError::Empty
+    /// [`InvalidDomain`]: ChainError::InvalidDomain
+    pub fn build<R>(self) -> Result<CurveChain<C, R>, ChainError>
+    where
+        C: Curve<R>,
+        R: Real,
+    {
+        let first = match self.segments.first() {
+            Some(first) => first,
+            None => return Err(ChainError::Empty),
+        };
+        let mut joins = Vec::with_capacity(self.segments.len() + 1);
+        let mut shifts = Vec::with_capacity(self.segments.len());
+        let mut join = first.domain()[0];
+        joins.push(join);
+        for (index, segment) in self.segments.iter().enumerate() {
+            let [start, end] = segment.domain();
+            // also rejects NaN
+            if !(R::min_value() <= start && start <= end && end <= R::max_value()) {
+                return Err(ChainError::InvalidDomain(index));
+            }
+            shifts.push(start - join);
+            join = join + (end - start);
+            joins.push(join);
+        }
+        Ok(CurveChain {
+            segments: self.segments,
+            joins,
+            shifts,
+        })
+    }
+
+    /// Build the chain and check that each segment starts where the previous one ends.
+    ///
+    /// Segments are continuous at a join if the distance between the end of the previous segment and
+    /// the start of the next segment is at most `tolerance`. The distance between two outputs is
+    /// calculated by the given closure.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Discontinuous`] if a segment does not start where the previous segment ends.
+    /// Returns any error of [`build()`].
+    ///
+    /// [`Discontinuous`]: ChainError::Discontinuous
+    /// [`build()`]: CurveChainBuilder::build()
+    pub fn build_continuous<R, F>(
+        self,
+        tolerance: R,
+        distance: F,
+    ) -> Result<CurveChain<C, R>, ChainError>
+    where
+        C: Curve<R>,
+        F: Fn(C::Output, C::Output) -> R,
+        R: Real,
+    {
+        let chain = self.build()?;
+        for (index, pair) in chain.segments.windows(2).enumerate() {
+            let (_, end) = pair[0].endpoints();
+            let (start, _) = pair[1].endpoints();
+            // also rejects NaN
+            if distance(end, start) <= tolerance {
+                continue;
+            }
+            return Err(ChainError::Discontinuous(index + 1));
+        }
+        Ok(chain)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::easing::FuncEase;
+    use crate::TransformInput;
+    use alloc::boxed::Box;
+
+    #[test]
+    fn chain() {
+        let square = FuncEase::new(|x: f64| x * x);
+        let shifted = square.slice(1.0..=2.0);
+        let segments: [Box<dyn Curve<f64, Output = f64>>; 3] = [
+            Box::new(square),
+            Box::new(shifted),
+            Box::new(FuncEase::new(|x: f64| 4.0 - x)),
+        ];
+        let chain = CurveChain::builder()
+            .segments(segments)
+            .build_continuous(1e-9, |a: f64, b: f64| (a - b).abs())
+            .unwrap();
+        assert_eq!(chain.domain(), [0.0, 3.0]);
+        assert_eq!(chain.joins(), [0.0, 1.0, 2.0, 3.0]);
+        assert_f64_near!(chain.gen(0.5), 0.25);
+        assert_f64_near!(chain.gen(1.0), 1.0);
+        assert_f64_near!(chain.gen(1.5), 2.25);
+        assert_f64_near!(chain.gen(2.5), 3.5);
+        // extrapolation by the outer segments
+        assert_f64_near!(chain.gen(-1.0), 1.0);
+        assert_f64_near!(chain.gen(4.0), 2.0);
+    }
+
+    #[test]
+    fn errors() {
+        let identity: fn(f64) -> f64 = |x| x;
+        let empty = CurveChainBuilder::<FuncEase<fn(f64) -> f64>>::new().build::<f64>();
+        assert_eq!(empty.unwrap_err(), ChainError::Empty);
+        let jump = CurveChain::builder()
+            .segments([FuncEase::new(identity), FuncEase::new(identity)])
+            .build_continuous(1e-9, |a: f64, b: f64| (a - b).abs());
+        assert_eq!(jump.unwrap_err(), ChainError::Discontinuous(1));
+        let reversed = CurveChain::builder()
+            .segment(TransformInput::new(FuncEase::new(identity), 0.0, -1.0))
+            .build();
+        assert_eq!(reversed.unwrap_err(), ChainError::InvalidDomain(0));
+    }
+}
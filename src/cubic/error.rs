@@ -0,0 +1,90 @@
+//! All error types for cubic interpolations.
+
+pub use crate::builder::{KnotElementInequality, TooFewElements};
+pub use crate::NotSorted;
+use core::{convert::From, fmt};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Errors which could occur when creating a cubic interpolation.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum CubicError {
+    /// Error returned if the elements are to few for a cubic interpolation.
+    TooFewElements(TooFewElements),
+    /// Error returned if the number of knots and elements are not equal.
+    KnotElementInequality(KnotElementInequality),
+    /// Error returned if knots are not strictly increasing.
+    NotSorted(NotSorted),
+    /// Error returned if the first and last value of a periodic interpolation differ.
+    NotPeriodic(NotPeriodic),
+}
+
+impl fmt::Display for CubicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CubicError::TooFewElements(inner) => inner.fmt(f),
+            CubicError::KnotElementInequality(inner) => inner.fmt(f),
+            CubicError::NotSorted(inner) => inner.fmt(f),
+            CubicError::NotPeriodic(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl From<TooFewElements> for CubicError {
+    fn from(from: TooFewElements) -> Self {
+        CubicError::TooFewElements(from)
+    }
+}
+
+impl From<KnotElementInequality> for CubicError {
+    fn from(from: KnotElementInequality) -> Self {
+        CubicError::KnotElementInequality(from)
+    }
+}
+
+impl From<NotSorted> for CubicError {
+    fn from(from: NotSorted) -> Self {
+        CubicError::NotSorted(from)
+    }
+}
+
+impl From<NotPeriodic> for CubicError {
+    fn from(from: NotPeriodic) -> Self {
+        CubicError::NotPeriodic(from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for CubicError {}
+
+/// Error returned if the first and last value given to a periodic interpolation are not equal.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NotPeriodic {}
+
+impl NotPeriodic {
+    /// Create a new error.
+    pub const fn new() -> Self {
+        NotPeriodic {}
+    }
+}
+
+impl Default for NotPeriodic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for NotPeriodic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The first and the last value have to be equal for a periodic interpolation."
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NotPeriodic {}
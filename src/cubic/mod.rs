@@ -0,0 +1,593 @@
+//! Interpolating piecewise cubic curves.
+//!
+//! In contrast to bezier curves and bsplines, the curves created here pass through all of their elements.
+//! Each segment between two knots is a cubic polynomial, which is defined by the elements at both knots
+//! and the derivatives at both knots. The constructors in this module differ in how these derivatives
+//! are chosen.
+//!
+//! As every piecewise cubic curve with continuous derivative is a bspline of degree 3,
+//! the curves returned are [`BSpline`]s.
+//! [`MonotoneCubic`] wraps such a bspline to provide a builder for the Fritsch–Carlson method.
+//! [`StreamingCubicSpline`] is a natural cubic spline to which values can be appended one after another.
+//!
+//! [`BSpline`]: crate::bspline::BSpline
+
+#[cfg(feature = "alloc")]
+use crate::bspline::BSpline;
+#[cfg(feature = "alloc")]
+use crate::{DiscreteGenerator, DynSpace, Sorted};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use num_traits::real::Real;
+#[cfg(feature = "alloc")]
+use num_traits::FromPrimitive;
+
+pub mod error;
+pub use error::{CubicError, KnotElementInequality, NotPeriodic, NotSorted, TooFewElements};
+
+mod monotone;
+pub use monotone::{MonotoneCubic, MonotoneCubicBuilder};
+
+#[cfg(feature = "alloc")]
+mod streaming;
+#[cfg(feature = "alloc")]
+pub use streaming::StreamingCubicSpline;
+
+/// Piecewise Cubic Hermite Interpolating Polynomial (PCHIP).
+///
+/// Creates a shape-preserving cubic interpolation through the given values at the given knots.
+/// Monotone data results in a monotone curve and local extrema of the data are preserved,
+/// such that the curve does not overshoot.
+/// The derivatives are chosen by the Fritsch–Carlson method with the same rules as
+/// SciPy's `PchipInterpolator`, which also includes the handling of the derivatives at both ends.
+/// With only two values given, the curve is the line through both of them.
+///
+/// The domain of the curve returned is given by the first and last knot.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{cubic::{pchip, CubicError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), CubicError> {
+/// let curve = pchip([0.0, 1.0, 2.0, 3.0], [0.0, 1.0, 1.0, 2.0])?;
+/// assert_eq!(curve.domain(), [0.0, 3.0]);
+/// assert_f64_near!(curve.gen(1.0), 1.0);
+/// // flat data stays flat
+/// assert_f64_near!(curve.gen(1.5), 1.0);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// [`TooFewElements`] if there are less than two values.
+/// [`KnotElementInequality`] if the number of knots and values differ.
+/// [`NotSorted`] if the knots are not strictly increasing.
+///
+/// [`TooFewElements`]: CubicError
+/// [`KnotElementInequality`]: CubicError
+/// [`NotSorted`]: CubicError
+#[cfg(feature = "alloc")]
+#[allow(clippy::type_complexity)]
+pub fn pchip<K, E, R>(
+    knots: K,
+    values: E,
+) -> Result<BSpline<Sorted<Vec<R>>, Vec<R>, DynSpace<R>>, CubicError>
+where
+    K: DiscreteGenerator<Output = R>,
+    E: DiscreteGenerator<Output = R>,
+    R: Real + FromPrimitive + Default,
+{
+    let (widths, slopes) = segments(&knots, &values)?;
+    let derivatives = pchip_derivatives(&widths, &slopes);
+    Ok(hermite_bspline(knots, values, &widths, &derivatives))
+}
+
+/// Periodic cubic spline interpolation.
+///
+/// Creates the cubic spline through the given values at the given knots, which is smooth
+/// across the seam of its period. The first knot and the last knot mark the start and end of one period,
+/// such that the first and last value have to be equal. The curve returned has the same value,
+/// first derivative and second derivative at the start and the end of its domain.
+/// This makes it a good fit for cyclic data, like values over a year.
+///
+/// The curve returned only describes a single period. To evaluate it at arbitrary inputs,
+/// wrap the input into its domain first, for example with `rem_euclid`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{cubic::{periodic, CubicError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), CubicError> {
+/// // average temperature at the start of each season and again at the start of the next year
+/// let curve = periodic([0.0, 3.0, 6.0, 9.0, 12.0], [2.0, 14.0, 21.0, 10.0, 2.0])?;
+/// assert_f64_near!(curve.gen(3.0), 14.0);
+/// // the middle of the second month of the next year
+/// assert_f64_near!(curve.gen(13.5_f64.rem_euclid(12.0)), curve.gen(1.5));
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// [`TooFewElements`] if there are less than two values.
+/// [`KnotElementInequality`] if the number of knots and values differ.
+/// [`NotSorted`] if the knots are not strictly increasing.
+/// [`NotPeriodic`] if the first and last value are not equal.
+///
+/// [`TooFewElements`]: CubicError
+/// [`KnotElementInequality`]: CubicError
+/// [`NotSorted`]: CubicError
+/// [`NotPeriodic`]: CubicError
+#[cfg(feature = "alloc")]
+#[allow(clippy::type_complexity)]
+pub fn periodic<K, E, R>(
+    knots: K,
+    values: E,
+) -> Result<BSpline<Sorted<Vec<R>>, Vec<R>, DynSpace<R>>, CubicError>
+where
+    K: DiscreteGenerator<Output = R>,
+    E: DiscreteGenerator<Output = R>,
+    R: Real + FromPrimitive + Default,
+{
+    let (widths, slopes) = segments(&knots, &values)?;
+    if values.first() != values.last() {
+        return Err(NotPeriodic::new().into());
+    }
+    let derivatives = periodic_derivatives(&widths, &slopes);
+    Ok(hermite_bspline(knots, values, &widths, &derivatives))
+}
+
+/// Natural cubic spline interpolation.
+///
+/// Creates the cubic spline through the given values at the given knots, which has a continuous
+/// second derivative everywhere and a second derivative of zero at the start and end of its domain.
+/// With only two values given, the curve is the line through both of them.
+///
+/// To add values one after another without solving the whole system every time,
+/// see [`StreamingCubicSpline`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{cubic::{natural, CubicError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), CubicError> {
+/// let curve = natural([0.0, 1.0, 2.0], [0.0, 1.0, 0.0])?;
+/// assert_eq!(curve.domain(), [0.0, 2.0]);
+/// assert_f64_near!(curve.gen(1.0), 1.0);
+/// assert_f64_near!(curve.gen(0.5), 0.6875);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// [`TooFewElements`] if there are less than two values.
+/// [`KnotElementInequality`] if the number of knots and values differ.
+/// [`NotSorted`] if the knots are not strictly increasing.
+///
+/// [`TooFewElements`]: CubicError
+/// [`KnotElementInequality`]: CubicError
+/// [`NotSorted`]: CubicError
+#[cfg(feature = "alloc")]
+#[allow(clippy::type_complexity)]
+pub fn natural<K, E, R>(
+    knots: K,
+    values: E,
+) -> Result<BSpline<Sorted<Vec<R>>, Vec<R>, DynSpace<R>>, CubicError>
+where
+    K: DiscreteGenerator<Output = R>,
+    E: DiscreteGenerator<Output = R>,
+    R: Real + FromPrimitive + Default,
+{
+    let (widths, slopes) = segments(&knots, &values)?;
+    let derivatives = natural_derivatives(&widths, &slopes);
+    Ok(hermite_bspline(knots, values, &widths, &derivatives))
+}
+
+/// Check the given knots and values and calculate the width and slope of each segment.
+#[cfg(feature = "alloc")]
+#[allow(clippy::type_complexity)]
+fn segments<K, E, R>(knots: &K, values: &E) -> Result<(Vec<R>, Vec<R>), CubicError>
+where
+    K: DiscreteGenerator<Output = R>,
+    E: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+    let len = values.len();
+    if len < 2 {
+        return Err(TooFewElements::new(len).into());
+    }
+    if knots.len() != len {
+        return Err(KnotElementInequality::new(len, knots.len()).into());
+    }
+    for i in 1..len {
+        // equal knots would result in a division by zero
+        if knots.gen(i - 1).partial_cmp(&knots.gen(i)) != Some(core::cmp::Ordering::Less) {
+            return Err(NotSorted::new(i - 1).into());
+        }
+    }
+    let widths: Vec<R> = (1..len).map(|i| knots.gen(i) - knots.gen(i - 1)).collect();
+    let slopes: Vec<R> = (1..len)
+        .map(|i| (values.gen(i) - values.gen(i - 1)) / widths[i - 1])
+        .collect();
+    Ok((widths, slopes))
+}
+
+/// Returns -1, 0 or 1 depending on the sign of the given number, where zero has no sign.
+#[cfg(feature = "alloc")]
+fn sign<R: Real>(value: R) -> R {
+    if value.is_zero() {
+        R::zero()
+    } else {
+        value.signum()
+    }
+}
+
+/// Calculate the derivatives at all knots, given the widths and slopes of all segments.
+#[cfg(feature = "alloc")]
+fn pchip_derivatives<R>(widths: &[R], slopes: &[R]) -> Vec<R>
+where
+    R: Real + FromPrimitive,
+{
+    if slopes.len() == 1 {
+        return vec![slopes[0]; 2];
+    }
+    let two = R::from_usize(2).unwrap();
+    let mut derivatives = Vec::with_capacity(slopes.len() + 1);
+    derivatives.push(pchip_edge(widths[0], widths[1], slopes[0], slopes[1]));
+    for i in 1..slopes.len() {
+        let (prev, next) = (slopes[i - 1], slopes[i]);
+        if sign(prev) != sign(next) || prev.is_zero() || next.is_zero() {
+            // local extremum or flat segment
+            derivatives.push(R::zero());
+        } else {
+            // weighted harmonic mean of the slopes
+            let w1 = two * widths[i] + widths[i - 1];
+            let w2 = widths[i] + two * widths[i - 1];
+            derivatives.push((w1 + w2) / (w1 / prev + w2 / next));
+        }
+    }
+    let last = slopes.len() - 1;
+    derivatives.push(pchip_edge(
+        widths[last],
+        widths[last - 1],
+        slopes[last],
+        slopes[last - 1],
+    ));
+    derivatives
+}
+
+/// Calculate the derivative at a border knot with a shape-preserving three-point formula.
+///
+/// `width` and `slope` belong to the segment at the border, the others to its neighbor.
+#[cfg(feature = "alloc")]
+fn pchip_edge<R>(width: R, neighbor_width: R, slope: R, neighbor_slope: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    let two = R::from_usize(2).unwrap();
+    let three = R::from_usize(3).unwrap();
+    let derivative = ((two * width + neighbor_width) * slope - width * neighbor_slope)
+        / (width + neighbor_width);
+    if sign(derivative) != sign(slope) {
+        R::zero()
+    } else if sign(slope) != sign(neighbor_slope) && derivative.abs() > three * slope.abs() {
+        three * slope
+    } else {
+        derivative
+    }
+}
+
+/// Calculate the derivatives at all knots such that the second derivative is continuous everywhere,
+/// including the seam between the end and the start of the period.
+#[cfg(feature = "alloc")]
+fn periodic_derivatives<R>(widths: &[R], slopes: &[R]) -> Vec<R>
+where
+    R: Real + FromPrimitive,
+{
+    let segments = slopes.len();
+    // The solver needs at least three unknowns, so short periods get repeated.
+    // The spline over repeated periods is the repetition of the spline over a single one.
+    let cycles = match segments {
+        1 => 3,
+        2 => 2,
+        _ => 1,
+    };
+    let len = segments * cycles;
+    let two = R::from_usize(2).unwrap();
+    let three = R::from_usize(3).unwrap();
+    let mut lower = Vec::with_capacity(len);
+    let mut diagonal = Vec::with_capacity(len);
+    let mut upper = Vec::with_capacity(len);
+    let mut right = Vec::with_capacity(len);
+    for i in 0..len {
+        let prev = (i + len - 1) % segments;
+        let next = i % segments;
+        lower.push(widths[next]);
+        diagonal.push(two * (widths[prev] + widths[next]));
+        upper.push(widths[prev]);
+        right.push(three * (widths[next] * slopes[prev] + widths[prev] * slopes[next]));
+    }
+    let mut derivatives = solve_cyclic(&lower, &diagonal, &upper, &right);
+    derivatives.truncate(segments);
+    derivatives.push(derivatives[0]);
+    derivatives
+}
+
+/// Calculate the derivatives at all knots such that the second derivative is continuous everywhere
+/// and zero at the first and last knot.
+#[cfg(feature = "alloc")]
+fn natural_derivatives<R>(widths: &[R], slopes: &[R]) -> Vec<R>
+where
+    R: Real + FromPrimitive,
+{
+    let len = slopes.len() + 1;
+    let mut lower = Vec::with_capacity(len);
+    let mut diagonal = Vec::with_capacity(len);
+    let mut upper = Vec::with_capacity(len);
+    let mut right = Vec::with_capacity(len);
+    for i in 0..len {
+        let [low, diag, up, rhs] = natural_row(widths, slopes, i);
+        lower.push(low);
+        diagonal.push(diag);
+        upper.push(up);
+        right.push(rhs);
+    }
+    solve_tridiagonal(&lower, &diagonal, &upper, &right)
+}
+
+/// Returns the row of the system of a natural cubic spline for the derivative at the knot with the given index.
+///
+/// The row consists of the coefficients of the derivatives at the previous, the current and the next knot
+/// and the right hand side, in this order. Only the segments next to the knot are used.
+#[cfg(feature = "alloc")]
+fn natural_row<R>(widths: &[R], slopes: &[R], index: usize) -> [R; 4]
+where
+    R: Real + FromPrimitive,
+{
+    let two = R::from_usize(2).unwrap();
+    let three = R::from_usize(3).unwrap();
+    if index == 0 {
+        return [R::zero(), two, R::one(), three * slopes[0]];
+    }
+    if index == slopes.len() {
+        return [R::one(), two, R::zero(), three * slopes[index - 1]];
+    }
+    let (prev, next) = (widths[index - 1], widths[index]);
+    [
+        next,
+        two * (prev + next),
+        prev,
+        three * (next * slopes[index - 1] + prev * slopes[index]),
+    ]
+}
+
+/// Solve a cyclic tridiagonal system of linear equations with the Sherman–Morrison formula.
+///
+/// Row `i` of the system is `lower[i] * x[i-1] + diagonal[i] * x[i] + upper[i] * x[i+1] = right[i]`,
+/// where the indices wrap around. The system has to have at least three rows.
+#[cfg(feature = "alloc")]
+fn solve_cyclic<R: Real>(lower: &[R], diagonal: &[R], upper: &[R], right: &[R]) -> Vec<R> {
+    let len = diagonal.len();
+    let last = len - 1;
+    // the corners of the matrix
+    let top_right = lower[0];
+    let bottom_left = upper[last];
+    let gamma = -diagonal[0];
+    let mut modified = diagonal.to_vec();
+    modified[0] = diagonal[0] - gamma;
+    modified[last] = diagonal[last] - bottom_left * top_right / gamma;
+    let mut solution = solve_tridiagonal(lower, &modified, upper, right);
+    let mut correction = vec![R::zero(); len];
+    correction[0] = gamma;
+    correction[last] = bottom_left;
+    let correction = solve_tridiagonal(lower, &modified, upper, &correction);
+    let factor = (solution[0] + top_right * solution[last] / gamma)
+        / (R::one() + correction[0] + top_right * correction[last] / gamma);
+    for (value, correction) in solution.iter_mut().zip(correction) {
+        *value = *value - factor * correction;
+    }
+    solution
+}
+
+/// Solve a tridiagonal system of linear equations with the Thomas algorithm.
+///
+/// The first element of `lower` and the last element of `upper` are ignored.
+#[cfg(feature = "alloc")]
+fn solve_tridiagonal<R: Real>(lower: &[R], diagonal: &[R], upper: &[R], right: &[R]) -> Vec<R> {
+    let len = diagonal.len();
+    let mut factors = Vec::with_capacity(len);
+    let mut solution = Vec::with_capacity(len);
+    factors.push(upper[0] / diagonal[0]);
+    solution.push(right[0] / diagonal[0]);
+    for i in 1..len {
+        let denominator = diagonal[i] - lower[i] * factors[i - 1];
+        factors.push(upper[i] / denominator);
+        solution.push((right[i] - lower[i] * solution[i - 1]) / denominator);
+    }
+    for i in (0..len - 1).rev() {
+        solution[i] = solution[i] - factors[i] * solution[i + 1];
+    }
+    solution
+}
+
+/// Create the bspline of the cubic hermite curve with the given values and derivatives at the knots.
+///
+/// Each segment is represented as a bezier curve, such that all inner knots have a multiplicity of three.
+#[cfg(feature = "alloc")]
+fn hermite_bspline<K, E, R>(
+    knots: K,
+    values: E,
+    widths: &[R],
+    derivatives: &[R],
+) -> BSpline<Sorted<Vec<R>>, Vec<R>, DynSpace<R>>
+where
+    K: DiscreteGenerator<Output = R>,
+    E: DiscreteGenerator<Output = R>,
+    R: Real + FromPrimitive + Default,
+{
+    let three = R::from_usize(3).unwrap();
+    let len = values.len();
+    let mut elements = Vec::with_capacity(3 * len - 2);
+    elements.push(values.gen(0));
+    for i in 1..len {
+        let third = widths[i - 1] / three;
+        elements.push(values.gen(i - 1) + derivatives[i - 1] * third);
+        elements.push(values.gen(i) - derivatives[i] * third);
+        elements.push(values.gen(i));
+    }
+    let mut full_knots = Vec::with_capacity(3 * len);
+    for i in 0..len {
+        for _ in 0..3 {
+            full_knots.push(knots.gen(i));
+        }
+    }
+    BSpline::new_unchecked(
+        elements,
+        Sorted::new_unchecked(full_knots),
+        DynSpace::new(4),
+    )
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod test {
+    use super::*;
+    use crate::{Curve, Generator};
+
+    #[test]
+    fn pchip_scipy() {
+        // reference values calculated with scipy.interpolate.PchipInterpolator
+        let curve = pchip(
+            [0.0, 1.0, 2.0, 3.0, 4.5, 6.0],
+            [0.0, 1.0, 3.0, 2.0, 2.0, 5.0],
+        )
+        .unwrap();
+        assert_eq!(curve.domain(), [0.0, 6.0]);
+        let expected = [
+            (0.0, 0.0),
+            (0.25, 0.1640625),
+            (0.5, 0.39583333333333337),
+            (1.0, 1.0),
+            (1.5, 2.1666666666666665),
+            (2.5, 2.5),
+            (3.0, 2.0),
+            (3.7, 2.0),
+            (5.0, 2.444444444444444),
+            (5.9, 4.700444444444446),
+            (6.0, 5.0),
+        ];
+        for (input, output) in expected {
+            assert_float_absolute_eq!(curve.gen(input), output, 1e-12);
+        }
+        let curve = pchip([1.0, 2.0, 4.0, 5.0], [1.0, 4.0, 2.0, -3.0]).unwrap();
+        let expected = [
+            (1.5, 3.0416666666666665),
+            (3.0, 3.45),
+            (4.5, 0.06666666666666665),
+        ];
+        for (input, output) in expected {
+            assert_float_absolute_eq!(curve.gen(input), output, 1e-12);
+        }
+        // the derivative at the start gets limited to three times the slope
+        let curve = pchip([0.0, 1.0, 2.0], [0.0, 1.0, -10.0]).unwrap();
+        assert_f64_near!(curve.gen(0.5), 0.875);
+        assert_f64_near!(curve.gen(1.5), -2.375);
+    }
+
+    #[test]
+    fn pchip_linear() {
+        let curve = pchip([0.0, 2.0], [1.0, 5.0]).unwrap();
+        for (input, output) in [(0.0, 1.0), (0.5, 2.0), (1.0, 3.0), (2.0, 5.0)] {
+            assert_f64_near!(curve.gen(input), output);
+        }
+    }
+
+    #[test]
+    fn natural_curvature() {
+        let curve = natural([0.0, 1.0, 2.5, 3.0, 4.5], [1.0, 3.0, -1.0, 0.5, 2.0]).unwrap();
+        // the second derivative vanishes at both ends
+        let step = 1e-6;
+        let start = (curve.gen_with_tangent(step)[1] - curve.gen_with_tangent(0.0)[1]) / step;
+        let end = (curve.gen_with_tangent(4.5)[1] - curve.gen_with_tangent(4.5 - step)[1]) / step;
+        assert_float_absolute_eq!(start, 0.0, 1e-4);
+        assert_float_absolute_eq!(end, 0.0, 1e-4);
+        let curve = natural([0.0, 2.0], [1.0, 5.0]).unwrap();
+        assert_f64_near!(curve.gen(0.5), 2.0);
+    }
+
+    #[test]
+    fn periodic_seam() {
+        let knots = [0.0, 1.0, 2.5, 3.0, 4.5, 6.0];
+        let values = [1.0, 3.0, -1.0, 0.5, 2.0, 1.0];
+        let curve = periodic(knots, values).unwrap();
+        for (&knot, value) in knots.as_slice().iter().zip(values) {
+            assert_float_absolute_eq!(curve.gen(knot), value, 1e-12);
+        }
+        let [start_value, start_tangent] = curve.gen_with_tangent(0.0);
+        let [end_value, end_tangent] = curve.gen_with_tangent(6.0);
+        assert_float_absolute_eq!(start_value, end_value, 1e-12);
+        assert_float_absolute_eq!(start_tangent, end_tangent, 1e-9);
+        // second derivative by one-sided differences of the tangents
+        let step = 1e-6;
+        let start_curvature = (curve.gen_with_tangent(step)[1] - start_tangent) / step;
+        let end_curvature = (end_tangent - curve.gen_with_tangent(6.0 - step)[1]) / step;
+        assert_float_absolute_eq!(start_curvature, end_curvature, 1e-4);
+        // the second derivative is also continuous at inner knots
+        let left = (curve.gen_with_tangent(2.5)[1] - curve.gen_with_tangent(2.5 - step)[1]) / step;
+        let right = (curve.gen_with_tangent(2.5 + step)[1] - curve.gen_with_tangent(2.5)[1]) / step;
+        assert_float_absolute_eq!(left, right, 1e-4);
+    }
+
+    #[test]
+    fn periodic_sine() {
+        use core::f64::consts::TAU;
+        let knots: Vec<f64> = (0..=16).map(|i| i as f64 / 16.0 * TAU).collect();
+        let mut values: Vec<f64> = knots.iter().map(|x| x.sin()).collect();
+        // sin(TAU) is not exactly zero
+        values[16] = values[0];
+        let curve = periodic(knots, values).unwrap();
+        for i in 0..=100 {
+            let x = i as f64 / 100.0 * TAU;
+            assert_float_absolute_eq!(curve.gen(x), x.sin(), 1e-3);
+        }
+        // short periods
+        let curve = periodic([0.0, 1.0], [2.0, 2.0]).unwrap();
+        assert_f64_near!(curve.gen(0.5), 2.0);
+        let curve = periodic([0.0, 1.0, 2.0], [0.0, 1.0, 0.0]).unwrap();
+        assert_f64_near!(curve.gen(1.0), 1.0);
+        assert_float_absolute_eq!(curve.gen_with_tangent(0.0)[1], 0.0, 1e-12);
+        assert_float_absolute_eq!(curve.gen_with_tangent(1.0)[1], 0.0, 1e-12);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            pchip([0.0], [1.0]),
+            Err(CubicError::TooFewElements(_))
+        ));
+        assert!(matches!(
+            pchip([0.0, 1.0, 2.0], [1.0, 2.0]),
+            Err(CubicError::KnotElementInequality(_))
+        ));
+        assert!(matches!(
+            pchip([0.0, 1.0, 1.0], [1.0, 2.0, 3.0]),
+            Err(CubicError::NotSorted(_))
+        ));
+        assert!(matches!(
+            periodic([0.0, 1.0, 2.0], [1.0, 2.0, 3.0]),
+            Err(CubicError::NotPeriodic(_))
+        ));
+    }
+}
@@ -0,0 +1,342 @@
+//! Monotone cubic interpolation with the Fritsch–Carlson method.
+
+#[cfg(feature = "alloc")]
+use super::{hermite_bspline, segments, sign, CubicError};
+use crate::bspline::BSpline;
+use crate::builder::Unknown;
+use crate::{Curve, Derivative, Generator};
+#[cfg(feature = "alloc")]
+use crate::{DiscreteGenerator, DynSpace, Sorted};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use num_traits::real::Real;
+#[cfg(feature = "alloc")]
+use num_traits::FromPrimitive;
+
+/// Monotone cubic interpolation.
+///
+/// Creates a piecewise cubic curve through the given values at the given knots with the
+/// Fritsch–Carlson method. Monotone data results in a monotone curve, such that for example
+/// cumulative distributions can be interpolated without overshooting.
+///
+/// The derivative at each knot starts as the average of the slopes of its neighbouring segments,
+/// or the slope of the only segment at the borders. At local extrema and on flat segments,
+/// that is consecutive equal values, the derivative is set to zero. Afterwards, derivatives which
+/// are too large to preserve monotonicity are scaled down.
+/// In contrast to [`pchip()`], which uses a weighted harmonic mean, the curve is less flat near
+/// steep changes of the data.
+///
+/// The curve is a [`BSpline`] of degree 3 internally, which can be retrieved with [`into_inner()`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{cubic::{MonotoneCubic, CubicError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), CubicError> {
+/// let cdf = MonotoneCubic::builder()
+///                 .elements([0.0, 0.1, 0.9, 1.0, 1.0])
+///                 .knots([0.0, 1.0, 2.0, 3.0, 4.0])
+///                 .build()?;
+/// assert_f64_near!(cdf.gen(2.0), 0.9);
+/// // the curve never overshoots the data
+/// assert!((&cdf).take(101).all(|value| (0.0..=1.0).contains(&value)));
+/// // flat data stays flat
+/// assert_f64_near!(cdf.gen(3.5), 1.0);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`pchip()`]: super::pchip()
+/// [`BSpline`]: crate::bspline::BSpline
+/// [`into_inner()`]: MonotoneCubic::into_inner()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MonotoneCubic<K, E, S>(BSpline<K, E, S>);
+
+/// Builder for monotone cubic interpolation.
+///
+/// Usually one creates an instance by using the [`builder()`] method on the interpolation itself.
+/// Before building, one has to give the elements with [`elements()`] and the knots with [`knots()`].
+/// All checks are done when building.
+///
+/// [`builder()`]: MonotoneCubic::builder()
+/// [`elements()`]: MonotoneCubicBuilder::elements()
+/// [`knots()`]: MonotoneCubicBuilder::knots()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MonotoneCubicBuilder<K, E> {
+    knots: K,
+    elements: E,
+}
+
+impl MonotoneCubic<Unknown, Unknown, Unknown> {
+    /// Get the builder for a monotone cubic interpolation.
+    ///
+    /// The builder takes:
+    /// - elements with [`elements()`]
+    /// - knots with [`knots()`]
+    ///
+    /// [`elements()`]: MonotoneCubicBuilder::elements()
+    /// [`knots()`]: MonotoneCubicBuilder::knots()
+    pub fn builder() -> MonotoneCubicBuilder<Unknown, Unknown> {
+        MonotoneCubicBuilder::new()
+    }
+}
+
+impl<K, E, S> MonotoneCubic<K, E, S> {
+    /// Returns a reference to the bspline representing the curve.
+    pub fn inner(&self) -> &BSpline<K, E, S> {
+        &self.0
+    }
+
+    /// Returns the bspline representing the curve.
+    pub fn into_inner(self) -> BSpline<K, E, S> {
+        self.0
+    }
+}
+
+impl<K, E, S, R> Generator<R> for MonotoneCubic<K, E, S>
+where
+    BSpline<K, E, S>: Generator<R>,
+{
+    type Output = <BSpline<K, E, S> as Generator<R>>::Output;
+    fn gen(&self, scalar: R) -> Self::Output {
+        self.0.gen(scalar)
+    }
+}
+
+impl<K, E, S, R> Curve<R> for MonotoneCubic<K, E, S>
+where
+    BSpline<K, E, S>: Curve<R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.0.domain()
+    }
+    fn gen_batch(&self, scalars: &[R], out: &mut [Self::Output]) {
+        self.0.gen_batch(scalars, out)
+    }
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        self.0.knot_midpoints()
+    }
+}
+
+impl<K, E, S, R> Derivative<R> for MonotoneCubic<K, E, S>
+where
+    BSpline<K, E, S>: Derivative<R>,
+    R: Real,
+{
+    fn derivative(&self, scalar: R) -> Self::Output {
+        self.0.derivative(scalar)
+    }
+}
+
+impl Default for MonotoneCubicBuilder<Unknown, Unknown> {
+    fn default() -> Self {
+        MonotoneCubicBuilder::new()
+    }
+}
+
+impl MonotoneCubicBuilder<Unknown, Unknown> {
+    /// Create a new monotone cubic interpolation builder.
+    pub const fn new() -> Self {
+        MonotoneCubicBuilder {
+            knots: Unknown,
+            elements: Unknown,
+        }
+    }
+
+    /// Set the elements of the interpolation.
+    pub fn elements<E>(self, elements: E) -> MonotoneCubicBuilder<Unknown, E> {
+        MonotoneCubicBuilder {
+            knots: self.knots,
+            elements,
+        }
+    }
+}
+
+impl<E> MonotoneCubicBuilder<Unknown, E> {
+    /// Set the knots of the interpolation.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    pub fn knots<K>(self, knots: K) -> MonotoneCubicBuilder<K, E> {
+        MonotoneCubicBuilder {
+            knots,
+            elements: self.elements,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, E, R> MonotoneCubicBuilder<K, E>
+where
+    K: DiscreteGenerator<Output = R>,
+    E: DiscreteGenerator<Output = R>,
+    R: Real + FromPrimitive + Default,
+{
+    /// Build a monotone cubic interpolation.
+    ///
+    /// # Errors
+    ///
+    /// [`TooFewElements`] if there are less than two elements.
+    /// [`KnotElementInequality`] if the number of knots and elements differ.
+    /// [`NotSorted`] if the knots are not strictly increasing.
+    ///
+    /// [`TooFewElements`]: CubicError
+    /// [`KnotElementInequality`]: CubicError
+    /// [`NotSorted`]: CubicError
+    #[allow(clippy::type_complexity)]
+    pub fn build(self) -> Result<MonotoneCubic<Sorted<Vec<R>>, Vec<R>, DynSpace<R>>, CubicError> {
+        let (widths, slopes) = segments(&self.knots, &self.elements)?;
+        let derivatives = fritsch_carlson_derivatives(&slopes);
+        Ok(MonotoneCubic(hermite_bspline(
+            self.knots,
+            self.elements,
+            &widths,
+            &derivatives,
+        )))
+    }
+}
+
+/// Calculate the derivatives at all knots with the Fritsch–Carlson method, given the slopes of all segments.
+#[cfg(feature = "alloc")]
+fn fritsch_carlson_derivatives<R>(slopes: &[R]) -> Vec<R>
+where
+    R: Real + FromPrimitive,
+{
+    let two = R::from_usize(2).unwrap();
+    let three = R::from_usize(3).unwrap();
+    let nine = R::from_usize(9).unwrap();
+    let mut derivatives = Vec::with_capacity(slopes.len() + 1);
+    derivatives.push(slopes[0]);
+    for i in 1..slopes.len() {
+        let (prev, next) = (slopes[i - 1], slopes[i]);
+        if sign(prev) != sign(next) || prev.is_zero() || next.is_zero() {
+            // local extremum or flat segment
+            derivatives.push(R::zero());
+        } else {
+            derivatives.push((prev + next) / two);
+        }
+    }
+    derivatives.push(slopes[slopes.len() - 1]);
+    for (i, slope) in slopes.iter().copied().enumerate() {
+        if slope.is_zero() {
+            // equal values have to be connected by a flat segment
+            derivatives[i] = R::zero();
+            derivatives[i + 1] = R::zero();
+            continue;
+        }
+        // both ratios are not negative, as the derivatives have the sign of the slope or are zero
+        let alpha = derivatives[i] / slope;
+        let beta = derivatives[i + 1] / slope;
+        let radius = alpha * alpha + beta * beta;
+        if radius > nine {
+            // restrict the derivatives to the circle of radius three, which guarantees monotonicity
+            let tau = three / radius.sqrt();
+            derivatives[i] = tau * alpha * slope;
+            derivatives[i + 1] = tau * beta * slope;
+        }
+    }
+    derivatives
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod test {
+    use super::*;
+    use crate::cubic::pchip;
+
+    #[test]
+    fn monotone() {
+        let knots = [0.0, 1.0, 2.0, 2.5, 4.0, 6.0];
+        let values = [0.0, 0.2, 0.25, 3.0, 3.1, 10.0];
+        let curve = MonotoneCubic::builder()
+            .elements(values)
+            .knots(knots)
+            .build()
+            .unwrap();
+        assert_eq!(curve.domain(), [0.0, 6.0]);
+        for (knot, value) in knots.as_slice().iter().zip(values.as_slice()) {
+            assert_float_absolute_eq!(curve.gen(*knot), *value, 1e-12);
+        }
+        let mut last = curve.gen(0.0);
+        for value in (&curve).take(1001) {
+            assert!(value >= last);
+            last = value;
+        }
+    }
+
+    #[test]
+    fn flat() {
+        let curve = MonotoneCubic::builder()
+            .elements([0.0, 1.0, 1.0, 1.0, 2.0])
+            .knots([0.0, 1.0, 2.0, 3.0, 4.0])
+            .build()
+            .unwrap();
+        for input in [1.0, 1.25, 1.5, 2.0, 2.75, 3.0] {
+            assert_f64_near!(curve.gen(input), 1.0);
+            assert_float_absolute_eq!(curve.derivative(input), 0.0, 1e-12);
+        }
+        // the segments next to the flat part do not overshoot
+        for value in (&curve).slice(0.0..1.0).take(101) {
+            assert!(value <= 1.0);
+        }
+        for value in (&curve).slice(3.0..4.0).take(101) {
+            assert!(value >= 1.0);
+        }
+    }
+
+    #[test]
+    fn derivatives() {
+        // average of the slopes
+        let curve = MonotoneCubic::builder()
+            .elements([0.0, 1.0, 3.0])
+            .knots([0.0, 1.0, 2.0])
+            .build()
+            .unwrap();
+        assert_float_absolute_eq!(curve.derivative(1.0), 1.5, 1e-12);
+        assert_float_absolute_eq!(curve.gen(0.5), 0.4375, 1e-12);
+        // limited derivatives
+        let curve = MonotoneCubic::builder()
+            .elements([0.0, 1.0, 10.0, 11.0])
+            .knots([0.0, 1.0, 2.0, 3.0])
+            .build()
+            .unwrap();
+        let limited = 15.0 / 26.0_f64.sqrt();
+        assert_float_absolute_eq!(curve.derivative(0.0), 3.0 / 26.0_f64.sqrt(), 1e-12);
+        assert_float_absolute_eq!(curve.derivative(1.0), limited, 1e-12);
+        assert_float_absolute_eq!(curve.derivative(2.0), limited, 1e-12);
+        // pchip chooses different derivatives for the same data
+        let reference = pchip([0.0, 1.0, 2.0, 3.0], [0.0, 1.0, 10.0, 11.0]).unwrap();
+        assert!((reference.derivative(1.0) - limited).abs() > 0.1);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            MonotoneCubic::builder()
+                .elements([1.0])
+                .knots([0.0])
+                .build(),
+            Err(CubicError::TooFewElements(_))
+        ));
+        assert!(matches!(
+            MonotoneCubic::builder()
+                .elements([1.0, 2.0])
+                .knots([0.0, 1.0, 2.0])
+                .build(),
+            Err(CubicError::KnotElementInequality(_))
+        ));
+        assert!(matches!(
+            MonotoneCubic::builder()
+                .elements([1.0, 2.0, 3.0])
+                .knots([0.0, 1.0, 1.0])
+                .build(),
+            Err(CubicError::NotSorted(_))
+        ));
+    }
+}
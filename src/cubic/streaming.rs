@@ -0,0 +1,282 @@
+//! Natural cubic spline which is built incrementally.
+
+use super::{natural_row, CubicError, NotSorted};
+use crate::utils::{hermite, hermite_segment};
+use crate::{Curve, Derivative, Generator, Sorted};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Natural cubic spline to which values can be appended one after another.
+///
+/// This is useful for live data, where the spline should be updated with every new value
+/// without solving the whole system of the spline again, as [`natural()`] would do.
+///
+/// Solving the system of a natural cubic spline consists of a forward sweep from the first
+/// to the last knot and a back substitution from the last to the first knot.
+/// The forward sweep is continued exactly with every value pushed. However, only the derivatives
+/// at the last `window` knots are recalculated by the back substitution, such that [`push()`]
+/// takes constant time. The derivatives at older knots are frozen with the values they had
+/// when they left the window.
+///
+/// # Accuracy
+///
+/// The influence of a new value on the derivative at a knot decreases by at least a factor of 2
+/// with each knot in between, for equidistant knots it decreases by a factor of about 3.7.
+/// Such, a window of 10 to 20 knots is usually indistinguishable from the natural cubic spline
+/// over all values, while the segments near the end of the spline are always exact.
+/// If the window is at least as big as the number of values, the curve is exactly the one
+/// returned by [`natural()`], however each [`push()`] then takes linear time.
+///
+/// # Panics
+///
+/// Generating values panics if less than two values were pushed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{cubic::{natural, StreamingCubicSpline, CubicError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_float_absolute_eq};
+/// #
+/// # fn main() -> Result<(), CubicError> {
+/// let knots = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+/// let values = [0.0, 1.0, 0.0, -1.0, 0.0, 1.0, 0.0];
+/// let mut stream = StreamingCubicSpline::new(4);
+/// for (&knot, &value) in knots.iter().zip(values.iter()) {
+///     let curve = stream.push(knot, value)?;
+///     assert_eq!(curve.domain(), [0.0, knot]);
+/// }
+/// let batch = natural(knots, values)?;
+/// for scalar in [0.5, 2.5, 4.5, 5.5] {
+///     assert_float_absolute_eq!(stream.gen(scalar), batch.gen(scalar), 1e-3);
+/// }
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`natural()`]: super::natural()
+/// [`push()`]: StreamingCubicSpline::push()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StreamingCubicSpline<R> {
+    knots: Vec<R>,
+    values: Vec<R>,
+    derivatives: Vec<R>,
+    /// The factors and right hand sides of the forward sweep, except for the row of the last knot.
+    factors: Vec<R>,
+    sweep: Vec<R>,
+    window: usize,
+}
+
+impl<R> StreamingCubicSpline<R>
+where
+    R: Real + FromPrimitive,
+{
+    /// Create an empty spline, which recalculates the derivatives at the last `window` knots with every push.
+    ///
+    /// The derivative at the knot before the pushed one is always recalculated,
+    /// such that a window of 0 behaves like a window of 1.
+    /// A window of `usize::MAX` results in an exact natural cubic spline.
+    pub fn new(window: usize) -> Self {
+        StreamingCubicSpline {
+            knots: Vec::new(),
+            values: Vec::new(),
+            derivatives: Vec::new(),
+            factors: Vec::new(),
+            sweep: Vec::new(),
+            window,
+        }
+    }
+
+    /// Append a value at the given knot and return the updated spline.
+    ///
+    /// # Errors
+    ///
+    /// [`NotSorted`] if the knot is not bigger than the last knot pushed.
+    ///
+    /// [`NotSorted`]: super::CubicError
+    pub fn push(&mut self, knot: R, value: R) -> Result<&Self, CubicError> {
+        let len = self.knots.len();
+        if let Some(&last) = self.knots.last() {
+            // equal knots would result in a division by zero
+            if last.partial_cmp(&knot) != Some(Ordering::Less) {
+                return Err(NotSorted::new(len - 1).into());
+            }
+        }
+        self.knots.push(knot);
+        self.values.push(value);
+        if len == 0 {
+            self.derivatives.push(R::zero());
+            return Ok(self);
+        }
+        // index of the new segment and the knot at its start
+        let segment = len - 1;
+        let width = knot - self.knots[segment];
+        let slope = (value - self.values[segment]) / width;
+        // the row of the knot at the start of the new segment does not change anymore
+        let [lower, diagonal, upper, right] = if segment == 0 {
+            natural_row(&[width], &[slope], 0)
+        } else {
+            let prev_width = self.knots[segment] - self.knots[segment - 1];
+            let prev_slope = (self.values[segment] - self.values[segment - 1]) / prev_width;
+            natural_row(&[prev_width, width], &[prev_slope, slope], 1)
+        };
+        let (prev_factor, prev_sweep) = match (self.factors.last(), self.sweep.last()) {
+            (Some(&factor), Some(&sweep)) => (factor, sweep),
+            _ => (R::zero(), R::zero()),
+        };
+        let denominator = diagonal - lower * prev_factor;
+        self.factors.push(upper / denominator);
+        self.sweep.push((right - lower * prev_sweep) / denominator);
+        // the row of the new knot with the natural end condition
+        let [lower, diagonal, _, right] = natural_row(&[width], &[slope], 1);
+        let mut derivative =
+            (right - lower * self.sweep[segment]) / (diagonal - lower * self.factors[segment]);
+        self.derivatives.push(derivative);
+        // back substitution within the window
+        let start = (segment + 1).saturating_sub(self.window.max(1));
+        for index in (start..=segment).rev() {
+            derivative = self.sweep[index] - self.factors[index] * derivative;
+            self.derivatives[index] = derivative;
+        }
+        Ok(self)
+    }
+
+    /// Returns the number of values pushed.
+    pub fn len(&self) -> usize {
+        self.knots.len()
+    }
+
+    /// Returns `true` if no values were pushed.
+    pub fn is_empty(&self) -> bool {
+        self.knots.is_empty()
+    }
+
+    /// Generate the value and its tangent, in this order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if less than two values were pushed or if `scalar` is NaN or similar.
+    pub fn gen_with_tangent(&self, scalar: R) -> [R; 2] {
+        // pushing values keeps the knots sorted
+        let knots = Sorted::new_unchecked(&self.knots);
+        let (lower, t, width) = hermite_segment(&knots, scalar, 1, self.knots.len() - 1);
+        let (start, end) = (self.values[lower], self.values[lower + 1]);
+        let (start_tangent, end_tangent) = (self.derivatives[lower], self.derivatives[lower + 1]);
+        hermite([start, end], [start_tangent, end_tangent], t, width)
+    }
+}
+
+impl<R> Generator<R> for StreamingCubicSpline<R>
+where
+    R: Real + FromPrimitive,
+{
+    type Output = R;
+    /// # Panics
+    ///
+    /// Panics if less than two values were pushed or if `scalar` is NaN or similar.
+    fn gen(&self, scalar: R) -> Self::Output {
+        let [value, _] = self.gen_with_tangent(scalar);
+        value
+    }
+}
+
+impl<R> Curve<R> for StreamingCubicSpline<R>
+where
+    R: Real + FromPrimitive,
+{
+    /// # Panics
+    ///
+    /// Panics if no value was pushed.
+    fn domain(&self) -> [R; 2] {
+        [self.knots[0], self.knots[self.knots.len() - 1]]
+    }
+}
+
+impl<R> Derivative<R> for StreamingCubicSpline<R>
+where
+    R: Real + FromPrimitive,
+{
+    fn derivative(&self, scalar: R) -> Self::Output {
+        let [_, tangent] = self.gen_with_tangent(scalar);
+        tangent
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::natural;
+    use super::*;
+
+    #[test]
+    fn matches_batch() {
+        let knots: Vec<f64> = (0..40)
+            .map(|i| i as f64 * 0.5 + (i % 3) as f64 * 0.1)
+            .collect();
+        let values: Vec<f64> = knots.iter().map(|x| x.sin() * 2.0 + x * 0.1).collect();
+        let batch = natural(knots.clone(), values.clone()).unwrap();
+        let mut exact = StreamingCubicSpline::new(usize::MAX);
+        let mut windowed = StreamingCubicSpline::new(12);
+        for (&knot, &value) in knots.iter().zip(values.iter()) {
+            exact.push(knot, value).unwrap();
+            windowed.push(knot, value).unwrap();
+        }
+        assert_eq!(exact.len(), 40);
+        assert_eq!(exact.domain(), batch.domain());
+        let [start, end] = batch.domain();
+        for i in 0..=200 {
+            let scalar = start + (end - start) * i as f64 / 200.0;
+            let expected = batch.gen(scalar);
+            assert_float_absolute_eq!(exact.gen(scalar), expected, 1e-10);
+            assert_float_absolute_eq!(windowed.gen(scalar), expected, 1e-6);
+            assert_float_absolute_eq!(
+                exact.derivative(scalar),
+                batch.gen_with_tangent(scalar)[1],
+                1e-8
+            );
+        }
+    }
+
+    #[test]
+    fn intermediate() {
+        // after every push, the curve is the natural spline over all values pushed so far
+        let knots = [0.0, 1.0, 2.5, 3.0, 4.5];
+        let values = [1.0, 3.0, -1.0, 0.5, 2.0];
+        let mut stream = StreamingCubicSpline::new(usize::MAX);
+        assert!(stream.is_empty());
+        stream.push(knots[0], values[0]).unwrap();
+        for len in 2..=knots.len() {
+            let curve = stream.push(knots[len - 1], values[len - 1]).unwrap();
+            let batch = natural(knots[..len].to_vec(), values[..len].to_vec()).unwrap();
+            for scalar in [0.0, 0.7, 2.0, 2.9, 4.0] {
+                assert_float_absolute_eq!(curve.gen(scalar), batch.gen(scalar), 1e-10);
+            }
+        }
+        // a line stays a line, even with the smallest window
+        let mut line = StreamingCubicSpline::new(0);
+        for i in 0..10 {
+            line.push(i as f64, 2.0 * i as f64).unwrap();
+        }
+        assert_f64_near!(line.gen(4.5), 9.0);
+    }
+
+    #[test]
+    fn errors() {
+        let mut stream = StreamingCubicSpline::new(8);
+        stream.push(0.0, 1.0).unwrap();
+        stream.push(1.0, 1.0).unwrap();
+        assert!(matches!(
+            stream.push(1.0, 2.0),
+            Err(CubicError::NotSorted(_))
+        ));
+        assert!(matches!(
+            stream.push(0.5, 2.0),
+            Err(CubicError::NotSorted(_))
+        ));
+        // failed pushes do not change the spline
+        assert_eq!(stream.len(), 2);
+        assert_eq!(stream.domain(), [0.0, 1.0]);
+    }
+}
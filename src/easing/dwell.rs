@@ -0,0 +1,52 @@
+use crate::{Curve, Generator};
+use num_traits::real::Real;
+
+/// Dwell is an easing curve which holds its start for a fraction of its domain and ramps up linearly afterwards.
+///
+/// Given to a linear interpolation, each segment holds the value of its first element for the first
+/// `dwell` fraction of the segment and interpolates linearly to the next element in the remaining part.
+/// This is useful for stepped outputs with smooth transitions, like dimmers.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Copy, Clone)]
+pub struct Dwell<R> {
+    dwell: R,
+}
+
+impl<R> Dwell<R>
+where
+    R: Real,
+{
+    /// Create a new Dwell easing curve with the given fraction to hold, which gets clamped to [0.0,1.0].
+    /// A dwell of 0.0 will be the same as the identity. A dwell of 1.0 will only return 0.0,
+    /// except for inputs of at least 1.0, which return 1.0.
+    pub fn new(dwell: R) -> Self {
+        Dwell {
+            dwell: dwell.max(R::zero()).min(R::one()),
+        }
+    }
+}
+
+impl<R> Generator<R> for Dwell<R>
+where
+    R: Real,
+{
+    type Output = R;
+    fn gen(&self, input: R) -> R {
+        if input < self.dwell {
+            R::zero()
+        } else if self.dwell >= R::one() {
+            R::one()
+        } else {
+            (input - self.dwell) / (R::one() - self.dwell)
+        }
+    }
+}
+
+impl<R> Curve<R> for Dwell<R>
+where
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        [R::zero(), R::one()]
+    }
+}
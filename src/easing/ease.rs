@@ -0,0 +1,319 @@
+use crate::{Curve, Generator};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// The family of an easing function.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EaseKind {
+    /// Power of 2, that is `x^2` for ease-in.
+    Quadratic,
+    /// Power of 3, that is `x^3` for ease-in.
+    Cubic,
+    /// A quarter of a cosine wave.
+    Sine,
+    /// Exponential growth with base 2, reaching 0 exactly at the start.
+    Exponential,
+    /// Oscillates with growing amplitude before snapping to the end, like a spring.
+    Elastic,
+    /// Bounces off the start with decreasing height, like a ball dropped on the floor.
+    Bounce,
+    /// Pulls back slightly below 0 before moving towards the end.
+    Back,
+}
+
+/// The part of the curve an easing function is applied to.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EaseMode {
+    /// Ease the start, also known as ease-in.
+    In,
+    /// Ease the end, also known as ease-out.
+    Out,
+    /// Ease both the start and the end, also known as ease-in-out.
+    InOut,
+}
+
+/// Ease is a collection of the common easing functions used for animations.
+///
+/// Every easing function is defined by its ease-in variant. The ease-out variant is the ease-in
+/// variant flipped on both axes and the ease-in-out variant plays the ease-in variant in the first
+/// half and the ease-out variant in the second half of the domain.
+/// All of them map 0.0 to 0.0 and 1.0 to 1.0, however some, like [`Back`] and [`Elastic`],
+/// leave the range [0.0,1.0] in between.
+///
+/// As easing functions are curves, they can be composed with other curves by [`composite()`],
+/// which expects the other curve to have a normalized domain, or by [`apply()`], which works
+/// with the domain of any curve.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "linear", doc = "```rust")]
+#[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+/// # use enterpolation::{linear::{Linear, LinearError}, easing::{Ease, EaseKind}, Curve, Generator};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), LinearError> {
+/// let linear = Linear::builder()
+///                 .elements([0.0,10.0])
+///                 .knots([0.0,2.0])
+///                 .build()?;
+/// let eased = Ease::ease_in(EaseKind::Quadratic).apply(linear);
+/// assert_eq!(eased.domain(), [0.0,2.0]);
+/// assert_f64_near!(eased.gen(1.0), 2.5);
+/// assert_f64_near!(eased.gen(2.0), 10.0);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Back`]: EaseKind::Back
+/// [`Elastic`]: EaseKind::Elastic
+/// [`composite()`]: crate::Generator::composite()
+/// [`apply()`]: Ease::apply()
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Ease {
+    kind: EaseKind,
+    mode: EaseMode,
+}
+
+impl Ease {
+    /// Create a new easing function of the given kind and mode.
+    pub const fn new(kind: EaseKind, mode: EaseMode) -> Self {
+        Ease { kind, mode }
+    }
+    /// Create the ease-in variant of the given kind.
+    pub const fn ease_in(kind: EaseKind) -> Self {
+        Ease::new(kind, EaseMode::In)
+    }
+    /// Create the ease-out variant of the given kind.
+    pub const fn ease_out(kind: EaseKind) -> Self {
+        Ease::new(kind, EaseMode::Out)
+    }
+    /// Create the ease-in-out variant of the given kind.
+    pub const fn ease_in_out(kind: EaseKind) -> Self {
+        Ease::new(kind, EaseMode::InOut)
+    }
+    /// Returns the kind of the easing function.
+    pub fn kind(&self) -> EaseKind {
+        self.kind
+    }
+    /// Returns the mode of the easing function.
+    pub fn mode(&self) -> EaseMode {
+        self.mode
+    }
+    /// Remap the input of the given curve with this easing function.
+    ///
+    /// The returned curve has the same domain as the given curve.
+    /// Its input is normalized to [0.0,1.0], eased and mapped back onto the domain of the curve.
+    pub fn apply<C>(self, curve: C) -> Eased<Self, C> {
+        Eased::new(self, curve)
+    }
+    /// The ease-in variant of the easing function.
+    fn ease_in_value<R>(&self, x: R) -> R
+    where
+        R: Real + FromPrimitive,
+    {
+        let constant =
+            |value: f64| R::from_f64(value).expect("Could not convert constant of easing function");
+        match self.kind {
+            EaseKind::Quadratic => x * x,
+            EaseKind::Cubic => x * x * x,
+            EaseKind::Sine => R::one() - (x * constant(core::f64::consts::FRAC_PI_2)).cos(),
+            EaseKind::Exponential => {
+                if x <= R::zero() {
+                    R::zero()
+                } else {
+                    constant(2.0).powf(constant(10.0) * (x - R::one()))
+                }
+            }
+            EaseKind::Elastic => {
+                if x <= R::zero() || x >= R::one() {
+                    x
+                } else {
+                    let exponent = constant(10.0) * (x - R::one());
+                    let phase = constant(10.0) * x - constant(10.75);
+                    -constant(2.0).powf(exponent)
+                        * (phase * constant(2.0 * core::f64::consts::FRAC_PI_3)).sin()
+                }
+            }
+            EaseKind::Bounce => R::one() - bounce_out(R::one() - x),
+            EaseKind::Back => {
+                let overshoot = constant(1.70158);
+                x * x * ((overshoot + R::one()) * x - overshoot)
+            }
+        }
+    }
+}
+
+/// The usual ease-out bounce, consisting of four parabolas.
+fn bounce_out<R>(x: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    let constant =
+        |value: f64| R::from_f64(value).expect("Could not convert constant of easing function");
+    let height = constant(7.5625);
+    let width = constant(2.75);
+    if x < R::one() / width {
+        height * x * x
+    } else if x < constant(2.0) / width {
+        let x = x - constant(1.5) / width;
+        height * x * x + constant(0.75)
+    } else if x < constant(2.5) / width {
+        let x = x - constant(2.25) / width;
+        height * x * x + constant(0.9375)
+    } else {
+        let x = x - constant(2.625) / width;
+        height * x * x + constant(0.984375)
+    }
+}
+
+impl<R> Generator<R> for Ease
+where
+    R: Real + FromPrimitive,
+{
+    type Output = R;
+    fn gen(&self, input: R) -> R {
+        let two = R::one() + R::one();
+        match self.mode {
+            EaseMode::In => self.ease_in_value(input),
+            EaseMode::Out => R::one() - self.ease_in_value(R::one() - input),
+            EaseMode::InOut => {
+                if input < two.recip() {
+                    self.ease_in_value(input * two) / two
+                } else {
+                    R::one() - self.ease_in_value(two - input * two) / two
+                }
+            }
+        }
+    }
+}
+
+impl<R> Curve<R> for Ease
+where
+    R: Real + FromPrimitive,
+{
+    fn domain(&self) -> [R; 2] {
+        [R::zero(), R::one()]
+    }
+}
+
+/// Curve whose input gets remapped by an easing function.
+///
+/// This struct is created by the [`apply()`] method. Please look there for more information.
+///
+/// [`apply()`]: Ease::apply()
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Copy, Clone)]
+pub struct Eased<E, C> {
+    ease: E,
+    curve: C,
+}
+
+impl<E, C> Eased<E, C> {
+    /// Remap the input of `curve` with the easing function `ease`.
+    ///
+    /// The easing function is expected to map [0.0,1.0] onto itself, for example [`Ease`] or [`FuncEase`].
+    ///
+    /// [`FuncEase`]: super::FuncEase
+    pub fn new(ease: E, curve: C) -> Self {
+        Eased { ease, curve }
+    }
+}
+
+impl<E, C, R> Generator<R> for Eased<E, C>
+where
+    E: Generator<R, Output = R>,
+    C: Curve<R>,
+    R: Real,
+{
+    type Output = C::Output;
+    fn gen(&self, input: R) -> Self::Output {
+        let [start, end] = self.curve.domain();
+        let length = end - start;
+        let eased = self.ease.gen((input - start) / length);
+        self.curve.gen(start + eased * length)
+    }
+}
+
+impl<E, C, R> Curve<R> for Eased<E, C>
+where
+    E: Generator<R, Output = R>,
+    C: Curve<R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.curve.domain()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Stepper;
+
+    const KINDS: [EaseKind; 7] = [
+        EaseKind::Quadratic,
+        EaseKind::Cubic,
+        EaseKind::Sine,
+        EaseKind::Exponential,
+        EaseKind::Elastic,
+        EaseKind::Bounce,
+        EaseKind::Back,
+    ];
+
+    #[test]
+    fn endpoints() {
+        for kind in KINDS {
+            for mode in [EaseMode::In, EaseMode::Out, EaseMode::InOut] {
+                let ease = Ease::new(kind, mode);
+                assert_float_absolute_eq!(ease.gen(0.0), 0.0, 1e-3);
+                assert_float_absolute_eq!(ease.gen(1.0), 1.0, 1e-3);
+            }
+            assert_f64_near!(Ease::ease_in_out(kind).gen(0.5), 0.5);
+        }
+    }
+
+    #[test]
+    fn symmetry() {
+        for kind in KINDS {
+            let (ease_in, ease_out) = (Ease::ease_in(kind), Ease::ease_out(kind));
+            for x in Stepper::normalized(21) {
+                let value: f64 = ease_out.gen(x);
+                assert_f64_near!(value, 1.0 - ease_in.gen(1.0 - x));
+            }
+        }
+    }
+
+    #[test]
+    fn values() {
+        assert_f64_near!(Ease::ease_in(EaseKind::Quadratic).gen(0.5), 0.25);
+        assert_f64_near!(Ease::ease_out(EaseKind::Cubic).gen(0.5), 0.875);
+        assert_f64_near!(Ease::ease_in_out(EaseKind::Quadratic).gen(0.25), 0.125);
+        assert_f64_near!(Ease::ease_in(EaseKind::Sine).gen(1.0), 1.0);
+        assert_f64_near!(Ease::ease_in(EaseKind::Exponential).gen(0.9), 0.5);
+        assert_f64_near!(Ease::ease_out(EaseKind::Bounce).gen(1.0 / 2.75), 1.0);
+        // back and elastic leave the range [0.0,1.0]
+        assert!(Ease::ease_in(EaseKind::Back).gen(0.3) < 0.0);
+        assert!(Ease::ease_out(EaseKind::Elastic).gen(0.1) > 1.0);
+    }
+
+    #[test]
+    #[cfg(feature = "linear")]
+    fn apply() {
+        use crate::linear::Linear;
+        let linear = Linear::builder()
+            .elements([0.0, 4.0])
+            .knots([1.0, 3.0])
+            .build()
+            .unwrap();
+        let eased = Ease::ease_in_out(EaseKind::Sine).apply(linear);
+        assert_eq!(eased.domain(), [1.0, 3.0]);
+        assert_f64_near!(eased.gen(1.0), 0.0);
+        assert_f64_near!(eased.gen(2.0), 2.0);
+        assert_f64_near!(eased.gen(3.0), 4.0);
+        assert!(eased.gen(1.5) < 1.0);
+    }
+}
@@ -2,12 +2,19 @@
 //!
 //! Easing function, in the context of this crate, are function which take as only input
 //! a real number in [0.0,1.0] and return a real number in [0.0,1.0].
+//!
+//! The common easing functions used for animations, like quadratic, sine or bounce easing
+//! with their in, out and in-out variants, are provided by [`Ease`].
 
 use crate::{Curve, Generator};
 use num_traits::real::Real;
 use num_traits::FromPrimitive;
 
+mod dwell;
+mod ease;
 mod plateau;
+pub use dwell::Dwell;
+pub use ease::{Ease, EaseKind, EaseMode, Eased};
 pub use plateau::Plateau;
 
 /// This is just a wrapper for easing functions.
@@ -47,7 +54,7 @@ where
 }
 
 /// Identity as Curve.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Identity {}
 
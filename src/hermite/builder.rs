@@ -0,0 +1,373 @@
+//! Builder module for cubic hermite splines.
+
+use super::error::HermiteError;
+use super::{CubicHermite, KnotElementInequality, TangentElementInequality, TooFewElements};
+use crate::builder::{Type, Unknown};
+use crate::{DiscreteGenerator, Equidistant, Sorted, SortedGenerator};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Builder for cubic hermite splines.
+///
+/// This struct helps create cubic hermite splines. The differene between this struct and [`CubicHermiteBuilder`]
+/// is that this struct may have other fallible methods and not only the [`build()`] method.
+///
+/// Before building, one has to give information for:
+/// - The points the spline should pass through and the tangents at these points with [`elements()`].
+/// - The knots the spline uses. This can be seen as the spacing between those points.
+///   Either by giving them directly with [`knots()`] or by using equidistant knots with [`equidistant()`].
+///
+/// ```rust
+/// # use enterpolation::{hermite::{CubicHermiteDirector, HermiteError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), HermiteError> {
+/// let spline = CubicHermiteDirector::new()
+///                 .elements([0.0,1.0,3.0], [0.0,2.0,0.0])?
+///                 .equidistant::<f64>()
+///                 .distance(0.0,1.0)
+///                 .build();
+/// assert_f64_near!(spline.gen(2.0), 3.0);
+/// assert_f64_near!(spline.gen(0.5), 0.25);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: CubicHermiteDirector::build()
+/// [`elements()`]: CubicHermiteDirector::elements()
+/// [`knots()`]: CubicHermiteDirector::knots()
+/// [`equidistant()`]: CubicHermiteDirector::equidistant()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CubicHermiteDirector<K, P, T> {
+    knots: K,
+    points: P,
+    tangents: T,
+}
+
+/// Builder for cubic hermite splines.
+///
+/// This struct helps create cubic hermite splines. Its only fallible method is [`build()`].
+/// Usually one creates an instance by using the [`builder()`] method on the spline itself.
+///
+/// Before building, one has to give information for:
+/// - The points the spline should pass through and the tangents at these points with [`elements()`].
+/// - The knots the spline uses. This can be seen as the spacing between those points.
+///   Either by giving them directly with [`knots()`] or by using equidistant knots with [`equidistant()`].
+///
+/// ```rust
+/// # use enterpolation::{hermite::{CubicHermite, HermiteError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), HermiteError> {
+/// let spline = CubicHermite::builder()
+///                 .elements([0.0,1.0,3.0], [0.0,2.0,0.0])
+///                 .equidistant::<f64>()
+///                 .distance(0.0,1.0)
+///                 .build()?;
+/// assert_f64_near!(spline.gen(2.0), 3.0);
+/// assert_f64_near!(spline.gen(0.5), 0.25);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: CubicHermiteBuilder::build()
+/// [`builder()`]: super::CubicHermite::builder()
+/// [`elements()`]: CubicHermiteBuilder::elements()
+/// [`knots()`]: CubicHermiteBuilder::knots()
+/// [`equidistant()`]: CubicHermiteBuilder::equidistant()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CubicHermiteBuilder<K, P, T> {
+    inner: Result<CubicHermiteDirector<K, P, T>, HermiteError>,
+}
+
+impl Default for CubicHermiteDirector<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        CubicHermiteDirector::new()
+    }
+}
+
+impl Default for CubicHermiteBuilder<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        CubicHermiteBuilder::new()
+    }
+}
+
+impl CubicHermiteDirector<Unknown, Unknown, Unknown> {
+    /// Create a new cubic hermite spline builder.
+    pub const fn new() -> Self {
+        CubicHermiteDirector {
+            knots: Unknown,
+            points: Unknown,
+            tangents: Unknown,
+        }
+    }
+
+    /// Set the points of the spline and the tangents at these points.
+    ///
+    /// The tangents are the derivatives of the curve at the knots.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 points are given.
+    /// Returns [`TangentElementInequality`] if the number of tangents is not equal to the number of points.
+    ///
+    /// [`TooFewElements`]: super::error::HermiteError
+    /// [`TangentElementInequality`]: super::error::HermiteError
+    pub fn elements<P, T>(
+        self,
+        points: P,
+        tangents: T,
+    ) -> Result<CubicHermiteDirector<Unknown, P, T>, HermiteError>
+    where
+        P: DiscreteGenerator,
+        T: DiscreteGenerator<Output = P::Output>,
+    {
+        if points.len() < 2 {
+            return Err(TooFewElements::new(points.len()).into());
+        }
+        if tangents.len() != points.len() {
+            return Err(TangentElementInequality::new(points.len(), tangents.len()).into());
+        }
+        Ok(CubicHermiteDirector {
+            knots: self.knots,
+            points,
+            tangents,
+        })
+    }
+}
+
+impl CubicHermiteBuilder<Unknown, Unknown, Unknown> {
+    /// Create a new cubic hermite spline builder.
+    pub const fn new() -> Self {
+        CubicHermiteBuilder {
+            inner: Ok(CubicHermiteDirector::new()),
+        }
+    }
+
+    /// Set the points of the spline and the tangents at these points.
+    ///
+    /// The tangents are the derivatives of the curve at the knots.
+    pub fn elements<P, T>(self, points: P, tangents: T) -> CubicHermiteBuilder<Unknown, P, T>
+    where
+        P: DiscreteGenerator,
+        T: DiscreteGenerator<Output = P::Output>,
+    {
+        CubicHermiteBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements(points, tangents)),
+        }
+    }
+}
+
+impl<P, T> CubicHermiteDirector<Unknown, P, T> {
+    /// Set the knots of the spline.
+    ///
+    /// The amount of knots must be equal to the amount of points.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of points.
+    /// Returns [`NotSorted`] if the knots are not sorted such that they are increasing.
+    ///
+    /// [`KnotElementInequality`]: super::error::HermiteError
+    /// [`NotSorted`]:  super::error::HermiteError
+    pub fn knots<K>(self, knots: K) -> Result<CubicHermiteDirector<Sorted<K>, P, T>, HermiteError>
+    where
+        P: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: Real,
+    {
+        if self.points.len() != knots.len() {
+            return Err(KnotElementInequality::new(self.points.len(), knots.len()).into());
+        }
+        Ok(CubicHermiteDirector {
+            knots: Sorted::new(knots)?,
+            points: self.points,
+            tangents: self.tangents,
+        })
+    }
+
+    /// Build a spline with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the spline and the spacing of the knots.
+    ///
+    /// [`domain()`]: CubicHermiteDirector::domain()
+    /// [`normalized()`]: CubicHermiteDirector::normalized()
+    /// [`distance()`]: CubicHermiteDirector::distance()
+    pub fn equidistant<R>(self) -> CubicHermiteDirector<Type<R>, P, T> {
+        CubicHermiteDirector {
+            knots: Type::new(),
+            points: self.points,
+            tangents: self.tangents,
+        }
+    }
+}
+
+impl<P, T> CubicHermiteBuilder<Unknown, P, T> {
+    /// Set the knots of the spline.
+    ///
+    /// The amount of knots must be equal to the amount of points.
+    pub fn knots<K>(self, knots: K) -> CubicHermiteBuilder<Sorted<K>, P, T>
+    where
+        P: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: Real,
+    {
+        CubicHermiteBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+
+    /// Build a spline with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the spline and the spacing of the knots.
+    ///
+    /// [`domain()`]: CubicHermiteBuilder::domain()
+    /// [`normalized()`]: CubicHermiteBuilder::normalized()
+    /// [`distance()`]: CubicHermiteBuilder::distance()
+    pub fn equidistant<R>(self) -> CubicHermiteBuilder<Type<R>, P, T> {
+        CubicHermiteBuilder {
+            inner: self.inner.map(|director| director.equidistant()),
+        }
+    }
+}
+
+impl<R, P, T> CubicHermiteDirector<Type<R>, P, T>
+where
+    P: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the spline.
+    pub fn domain(self, start: R, end: R) -> CubicHermiteDirector<Equidistant<R>, P, T> {
+        CubicHermiteDirector {
+            knots: Equidistant::new(self.points.len(), start, end),
+            points: self.points,
+            tangents: self.tangents,
+        }
+    }
+
+    /// Set the domain of the spline to be [0.0,1.0].
+    pub fn normalized(self) -> CubicHermiteDirector<Equidistant<R>, P, T> {
+        CubicHermiteDirector {
+            knots: Equidistant::normalized(self.points.len()),
+            points: self.points,
+            tangents: self.tangents,
+        }
+    }
+
+    /// Set the domain of the spline by defining the distance between the knots
+    pub fn distance(self, start: R, step: R) -> CubicHermiteDirector<Equidistant<R>, P, T> {
+        CubicHermiteDirector {
+            knots: Equidistant::step(self.points.len(), start, step),
+            points: self.points,
+            tangents: self.tangents,
+        }
+    }
+}
+
+impl<R, P, T> CubicHermiteBuilder<Type<R>, P, T>
+where
+    P: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the spline.
+    pub fn domain(self, start: R, end: R) -> CubicHermiteBuilder<Equidistant<R>, P, T> {
+        CubicHermiteBuilder {
+            inner: self.inner.map(|director| director.domain(start, end)),
+        }
+    }
+
+    /// Set the domain of the spline to be [0.0,1.0].
+    pub fn normalized(self) -> CubicHermiteBuilder<Equidistant<R>, P, T> {
+        CubicHermiteBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+
+    /// Set the domain of the spline by defining the distance between the knots
+    pub fn distance(self, start: R, step: R) -> CubicHermiteBuilder<Equidistant<R>, P, T> {
+        CubicHermiteBuilder {
+            inner: self.inner.map(|director| director.distance(start, step)),
+        }
+    }
+}
+
+impl<K, A, P, T> CubicHermiteDirector<Sorted<K, A>, P, T> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<S>(self, strategy: S) -> CubicHermiteDirector<Sorted<K, S>, P, T> {
+        CubicHermiteDirector {
+            knots: self.knots.search(strategy),
+            points: self.points,
+            tangents: self.tangents,
+        }
+    }
+}
+
+impl<K, A, P, T> CubicHermiteBuilder<Sorted<K, A>, P, T> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<S>(self, strategy: S) -> CubicHermiteBuilder<Sorted<K, S>, P, T> {
+        CubicHermiteBuilder {
+            inner: self.inner.map(|director| director.search(strategy)),
+        }
+    }
+}
+
+impl<K, P, T> CubicHermiteDirector<K, P, T>
+where
+    K: SortedGenerator,
+    K::Output: Real,
+    P: DiscreteGenerator,
+    T: DiscreteGenerator<Output = P::Output>,
+{
+    /// Build a cubic hermite spline.
+    pub fn build(self) -> CubicHermite<K, P, T> {
+        CubicHermite::new_unchecked(self.points, self.tangents, self.knots)
+    }
+}
+
+impl<K, P, T> CubicHermiteBuilder<K, P, T>
+where
+    K: SortedGenerator,
+    K::Output: Real,
+    P: DiscreteGenerator,
+    T: DiscreteGenerator<Output = P::Output>,
+{
+    /// Build a cubic hermite spline.
+    pub fn build(self) -> Result<CubicHermite<K, P, T>, HermiteError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
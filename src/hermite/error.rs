@@ -0,0 +1,90 @@
+//! All error types for cubic hermite splines.
+
+pub use crate::builder::{KnotElementInequality, TooFewElements};
+pub use crate::NotSorted;
+use core::{convert::From, fmt};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Errors which could occur when creating a cubic hermite spline.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum HermiteError {
+    /// Error returned if the points are to few for a cubic hermite spline.
+    TooFewElements(TooFewElements),
+    /// Error returned if the number of knots and points are not equal.
+    KnotElementInequality(KnotElementInequality),
+    /// Error returned if the number of points and tangents are not equal.
+    TangentElementInequality(TangentElementInequality),
+    /// Error returned if knots are not sorted.
+    NotSorted(NotSorted),
+}
+
+impl fmt::Display for HermiteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HermiteError::TooFewElements(inner) => inner.fmt(f),
+            HermiteError::KnotElementInequality(inner) => inner.fmt(f),
+            HermiteError::TangentElementInequality(inner) => inner.fmt(f),
+            HermiteError::NotSorted(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl From<TooFewElements> for HermiteError {
+    fn from(from: TooFewElements) -> Self {
+        HermiteError::TooFewElements(from)
+    }
+}
+
+impl From<KnotElementInequality> for HermiteError {
+    fn from(from: KnotElementInequality) -> Self {
+        HermiteError::KnotElementInequality(from)
+    }
+}
+
+impl From<TangentElementInequality> for HermiteError {
+    fn from(from: TangentElementInequality) -> Self {
+        HermiteError::TangentElementInequality(from)
+    }
+}
+
+impl From<NotSorted> for HermiteError {
+    fn from(from: NotSorted) -> Self {
+        HermiteError::NotSorted(from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for HermiteError {}
+
+/// Error returned if the number of points and the number of tangents are not matching.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TangentElementInequality {
+    /// The number of points found.
+    elements: usize,
+    /// The number of tangents found.
+    tangents: usize,
+}
+
+impl TangentElementInequality {
+    /// Create a new error with the number of points and tangents found.
+    pub fn new(elements: usize, tangents: usize) -> Self {
+        TangentElementInequality { elements, tangents }
+    }
+}
+
+impl fmt::Display for TangentElementInequality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "There has to be as many tangents as points, however we found {} points and {} tangents.",
+            self.elements, self.tangents
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TangentElementInequality {}
@@ -0,0 +1,388 @@
+//! Cubic hermite splines.
+//!
+//! Cubic hermite splines are piecewise cubic curves which pass through all of their points
+//! with the tangents given at each point. In contrast to [Catmull-Rom splines], which derive the tangents
+//! from the neighbouring points, the tangents are given explicitly, for example if they are known from
+//! the velocity of a simulation. The easiest way to create one is by using the builder pattern of
+//! [`CubicHermiteBuilder`].
+//!
+//! ```rust
+//! # use enterpolation::{hermite::{CubicHermite, HermiteError}, Generator, Curve, Derivative};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # fn main() -> Result<(), HermiteError> {
+//! let spline = CubicHermite::builder()
+//!                 .elements([0.0,1.0,3.0], [0.0,2.0,0.0])
+//!                 .knots([0.0,1.0,2.0])
+//!                 .build()?;
+//! assert_eq!(spline.domain(), [0.0,2.0]);
+//! assert_f64_near!(spline.gen(1.0), 1.0);
+//! assert_f64_near!(spline.derivative(1.0), 2.0);
+//! assert_f64_near!(spline.gen(0.5), 0.25);
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! The tangents are the derivatives of the curve at the knots. Such, they are given with respect to the
+//! knots and not the segments, which means scaling the knots also changes the shape of the curve.
+//!
+//! [Catmull-Rom splines]: crate::catmullrom
+//! [`CubicHermiteBuilder`]: CubicHermiteBuilder
+
+#[cfg(feature = "alloc")]
+use crate::base::knot_midpoints;
+use crate::builder::Unknown;
+use crate::utils::{hermite, hermite_segment};
+use crate::{Curve, Derivative, DiscreteGenerator, Generator, SortedGenerator};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+
+mod builder;
+pub use builder::{CubicHermiteBuilder, CubicHermiteDirector};
+
+pub mod error;
+pub use error::{
+    HermiteError, KnotElementInequality, NotSorted, TangentElementInequality, TooFewElements,
+};
+
+/// Cubic hermite spline.
+///
+/// See [hermite module] for more information.
+///
+/// [hermite module]: self
+#[derive(Debug, Copy, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CubicHermite<K, P, T> {
+    points: P,
+    tangents: T,
+    knots: K,
+}
+
+impl CubicHermite<Unknown, Unknown, Unknown> {
+    /// Get the builder for a cubic hermite spline.
+    ///
+    /// The builder takes:
+    /// - points and their tangents with [`elements()`]
+    /// - knots with either [`knots()`] or [`equidistant()`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{hermite::{CubicHermite, HermiteError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), HermiteError> {
+    /// let spline = CubicHermite::builder()
+    ///                 .elements([0.0,1.0,2.0], [2.0,2.0,2.0])
+    ///                 .equidistant::<f64>()
+    ///                 .normalized()
+    ///                 .build()?;
+    /// // the tangents match the slope of the points, such that the curve is a line
+    /// let results = [0.0,0.5,1.0,1.5,2.0];
+    /// for (value,result) in spline.take(5).zip(results.iter().copied()){
+    ///     assert_f64_near!(value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`elements()`]: CubicHermiteBuilder::elements()
+    /// [`knots()`]: CubicHermiteBuilder::knots()
+    /// [`equidistant()`]: CubicHermiteBuilder::equidistant()
+    pub fn builder() -> CubicHermiteBuilder<Unknown, Unknown, Unknown> {
+        CubicHermiteBuilder::new()
+    }
+}
+
+impl<K, P, T, R> CubicHermite<K, P, T>
+where
+    K: SortedGenerator<Output = R>,
+    P: DiscreteGenerator,
+    T: DiscreteGenerator<Output = P::Output>,
+    R: Real,
+{
+    /// Create a cubic hermite spline with slice-like collections of points, tangents and knots.
+    ///
+    /// Knots have to be sorted and there should be as many knots and tangents as points.
+    /// There has to be at least 2 points.
+    pub fn new(points: P, tangents: T, knots: K) -> Result<Self, HermiteError> {
+        if points.len() < 2 {
+            return Err(TooFewElements::new(points.len()).into());
+        }
+        if tangents.len() != points.len() {
+            return Err(TangentElementInequality::new(points.len(), tangents.len()).into());
+        }
+        if knots.len() != points.len() {
+            return Err(KnotElementInequality::new(points.len(), knots.len()).into());
+        }
+        Ok(CubicHermite {
+            points,
+            tangents,
+            knots,
+        })
+    }
+
+    /// Create a cubic hermite spline with slice-like collections of points, tangents and knots.
+    ///
+    /// # Panics
+    ///
+    /// Knots should be in increasing order, there should be as many knots and tangents as points
+    /// and there has to be at least *two* points.
+    /// If any of these requirements are not uphold, the library may panic at any time.
+    pub fn new_unchecked(points: P, tangents: T, knots: K) -> Self {
+        CubicHermite {
+            points,
+            tangents,
+            knots,
+        }
+    }
+
+    /// Returns the index of the segment in which the scalar lies, the position of the scalar
+    /// within the segment normalized to [0.0,1.0] and the width of the segment.
+    fn segment(&self, scalar: R) -> (usize, R, R) {
+        hermite_segment(&self.knots, scalar, 1, self.knots.len() - 1)
+    }
+}
+
+impl<K, P, T, R> CubicHermite<K, P, T>
+where
+    K: SortedGenerator<Output = R>,
+    P: DiscreteGenerator,
+    T: DiscreteGenerator<Output = P::Output>,
+    P::Output:
+        Add<Output = P::Output> + Sub<Output = P::Output> + Mul<R, Output = P::Output> + Copy,
+    R: Real,
+{
+    /// Generate the value and its tangent, in this order.
+    ///
+    /// Both are calculated at once, which is cheaper than calculating them separately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalar` is NaN or similar.
+    pub fn gen_with_tangent(&self, scalar: R) -> [P::Output; 2] {
+        let (index, t, width) = self.segment(scalar);
+        let start = self.points.gen(index);
+        let end = self.points.gen(index + 1);
+        let start_tangent = self.tangents.gen(index);
+        let end_tangent = self.tangents.gen(index + 1);
+        hermite([start, end], [start_tangent, end_tangent], t, width)
+    }
+}
+
+impl<K, P, T, R> CubicHermite<K, P, T>
+where
+    K: SortedGenerator<Output = R>,
+    P: DiscreteGenerator,
+    T: AsMut<[P::Output]>,
+    P::Output: Sub<Output = P::Output> + Mul<R, Output = P::Output> + Copy,
+    R: Real,
+{
+    /// Recompute the tangents at all interior points from their neighbours, in place.
+    ///
+    /// The tangent at each interior point is set to the slope between its two neighbouring points,
+    /// as it is done by Catmull-Rom splines. The points themselves and the tangents at the first and last
+    /// point are kept. This is useful to clean up a curve after its points were edited, as the result is
+    /// a smooth curve through the same points, whose direction changes gradually at every knot.
+    ///
+    /// Only curves whose tangents are stored in a mutable collection, like a `Vec` or an array, can be smoothed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{hermite::{CubicHermite, HermiteError}, Generator, Curve, Derivative};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), HermiteError> {
+    /// let mut spline = CubicHermite::builder()
+    ///                 .elements([0.0,1.0,4.0], [1.0,-3.0,1.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// spline.smooth_tangents();
+    /// assert_f64_near!(spline.derivative(1.0), 2.0);
+    /// assert_f64_near!(spline.gen(1.0), 1.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn smooth_tangents(&mut self) {
+        let tangents = self.tangents.as_mut();
+        let last = tangents.len() - 1;
+        for (index, tangent) in tangents.iter_mut().enumerate().take(last).skip(1) {
+            let width = self.knots.gen(index + 1) - self.knots.gen(index - 1);
+            *tangent = (self.points.gen(index + 1) - self.points.gen(index - 1)) * width.recip();
+        }
+    }
+}
+
+impl<K, P, T, R> Generator<R> for CubicHermite<K, P, T>
+where
+    K: SortedGenerator<Output = R>,
+    P: DiscreteGenerator,
+    T: DiscreteGenerator<Output = P::Output>,
+    P::Output:
+        Add<Output = P::Output> + Sub<Output = P::Output> + Mul<R, Output = P::Output> + Copy,
+    R: Real,
+{
+    type Output = P::Output;
+    /// # Panics
+    ///
+    /// Panics if `scalar` is NaN or similar.
+    fn gen(&self, scalar: R) -> Self::Output {
+        let [value, _] = self.gen_with_tangent(scalar);
+        value
+    }
+}
+
+impl<K, P, T, R> Curve<R> for CubicHermite<K, P, T>
+where
+    K: SortedGenerator<Output = R>,
+    P: DiscreteGenerator,
+    T: DiscreteGenerator<Output = P::Output>,
+    P::Output:
+        Add<Output = P::Output> + Sub<Output = P::Output> + Mul<R, Output = P::Output> + Copy,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.knots.first().unwrap(), self.knots.last().unwrap()]
+    }
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        knot_midpoints(&self.knots, 0, self.knots.len() - 1)
+    }
+}
+
+impl<K, P, T, R> Derivative<R> for CubicHermite<K, P, T>
+where
+    K: SortedGenerator<Output = R>,
+    P: DiscreteGenerator,
+    T: DiscreteGenerator<Output = P::Output>,
+    P::Output:
+        Add<Output = P::Output> + Sub<Output = P::Output> + Mul<R, Output = P::Output> + Copy,
+    R: Real,
+{
+    fn derivative(&self, scalar: R) -> Self::Output {
+        let [_, tangent] = self.gen_with_tangent(scalar);
+        tangent
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Curve, Derivative, Generator, Sorted};
+
+    #[test]
+    fn interpolates_points_and_tangents() {
+        let points = [0.0, 2.0, -1.0, 4.0, 3.0];
+        let tangents = [1.0, 0.0, -2.0, 0.5, 3.0];
+        let knots = [0.0, 1.0, 3.0, 4.0, 7.0];
+        let spline = CubicHermite::builder()
+            .elements(points, tangents)
+            .knots(knots)
+            .build()
+            .unwrap();
+        assert_eq!(spline.domain(), [0.0, 7.0]);
+        for ((knot, point), tangent) in knots
+            .as_slice()
+            .iter()
+            .zip(points.as_slice())
+            .zip(tangents.as_slice())
+        {
+            assert_f64_near!(spline.gen(*knot), *point);
+            assert_float_absolute_eq!(spline.derivative(*knot), *tangent, 1e-12);
+        }
+        // compare with finite differences
+        let finite = spline.finite_difference();
+        for x in [0.5, 2.0, 3.5, 5.0] {
+            assert_float_absolute_eq!(spline.derivative(x), finite.derivative(x), 1e-6);
+        }
+    }
+
+    #[cfg(feature = "catmullrom")]
+    #[test]
+    fn catmullrom() {
+        use crate::catmullrom::CatmullRom;
+        // with the tangents of a Catmull-Rom spline, both splines are the same
+        let points = [0.0, 2.0, -1.0, 4.0];
+        let tangents = [2.0, -0.5, 1.0, 5.0];
+        let hermite = CubicHermite::builder()
+            .elements(points, tangents)
+            .equidistant::<f64>()
+            .distance(0.0, 1.0)
+            .build()
+            .unwrap();
+        let catmullrom = CatmullRom::builder()
+            .elements(points)
+            .equidistant::<f64>()
+            .distance(0.0, 1.0)
+            .build()
+            .unwrap();
+        for (a, b) in hermite.take(20).zip(catmullrom.take(20)) {
+            assert_f64_near!(a, b);
+        }
+    }
+
+    #[test]
+    fn smooth_tangents() {
+        let points = [0.0, 2.0, -1.0, 4.0, 3.0];
+        let knots = [0.0, 1.0, 3.0, 4.0, 7.0];
+        // tangents which do not fit the points at all
+        let mut spline = CubicHermite::builder()
+            .elements(points, [1.0, 5.0, 5.0, -5.0, 3.0])
+            .knots(knots)
+            .build()
+            .unwrap();
+        spline.smooth_tangents();
+        // the end tangents are kept, the interior ones are given by the neighbours
+        assert_eq!(spline.tangents, [1.0, -1.0 / 3.0, 2.0 / 3.0, 1.0, 3.0]);
+        let step = 1e-6;
+        for (knot, point) in knots.as_slice().iter().zip(points.as_slice()) {
+            assert_f64_near!(spline.gen(*knot), *point);
+        }
+        for knot in &knots[1..4] {
+            let value = spline.gen(*knot);
+            let left = (value - spline.gen(knot - step)) / step;
+            let right = (spline.gen(knot + step) - value) / step;
+            assert_float_absolute_eq!(left, right, 1e-4);
+            assert_float_absolute_eq!(left, spline.derivative(*knot), 1e-4);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            CubicHermite::builder()
+                .elements([1.0], [0.0])
+                .knots([0.0])
+                .build(),
+            Err(HermiteError::TooFewElements(_))
+        ));
+        assert!(matches!(
+            CubicHermite::builder()
+                .elements([1.0, 2.0, 3.0], [0.0, 1.0])
+                .knots([0.0, 1.0, 2.0])
+                .build(),
+            Err(HermiteError::TangentElementInequality(_))
+        ));
+        assert!(matches!(
+            CubicHermite::builder()
+                .elements([1.0, 2.0, 3.0], [0.0, 1.0, 0.0])
+                .knots([0.0, 1.0])
+                .build(),
+            Err(HermiteError::KnotElementInequality(_))
+        ));
+        assert!(matches!(
+            CubicHermite::builder()
+                .elements([1.0, 2.0, 3.0], [0.0, 1.0, 0.0])
+                .knots([0.0, 2.0, 1.0])
+                .build(),
+            Err(HermiteError::NotSorted(_))
+        ));
+        assert!(CubicHermite::new([1.0, 2.0], [0.0], Sorted::new([0.0, 1.0]).unwrap()).is_err());
+    }
+}
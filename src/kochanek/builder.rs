@@ -0,0 +1,451 @@
+//! Builder module for Kochanek-Bartels splines.
+
+use super::error::KochanekBartelsError;
+use super::{
+    KnotElementInequality, KochanekBartels, ParameterElementInequality, Tcb, TooFewElements,
+};
+use crate::builder::{Type, Unknown};
+use crate::{DiscreteGenerator, Equidistant, Repeat, Sorted, SortedGenerator};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Builder for Kochanek-Bartels splines.
+///
+/// This struct helps create Kochanek-Bartels splines. The differene between this struct and [`KochanekBartelsBuilder`]
+/// is that this struct may have other fallible methods and not only the [`build()`] method.
+///
+/// Before building, one has to give information for:
+/// - The elements the spline should use with [`elements()`].
+/// - The knots the spline uses. This can be seen as the spacing between those elements.
+///   Either by giving them directly with [`knots()`] or by using equidistant knots with [`equidistant()`].
+/// - The tension, continuity and bias at the elements. Either for each element with [`parameters()`]
+///   or for all elements at once with [`tcb()`].
+///
+/// ```rust
+/// # use enterpolation::{kochanek::{KochanekBartelsDirector, KochanekBartelsError, Tcb}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), KochanekBartelsError> {
+/// let spline = KochanekBartelsDirector::new()
+///                 .elements([0.0,1.0,3.0,2.0])?
+///                 .equidistant::<f64>()
+///                 .distance(0.0,1.0)
+///                 .tcb(Tcb::new(1.0,0.0,0.0))
+///                 .build();
+/// assert_f64_near!(spline.gen(2.0), 3.0);
+/// assert_f64_near!(spline.gen(1.5), 2.0);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: KochanekBartelsDirector::build()
+/// [`elements()`]: KochanekBartelsDirector::elements()
+/// [`knots()`]: KochanekBartelsDirector::knots()
+/// [`equidistant()`]: KochanekBartelsDirector::equidistant()
+/// [`parameters()`]: KochanekBartelsDirector::parameters()
+/// [`tcb()`]: KochanekBartelsDirector::tcb()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KochanekBartelsDirector<K, E, S> {
+    knots: K,
+    elements: E,
+    parameters: S,
+}
+
+/// Builder for Kochanek-Bartels splines.
+///
+/// This struct helps create Kochanek-Bartels splines. Its only fallible method is [`build()`].
+/// Usually one creates an instance by using the [`builder()`] method on the spline itself.
+///
+/// Before building, one has to give information for:
+/// - The elements the spline should use with [`elements()`].
+/// - The knots the spline uses. This can be seen as the spacing between those elements.
+///   Either by giving them directly with [`knots()`] or by using equidistant knots with [`equidistant()`].
+/// - The tension, continuity and bias at the elements. Either for each element with [`parameters()`]
+///   or for all elements at once with [`tcb()`].
+///
+/// ```rust
+/// # use enterpolation::{kochanek::{KochanekBartels, KochanekBartelsError, Tcb}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), KochanekBartelsError> {
+/// let spline = KochanekBartels::builder()
+///                 .elements([0.0,1.0,3.0,2.0])
+///                 .equidistant::<f64>()
+///                 .distance(0.0,1.0)
+///                 .tcb(Tcb::new(1.0,0.0,0.0))
+///                 .build()?;
+/// assert_f64_near!(spline.gen(2.0), 3.0);
+/// assert_f64_near!(spline.gen(1.5), 2.0);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: KochanekBartelsBuilder::build()
+/// [`builder()`]: super::KochanekBartels::builder()
+/// [`elements()`]: KochanekBartelsBuilder::elements()
+/// [`knots()`]: KochanekBartelsBuilder::knots()
+/// [`equidistant()`]: KochanekBartelsBuilder::equidistant()
+/// [`parameters()`]: KochanekBartelsBuilder::parameters()
+/// [`tcb()`]: KochanekBartelsBuilder::tcb()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KochanekBartelsBuilder<K, E, S> {
+    inner: Result<KochanekBartelsDirector<K, E, S>, KochanekBartelsError>,
+}
+
+impl Default for KochanekBartelsDirector<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        KochanekBartelsDirector::new()
+    }
+}
+
+impl Default for KochanekBartelsBuilder<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        KochanekBartelsBuilder::new()
+    }
+}
+
+impl KochanekBartelsDirector<Unknown, Unknown, Unknown> {
+    /// Create a new Kochanek-Bartels spline builder.
+    pub const fn new() -> Self {
+        KochanekBartelsDirector {
+            knots: Unknown,
+            elements: Unknown,
+            parameters: Unknown,
+        }
+    }
+
+    /// Set the elements of the spline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 elements are given.
+    ///
+    /// [`TooFewElements`]: super::error::KochanekBartelsError
+    pub fn elements<E>(
+        self,
+        elements: E,
+    ) -> Result<KochanekBartelsDirector<Unknown, E, Unknown>, TooFewElements>
+    where
+        E: DiscreteGenerator,
+    {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()));
+        }
+        Ok(KochanekBartelsDirector {
+            knots: self.knots,
+            elements,
+            parameters: self.parameters,
+        })
+    }
+}
+
+impl KochanekBartelsBuilder<Unknown, Unknown, Unknown> {
+    /// Create a new Kochanek-Bartels spline builder.
+    pub const fn new() -> Self {
+        KochanekBartelsBuilder {
+            inner: Ok(KochanekBartelsDirector::new()),
+        }
+    }
+
+    /// Set the elements of the spline.
+    pub fn elements<E>(self, elements: E) -> KochanekBartelsBuilder<Unknown, E, Unknown>
+    where
+        E: DiscreteGenerator,
+    {
+        KochanekBartelsBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements(elements).map_err(|err| err.into())),
+        }
+    }
+}
+
+impl<E> KochanekBartelsDirector<Unknown, E, Unknown> {
+    /// Set the knots of the spline.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    /// Returns [`NotSorted`] if the knots are not sorted such that they are increasing.
+    ///
+    /// [`KnotElementInequality`]: super::error::KochanekBartelsError
+    /// [`NotSorted`]:  super::error::KochanekBartelsError
+    pub fn knots<K>(
+        self,
+        knots: K,
+    ) -> Result<KochanekBartelsDirector<Sorted<K>, E, Unknown>, KochanekBartelsError>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: Real,
+    {
+        if self.elements.len() != knots.len() {
+            return Err(KnotElementInequality::new(self.elements.len(), knots.len()).into());
+        }
+        Ok(KochanekBartelsDirector {
+            knots: Sorted::new(knots)?,
+            elements: self.elements,
+            parameters: self.parameters,
+        })
+    }
+
+    /// Build a spline with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the spline and the spacing of the knots.
+    ///
+    /// [`domain()`]: KochanekBartelsDirector::domain()
+    /// [`normalized()`]: KochanekBartelsDirector::normalized()
+    /// [`distance()`]: KochanekBartelsDirector::distance()
+    pub fn equidistant<R>(self) -> KochanekBartelsDirector<Type<R>, E, Unknown> {
+        KochanekBartelsDirector {
+            knots: Type::new(),
+            elements: self.elements,
+            parameters: self.parameters,
+        }
+    }
+}
+
+impl<E> KochanekBartelsBuilder<Unknown, E, Unknown> {
+    /// Set the knots of the spline.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    pub fn knots<K>(self, knots: K) -> KochanekBartelsBuilder<Sorted<K>, E, Unknown>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: Real,
+    {
+        KochanekBartelsBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+
+    /// Build a spline with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the spline and the spacing of the knots.
+    ///
+    /// [`domain()`]: KochanekBartelsBuilder::domain()
+    /// [`normalized()`]: KochanekBartelsBuilder::normalized()
+    /// [`distance()`]: KochanekBartelsBuilder::distance()
+    pub fn equidistant<R>(self) -> KochanekBartelsBuilder<Type<R>, E, Unknown> {
+        KochanekBartelsBuilder {
+            inner: self.inner.map(|director| director.equidistant()),
+        }
+    }
+}
+
+impl<R, E> KochanekBartelsDirector<Type<R>, E, Unknown>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the spline.
+    pub fn domain(self, start: R, end: R) -> KochanekBartelsDirector<Equidistant<R>, E, Unknown> {
+        KochanekBartelsDirector {
+            knots: Equidistant::new(self.elements.len(), start, end),
+            elements: self.elements,
+            parameters: self.parameters,
+        }
+    }
+
+    /// Set the domain of the spline to be [0.0,1.0].
+    pub fn normalized(self) -> KochanekBartelsDirector<Equidistant<R>, E, Unknown> {
+        KochanekBartelsDirector {
+            knots: Equidistant::normalized(self.elements.len()),
+            elements: self.elements,
+            parameters: self.parameters,
+        }
+    }
+
+    /// Set the domain of the spline by defining the distance between the knots
+    pub fn distance(
+        self,
+        start: R,
+        step: R,
+    ) -> KochanekBartelsDirector<Equidistant<R>, E, Unknown> {
+        KochanekBartelsDirector {
+            knots: Equidistant::step(self.elements.len(), start, step),
+            elements: self.elements,
+            parameters: self.parameters,
+        }
+    }
+}
+
+impl<R, E> KochanekBartelsBuilder<Type<R>, E, Unknown>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the spline.
+    pub fn domain(self, start: R, end: R) -> KochanekBartelsBuilder<Equidistant<R>, E, Unknown> {
+        KochanekBartelsBuilder {
+            inner: self.inner.map(|director| director.domain(start, end)),
+        }
+    }
+
+    /// Set the domain of the spline to be [0.0,1.0].
+    pub fn normalized(self) -> KochanekBartelsBuilder<Equidistant<R>, E, Unknown> {
+        KochanekBartelsBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+
+    /// Set the domain of the spline by defining the distance between the knots
+    pub fn distance(self, start: R, step: R) -> KochanekBartelsBuilder<Equidistant<R>, E, Unknown> {
+        KochanekBartelsBuilder {
+            inner: self.inner.map(|director| director.distance(start, step)),
+        }
+    }
+}
+
+impl<K, A, E, S> KochanekBartelsDirector<Sorted<K, A>, E, S> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<T>(self, strategy: T) -> KochanekBartelsDirector<Sorted<K, T>, E, S> {
+        KochanekBartelsDirector {
+            knots: self.knots.search(strategy),
+            elements: self.elements,
+            parameters: self.parameters,
+        }
+    }
+}
+
+impl<K, A, E, S> KochanekBartelsBuilder<Sorted<K, A>, E, S> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<T>(self, strategy: T) -> KochanekBartelsBuilder<Sorted<K, T>, E, S> {
+        KochanekBartelsBuilder {
+            inner: self.inner.map(|director| director.search(strategy)),
+        }
+    }
+}
+
+impl<K, E, R> KochanekBartelsDirector<K, E, Unknown>
+where
+    E: DiscreteGenerator,
+    K: SortedGenerator<Output = R>,
+    R: Real,
+{
+    /// Set the tension, continuity and bias of each element.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParameterElementInequality`] if the number of parameters is not equal to the number of elements.
+    ///
+    /// [`ParameterElementInequality`]: super::error::KochanekBartelsError
+    pub fn parameters<S>(
+        self,
+        parameters: S,
+    ) -> Result<KochanekBartelsDirector<K, E, S>, ParameterElementInequality>
+    where
+        S: DiscreteGenerator<Output = Tcb<R>>,
+    {
+        if self.elements.len() != parameters.len() {
+            return Err(ParameterElementInequality::new(
+                self.elements.len(),
+                parameters.len(),
+            ));
+        }
+        Ok(KochanekBartelsDirector {
+            knots: self.knots,
+            elements: self.elements,
+            parameters,
+        })
+    }
+
+    /// Set the same tension, continuity and bias for all elements.
+    pub fn tcb(self, tcb: Tcb<R>) -> KochanekBartelsDirector<K, E, Repeat<[Tcb<R>; 1]>> {
+        KochanekBartelsDirector {
+            knots: self.knots,
+            elements: self.elements,
+            parameters: Repeat::new([tcb]),
+        }
+    }
+}
+
+impl<K, E, R> KochanekBartelsBuilder<K, E, Unknown>
+where
+    E: DiscreteGenerator,
+    K: SortedGenerator<Output = R>,
+    R: Real,
+{
+    /// Set the tension, continuity and bias of each element.
+    ///
+    /// The amount of parameters must be equal to the amount of elements.
+    pub fn parameters<S>(self, parameters: S) -> KochanekBartelsBuilder<K, E, S>
+    where
+        S: DiscreteGenerator<Output = Tcb<R>>,
+    {
+        KochanekBartelsBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.parameters(parameters).map_err(|err| err.into())),
+        }
+    }
+
+    /// Set the same tension, continuity and bias for all elements.
+    pub fn tcb(self, tcb: Tcb<R>) -> KochanekBartelsBuilder<K, E, Repeat<[Tcb<R>; 1]>> {
+        KochanekBartelsBuilder {
+            inner: self.inner.map(|director| director.tcb(tcb)),
+        }
+    }
+}
+
+impl<K, E, S, R> KochanekBartelsDirector<K, E, S>
+where
+    E: DiscreteGenerator,
+    K: SortedGenerator<Output = R>,
+    S: DiscreteGenerator<Output = Tcb<R>>,
+    R: Real,
+{
+    /// Build a Kochanek-Bartels spline.
+    pub fn build(self) -> KochanekBartels<K, E, S> {
+        KochanekBartels::new_unchecked(self.elements, self.knots, self.parameters)
+    }
+}
+
+impl<K, E, S, R> KochanekBartelsBuilder<K, E, S>
+where
+    E: DiscreteGenerator,
+    K: SortedGenerator<Output = R>,
+    S: DiscreteGenerator<Output = Tcb<R>>,
+    R: Real,
+{
+    /// Build a Kochanek-Bartels spline.
+    pub fn build(self) -> Result<KochanekBartels<K, E, S>, KochanekBartelsError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! All error types for Kochanek-Bartels splines.
+
+pub use crate::builder::{KnotElementInequality, TooFewElements};
+pub use crate::NotSorted;
+use core::{convert::From, fmt};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Errors which could occur when creating a Kochanek-Bartels spline.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum KochanekBartelsError {
+    /// Error returned if the elements are to few for a Kochanek-Bartels spline.
+    TooFewElements(TooFewElements),
+    /// Error returned if the number of knots and elements are not equal.
+    KnotElementInequality(KnotElementInequality),
+    /// Error returned if the number of parameters and elements are not equal.
+    ParameterElementInequality(ParameterElementInequality),
+    /// Error returned if knots are not sorted.
+    NotSorted(NotSorted),
+}
+
+impl fmt::Display for KochanekBartelsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KochanekBartelsError::TooFewElements(inner) => inner.fmt(f),
+            KochanekBartelsError::KnotElementInequality(inner) => inner.fmt(f),
+            KochanekBartelsError::ParameterElementInequality(inner) => inner.fmt(f),
+            KochanekBartelsError::NotSorted(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl From<TooFewElements> for KochanekBartelsError {
+    fn from(from: TooFewElements) -> Self {
+        KochanekBartelsError::TooFewElements(from)
+    }
+}
+
+impl From<KnotElementInequality> for KochanekBartelsError {
+    fn from(from: KnotElementInequality) -> Self {
+        KochanekBartelsError::KnotElementInequality(from)
+    }
+}
+
+impl From<ParameterElementInequality> for KochanekBartelsError {
+    fn from(from: ParameterElementInequality) -> Self {
+        KochanekBartelsError::ParameterElementInequality(from)
+    }
+}
+
+impl From<NotSorted> for KochanekBartelsError {
+    fn from(from: NotSorted) -> Self {
+        KochanekBartelsError::NotSorted(from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for KochanekBartelsError {}
+
+/// Error returned if the number of elements and the number of parameters are not matching.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ParameterElementInequality {
+    /// The number of elements found.
+    elements: usize,
+    /// The number of parameters found.
+    parameters: usize,
+}
+
+impl ParameterElementInequality {
+    /// Create a new error with the number of elements and parameters found.
+    pub fn new(elements: usize, parameters: usize) -> Self {
+        ParameterElementInequality {
+            elements,
+            parameters,
+        }
+    }
+}
+
+impl fmt::Display for ParameterElementInequality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "There has to be a parameter for each element, however we found {} elements and {} parameters.",
+            self.elements, self.parameters
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParameterElementInequality {}
@@ -0,0 +1,421 @@
+//! Kochanek-Bartels splines, also known as TCB splines.
+//!
+//! Kochanek-Bartels splines are piecewise cubic curves which pass through all of their elements,
+//! just like [Catmull-Rom splines]. However, the tangents at each element are shaped by three parameters:
+//! - The *tension* changes the length of the tangents. A tension of `1.0` results in tangents of
+//!   length zero, negative tensions result in rounder curves.
+//! - The *continuity* changes the sharpness of the curve at the element. With a continuity other
+//!   than `0.0`, the curve arrives at the element with a different tangent than it leaves with, such that
+//!   the curve has a corner there. Negative continuities result in corners pointing inwards,
+//!   positive continuities in corners pointing outwards.
+//! - The *bias* changes the direction of the tangents. A bias of `1.0` results in tangents pointing
+//!   from the previous element, a bias of `-1.0` results in tangents pointing to the next element.
+//!
+//! All three are given as [`Tcb`] and are usually in the range [-1.0,1.0].
+//! With all parameters being `0.0`, the curve is a Catmull-Rom spline.
+//! The easiest way to create one is by using the builder pattern of [`KochanekBartelsBuilder`].
+//!
+//! ```rust
+//! # use enterpolation::{kochanek::{KochanekBartels, KochanekBartelsError, Tcb}, Generator, Curve};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # fn main() -> Result<(), KochanekBartelsError> {
+//! let spline = KochanekBartels::builder()
+//!                 .elements([0.0,1.0,3.0,2.0])
+//!                 .knots([0.0,1.0,2.0,3.0])
+//!                 .tcb(Tcb::new(0.0,0.0,0.0))
+//!                 .build()?;
+//! assert_eq!(spline.domain(), [0.0,3.0]);
+//! assert_f64_near!(spline.gen(1.0), 1.0);
+//! // the same as a Catmull-Rom spline
+//! assert_f64_near!(spline.gen(1.5), 2.125);
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! The first and last element are missing a neighbour to calculate their tangent from.
+//! The missing neighbour is replaced by the element itself, which is the same as
+//! duplicating the first and last element.
+//!
+//! [Catmull-Rom splines]: crate::catmullrom
+//! [`KochanekBartelsBuilder`]: KochanekBartelsBuilder
+
+#[cfg(feature = "alloc")]
+use crate::base::knot_midpoints;
+use crate::builder::Unknown;
+use crate::utils::{hermite, hermite_segment};
+use crate::{Curve, Derivative, DiscreteGenerator, Generator, SortedGenerator};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+
+mod builder;
+pub use builder::{KochanekBartelsBuilder, KochanekBartelsDirector};
+
+pub mod error;
+pub use error::{
+    KnotElementInequality, KochanekBartelsError, NotSorted, ParameterElementInequality,
+    TooFewElements,
+};
+
+/// The tension, continuity and bias at an element of a Kochanek-Bartels spline.
+///
+/// See [kochanek module] for more information.
+///
+/// [kochanek module]: self
+#[derive(Debug, Default, Copy, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Tcb<R> {
+    /// The tension, which changes the length of the tangents.
+    pub tension: R,
+    /// The continuity, which changes the sharpness of the curve.
+    pub continuity: R,
+    /// The bias, which changes the direction of the tangents.
+    pub bias: R,
+}
+
+impl<R> Tcb<R> {
+    /// Create new parameters from the tension, continuity and bias, in this order.
+    pub const fn new(tension: R, continuity: R, bias: R) -> Self {
+        Tcb {
+            tension,
+            continuity,
+            bias,
+        }
+    }
+}
+
+/// Kochanek-Bartels spline.
+///
+/// See [kochanek module] for more information.
+///
+/// [kochanek module]: self
+#[derive(Debug, Copy, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KochanekBartels<K, E, S> {
+    elements: E,
+    knots: K,
+    parameters: S,
+}
+
+impl KochanekBartels<Unknown, Unknown, Unknown> {
+    /// Get the builder for a Kochanek-Bartels spline.
+    ///
+    /// The builder takes:
+    /// - elements with [`elements()`]
+    /// - knots with either [`knots()`] or [`equidistant()`]
+    /// - the parameters of each element with [`parameters()`] or the parameters of all elements with [`tcb()`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{kochanek::{KochanekBartels, KochanekBartelsError, Tcb}, Generator, Curve, Derivative};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), KochanekBartelsError> {
+    /// let spline = KochanekBartels::builder()
+    ///                 .elements([0.0,2.0,0.0])
+    ///                 .equidistant::<f64>()
+    ///                 .normalized()
+    ///                 .parameters([Tcb::default(), Tcb::new(0.0,-1.0,0.0), Tcb::default()])
+    ///                 .build()?;
+    /// assert_f64_near!(spline.gen(0.5), 2.0);
+    /// // the curve arrives at the peak with another tangent than it leaves with
+    /// assert!(spline.derivative(0.5 - 1e-9) > 0.0);
+    /// assert!(spline.derivative(0.5) < 0.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`elements()`]: KochanekBartelsBuilder::elements()
+    /// [`knots()`]: KochanekBartelsBuilder::knots()
+    /// [`equidistant()`]: KochanekBartelsBuilder::equidistant()
+    /// [`parameters()`]: KochanekBartelsBuilder::parameters()
+    /// [`tcb()`]: KochanekBartelsBuilder::tcb()
+    pub fn builder() -> KochanekBartelsBuilder<Unknown, Unknown, Unknown> {
+        KochanekBartelsBuilder::new()
+    }
+}
+
+impl<K, E, S, R> KochanekBartels<K, E, S>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    S: DiscreteGenerator<Output = Tcb<R>>,
+    R: Real,
+{
+    /// Create a Kochanek-Bartels spline with slice-like collections of elements, knots and parameters.
+    ///
+    /// Knots have to be sorted and there should be as many knots as elements.
+    /// There has to be at least as many parameters as elements, additional parameters are ignored.
+    /// Such, the same parameters can be used for all elements with [`repeat()`].
+    /// There has to be at least 2 elements.
+    ///
+    /// [`repeat()`]: crate::DiscreteGenerator::repeat()
+    pub fn new(elements: E, knots: K, parameters: S) -> Result<Self, KochanekBartelsError> {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()).into());
+        }
+        if knots.len() != elements.len() {
+            return Err(KnotElementInequality::new(elements.len(), knots.len()).into());
+        }
+        if parameters.len() < elements.len() {
+            return Err(ParameterElementInequality::new(elements.len(), parameters.len()).into());
+        }
+        Ok(KochanekBartels {
+            elements,
+            knots,
+            parameters,
+        })
+    }
+
+    /// Create a Kochanek-Bartels spline with slice-like collections of elements, knots and parameters.
+    ///
+    /// # Panics
+    ///
+    /// Knots should be in increasing order, there should be as many knots and at least as many
+    /// parameters as elements and there has to be at least *two* elements.
+    /// If any of these requirements are not uphold, the library may panic at any time.
+    pub fn new_unchecked(elements: E, knots: K, parameters: S) -> Self {
+        KochanekBartels {
+            elements,
+            knots,
+            parameters,
+        }
+    }
+
+    /// Returns the index of the segment in which the scalar lies, the position of the scalar
+    /// within the segment normalized to [0.0,1.0] and the width of the segment.
+    fn segment(&self, scalar: R) -> (usize, R, R) {
+        hermite_segment(&self.knots, scalar, 1, self.knots.len() - 1)
+    }
+}
+
+impl<K, E, S, R> KochanekBartels<K, E, S>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    S: DiscreteGenerator<Output = Tcb<R>>,
+    E::Output:
+        Add<Output = E::Output> + Sub<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    R: Real,
+{
+    /// Returns the tangents with which the curve arrives at and leaves the element with the given index,
+    /// in this order.
+    fn tangents(&self, index: usize) -> [E::Output; 2] {
+        let Tcb {
+            tension,
+            continuity,
+            bias,
+        } = self.parameters.gen(index);
+        let one = R::one();
+        let prev = index.saturating_sub(1);
+        let next = (index + 1).min(self.elements.len() - 1);
+        let element = self.elements.gen(index);
+        let incoming = element - self.elements.gen(prev);
+        let outgoing = self.elements.gen(next) - element;
+        // with all parameters being zero, the tangent is the one of a Catmull-Rom spline
+        let factor = (one - tension) / (self.knots.gen(next) - self.knots.gen(prev));
+        [
+            incoming * ((one - continuity) * (one + bias) * factor)
+                + outgoing * ((one + continuity) * (one - bias) * factor),
+            incoming * ((one + continuity) * (one + bias) * factor)
+                + outgoing * ((one - continuity) * (one - bias) * factor),
+        ]
+    }
+
+    /// Generate the value and its tangent, in this order.
+    ///
+    /// Both are calculated at once, which is cheaper than calculating them separately.
+    /// At elements with a continuity other than zero, the tangent with which the curve leaves
+    /// the element is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalar` is NaN or similar.
+    pub fn gen_with_tangent(&self, scalar: R) -> [E::Output; 2] {
+        let (index, t, width) = self.segment(scalar);
+        let start = self.elements.gen(index);
+        let end = self.elements.gen(index + 1);
+        let [_, start_tangent] = self.tangents(index);
+        let [end_tangent, _] = self.tangents(index + 1);
+        hermite([start, end], [start_tangent, end_tangent], t, width)
+    }
+}
+
+impl<K, E, S, R> Generator<R> for KochanekBartels<K, E, S>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    S: DiscreteGenerator<Output = Tcb<R>>,
+    E::Output:
+        Add<Output = E::Output> + Sub<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    R: Real,
+{
+    type Output = E::Output;
+    /// # Panics
+    ///
+    /// Panics if `scalar` is NaN or similar.
+    fn gen(&self, scalar: R) -> Self::Output {
+        let [value, _] = self.gen_with_tangent(scalar);
+        value
+    }
+}
+
+impl<K, E, S, R> Curve<R> for KochanekBartels<K, E, S>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    S: DiscreteGenerator<Output = Tcb<R>>,
+    E::Output:
+        Add<Output = E::Output> + Sub<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.knots.gen(0), self.knots.gen(self.knots.len() - 1)]
+    }
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        knot_midpoints(&self.knots, 0, self.knots.len() - 1)
+    }
+}
+
+impl<K, E, S, R> Derivative<R> for KochanekBartels<K, E, S>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    S: DiscreteGenerator<Output = Tcb<R>>,
+    E::Output:
+        Add<Output = E::Output> + Sub<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    R: Real,
+{
+    fn derivative(&self, scalar: R) -> Self::Output {
+        let [_, tangent] = self.gen_with_tangent(scalar);
+        tangent
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Curve, Derivative, Generator, Sorted};
+
+    #[test]
+    fn interpolates_elements() {
+        let elements = [0.0, 2.0, -1.0, 4.0, 3.0];
+        let knots = [0.0, 1.0, 3.0, 4.0, 7.0];
+        let spline = KochanekBartels::builder()
+            .elements(elements)
+            .knots(knots)
+            .parameters([
+                Tcb::new(0.5, 0.0, 0.0),
+                Tcb::new(-0.3, 0.2, 0.5),
+                Tcb::new(0.0, -0.7, 0.0),
+                Tcb::new(0.9, 0.0, -1.0),
+                Tcb::new(0.0, 1.0, 1.0),
+            ])
+            .build()
+            .unwrap();
+        assert_eq!(spline.domain(), [0.0, 7.0]);
+        for (knot, element) in knots.as_slice().iter().zip(elements.as_slice()) {
+            assert_f64_near!(spline.gen(*knot), *element);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "catmullrom")]
+    fn catmull_rom() {
+        use crate::catmullrom::CatmullRom;
+        let elements = [0.0, 2.0, -1.0, 4.0, 3.0];
+        let knots = [0.0, 1.0, 3.0, 4.0, 7.0];
+        let catmull_rom = CatmullRom::builder()
+            .elements(elements)
+            .knots(knots)
+            .tension(0.4)
+            .build()
+            .unwrap();
+        let spline = KochanekBartels::builder()
+            .elements(elements)
+            .knots(knots)
+            .tcb(Tcb::new(0.4, 0.0, 0.0))
+            .build()
+            .unwrap();
+        for x in [0.0, 0.5, 1.0, 2.0, 3.5, 5.0, 7.0] {
+            assert_f64_near!(spline.gen(x), catmull_rom.gen(x));
+            assert_f64_near!(spline.derivative(x), catmull_rom.derivative(x));
+        }
+    }
+
+    #[test]
+    fn parameters() {
+        let spline = |tcb| {
+            KochanekBartels::builder()
+                .elements([0.0, 1.0, 3.0])
+                .equidistant::<f64>()
+                .distance(0.0, 1.0)
+                .parameters([Tcb::default(), tcb, Tcb::default()])
+                .build()
+                .unwrap()
+        };
+        // full tension results in a vanishing tangent
+        assert_f64_near!(spline(Tcb::new(1.0, 0.0, 0.0)).derivative(1.0), 0.0);
+        // full bias points the tangent from the previous element
+        assert_f64_near!(spline(Tcb::new(0.0, 0.0, 1.0)).derivative(1.0), 1.0);
+        // negative bias points the tangent to the next element
+        assert_f64_near!(spline(Tcb::new(0.0, 0.0, -1.0)).derivative(1.0), 2.0);
+        // continuity results in different tangents before and after the element
+        let corner = spline(Tcb::new(0.0, 0.5, 0.0));
+        let [before, after] = corner.tangents(1);
+        assert_f64_near!(before, (0.5 * 1.0 + 1.5 * 2.0) / 2.0);
+        assert_f64_near!(after, (1.5 * 1.0 + 0.5 * 2.0) / 2.0);
+        assert_float_absolute_eq!(corner.derivative(1.0 - 1e-9), before, 1e-6);
+        assert_f64_near!(corner.derivative(1.0), after);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            KochanekBartels::builder()
+                .elements([1.0])
+                .knots([0.0])
+                .tcb(Tcb::default())
+                .build(),
+            Err(KochanekBartelsError::TooFewElements(_))
+        ));
+        assert!(matches!(
+            KochanekBartels::builder()
+                .elements([1.0, 2.0, 3.0])
+                .knots([0.0, 1.0])
+                .tcb(Tcb::default())
+                .build(),
+            Err(KochanekBartelsError::KnotElementInequality(_))
+        ));
+        assert!(matches!(
+            KochanekBartels::builder()
+                .elements([1.0, 2.0, 3.0])
+                .knots([0.0, 2.0, 1.0])
+                .tcb(Tcb::default())
+                .build(),
+            Err(KochanekBartelsError::NotSorted(_))
+        ));
+        assert!(matches!(
+            KochanekBartels::builder()
+                .elements([1.0, 2.0, 3.0])
+                .knots([0.0, 1.0, 2.0])
+                .parameters([Tcb::default(); 2])
+                .build(),
+            Err(KochanekBartelsError::ParameterElementInequality(_))
+        ));
+        assert!(matches!(
+            KochanekBartels::new(
+                [1.0, 2.0],
+                Sorted::new_unchecked([0.0, 1.0]),
+                [Tcb::new(0.0, 0.0, 0.0)]
+            ),
+            Err(KochanekBartelsError::ParameterElementInequality(_))
+        ));
+    }
+}
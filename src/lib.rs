@@ -1,5 +1,7 @@
 #![cfg_attr(any(not(doctest), all(feature = "linear", feature = "bspline")), doc = include_str!("../README.md"))]
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 #![warn(
     anonymous_parameters,
     missing_copy_implementations,
@@ -16,9 +18,13 @@
     variant_size_differences
 )]
 
+#[cfg(test)]
 #[macro_use]
 extern crate assert_float_eq;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(not(any(feature = "std", feature = "libm")))]
 compile_error!(
     "The enterpolation crate needs a library for floats. Please enable either \"std\" or \"libm\" as a feature."
@@ -28,23 +34,42 @@ compile_error!(
 pub mod bezier;
 #[cfg(feature = "bspline")]
 pub mod bspline;
+#[cfg(feature = "catmullrom")]
+pub mod catmullrom;
+#[cfg(feature = "chain")]
+pub mod chain;
+#[cfg(feature = "cubic")]
+pub mod cubic;
 pub mod easing;
+#[cfg(feature = "hermite")]
+pub mod hermite;
+#[cfg(feature = "kochanek")]
+pub mod kochanek;
 #[cfg(feature = "linear")]
 pub mod linear;
+#[cfg(feature = "motion")]
+pub mod motion;
+pub mod packed;
 pub mod utils;
 pub mod weights;
 
 mod base;
 mod builder;
 
-pub use topology_traits::Merge;
+#[cfg(feature = "derive")]
+pub use enterpolation_derive::Merge;
+pub use topology_traits::{Merge, QuasiMetric};
 
-#[cfg(feature = "std")]
-pub use base::DynSpace;
 pub use base::{
-    Clamp, Composite, ConstDiscreteGenerator, ConstEquidistant, ConstSpace, Curve,
-    DiscreteGenerator, Equidistant, Extract, Generator, NotSorted, Repeat, Slice, Sorted,
-    SortedGenerator, Space, Stack, Stepper, TransformInput, Wrap,
+    BinarySearch, Boundary, BoundaryMode, Bounded, ChebyshevNodes, Clamp, Combine, Composite,
+    ConstDiscreteGenerator, ConstEquidistant, ConstSpace, Curve, Derivative, Difference,
+    DiscreteGenerator, Equidistant, Extract, FiniteDifference, Generator, Geometric,
+    InterpolationSearch, Linspace, LogStepper, Negate, Nested, NotPositive, NotSorted, Observed,
+    Ops, OutOfDomain, Pow, Reflect, Repeat, Retime, RetimeMode, SamplePairs, Scale, SearchStrategy,
+    Select, Slice, Smooth, SoftMin, Sorted, SortedGenerator, Space, Speed, Stack, Stepper, Sum,
+    Surface, Take, TransformInput, Wrap, ZipWith,
 };
+#[cfg(feature = "alloc")]
+pub use base::{DynSpace, Lut, Pooled, PooledSpace};
 pub use easing::Identity;
 // pub use weights::{Homogeneous, Weighted, Weights, IntoWeight};
@@ -1,10 +1,16 @@
 //! Builder module for linear interpolations.
 
 use super::error::LinearError;
+#[cfg(feature = "alloc")]
+use super::NotSorted;
 use super::{KnotElementInequality, Linear, TooFewElements};
 use crate::builder::{Type, Unknown, WithWeight, WithoutWeight};
-use crate::weights::{IntoWeight, Weighted, Weights};
+use crate::weights::{IntoWeight, Rescaled, Weighted, Weights};
 use crate::{DiscreteGenerator, Equidistant, Generator, Identity, Sorted, SortedGenerator};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
 use core::marker::PhantomData;
 use core::ops::Mul;
 use num_traits::identities::Zero;
@@ -180,6 +186,29 @@ impl<F> LinearDirector<Unknown, Unknown, F, Unknown> {
         })
     }
 
+    /// Set the elements of the linear interpolation by collecting them into a vector.
+    ///
+    /// This is useful if the elements are only available as an iterator,
+    /// for example as the result of mapping another collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 elements are given.
+    ///
+    /// [`TooFewElements`]: super::error::TooFewElements
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    pub fn elements_from_iter<I>(
+        self,
+        iter: I,
+    ) -> Result<LinearDirector<Unknown, Vec<I::Item>, F, WithoutWeight>, TooFewElements>
+    where
+        I: IntoIterator,
+        I::Item: Clone,
+    {
+        self.elements(iter.into_iter().collect())
+    }
+
     /// Set the elements and their weights for this interpolation.
     ///
     /// Weights of `Zero` can achieve unwanted results as their corresponding elements are considered
@@ -239,6 +268,55 @@ impl<F> LinearDirector<Unknown, Unknown, F, Unknown> {
     }
 }
 
+impl<F> LinearDirector<Unknown, Unknown, F, Unknown> {
+    /// Set the knots and elements of the interpolation at once from pairs of a knot and its element.
+    ///
+    /// The pairs are collected and validated in one go, which is handy for streaming sources.
+    /// Collecting stops at the first knot which is smaller than or not comparable to its predecessor.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotSorted`] with the index of the first knot out of order.
+    /// Returns [`TooFewElements`] if not at least 2 pairs are given.
+    ///
+    /// [`NotSorted`]: super::error::LinearError
+    /// [`TooFewElements`]: super::error::LinearError
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    pub fn pairs<I, R, T>(
+        self,
+        pairs: I,
+    ) -> Result<LinearDirector<Sorted<Vec<R>>, Vec<T>, F, WithoutWeight>, LinearError>
+    where
+        I: IntoIterator<Item = (R, T)>,
+        R: PartialOrd + Copy,
+        T: Clone,
+    {
+        let pairs = pairs.into_iter();
+        let (lower, _) = pairs.size_hint();
+        let mut knots: Vec<R> = Vec::with_capacity(lower);
+        let mut elements = Vec::with_capacity(lower);
+        for (knot, element) in pairs {
+            if let Some(&last) = knots.as_slice().last() {
+                if let None | Some(Ordering::Greater) = last.partial_cmp(&knot) {
+                    return Err(NotSorted::new(knots.len()).into());
+                }
+            }
+            knots.push(knot);
+            elements.push(element);
+        }
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()).into());
+        }
+        Ok(LinearDirector {
+            knots: Sorted::new_unchecked(knots),
+            elements,
+            easing: self.easing,
+            _phantom: PhantomData,
+        })
+    }
+}
+
 impl<F> LinearBuilder<Unknown, Unknown, F, Unknown> {
     /// Set the elements of the linear interpolation.
     pub fn elements<E>(self, elements: E) -> LinearBuilder<Unknown, E, F, WithoutWeight>
@@ -252,6 +330,45 @@ impl<F> LinearBuilder<Unknown, Unknown, F, Unknown> {
         }
     }
 
+    /// Set the elements of the linear interpolation by collecting them into a vector.
+    ///
+    /// This is useful if the elements are only available as an iterator,
+    /// for example as the result of mapping another collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let readings = vec![[1.0f32, 2.0, 3.0], [4.0, 0.0, 1.0]];
+    /// let linear = Linear::builder()
+    ///                 .elements_from_iter(readings.iter().map(|&[x, _, z]| x + z))
+    ///                 .equidistant::<f32>()
+    ///                 .normalized()
+    ///                 .build()?;
+    /// assert_eq!(linear.gen(0.5), 4.5);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn elements_from_iter<I>(
+        self,
+        iter: I,
+    ) -> LinearBuilder<Unknown, Vec<I::Item>, F, WithoutWeight>
+    where
+        I: IntoIterator,
+        I::Item: Clone,
+    {
+        LinearBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements_from_iter(iter).map_err(|err| err.into())),
+        }
+    }
+
     /// Set the elements and their weights for this interpolation.
     ///
     /// Weights of `Zero` can achieve unwanted results as their corresponding elements are considered
@@ -301,6 +418,40 @@ impl<F> LinearBuilder<Unknown, Unknown, F, Unknown> {
             }),
         }
     }
+
+    /// Set the knots and elements of the interpolation at once from pairs of a knot and its element.
+    ///
+    /// The pairs are collected and validated in one go, which is handy for streaming sources.
+    /// Collecting stops at the first knot which is smaller than or not comparable to its predecessor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let readings = vec![(0.0, 10.0), (2.0, 14.0), (5.0, 8.0)];
+    /// let linear = Linear::builder()
+    ///                 .pairs(readings)
+    ///                 .build()?;
+    /// assert_eq!(linear.domain(), [0.0, 5.0]);
+    /// assert_f64_near!(linear.gen(1.0), 12.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn pairs<I, R, T>(self, pairs: I) -> LinearBuilder<Sorted<Vec<R>>, Vec<T>, F, WithoutWeight>
+    where
+        I: IntoIterator<Item = (R, T)>,
+        R: PartialOrd + Copy,
+        T: Clone,
+    {
+        LinearBuilder {
+            inner: self.inner.and_then(|director| director.pairs(pairs)),
+        }
+    }
 }
 
 impl<E, F, W> LinearDirector<Unknown, E, F, W> {
@@ -339,6 +490,41 @@ impl<E, F, W> LinearDirector<Unknown, E, F, W> {
         })
     }
 
+    /// Set the knots of the interpolation by the duration of each segment.
+    ///
+    /// The knots are the accumulated durations, starting at zero.
+    /// For example, the durations `[2.0, 3.0]` result in the knots `[0.0, 2.0, 5.0]`.
+    /// This is natural for timeline data, in which each segment is given by its length.
+    /// The amount of durations must be one less than the amount of elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of durations is not one less than the number of elements.
+    /// Returns [`NotSorted`] if any duration is negative.
+    ///
+    /// [`KnotElementInequality`]: super::error::LinearError
+    /// [`NotSorted`]:  super::error::LinearError
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    pub fn durations<D, R>(
+        self,
+        durations: D,
+    ) -> Result<LinearDirector<Sorted<Vec<R>>, E, F, W>, LinearError>
+    where
+        E: DiscreteGenerator,
+        D: DiscreteGenerator<Output = R>,
+        R: Real,
+    {
+        let mut knots = Vec::with_capacity(durations.len() + 1);
+        let mut time = R::zero();
+        knots.push(time);
+        for duration in durations.into_iter() {
+            time = time + duration;
+            knots.push(time);
+        }
+        self.knots(knots)
+    }
+
     /// Build an interpolation with equidistant knots.
     ///
     /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
@@ -391,6 +577,26 @@ impl<E, F, W> LinearBuilder<Unknown, E, F, W> {
         }
     }
 
+    /// Set the knots of the interpolation by the duration of each segment.
+    ///
+    /// The knots are the accumulated durations, starting at zero.
+    /// For example, the durations `[2.0, 3.0]` result in the knots `[0.0, 2.0, 5.0]`.
+    /// This is natural for timeline data, in which each segment is given by its length.
+    /// The amount of durations must be one less than the amount of elements.
+    #[cfg(feature = "alloc")]
+    pub fn durations<D, R>(self, durations: D) -> LinearBuilder<Sorted<Vec<R>>, E, F, W>
+    where
+        E: DiscreteGenerator,
+        D: DiscreteGenerator<Output = R>,
+        R: Real,
+    {
+        LinearBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.durations(durations)),
+        }
+    }
+
     /// Build an interpolation with equidistant knots.
     ///
     /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
@@ -479,6 +685,37 @@ where
     }
 }
 
+impl<K, A, E, F, W> LinearDirector<Sorted<K, A>, E, F, W> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<T>(self, strategy: T) -> LinearDirector<Sorted<K, T>, E, F, W> {
+        LinearDirector {
+            knots: self.knots.search(strategy),
+            elements: self.elements,
+            easing: self.easing,
+            _phantom: self._phantom,
+        }
+    }
+}
+
+impl<K, A, E, F, W> LinearBuilder<Sorted<K, A>, E, F, W> {
+    /// Set the strategy used to search through the knots.
+    ///
+    /// Per default binary search is used.
+    /// For huge amounts of near equidistant knots, [`InterpolationSearch`] may be faster.
+    ///
+    /// [`InterpolationSearch`]: crate::InterpolationSearch
+    pub fn search<T>(self, strategy: T) -> LinearBuilder<Sorted<K, T>, E, F, W> {
+        LinearBuilder {
+            inner: self.inner.map(|director| director.search(strategy)),
+        }
+    }
+}
+
 impl<K, E, F, W> LinearDirector<K, E, F, W>
 where
     K: SortedGenerator,
@@ -524,11 +761,106 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<K, E, F> LinearDirector<K, E, F, WithoutWeight>
+where
+    E: DiscreteGenerator,
+    E::Output: PartialOrd,
+    K: SortedGenerator,
+{
+    /// Drop all elements which are NaN together with their knots.
+    ///
+    /// This is meant for scalar data with gaps, like sensor logs, such that the interpolation
+    /// bridges the gaps instead of generating NaN. An element is considered to be NaN
+    /// if it is not comparable to itself.
+    ///
+    /// Afterwards the interpolation has less elements and knots. Gaps at the start or end of the data
+    /// shrink the domain. As dropping knots breaks equidistant spacing, the knots and elements
+    /// are always collected into vectors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 elements are left.
+    ///
+    /// [`TooFewElements`]: super::error::TooFewElements
+    #[allow(clippy::type_complexity)]
+    pub fn skip_nan(
+        self,
+    ) -> Result<
+        LinearDirector<Sorted<Vec<K::Output>>, Vec<E::Output>, F, WithoutWeight>,
+        TooFewElements,
+    > {
+        let mut knots = Vec::with_capacity(self.elements.len());
+        let mut elements = Vec::with_capacity(self.elements.len());
+        for index in 0..self.elements.len() {
+            let element = self.elements.gen(index);
+            if element.partial_cmp(&element).is_some() {
+                knots.push(self.knots.gen(index));
+                elements.push(element);
+            }
+        }
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()));
+        }
+        Ok(LinearDirector {
+            knots: Sorted::new_unchecked(knots),
+            elements,
+            easing: self.easing,
+            _phantom: self._phantom,
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, E, F> LinearBuilder<K, E, F, WithoutWeight>
+where
+    E: DiscreteGenerator,
+    E::Output: PartialOrd,
+    K: SortedGenerator,
+{
+    /// Drop all elements which are NaN together with their knots.
+    ///
+    /// This is meant for scalar data with gaps, like sensor logs, such that the interpolation
+    /// bridges the gaps instead of generating NaN. An element is considered to be NaN
+    /// if it is not comparable to itself.
+    ///
+    /// Afterwards the interpolation has less elements and knots. Gaps at the start or end of the data
+    /// shrink the domain. As dropping knots breaks equidistant spacing, the knots and elements
+    /// are always collected into vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([1.0, f64::NAN, 5.0])
+    ///                 .knots([0.0, 1.0, 2.0])
+    ///                 .skip_nan()
+    ///                 .build()?;
+    /// assert_f64_near!(linear.gen(1.0), 3.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn skip_nan(
+        self,
+    ) -> LinearBuilder<Sorted<Vec<K::Output>>, Vec<E::Output>, F, WithoutWeight> {
+        LinearBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.skip_nan().map_err(|err| err.into())),
+        }
+    }
+}
+
 impl<K, E, F> LinearDirector<K, E, F, WithoutWeight>
 where
     E: DiscreteGenerator,
     K: SortedGenerator,
-    E::Output: Merge<K::Output>,
     K::Output: Real,
 {
     /// Build a linear interpolation.
@@ -541,7 +873,6 @@ impl<K, E, F> LinearBuilder<K, E, F, WithoutWeight>
 where
     E: DiscreteGenerator,
     K: SortedGenerator,
-    E::Output: Merge<K::Output>,
     K::Output: Real,
 {
     /// Build a linear interpolation.
@@ -588,14 +919,250 @@ where
     }
 }
 
+impl<K, G, F> LinearDirector<K, Weights<G>, F, WithWeight>
+where
+    G: DiscreteGenerator,
+    G::Output: IntoWeight,
+    <G::Output as IntoWeight>::Weight: Real,
+{
+    /// Scale all weights such that the largest weight in absolute value is one.
+    ///
+    /// Scaling all weights by the same factor leaves a rational curve invariant,
+    /// as only the ratios between the weights matter.
+    /// Normalizing them avoids numerical problems with very large or very small weights.
+    /// See [`LinearBuilder::normalized_weights()`] for more information.
+    #[allow(clippy::type_complexity)]
+    pub fn normalized_weights(
+        self,
+    ) -> LinearDirector<K, Weights<Rescaled<G, <G::Output as IntoWeight>::Weight>>, F, WithWeight>
+    {
+        LinearDirector {
+            knots: self.knots,
+            elements: Weights::new(Rescaled::normalized(self.elements.inner())),
+            easing: self.easing,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<K, G, F> LinearBuilder<K, Weights<G>, F, WithWeight>
+where
+    G: DiscreteGenerator,
+    G::Output: IntoWeight,
+    <G::Output as IntoWeight>::Weight: Real,
+{
+    /// Scale all weights such that the largest weight in absolute value is one.
+    ///
+    /// Scaling all weights by the same factor leaves a rational curve invariant,
+    /// as only the ratios between the weights matter.
+    /// Normalizing them avoids numerical problems with very large or very small weights.
+    /// If all weights are zero, they are not scaled at all.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements_with_weights([(1.0,1e12),(2.0,4e12),(3.0,2e12)])
+    ///                 .normalized_weights()
+    ///                 .equidistant::<f64>()
+    ///                 .normalized()
+    ///                 .build()?;
+    /// assert_f64_near!(linear.gen(0.25), 1.8);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn normalized_weights(
+        self,
+    ) -> LinearBuilder<K, Weights<Rescaled<G, <G::Output as IntoWeight>::Weight>>, F, WithWeight>
+    {
+        LinearBuilder {
+            inner: self.inner.map(|director| director.normalized_weights()),
+        }
+    }
+}
+
+/// Collect elements into a builder of a linear interpolation with equidistant knots in [0.0,1.0].
+///
+/// The builder is ready to be built, errors like too few elements are returned by [`build()`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{linear::{Linear, LinearBuilder, LinearError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), LinearError> {
+/// let points = vec![0.0, 4.0, 2.0];
+/// let linear: Linear<_, _, _> = points.into_iter().collect::<LinearBuilder<_, _, _, _>>().build()?;
+/// assert_eq!(linear.domain(), [0.0, 1.0]);
+/// assert_f64_near!(linear.gen(0.25), 2.0);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: LinearBuilder::build()
+#[cfg(feature = "alloc")]
+impl<T, R> FromIterator<T> for LinearBuilder<Equidistant<R>, Vec<T>, Identity, WithoutWeight>
+where
+    T: Clone,
+    R: Real + FromPrimitive,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        LinearBuilder::new()
+            .elements_from_iter(iter)
+            .equidistant()
+            .normalized()
+    }
+}
+
 /// Type alias for weighted linear interpolations
 type WeightedLinear<K, G, F> = Weighted<Linear<K, Weights<G>, F>>;
 
 #[cfg(test)]
 mod test {
     use super::LinearBuilder;
+    #[cfg(feature = "alloc")]
+    use super::{Equidistant, NotSorted};
     // Homogeneous for creating Homogeneous, Generator for using .stack()
     use crate::{linear::LinearDirector, weights::Homogeneous, Generator};
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn durations() {
+        use crate::Curve;
+        let linear = LinearBuilder::new()
+            .elements([1.0, 3.0, 0.0])
+            .durations([2.0, 3.0])
+            .build()
+            .unwrap();
+        assert_eq!(linear.domain(), [0.0, 5.0]);
+        assert_f64_near!(linear.gen(2.0), 3.0);
+        assert_f64_near!(linear.gen(3.5), 1.5);
+        assert!(LinearBuilder::new()
+            .elements([1.0, 3.0, 0.0])
+            .durations([2.0, 3.0, 1.0])
+            .build()
+            .is_err());
+        assert!(LinearBuilder::new()
+            .elements([1.0, 3.0, 0.0])
+            .durations([2.0, -3.0])
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn from_iter() {
+        use crate::linear::{Linear, LinearError};
+        use crate::Curve;
+        let points = [[1.0, 2.0], [3.0, -2.0], [5.0, 0.0]];
+        let linear = LinearBuilder::new()
+            .elements_from_iter(points.as_slice().iter().map(|[x, y]| x * y))
+            .knots([0.0, 1.0, 3.0])
+            .build()
+            .unwrap();
+        assert_f64_near!(linear.gen(0.5), -2.0);
+        let collected: Linear<_, _, _> = points
+            .as_slice()
+            .iter()
+            .map(|[x, _]| *x)
+            .collect::<LinearBuilder<_, _, _, _>>()
+            .build()
+            .unwrap();
+        assert_eq!(collected.domain(), [0.0, 1.0]);
+        assert_f64_near!(collected.gen(0.75), 4.0);
+        assert!(matches!(
+            core::iter::once(1.0)
+                .collect::<LinearBuilder<Equidistant<f64>, _, _, _>>()
+                .build(),
+            Err(LinearError::TooFewElements(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn pairs() {
+        use crate::linear::LinearError;
+        use crate::Curve;
+        use alloc::string::ToString;
+        let stream = (0..5).map(|i| (i as f64 * 0.5, (i * i) as f64));
+        let linear = LinearBuilder::new().pairs(stream).build().unwrap();
+        assert_eq!(linear.domain(), [0.0, 2.0]);
+        assert_f64_near!(linear.gen(0.5), 1.0);
+        assert_f64_near!(linear.gen(1.25), 6.5);
+        // stops at the first knot out of order
+        let stream = [
+            (0.0, 1.0),
+            (1.0, 2.0),
+            (3.0, 0.0),
+            (2.0, 5.0),
+            (f64::NAN, 0.0),
+        ]
+        .into_iter()
+        .inspect(|(knot, _)| assert!(!knot.is_nan()));
+        match LinearBuilder::new().pairs(stream).build() {
+            Err(LinearError::NotSorted(err)) => {
+                assert_eq!(err.to_string(), NotSorted::new(3).to_string())
+            }
+            _ => panic!("pairs out of order have to be rejected"),
+        }
+        // knots which are not comparable are rejected as well
+        let stream = [(0.0, 1.0), (1.0, 2.0), (f64::NAN, 0.0)];
+        match LinearBuilder::new().pairs(stream).build() {
+            Err(LinearError::NotSorted(err)) => {
+                assert_eq!(err.to_string(), NotSorted::new(2).to_string())
+            }
+            _ => panic!("NaN knots have to be rejected"),
+        }
+        assert!(matches!(
+            LinearBuilder::new().pairs([(0.0, 1.0)]).build(),
+            Err(LinearError::TooFewElements(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn skip_nan() {
+        use crate::linear::LinearError;
+        use crate::Curve;
+        let linear = LinearBuilder::new()
+            .elements([1.0, 2.0, f64::NAN, f64::NAN, 6.0, 4.0])
+            .knots([0.0, 1.0, 2.0, 3.0, 5.0, 6.0])
+            .skip_nan()
+            .build()
+            .unwrap();
+        assert_eq!(linear.domain(), [0.0, 6.0]);
+        // the gap between 1.0 and 5.0 is bridged
+        for (input, output) in [(1.0, 2.0), (2.0, 3.0), (3.5, 4.5), (5.5, 5.0)] {
+            assert_f64_near!(linear.gen(input), output);
+        }
+        // equidistant knots are kept at their position, gaps at the border shrink the domain
+        let linear = LinearBuilder::new()
+            .elements([f64::NAN, 2.0, f64::NAN, 4.0, 8.0])
+            .equidistant::<f64>()
+            .domain(0.0, 4.0)
+            .skip_nan()
+            .build()
+            .unwrap();
+        assert_eq!(linear.domain(), [1.0, 4.0]);
+        assert_f64_near!(linear.gen(2.0), 3.0);
+        assert_f64_near!(linear.gen(3.5), 6.0);
+        assert!(matches!(
+            LinearBuilder::new()
+                .elements([f64::NAN, 2.0, f64::NAN])
+                .knots([0.0, 1.0, 2.0])
+                .skip_nan()
+                .build(),
+            Err(LinearError::TooFewElements(_))
+        ));
+    }
+
     #[test]
     fn building_weights() {
         LinearBuilder::new()
@@ -627,6 +1194,30 @@ mod test {
             .unwrap();
     }
 
+    #[test]
+    fn normalized_weights() {
+        // scaling also keeps points at infinity in place
+        let elements = [
+            Homogeneous::weighted_unchecked(1.0, 0.5),
+            Homogeneous::weighted_unchecked(2.0, 8.0),
+            Homogeneous::infinity(3.0),
+        ];
+        let raw = LinearBuilder::new()
+            .elements_with_weights(elements)
+            .knots([0.0, 1.0, 2.0])
+            .build()
+            .unwrap();
+        let normalized = LinearBuilder::new()
+            .elements_with_weights(elements)
+            .normalized_weights()
+            .knots([0.0, 1.0, 2.0])
+            .build()
+            .unwrap();
+        for x in [0.0, 0.3, 1.0, 1.5, 1.9] {
+            assert_f64_near!(raw.gen(x), normalized.gen(x));
+        }
+    }
+
     #[test]
     fn builder_errors() {
         assert!(LinearBuilder::new()
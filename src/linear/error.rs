@@ -1,6 +1,6 @@
 //! All error types for linear interpolation.
 
-pub use crate::builder::TooFewElements;
+pub use crate::builder::{KnotElementInequality, TooFewElements};
 pub use crate::NotSorted;
 use core::{convert::From, fmt};
 
@@ -49,33 +49,3 @@ impl From<NotSorted> for LinearError {
 
 #[cfg(feature = "std")]
 impl Error for LinearError {}
-
-/// Error returned if the number of elements and the number of knots are not matching.
-#[derive(Debug, Copy, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-pub struct KnotElementInequality {
-    /// The number of elements found.
-    elements: usize,
-    /// The number of knots found.
-    knots: usize,
-}
-
-impl fmt::Display for KnotElementInequality {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "There has to be as many knots as elements, however we found {} elements and {} knots.",
-            self.elements, self.knots
-        )
-    }
-}
-
-#[cfg(feature = "std")]
-impl Error for KnotElementInequality {}
-
-impl KnotElementInequality {
-    /// Create a new error with the number of elements and knots found.
-    pub fn new(elements: usize, knots: usize) -> Self {
-        KnotElementInequality { elements, knots }
-    }
-}
@@ -42,12 +42,25 @@
 //! [`easing()`]: LinearBuilder::easing()
 //! [`equidistant_unchecked()`]: Linear::equidistant_unchecked()
 
+#[cfg(feature = "alloc")]
+use crate::base::knot_midpoints;
 use crate::builder::Unknown;
-use crate::{ConstEquidistant, Curve, DiscreteGenerator, Generator, Identity, SortedGenerator};
+use crate::easing::Dwell;
+use crate::{
+    ConstEquidistant, Curve, Derivative, DiscreteGenerator, Generator, Identity, Nested,
+    SortedGenerator, Surface,
+};
+#[cfg(feature = "alloc")]
+use crate::{Equidistant, Sorted};
 use num_traits::real::Real;
+#[cfg(feature = "alloc")]
+use num_traits::FromPrimitive;
 use topology_traits::Merge;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::fmt::Debug;
+use core::ops::{Mul, Sub};
 
 // mod hyper;
 mod builder;
@@ -56,12 +69,43 @@ pub use builder::{LinearBuilder, LinearDirector};
 pub mod error;
 pub use error::{KnotElementInequality, LinearError, NotSorted, TooFewElements};
 
+/// Linearly interpolate between two elements.
+///
+/// This is the same calculation a [`Linear`] curve does between two of its elements,
+/// such that the results are consistent with a built linear interpolation.
+/// Use this function if you only need to interpolate between two elements once
+/// and do not want to construct a curve.
+/// In contrast to [`utils::lerp()`], the elements only have to implement [`Merge`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::linear::lerp;
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// assert_f64_near!(lerp(2.0, 6.0, 0.25), 3.0);
+/// assert_f64_near!(lerp(2.0, 6.0, 1.5), 8.0);
+/// ```
+///
+/// [`utils::lerp()`]: crate::utils::lerp()
+pub fn lerp<T, R>(first: T, second: T, factor: R) -> T
+where
+    T: Merge<R>,
+    R: Real,
+{
+    first.merge(second, factor)
+}
+
 /// Linear Interpolation.
 ///
 /// See [linear module] for more information.
 ///
+/// The curve implements [`Hash`] and [`Eq`] if its elements, knots and easing do, such that it can be used as key for caching.
+/// As floats do not implement [`Hash`], they have to be wrapped in a hashable type,
+/// for example `OrderedFloat` of the crate `ordered-float`.
+///
 /// [linear module]: self
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Linear<K, E, F> {
     elements: E,
@@ -128,6 +172,91 @@ where
     }
 }
 
+impl<R, K, E, F> Generator<(R, R)> for Nested<Linear<K, E, F>>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Generator<R>,
+    <E::Output as Generator<R>>::Output: Merge<R> + Debug,
+    F: Curve<R, Output = R>,
+    R: Real + Debug,
+{
+    type Output = <E::Output as Generator<R>>::Output;
+    /// # Panics
+    ///
+    /// Panics if `outer` is NaN or similar.
+    fn gen(&self, (outer, inner): (R, R)) -> Self::Output {
+        let linear = self.inner();
+        let (min_index, max_index, factor) = linear.knots.upper_border(outer);
+        // as we are linear, evaluating the inner curves first is the same as evaluating the outer curve first
+        let min_point = linear.elements.gen(min_index).gen(inner);
+        let max_point = linear.elements.gen(max_index).gen(inner);
+        min_point.merge(max_point, linear.easing.gen(factor))
+    }
+}
+
+impl<R, K, E, F> Surface<R> for Nested<Linear<K, E, F>>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Curve<R>,
+    <E::Output as Generator<R>>::Output: Merge<R> + Debug,
+    F: Curve<R, Output = R>,
+    R: Real + Debug,
+{
+    /// Return the domain of the surface.
+    ///
+    /// The domain of `u` is given by the knots, the domain of `v` by the first curve.
+    /// All curves are expected to share the same domain.
+    fn domain(&self) -> [[R; 2]; 2] {
+        let linear = self.inner();
+        [
+            [linear.knots.first().unwrap(), linear.knots.last().unwrap()],
+            linear.elements.first().unwrap().domain(),
+        ]
+    }
+}
+
+impl<R, K, E> Linear<K, E, Identity>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Mul<R, Output = E::Output> + Sub<Output = E::Output> + Copy + Debug,
+    R: Real + Debug,
+{
+    /// Generate the value and its tangent, in this order.
+    ///
+    /// Both are calculated at once, which is cheaper than calculating them separately.
+    /// At a knot, the tangent of the segment after the knot is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalar` is NaN or similar.
+    pub fn gen_with_tangent(&self, scalar: R) -> [E::Output; 2] {
+        let (min_index, max_index, factor) = self.knots.upper_border(scalar);
+        let min_point = self.elements.gen(min_index);
+        let max_point = self.elements.gen(max_index);
+        let span = self.knots.gen(max_index) - self.knots.gen(min_index);
+        [
+            min_point.merge(max_point, factor),
+            (max_point - min_point) * span.recip(),
+        ]
+    }
+}
+
+impl<R, K, E> Derivative<R> for Linear<K, E, Identity>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Mul<R, Output = E::Output> + Sub<Output = E::Output> + Copy + Debug,
+    R: Real + Debug,
+{
+    fn derivative(&self, scalar: R) -> Self::Output {
+        let [_, tangent] = self.gen_with_tangent(scalar);
+        tangent
+    }
+}
+
 impl<R, K, E, F> Curve<R> for Linear<K, E, F>
 where
     K: SortedGenerator<Output = R>,
@@ -139,6 +268,10 @@ where
     fn domain(&self) -> [R; 2] {
         [self.knots.first().unwrap(), self.knots.last().unwrap()]
     }
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        knot_midpoints(&self.knots, 0, self.knots.len() - 1)
+    }
 }
 
 impl<K, E, F> Linear<K, E, F>
@@ -146,7 +279,6 @@ where
     K: SortedGenerator,
     K::Output: Real,
     E: DiscreteGenerator,
-    E::Output: Merge<K::Output>,
 {
     /// Create a linear interpolation with slice-like collections of elements and knots.
     ///
@@ -171,7 +303,6 @@ impl<K, E, F> Linear<K, E, F>
 where
     E: DiscreteGenerator,
     K: SortedGenerator,
-    E::Output: Merge<K::Output>,
     K::Output: Real,
 {
     /// Create a linear interpolation with slice-like collections of elements and knots.
@@ -190,6 +321,47 @@ where
     }
 }
 
+impl<K, E, F> Linear<K, E, F> {
+    /// Returns the elements of the curve.
+    ///
+    /// As every collection of elements is a [`DiscreteGenerator`], they can be read back with its
+    /// [`iter()`] method, for example to export the curve into another format.
+    ///
+    /// [`iter()`]: DiscreteGenerator::iter()
+    pub fn elements(&self) -> &E {
+        &self.elements
+    }
+
+    /// Returns the knots of the curve.
+    ///
+    /// Knots which are computed on demand, like [`Equidistant`], can be read back with the
+    /// [`iter()`] method of [`DiscreteGenerator`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, DiscreteGenerator};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .equidistant::<f64>()
+    ///                 .normalized()
+    ///                 .build()?;
+    /// let knots: Vec<f64> = linear.knots().iter().collect();
+    /// assert_eq!(knots, vec![0.0,0.5,1.0]);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Equidistant`]: crate::Equidistant
+    /// [`iter()`]: DiscreteGenerator::iter()
+    pub fn knots(&self) -> &K {
+        &self.knots
+    }
+}
+
 impl<R, T, const N: usize> Linear<ConstEquidistant<R, N>, [T; N], Identity> {
     /// Create a linear interpolation with an array of elements.
     ///
@@ -207,6 +379,83 @@ impl<R, T, const N: usize> Linear<ConstEquidistant<R, N>, [T; N], Identity> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<R, T, F> Linear<Sorted<Vec<R>>, Vec<T>, F>
+where
+    R: Real,
+{
+    /// Append an element at the end of the curve, with its knot `delta` after the last knot.
+    ///
+    /// The domain of the curve gets extended by `delta` while the curve stays the same within its old domain.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotSorted`] if `delta` is negative or NaN. In this case the curve is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let mut path = Linear::builder()
+    ///                 .elements(vec![0.0,5.0])
+    ///                 .knots(vec![0.0,1.0])
+    ///                 .build()?;
+    /// path.append_point(3.0, 0.5)?;
+    /// assert_eq!(path.domain(), [0.0,1.5]);
+    /// assert_f64_near!(path.gen(1.25), 4.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn append_point(&mut self, element: T, delta: R) -> Result<(), NotSorted> {
+        let last = self.knots.gen(self.knots.len() - 1);
+        self.knots.push(last + delta)?;
+        self.elements.push(element);
+        Ok(())
+    }
+
+    /// Insert an element at the start of the curve, with its knot `delta` before the first knot.
+    ///
+    /// The domain of the curve gets extended by `delta` while the curve stays the same within its old domain.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotSorted`] if `delta` is negative or NaN. In this case the curve is left unchanged.
+    pub fn prepend_point(&mut self, element: T, delta: R) -> Result<(), NotSorted> {
+        let first = self.knots.gen(0);
+        self.knots.push_front(first - delta)?;
+        self.elements.insert(0, element);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R, T, F> Linear<Equidistant<R>, Vec<T>, F>
+where
+    R: Real + FromPrimitive,
+{
+    /// Append an element at the end of the curve, one knot distance after the last knot.
+    ///
+    /// The domain of the curve gets extended by the distance between two knots
+    /// while the curve stays the same within its old domain.
+    pub fn append_point(&mut self, element: T) {
+        self.knots.extend_back();
+        self.elements.push(element);
+    }
+
+    /// Insert an element at the start of the curve, one knot distance before the first knot.
+    ///
+    /// The domain of the curve gets extended by the distance between two knots
+    /// while the curve stays the same within its old domain.
+    pub fn prepend_point(&mut self, element: T) {
+        self.knots.extend_front();
+        self.elements.insert(0, element);
+    }
+}
+
 /// An array-allocated, const-creatable, linear interpolation with equidistant knot distribution.
 ///
 /// This alias is used for convenience to help create constant curves.
@@ -215,10 +464,146 @@ impl<R, T, const N: usize> Linear<ConstEquidistant<R, N>, [T; N], Identity> {
 pub type ConstEquidistantLinear<R, T, const N: usize> =
     Linear<ConstEquidistant<R, N>, [T; N], Identity>;
 
+/// A linear interpolation which holds each element for a fraction of its segment before ramping to the next one.
+///
+/// The fraction is given by the [`Dwell`] easing, which is set with the [`easing()`] method of the builder.
+/// A dwell of 0.0 results in a linear interpolation, a dwell of 1.0 in a step function.
+///
+/// ```rust
+/// # use enterpolation::{linear::{Linear, StepLinear, LinearError}, easing::Dwell, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), LinearError> {
+/// let dimmer: StepLinear<_, _, f64> = Linear::builder()
+///                 .elements([0.0,1.0])
+///                 .knots([0.0,1.0])
+///                 .easing(Dwell::new(0.5))
+///                 .build()?;
+/// assert_f64_near!(dimmer.gen(0.25), 0.0);
+/// assert_f64_near!(dimmer.gen(0.75), 0.5);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// **Because this is an alias, not all its methods are listed here. See the [`Linear`](crate::linear::Linear) type too.**
+///
+/// [`easing()`]: LinearBuilder::easing()
+pub type StepLinear<K, E, R> = Linear<K, E, Dwell<R>>;
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::Curve;
+    use crate::{Curve, Stepper};
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+
+    #[test]
+    fn units() {
+        use uom::si::f64::Length;
+        use uom::si::length::{kilometer, meter};
+        let lin = Linear::builder()
+            .elements([Length::new::<meter>(100.0), Length::new::<kilometer>(2.0)])
+            .knots([0.0, 1.0])
+            .build()
+            .unwrap();
+        // the output is still a length
+        let result: Length = lin.gen(0.5);
+        assert_f64_near!(result.get::<meter>(), 1050.0);
+    }
+
+    #[test]
+    fn premultiplied_alpha() {
+        use crate::weights::Homogeneous;
+        use palette::LinSrgb;
+        // using the alpha as weight interpolates the colors in premultiplied space
+        let lin = Linear::builder()
+            .elements_with_weights([
+                (LinSrgb::new(1.0, 0.0, 0.0), 1.0),
+                (LinSrgb::new(0.0, 0.0, 1.0), 0.25),
+            ])
+            .knots([0.0, 1.0])
+            .build()
+            .unwrap();
+        let color: LinSrgb<f64> = lin.gen(0.5);
+        assert_f64_near!(color.red, 0.8);
+        assert_f64_near!(color.green, 0.0);
+        assert_f64_near!(color.blue, 0.2);
+        assert_f64_near!(lin.gen(1.0).blue, 1.0);
+        // a weight of zero given as tuple is a point at infinity, so fully transparent colors
+        // have to be premultiplied explicitly; they then do not bleed into their neighbours
+        let lin = Linear::builder()
+            .elements_with_weights([
+                Homogeneous::new(LinSrgb::new(1.0, 0.0, 0.0)),
+                Homogeneous::weighted_unchecked(LinSrgb::new(0.0, 0.0, 1.0), 0.0),
+            ])
+            .knots([0.0, 1.0])
+            .build()
+            .unwrap();
+        let color: LinSrgb<f64> = lin.gen(0.5);
+        assert_f64_near!(color.red, 1.0);
+        assert_f64_near!(color.blue, 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    fn complex() {
+        use num_complex::Complex;
+        let start = Complex::new(1.0, -2.0);
+        let end = Complex::new(-3.0, 4.0);
+        let lin = Linear::builder()
+            .elements([start, end])
+            .knots([0.0, 1.0])
+            .build()
+            .unwrap();
+        // real and imaginary part are interpolated independently
+        for t in Stepper::normalized(11) {
+            let result: Complex<f64> = lin.gen(t);
+            assert_f64_near!(result.re, start.re * (1.0 - t) + end.re * t);
+            assert_f64_near!(result.im, start.im * (1.0 - t) + end.im * t);
+        }
+        assert_eq!(
+            lin.sample_bounds(11),
+            [Complex::new(-3.0, -2.0), Complex::new(1.0, 4.0)]
+        );
+    }
+
+    #[test]
+    fn step_linear() {
+        let elements = [1.0, 4.0, -2.0, 0.0];
+        let knots = [0.0, 1.0, 3.0, 4.0];
+        let linear = Linear::builder()
+            .elements(elements)
+            .knots(knots)
+            .build()
+            .unwrap();
+        let ramp: StepLinear<_, _, f64> = Linear::builder()
+            .elements(elements)
+            .knots(knots)
+            .easing(Dwell::new(0.0))
+            .build()
+            .unwrap();
+        let hold: StepLinear<_, _, f64> = Linear::builder()
+            .elements(elements)
+            .knots(knots)
+            .easing(Dwell::new(1.0))
+            .build()
+            .unwrap();
+        for input in Stepper::<f64>::normalized(41).map(|t| t * 4.0) {
+            assert_f64_near!(ramp.gen(input), linear.gen(input));
+            // value of the last knot which is not bigger than the input
+            let index = knots.as_slice().partition_point(|&knot| knot <= input) - 1;
+            assert_f64_near!(hold.gen(input), elements[index]);
+        }
+        let half: StepLinear<_, _, f64> = Linear::builder()
+            .elements(elements)
+            .knots(knots)
+            .easing(Dwell::new(0.5))
+            .build()
+            .unwrap();
+        assert_f64_near!(half.gen(1.8), 4.0);
+        assert_f64_near!(half.gen(2.5), 1.0);
+    }
 
     #[test]
     fn linear_equidistant() {
@@ -230,9 +615,9 @@ mod test {
             .unwrap();
         let expected = [20.0, 60.0, 100.0, 50.0, 0.0, 100.0, 200.0];
         let mut iter = lin.take(expected.len());
-        for i in 0..expected.len() {
+        for expect in expected {
             let val = iter.next().unwrap();
-            assert_f64_near!(val, expected[i]);
+            assert_f64_near!(val, expect);
         }
     }
 
@@ -246,9 +631,9 @@ mod test {
             .unwrap();
         let expected = [20.0, 60.0, 100.0, 50.0, 0.0, 100.0, 200.0];
         let mut iter = lin.take(expected.len());
-        for i in 0..expected.len() {
+        for expect in expected {
             let val = iter.next().unwrap();
-            assert_f64_near!(val, expected[i]);
+            assert_f64_near!(val, expect);
         }
     }
 
@@ -284,9 +669,197 @@ mod test {
         // const LIN : Linear<f64,f64,ConstEquidistant<f64>,CollectionWrapper<[f64;4],f64>> = Linear::new_equidistant_unchecked([20.0,100.0,0.0,200.0]);
         let expected = [20.0, 60.0, 100.0, 50.0, 0.0, 100.0, 200.0];
         let mut iter = LIN.take(expected.len());
-        for i in 0..expected.len() {
+        for expect in expected {
             let val = iter.next().unwrap();
-            assert_f64_near!(val, expected[i]);
+            assert_f64_near!(val, expect);
+        }
+    }
+
+    #[test]
+    fn raw_lerp() {
+        let linear = Linear::builder()
+            .elements([2.0, 6.0])
+            .knots([0.0, 1.0])
+            .build()
+            .unwrap();
+        for input in Stepper::new(11, -1.0, 2.0) {
+            assert_f64_near!(lerp(2.0, 6.0, input), linear.gen(input));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash() {
+        use std::collections::hash_map::RandomState;
+        use std::collections::HashMap;
+        use std::hash::BuildHasher;
+        let first: Linear<ConstEquidistant<f64, 3>, _, _> =
+            Linear::equidistant_unchecked([1, 5, 3]);
+        let second: Linear<ConstEquidistant<f64, 3>, _, _> =
+            Linear::equidistant_unchecked([1, 5, 3]);
+        let other: Linear<ConstEquidistant<f64, 3>, _, _> =
+            Linear::equidistant_unchecked([1, 5, 4]);
+        let state = RandomState::new();
+        assert_eq!(state.hash_one(first), state.hash_one(second));
+        assert_ne!(state.hash_one(first), state.hash_one(other));
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        // curves can be used as keys
+        let mut cache = HashMap::new();
+        cache.insert(first, "first");
+        assert_eq!(cache.get(&second), Some(&"first"));
+        assert_eq!(cache.get(&other), None);
+    }
+
+    #[cfg(feature = "bezier")]
+    #[test]
+    fn morph() {
+        use crate::bezier::Bezier;
+        let start = Bezier::builder()
+            .elements([0.0, 4.0, 0.0])
+            .normalized::<f64>()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let end = Bezier::builder()
+            .elements([2.0, 0.0, 1.0])
+            .normalized::<f64>()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let morph = Nested::new(
+            Linear::builder()
+                .elements([start, end])
+                .knots([0.0, 2.0])
+                .build()
+                .unwrap(),
+        );
+        // the shape at time 0.5 is the bezier curve with the elements merged
+        let expected = Bezier::builder()
+            .elements([0.5, 3.0, 0.25])
+            .normalized::<f64>()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        for (inner, value) in Stepper::normalized(11).zip(expected.take(11)) {
+            assert_f64_near!(morph.gen((0.5, inner)), value);
+        }
+        assert_f64_near!(morph.gen((0.0, 0.5)), start.gen(0.5));
+        assert_f64_near!(morph.gen((2.0, 0.5)), end.gen(0.5));
+    }
+
+    #[test]
+    #[cfg(feature = "bezier")]
+    fn surface() {
+        use crate::bezier::Bezier;
+        fn corners<S: Surface<f64>>(surface: S) -> [S::Output; 4] {
+            let [[u_start, u_end], [v_start, v_end]] = surface.domain();
+            [
+                surface.gen((u_start, v_start)),
+                surface.gen((u_start, v_end)),
+                surface.gen((u_end, v_start)),
+                surface.gen((u_end, v_end)),
+            ]
+        }
+        let start = Bezier::builder()
+            .elements([0.0, 4.0, 1.0])
+            .normalized::<f64>()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let end = Bezier::builder()
+            .elements([2.0, 0.0, 3.0])
+            .normalized::<f64>()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let surface = Nested::new(
+            Linear::builder()
+                .elements([start, end])
+                .knots([-1.0, 2.0])
+                .build()
+                .unwrap(),
+        );
+        let domain = surface.domain();
+        assert_f64_near!(domain[0][0], -1.0);
+        assert_f64_near!(domain[0][1], 2.0);
+        assert_f64_near!(domain[1][0], 0.0);
+        assert_f64_near!(domain[1][1], 1.0);
+        let values = corners(&surface);
+        let expected = [0.0, 1.0, 2.0, 3.0];
+        for (value, result) in values.as_slice().iter().zip(expected) {
+            assert_f64_near!(*value, result);
+        }
+    }
+
+    #[test]
+    fn tangent() {
+        let lin = Linear::builder()
+            .elements([20.0, 100.0, 0.0, 200.0])
+            .knots([0.0, 1.0, 3.0, 3.5])
+            .build()
+            .unwrap();
+        let step = 1e-6;
+        for input in [-0.5, 0.3, 0.9, 1.5, 2.2, 3.2, 4.0] {
+            let [value, tangent] = lin.gen_with_tangent(input);
+            assert_f64_near!(value, lin.gen(input));
+            let expected = (lin.gen(input + step) - lin.gen(input - step)) / (2.0 * step);
+            assert_float_absolute_eq!(tangent, expected, 1e-6);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn append_point() {
+        let mut linear = Linear::builder()
+            .elements(vec![0.0, 5.0, 3.0])
+            .knots(vec![0.0, 1.0, 2.0])
+            .build()
+            .unwrap();
+        let before = linear.clone();
+        linear.append_point(7.0, 0.5).unwrap();
+        linear.prepend_point(-1.0, 2.0).unwrap();
+        assert_eq!(linear.domain(), [-2.0, 2.5]);
+        for input in Stepper::<f64>::new(21, 0.0, 2.0) {
+            assert_f64_near!(linear.gen(input), before.gen(input));
+        }
+        assert_f64_near!(linear.gen(2.25), 5.0);
+        assert_f64_near!(linear.gen(-1.0), -0.5);
+        // knots would not be sorted anymore
+        assert!(linear.append_point(1.0, -0.5).is_err());
+        assert!(linear.prepend_point(1.0, f64::NAN).is_err());
+        assert_eq!(linear.domain(), [-2.0, 2.5]);
+
+        let mut linear = Linear::builder()
+            .elements(vec![0.0, 5.0, 3.0])
+            .equidistant::<f64>()
+            .normalized()
+            .build()
+            .unwrap();
+        let before = linear.clone();
+        linear.append_point(7.0);
+        linear.prepend_point(-1.0);
+        assert_eq!(linear.domain(), [-0.5, 1.5]);
+        for input in Stepper::<f64>::normalized(21) {
+            assert_f64_near!(linear.gen(input), before.gen(input));
+        }
+        assert_f64_near!(linear.gen(1.5), 7.0);
+        assert_f64_near!(linear.gen(-0.5), -1.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let linear = Linear::builder()
+            .elements([1.0, 3.0, -2.0])
+            .knots([0.0, 0.5, 2.0])
+            .build()
+            .unwrap();
+        let json = serde_json::to_string(&linear).unwrap();
+        let restored: Linear<Sorted<[f64; 3]>, [f64; 3], Identity> =
+            serde_json::from_str(&json).unwrap();
+        for t in Stepper::new(11, 0.0, 2.0) {
+            assert_eq!(restored.gen(t), linear.gen(t));
         }
     }
 }
@@ -0,0 +1,36 @@
+//! All error types for motion profiles.
+
+use core::fmt;
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Errors which could occur when creating a motion profile.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum MotionError {
+    /// Error returned if the maximal velocity is not positive and finite.
+    InvalidVelocity,
+    /// Error returned if the maximal acceleration is not positive and finite.
+    InvalidAcceleration,
+    /// Error returned if the maximal jerk is not positive and finite.
+    InvalidJerk,
+}
+
+impl fmt::Display for MotionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limit = match self {
+            MotionError::InvalidVelocity => "velocity",
+            MotionError::InvalidAcceleration => "acceleration",
+            MotionError::InvalidJerk => "jerk",
+        };
+        write!(
+            f,
+            "The maximal {} of a motion profile has to be positive and finite.",
+            limit
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for MotionError {}
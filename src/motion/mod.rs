@@ -0,0 +1,489 @@
+//! Motion profiles.
+//!
+//! Motion profiles describe the position over time of a motion from rest to rest between two positions,
+//! such that the velocity, acceleration and possibly the jerk stay within given limits.
+//! They are the building block of the movement of machines like robot arms or 3D printers.
+//!
+//! A [`TrapezoidalProfile`] limits velocity and acceleration. Its velocity, plotted over time,
+//! forms a trapezoid. An [`SCurveProfile`] additionally limits the jerk, such that the acceleration
+//! changes continuously and the position, plotted over time, forms an S-shaped curve.
+//!
+//! Both profiles are curves mapping time to position, whose domain starts at zero and ends with the
+//! duration of the motion. They implement [`Derivative`], such that their velocity is also available.
+//!
+//! ```rust
+//! # use enterpolation::{motion::{TrapezoidalProfile, MotionError}, Generator, Curve, Derivative};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # fn main() -> Result<(), MotionError> {
+//! let profile = TrapezoidalProfile::new(0.0, 10.0, 2.0, 1.0)?;
+//! // accelerating for 2 seconds, cruising for 3 seconds and decelerating for 2 seconds
+//! assert_eq!(profile.domain(), [0.0, 7.0]);
+//! assert_f64_near!(profile.gen(2.0), 2.0);
+//! assert_f64_near!(profile.derivative(3.5), 2.0);
+//! assert_f64_near!(profile.gen(7.0), 10.0);
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+
+use crate::{Curve, Derivative, Generator};
+use num_traits::real::Real;
+
+mod error;
+pub use error::MotionError;
+
+/// Check if the given limit is positive and finite.
+fn valid_limit<R: Real>(limit: R) -> bool {
+    limit > R::zero() && limit <= R::max_value()
+}
+
+/// Motion profile limiting velocity and acceleration.
+///
+/// The motion accelerates with the maximal acceleration until the maximal velocity is reached,
+/// cruises with that velocity and decelerates in time to stop at the end position.
+/// If the distance is too short to reach the maximal velocity, the cruising phase is omitted.
+///
+/// Inputs before zero or after the duration of the motion result in the start or end position.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TrapezoidalProfile<R> {
+    start: R,
+    end: R,
+    acceleration: R,
+    /// velocity while cruising
+    peak: R,
+    /// duration of the acceleration phase
+    accelerating: R,
+    /// duration of the cruising phase
+    cruising: R,
+}
+
+impl<R> TrapezoidalProfile<R>
+where
+    R: Real,
+{
+    /// Create a motion profile from `start` to `end` with the given maximal velocity and acceleration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidVelocity`] or [`InvalidAcceleration`] if the respective limit is not positive and finite.
+    ///
+    /// [`InvalidVelocity`]: MotionError::InvalidVelocity
+    /// [`InvalidAcceleration`]: MotionError::InvalidAcceleration
+    pub fn new(start: R, end: R, velocity: R, acceleration: R) -> Result<Self, MotionError> {
+        if !valid_limit(velocity) {
+            return Err(MotionError::InvalidVelocity);
+        }
+        if !valid_limit(acceleration) {
+            return Err(MotionError::InvalidAcceleration);
+        }
+        let distance = (end - start).abs();
+        // distance needed to accelerate to the maximal velocity and decelerate again
+        let ramps = velocity * velocity / acceleration;
+        let (peak, cruising) = if distance >= ramps {
+            (velocity, (distance - ramps) / velocity)
+        } else {
+            ((distance * acceleration).sqrt(), R::zero())
+        };
+        Ok(TrapezoidalProfile {
+            start,
+            end,
+            acceleration,
+            peak,
+            accelerating: peak / acceleration,
+            cruising,
+        })
+    }
+
+    /// Returns the duration of the motion.
+    pub fn duration(&self) -> R {
+        self.accelerating + self.accelerating + self.cruising
+    }
+
+    /// Returns the highest velocity reached.
+    pub fn peak_velocity(&self) -> R {
+        self.peak
+    }
+
+    /// Distance travelled and speed after the given time, which is at most half of the duration.
+    fn first_half(&self, time: R) -> [R; 2] {
+        let two = R::one() + R::one();
+        if time <= self.accelerating {
+            [
+                self.acceleration * time * time / two,
+                self.acceleration * time,
+            ]
+        } else {
+            let ramp = self.peak * self.accelerating / two;
+            [ramp + self.peak * (time - self.accelerating), self.peak]
+        }
+    }
+
+    /// Returns position and velocity at the given time.
+    ///
+    /// The second half of the motion mirrors the first one, such that the end position is reached exactly.
+    pub fn gen_with_velocity(&self, time: R) -> [R; 2] {
+        let duration = self.duration();
+        let time = time.max(R::zero()).min(duration);
+        let direction = (self.end - self.start).signum();
+        if time + time <= duration {
+            let [distance, speed] = self.first_half(time);
+            [self.start + direction * distance, direction * speed]
+        } else {
+            let [distance, speed] = self.first_half(duration - time);
+            [self.end - direction * distance, direction * speed]
+        }
+    }
+}
+
+impl<R> Generator<R> for TrapezoidalProfile<R>
+where
+    R: Real,
+{
+    type Output = R;
+    fn gen(&self, time: R) -> R {
+        self.gen_with_velocity(time)[0]
+    }
+}
+
+impl<R> Curve<R> for TrapezoidalProfile<R>
+where
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        [R::zero(), self.duration()]
+    }
+}
+
+impl<R> Derivative<R> for TrapezoidalProfile<R>
+where
+    R: Real,
+{
+    fn derivative(&self, time: R) -> R {
+        self.gen_with_velocity(time)[1]
+    }
+}
+
+/// Motion profile limiting velocity, acceleration and jerk.
+///
+/// The acceleration is built up and reduced again with the maximal jerk, such that the acceleration
+/// is continuous. The motion consists of seven phases: increasing, constant and decreasing acceleration,
+/// cruising with the maximal velocity and increasing, constant and decreasing deceleration.
+/// If a limit can not be reached in the given distance, the phases using it are omitted.
+///
+/// Inputs before zero or after the duration of the motion result in the start or end position.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{motion::{SCurveProfile, MotionError}, Generator, Curve, Derivative};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), MotionError> {
+/// let profile = SCurveProfile::new(0.0, 10.0, 2.0, 1.0, 1.0)?;
+/// // accelerating for 3 seconds, cruising for 2 seconds and decelerating for 3 seconds
+/// assert_eq!(profile.domain(), [0.0, 8.0]);
+/// assert_f64_near!(profile.derivative(4.0), 2.0);
+/// assert_f64_near!(profile.gen(4.0), 5.0);
+/// assert_f64_near!(profile.gen(8.0), 10.0);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SCurveProfile<R> {
+    start: R,
+    end: R,
+    jerk: R,
+    /// velocity while cruising
+    peak: R,
+    /// duration of every phase with changing acceleration
+    jerking: R,
+    /// duration of every phase with constant, non-zero acceleration
+    accelerating: R,
+    /// duration of the cruising phase
+    cruising: R,
+}
+
+impl<R> SCurveProfile<R>
+where
+    R: Real,
+{
+    /// Create a motion profile from `start` to `end` with the given maximal velocity, acceleration and jerk.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidVelocity`], [`InvalidAcceleration`] or [`InvalidJerk`] if the respective limit
+    /// is not positive and finite.
+    ///
+    /// [`InvalidVelocity`]: MotionError::InvalidVelocity
+    /// [`InvalidAcceleration`]: MotionError::InvalidAcceleration
+    /// [`InvalidJerk`]: MotionError::InvalidJerk
+    pub fn new(
+        start: R,
+        end: R,
+        velocity: R,
+        acceleration: R,
+        jerk: R,
+    ) -> Result<Self, MotionError> {
+        if !valid_limit(velocity) {
+            return Err(MotionError::InvalidVelocity);
+        }
+        if !valid_limit(acceleration) {
+            return Err(MotionError::InvalidAcceleration);
+        }
+        if !valid_limit(jerk) {
+            return Err(MotionError::InvalidJerk);
+        }
+        let two = R::one() + R::one();
+        let distance = (end - start).abs();
+        // durations of the jerk and constant acceleration phases needed to reach the given velocity
+        let ramp = |peak: R| {
+            if peak * jerk >= acceleration * acceleration {
+                let constant = peak / acceleration - acceleration / jerk;
+                (acceleration / jerk, constant.max(R::zero()))
+            } else {
+                ((peak / jerk).sqrt(), R::zero())
+            }
+        };
+        let (jerking, accelerating) = ramp(velocity);
+        // distance needed to accelerate to the maximal velocity and decelerate again
+        let ramps = velocity * (jerking + jerking + accelerating);
+        if distance >= ramps {
+            return Ok(SCurveProfile {
+                start,
+                end,
+                jerk,
+                peak: velocity,
+                jerking,
+                accelerating,
+                cruising: (distance - ramps) / velocity,
+            });
+        }
+        // without constant acceleration, the distance is 2 * peak^(3/2) / sqrt(jerk)
+        let mut peak = (distance * distance * jerk / (two * two)).cbrt();
+        if peak * jerk > acceleration * acceleration {
+            // otherwise the distance is peak^2 / acceleration + peak * acceleration / jerk
+            let ratio = acceleration / jerk;
+            peak = acceleration / two
+                * ((ratio * ratio + two * two * distance / acceleration).sqrt() - ratio);
+        }
+        let (jerking, accelerating) = ramp(peak);
+        Ok(SCurveProfile {
+            start,
+            end,
+            jerk,
+            peak,
+            jerking,
+            accelerating,
+            cruising: R::zero(),
+        })
+    }
+
+    /// Returns the duration of the motion.
+    pub fn duration(&self) -> R {
+        let two = R::one() + R::one();
+        two * (self.jerking + self.jerking + self.accelerating) + self.cruising
+    }
+
+    /// Returns the highest velocity reached.
+    pub fn peak_velocity(&self) -> R {
+        self.peak
+    }
+
+    /// Distance travelled and speed after the given time, which is at most half of the duration.
+    fn first_half(&self, time: R) -> [R; 2] {
+        let two = R::one() + R::one();
+        let six = two * (two + R::one());
+        let jerk = self.jerk;
+        let acceleration = jerk * self.jerking;
+        // increasing acceleration
+        if time <= self.jerking {
+            return [jerk * time * time * time / six, jerk * time * time / two];
+        }
+        let speed = acceleration * self.jerking / two;
+        let distance = speed * self.jerking / (two + R::one());
+        // constant acceleration
+        let time = time - self.jerking;
+        if time <= self.accelerating {
+            return [
+                distance + speed * time + acceleration * time * time / two,
+                speed + acceleration * time,
+            ];
+        }
+        let distance = distance
+            + speed * self.accelerating
+            + acceleration * self.accelerating * self.accelerating / two;
+        let speed = speed + acceleration * self.accelerating;
+        // decreasing acceleration
+        let time = time - self.accelerating;
+        let decreasing = time.min(self.jerking);
+        let distance = distance + speed * decreasing + acceleration * decreasing * decreasing / two
+            - jerk * decreasing * decreasing * decreasing / six;
+        let speed = speed + acceleration * decreasing - jerk * decreasing * decreasing / two;
+        // cruising
+        [distance + self.peak * (time - decreasing), speed]
+    }
+
+    /// Returns position and velocity at the given time.
+    ///
+    /// The second half of the motion mirrors the first one, such that the end position is reached exactly.
+    pub fn gen_with_velocity(&self, time: R) -> [R; 2] {
+        let duration = self.duration();
+        let time = time.max(R::zero()).min(duration);
+        let direction = (self.end - self.start).signum();
+        if time + time <= duration {
+            let [distance, speed] = self.first_half(time);
+            [self.start + direction * distance, direction * speed]
+        } else {
+            let [distance, speed] = self.first_half(duration - time);
+            [self.end - direction * distance, direction * speed]
+        }
+    }
+}
+
+impl<R> Generator<R> for SCurveProfile<R>
+where
+    R: Real,
+{
+    type Output = R;
+    fn gen(&self, time: R) -> R {
+        self.gen_with_velocity(time)[0]
+    }
+}
+
+impl<R> Curve<R> for SCurveProfile<R>
+where
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        [R::zero(), self.duration()]
+    }
+}
+
+impl<R> Derivative<R> for SCurveProfile<R>
+where
+    R: Real,
+{
+    fn derivative(&self, time: R) -> R {
+        self.gen_with_velocity(time)[1]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Stepper;
+
+    /// Check the limits and endpoints of a motion profile by dense sampling.
+    fn check<C>(profile: &C, start: f64, end: f64, velocity: f64, acceleration: f64) -> f64
+    where
+        C: Derivative<f64, Output = f64>,
+    {
+        let [first, last] = profile.domain();
+        assert_eq!(first, 0.0);
+        assert_eq!(profile.gen(0.0), start);
+        assert_eq!(profile.gen(last), end);
+        assert_eq!(profile.gen(-1.0), start);
+        assert_eq!(profile.gen(last + 1.0), end);
+        assert_eq!(profile.derivative(0.0), 0.0);
+        assert_float_absolute_eq!(profile.derivative(last), 0.0, 1e-12);
+        let samples = 10_001;
+        let step = last / (samples - 1) as f64;
+        let mut previous: Option<(f64, f64)> = None;
+        let mut max_acceleration: f64 = 0.0;
+        for time in Stepper::new(samples, 0.0, last) {
+            let position = profile.gen(time);
+            let speed = profile.derivative(time);
+            assert!(speed.abs() <= velocity + 1e-12);
+            // the motion is monotone
+            assert!(speed * (end - start) >= 0.0);
+            if let Some((position_before, speed_before)) = previous {
+                // positions and velocities are consistent and continuous
+                assert_float_absolute_eq!(
+                    position - position_before,
+                    (speed + speed_before) / 2.0 * step,
+                    1e-5
+                );
+                let change = (speed - speed_before) / step;
+                assert!(change.abs() <= acceleration + 1e-6);
+                max_acceleration = max_acceleration.max(change.abs());
+            }
+            previous = Some((position, speed));
+        }
+        max_acceleration
+    }
+
+    #[test]
+    fn trapezoidal() {
+        let profile = TrapezoidalProfile::new(0.0, 10.0, 2.0, 1.0).unwrap();
+        assert_eq!(profile.duration(), 7.0);
+        assert_eq!(profile.peak_velocity(), 2.0);
+        check(&profile, 0.0, 10.0, 2.0, 1.0);
+        // too short to reach the maximal velocity
+        let profile = TrapezoidalProfile::new(3.0, 2.0, 2.0, 4.0).unwrap();
+        assert_f64_near!(profile.peak_velocity(), 2.0);
+        assert_f64_near!(profile.duration(), 1.0);
+        let profile = TrapezoidalProfile::new(3.0, 2.5, 2.0, 4.0).unwrap();
+        assert!(profile.peak_velocity() < 2.0);
+        let reached = check(&profile, 3.0, 2.5, 2.0, 4.0);
+        assert_float_absolute_eq!(reached, 4.0, 1e-6);
+        // no motion at all
+        let profile = TrapezoidalProfile::new(1.0, 1.0, 2.0, 4.0).unwrap();
+        assert_eq!(profile.domain(), [0.0, 0.0]);
+        assert_eq!(profile.gen(0.5), 1.0);
+    }
+
+    #[test]
+    fn s_curve() {
+        let profile = SCurveProfile::new(0.0, 10.0, 2.0, 1.0, 1.0).unwrap();
+        assert_f64_near!(profile.duration(), 8.0);
+        let reached = check(&profile, 0.0, 10.0, 2.0, 1.0);
+        assert_float_absolute_eq!(reached, 1.0, 1e-3);
+        // the acceleration changes continuously with limited jerk
+        let step = 1e-3;
+        let mut before = 0.0;
+        for time in Stepper::new(8001, 0.0, 8.0).skip(1) {
+            let acceleration = (profile.derivative(time) - profile.derivative(time - step)) / step;
+            assert!((acceleration - before).abs() <= 1.0 * step + 1e-6);
+            before = acceleration;
+        }
+        // the maximal acceleration is not reached
+        let profile = SCurveProfile::new(0.0, -0.5, 2.0, 1.0, 1.0).unwrap();
+        assert!(profile.peak_velocity() < 1.0);
+        let reached = check(&profile, 0.0, -0.5, 2.0, 1.0);
+        assert!(reached < 1.0);
+        // the maximal velocity is not reached
+        let profile = SCurveProfile::new(-1.0, 3.0, 2.0, 1.0, 1.0).unwrap();
+        assert!(profile.peak_velocity() < 2.0);
+        let reached = check(&profile, -1.0, 3.0, 2.0, 1.0);
+        assert_float_absolute_eq!(reached, 1.0, 1e-3);
+        // a big jerk approaches the trapezoidal profile
+        let profile = SCurveProfile::new(0.0, 10.0, 2.0, 1.0, 1e6).unwrap();
+        let trapezoidal = TrapezoidalProfile::new(0.0, 10.0, 2.0, 1.0).unwrap();
+        assert_float_absolute_eq!(profile.duration(), trapezoidal.duration(), 1e-5);
+        assert_float_absolute_eq!(profile.gen(1.5), trapezoidal.gen(1.5), 1e-5);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            TrapezoidalProfile::new(0.0, 1.0, 0.0, 1.0),
+            Err(MotionError::InvalidVelocity)
+        );
+        assert_eq!(
+            TrapezoidalProfile::new(0.0, 1.0, 1.0, f64::INFINITY),
+            Err(MotionError::InvalidAcceleration)
+        );
+        assert_eq!(
+            SCurveProfile::new(0.0, 1.0, 1.0, 1.0, -1.0),
+            Err(MotionError::InvalidJerk)
+        );
+        assert_eq!(
+            SCurveProfile::new(0.0, 1.0, 1.0, f64::NAN, 1.0),
+            Err(MotionError::InvalidAcceleration)
+        );
+    }
+}
@@ -0,0 +1,281 @@
+//! Packed vectors of floats to be used as elements.
+//!
+//! Arrays do not implement arithmetic operators, such that they can not be used as elements of curves directly.
+//! [`F32x2`] and [`F32x4`] wrap arrays of two and four floats and implement all operators necessary
+//! to be used as elements, for example as 2D points or homogeneous 3D points.
+//!
+//! With the **simd** feature enabled, which needs a nightly compiler, the lanes of these vectors are
+//! stored as `core::simd::Simd`, such that every operation, and such every merge of two elements,
+//! is done with one SIMD instruction for all lanes at once. Without the feature, the lanes are computed
+//! one after another. The API is the same in both cases.
+//!
+//! These vectors are new types instead of a specialization for `[f32; 2]` and `[f32; 4]`, as arrays are
+//! not elements by themselves and specialization is not available on stable Rust. Code using arrays as
+//! points has to switch to these types to profit from the **simd** feature.
+//!
+//! The `packed` group of the benchmarks samples curves over these vectors and can be used to compare
+//! runs with and without the feature.
+//!
+//! With the **serde** feature enabled, the vectors are (de)serialized as arrays of their lanes.
+//!
+#![cfg_attr(feature = "bezier", doc = "```rust")]
+#![cfg_attr(not(feature = "bezier"), doc = "```ignore")]
+//! # use enterpolation::{bezier::{Bezier, BezierError}, packed::F32x2, Generator};
+//! #
+//! # fn main() -> Result<(), BezierError> {
+//! let curve = Bezier::builder()
+//!                 .elements([F32x2::new([0.0, 0.0]), F32x2::new([1.0, 2.0]), F32x2::new([2.0, 0.0])])
+//!                 .normalized::<f32>()
+//!                 .constant::<3>()
+//!                 .build()?;
+//! assert_eq!(curve.gen(0.5).to_array(), [1.0, 1.0]);
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+
+use crate::Bounded;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(feature = "simd")]
+type Lanes<const N: usize> = core::simd::Simd<f32, N>;
+#[cfg(not(feature = "simd"))]
+type Lanes<const N: usize> = [f32; N];
+
+#[cfg(feature = "simd")]
+mod lanes {
+    use super::Lanes;
+    use core::simd::num::SimdFloat;
+
+    pub(super) fn from_array<const N: usize>(array: [f32; N]) -> Lanes<N> {
+        Lanes::from_array(array)
+    }
+    pub(super) fn to_array<const N: usize>(lanes: Lanes<N>) -> [f32; N] {
+        lanes.to_array()
+    }
+    pub(super) fn add<const N: usize>(first: Lanes<N>, second: Lanes<N>) -> Lanes<N> {
+        first + second
+    }
+    pub(super) fn sub<const N: usize>(first: Lanes<N>, second: Lanes<N>) -> Lanes<N> {
+        first - second
+    }
+    pub(super) fn scale<const N: usize>(lanes: Lanes<N>, factor: f32) -> Lanes<N> {
+        lanes * Lanes::splat(factor)
+    }
+    pub(super) fn div<const N: usize>(lanes: Lanes<N>, divisor: f32) -> Lanes<N> {
+        lanes / Lanes::splat(divisor)
+    }
+    pub(super) fn min<const N: usize>(first: Lanes<N>, second: Lanes<N>) -> Lanes<N> {
+        first.simd_min(second)
+    }
+    pub(super) fn max<const N: usize>(first: Lanes<N>, second: Lanes<N>) -> Lanes<N> {
+        first.simd_max(second)
+    }
+}
+
+#[cfg(not(feature = "simd"))]
+mod lanes {
+    use super::Lanes;
+
+    pub(super) fn from_array<const N: usize>(array: [f32; N]) -> Lanes<N> {
+        array
+    }
+    pub(super) fn to_array<const N: usize>(lanes: Lanes<N>) -> [f32; N] {
+        lanes
+    }
+    pub(super) fn add<const N: usize>(first: Lanes<N>, second: Lanes<N>) -> Lanes<N> {
+        core::array::from_fn(|i| first[i] + second[i])
+    }
+    pub(super) fn sub<const N: usize>(first: Lanes<N>, second: Lanes<N>) -> Lanes<N> {
+        core::array::from_fn(|i| first[i] - second[i])
+    }
+    pub(super) fn scale<const N: usize>(lanes: Lanes<N>, factor: f32) -> Lanes<N> {
+        core::array::from_fn(|i| lanes[i] * factor)
+    }
+    pub(super) fn div<const N: usize>(lanes: Lanes<N>, divisor: f32) -> Lanes<N> {
+        core::array::from_fn(|i| lanes[i] / divisor)
+    }
+    pub(super) fn min<const N: usize>(first: Lanes<N>, second: Lanes<N>) -> Lanes<N> {
+        core::array::from_fn(|i| first[i].min(second[i]))
+    }
+    pub(super) fn max<const N: usize>(first: Lanes<N>, second: Lanes<N>) -> Lanes<N> {
+        core::array::from_fn(|i| first[i].max(second[i]))
+    }
+}
+
+macro_rules! packed {
+    ($name:ident, $lanes:literal, $doc:literal) => {
+        #[doc = $doc]
+        ///
+        /// See [packed module] for more information.
+        ///
+        /// [packed module]: self
+        #[derive(Debug, Copy, Clone, PartialEq, Default)]
+        pub struct $name(Lanes<$lanes>);
+
+        impl $name {
+            /// Create a vector with the given lanes.
+            pub fn new(lanes: [f32; $lanes]) -> Self {
+                $name(lanes::from_array(lanes))
+            }
+            /// Create a vector with all lanes set to the given value.
+            pub fn splat(value: f32) -> Self {
+                $name::new([value; $lanes])
+            }
+            /// Returns the lanes of the vector as array.
+            pub fn to_array(self) -> [f32; $lanes] {
+                lanes::to_array(self.0)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serde::Serialize::serialize(&self.to_array(), serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                <[f32; $lanes] as serde::Deserialize>::deserialize(deserializer).map($name::new)
+            }
+        }
+
+        impl From<[f32; $lanes]> for $name {
+            fn from(lanes: [f32; $lanes]) -> Self {
+                $name::new(lanes)
+            }
+        }
+
+        impl From<$name> for [f32; $lanes] {
+            fn from(vector: $name) -> Self {
+                vector.to_array()
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+            fn add(self, other: $name) -> $name {
+                $name(lanes::add(self.0, other.0))
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+            fn sub(self, other: $name) -> $name {
+                $name(lanes::sub(self.0, other.0))
+            }
+        }
+
+        impl Mul<f32> for $name {
+            type Output = $name;
+            fn mul(self, factor: f32) -> $name {
+                $name(lanes::scale(self.0, factor))
+            }
+        }
+
+        impl Div<f32> for $name {
+            type Output = $name;
+            fn div(self, divisor: f32) -> $name {
+                $name(lanes::div(self.0, divisor))
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+            fn neg(self) -> $name {
+                $name(lanes::scale(self.0, -1.0))
+            }
+        }
+
+        impl Bounded for $name {
+            fn component_min(self, other: Self) -> Self {
+                $name(lanes::min(self.0, other.0))
+            }
+            fn component_max(self, other: Self) -> Self {
+                $name(lanes::max(self.0, other.0))
+            }
+        }
+    };
+}
+
+packed!(F32x2, 2, "Vector of two floats, for example a 2D point.");
+packed!(
+    F32x4,
+    4,
+    "Vector of four floats, for example a homogeneous 3D point or a color with alpha."
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Merge;
+
+    #[test]
+    fn operators() {
+        let first = F32x4::new([1.0, 2.0, 3.0, 4.0]);
+        let second = F32x4::from([4.0, 2.0, 0.0, -4.0]);
+        assert_eq!((first + second).to_array(), [5.0, 4.0, 3.0, 0.0]);
+        assert_eq!((first - second).to_array(), [-3.0, 0.0, 3.0, 8.0]);
+        assert_eq!((first * 2.0).to_array(), [2.0, 4.0, 6.0, 8.0]);
+        assert_eq!((first / 2.0).to_array(), [0.5, 1.0, 1.5, 2.0]);
+        assert_eq!((-first).to_array(), [-1.0, -2.0, -3.0, -4.0]);
+        assert_eq!(first.merge(second, 0.5).to_array(), [2.5, 2.0, 1.5, 0.0]);
+        assert_eq!(
+            first.component_min(second),
+            F32x4::new([1.0, 2.0, 0.0, -4.0])
+        );
+        assert_eq!(
+            <[f32; 2]>::from(F32x2::splat(1.5).component_max(F32x2::new([2.0, 1.0]))),
+            [2.0, 1.5]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bezier")]
+    fn bezier() {
+        use crate::bezier::Bezier;
+        use crate::{Curve, Generator};
+        let elements = [
+            F32x4::new([0.0, 0.0, 0.0, 1.0]),
+            F32x4::new([1.0, 2.0, -1.0, 1.0]),
+            F32x4::new([2.0, 2.0, -2.0, 1.0]),
+            F32x4::new([3.0, 0.0, 0.0, 1.0]),
+        ];
+        let curve = Bezier::builder()
+            .elements(elements)
+            .normalized::<f32>()
+            .constant::<4>()
+            .build()
+            .unwrap();
+        // every lane is interpolated independently
+        for lane in 0..4 {
+            let scalar = Bezier::builder()
+                .elements(elements.map(|element| element.to_array()[lane]))
+                .normalized::<f32>()
+                .constant::<4>()
+                .build()
+                .unwrap();
+            for (vector, value) in curve.by_ref().take(11).zip(scalar.take(11)) {
+                assert_f32_near!(vector.to_array()[lane], value);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let vector = F32x4::new([1.0, -2.5, 0.0, 4.0]);
+        let json = serde_json::to_string(&vector).unwrap();
+        assert_eq!(json, "[1.0,-2.5,0.0,4.0]");
+        let restored: F32x4 = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, vector);
+    }
+}
@@ -1,6 +1,29 @@
 //! Module for different utilities which are used across other modules or to help the user of the library.
+#[cfg(any(
+    feature = "catmullrom",
+    feature = "cubic",
+    feature = "hermite",
+    feature = "kochanek"
+))]
+use crate::SortedGenerator;
+use crate::{Curve, Derivative};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::cmp::Ordering;
+#[cfg(any(
+    feature = "catmullrom",
+    feature = "cubic",
+    feature = "hermite",
+    feature = "kochanek"
+))]
+use core::ops::Sub;
 use core::ops::{Add, Mul};
 use num_traits::real::Real;
+use num_traits::{cast, Bounded};
+#[cfg(feature = "std")]
+use std::time::Duration;
+use topology_traits::Merge;
 
 /// Linear interpolation of the two values given.
 pub fn lerp<T, R>(first: T, second: T, factor: R) -> T
@@ -10,3 +33,706 @@ where
 {
     first * (R::one() - factor) + second * factor
 }
+
+/// Intersect two domains.
+///
+/// Domains are allowed to be reversed, that is, their start may be bigger than their end.
+/// The returned domain is always in increasing order.
+/// Returns `None` if the domains are disjoint. Touching domains intersect in a single point.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::utils::intersect_domains;
+/// assert_eq!(intersect_domains([0.0, 2.0], [1.0, 3.0]), Some([1.0, 2.0]));
+/// assert_eq!(intersect_domains([2.0, 0.0], [1.0, 3.0]), Some([1.0, 2.0]));
+/// assert_eq!(intersect_domains([0.0, 1.0], [2.0, 3.0]), None);
+/// ```
+pub fn intersect_domains<R>(first: [R; 2], second: [R; 2]) -> Option<[R; 2]>
+where
+    R: Real,
+{
+    let start = first[0].min(first[1]).max(second[0].min(second[1]));
+    let end = first[0].max(first[1]).min(second[0].max(second[1]));
+    if start > end {
+        return None;
+    }
+    Some([start, end])
+}
+
+/// Wrapper for integer-like elements to merge them with saturating arithmetic.
+///
+/// The wrapped values are cast into the type of the factor, linearly interpolated and rounded
+/// to the nearest value. If the result does not fit into the wrapped type,
+/// it saturates at the minimum or maximum value of the type instead of wrapping around.
+/// This allows one to interpolate, for example, `u8` color channels without overflowing,
+/// even when extrapolating.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{utils::SaturatingMerge, Merge};
+/// let black = SaturatingMerge(0u8);
+/// let white = SaturatingMerge(255u8);
+/// assert_eq!(black.merge(white, 0.5), SaturatingMerge(128));
+/// assert_eq!(black.merge(white, 1.5), SaturatingMerge(255));
+/// assert_eq!(black.merge(white, -0.5), SaturatingMerge(0));
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SaturatingMerge<T>(pub T);
+
+impl<T, R> Merge<R> for SaturatingMerge<T>
+where
+    T: cast::NumCast + Bounded + Copy,
+    R: Real,
+{
+    fn merge(self, to: Self, factor: R) -> Self {
+        let cast = |value: T| R::from(value).expect("value is not representable by the factor");
+        let value = lerp(cast(self.0), cast(to.0), factor).round();
+        SaturatingMerge(T::from(value).unwrap_or_else(|| {
+            if value > R::zero() {
+                T::max_value()
+            } else {
+                T::min_value()
+            }
+        }))
+    }
+}
+
+/// Wrapper for [`Duration`] to merge durations, such that they can be used as elements of curves.
+///
+/// The durations are converted into nanoseconds in the type of the factor, linearly interpolated
+/// and rounded to the nearest nanosecond. As such, the precision is limited by the factor:
+/// with `f64`, durations up to roughly 104 days are merged without losing any nanosecond.
+/// If the result is negative, it saturates at [`Duration::ZERO`],
+/// if it is too large to be represented, it saturates at [`Duration::MAX`].
+/// This may happen when extrapolating, for example with a factor outside of [0.0,1.0].
+/// A factor of NaN results in [`Duration::ZERO`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{utils::DurationMerge, Merge};
+/// # use std::time::Duration;
+/// let short = DurationMerge(Duration::from_millis(100));
+/// let long = DurationMerge(Duration::from_secs(1));
+/// assert_eq!(short.merge(long, 0.5), DurationMerge(Duration::from_millis(550)));
+/// assert_eq!(long.merge(short, 2.0), DurationMerge(Duration::ZERO));
+/// ```
+///
+/// [`Duration`]: std::time::Duration
+/// [`Duration::ZERO`]: std::time::Duration::ZERO
+/// [`Duration::MAX`]: std::time::Duration::MAX
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DurationMerge(pub Duration);
+
+#[cfg(feature = "std")]
+impl<R> Merge<R> for DurationMerge
+where
+    R: Real,
+{
+    fn merge(self, to: Self, factor: R) -> Self {
+        let nanos_per_sec = R::from(1_000_000_000u32).unwrap();
+        let cast = |value: Duration| {
+            R::from(value.as_secs()).expect("duration is not representable by the factor")
+                * nanos_per_sec
+                + R::from(value.subsec_nanos()).unwrap()
+        };
+        let nanos = lerp(cast(self.0), cast(to.0), factor).round();
+        // NaN is not comparable and also results in zero
+        if nanos.partial_cmp(&R::zero()) != Some(Ordering::Greater) {
+            return DurationMerge(Duration::ZERO);
+        }
+        let secs = (nanos / nanos_per_sec).trunc();
+        let subsec_nanos = (nanos - secs * nanos_per_sec).to_u64().unwrap_or(0);
+        DurationMerge(match secs.to_u64() {
+            Some(secs) => {
+                Duration::from_secs(secs).saturating_add(Duration::from_nanos(subsec_nanos))
+            }
+            None => Duration::MAX,
+        })
+    }
+}
+
+/// Calculate the Frenet frame of a 3D curve at the given input.
+///
+/// The accessor is used to transform the output of the curve into a point in 3D space.
+/// Returned are the tangent, normal and binormal of the curve at the given input, all normalized.
+///
+/// The first and second derivative of the curve are approximated with central differences,
+/// such the curve does not have to provide its derivatives itself.
+///
+/// If the normal is not defined, as the curve is (locally) a straight line, the normal falls back to
+/// the coordinate axis least aligned with the tangent, made orthogonal to the tangent.
+/// As such, the returned frame is always orthonormal as long as the first derivative of the curve does not vanish.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{utils::frenet_frame, Curve, Generator};
+/// # use assert_float_eq::assert_float_absolute_eq;
+/// #
+/// // a circle in the xy-plane
+/// struct Circle;
+/// impl Generator<f64> for Circle {
+///     type Output = [f64; 3];
+///     fn gen(&self, t: f64) -> [f64; 3] {
+///         [t.cos(), t.sin(), 0.0]
+///     }
+/// }
+/// impl Curve<f64> for Circle {
+///     fn domain(&self) -> [f64; 2] {
+///         [0.0, 6.0]
+///     }
+/// }
+/// let (tangent, normal, binormal) = frenet_frame(&Circle, 0.0, |p| p);
+/// assert_float_absolute_eq!(tangent[1], 1.0, 1e-6);
+/// assert_float_absolute_eq!(normal[0], -1.0, 1e-6);
+/// assert_float_absolute_eq!(binormal[2], 1.0, 1e-6);
+/// ```
+pub fn frenet_frame<C, R, F>(curve: &C, input: R, accessor: F) -> ([R; 3], [R; 3], [R; 3])
+where
+    C: Curve<R>,
+    R: Real,
+    F: Fn(C::Output) -> [R; 3],
+{
+    frenet_frame_with_hint(curve, input, None, accessor)
+}
+
+/// Calculate the Frenet frame of a 3D curve at the given input, reusing the normal of a previous frame.
+///
+/// This works like [`frenet_frame()`], however if the normal is not defined, the given previous normal
+/// is transported to the tangent instead of falling back to a coordinate axis.
+/// This avoids sudden flips of the normal when calculating frames one after another along a curve
+/// which contains straight parts.
+pub fn frenet_frame_with_hint<C, R, F>(
+    curve: &C,
+    input: R,
+    previous_normal: Option<[R; 3]>,
+    accessor: F,
+) -> ([R; 3], [R; 3], [R; 3])
+where
+    C: Curve<R>,
+    R: Real,
+    F: Fn(C::Output) -> [R; 3],
+{
+    let (first, second) = derivatives(curve, input, &accessor);
+    frame_of(first, second, curve.domain(), previous_normal)
+}
+
+/// Calculate rotation minimizing frames along a 3D curve.
+///
+/// The accessor is used to transform the output of the curve into a point in 3D space.
+/// The curve is sampled at `samples` equidistant inputs over its whole domain and for each of them
+/// the tangent, normal and binormal are returned, all normalized.
+///
+/// In contrast to [`frenet_frame()`], the normal of these frames does not flip at inflection points
+/// and only rotates as much as necessary to stay orthogonal to the tangent.
+/// This makes them the prefered choice to sweep geometry, like tubes or ribbons, along a curve.
+/// The frames are calculated with the double reflection method, starting from the Frenet frame
+/// at the start of the domain.
+///
+/// # Panics
+///
+/// Panics if given size of samples is 0 or if `samples - 1` can not be converted to the type `R`.
+#[cfg(feature = "alloc")]
+pub fn rmf<C, R, F>(curve: &C, samples: usize, accessor: F) -> Vec<([R; 3], [R; 3], [R; 3])>
+where
+    C: Curve<R>,
+    R: Real + num_traits::FromPrimitive,
+    F: Fn(C::Output) -> [R; 3],
+{
+    let [start, end] = curve.domain();
+    let mut frames = Vec::with_capacity(samples);
+    let mut inputs = crate::Stepper::new(samples, start, end);
+    let first = inputs.next().expect("samples have to be at least 1");
+    let mut point = accessor(curve.gen(first));
+    let (mut tangent, mut normal, binormal) = frenet_frame(curve, first, &accessor);
+    frames.push((tangent, normal, binormal));
+    for input in inputs {
+        let next_point = accessor(curve.gen(input));
+        let next_tangent = normalize(derivatives(curve, input, &accessor).0);
+        // first reflection at the bisecting plane of the two points
+        let v1 = sub(next_point, point);
+        let c1 = dot(v1, v1);
+        let (reflected_normal, reflected_tangent) = if c1 > R::zero() {
+            (reflect(normal, v1, c1), reflect(tangent, v1, c1))
+        } else {
+            (normal, tangent)
+        };
+        // second reflection to align the reflected tangent with the next tangent
+        let v2 = sub(next_tangent, reflected_tangent);
+        let c2 = dot(v2, v2);
+        normal = if c2 > R::zero() {
+            reflect(reflected_normal, v2, c2)
+        } else {
+            reflected_normal
+        };
+        // counteract the error of the approximated tangent, such the frame stays orthonormal
+        normal = normalize(sub(normal, scale(next_tangent, dot(normal, next_tangent))));
+        point = next_point;
+        tangent = next_tangent;
+        frames.push((tangent, normal, cross(tangent, normal)));
+    }
+    frames
+}
+
+/// Returns the first derivative of the curve and approximates the second derivative
+/// by central differences of the first.
+///
+/// Near the borders of the domain one-sided differences are used, such the curve is never evaluated
+/// outside of its domain.
+pub(crate) fn analytic_derivatives<C, R>(curve: &C, input: R) -> ([R; 3], [R; 3])
+where
+    C: Derivative<R, Output = [R; 3]>,
+    R: Real,
+{
+    let [start, end] = curve.domain();
+    // the cube root of the machine epsilon balances truncation and rounding error of first differences
+    let step = R::epsilon().cbrt() * (end - start);
+    let two = R::one() + R::one();
+    let first = curve.derivative(input);
+    if input - step >= start && input + step <= end {
+        let before = curve.derivative(input - step);
+        let after = curve.derivative(input + step);
+        return (first, scale(sub(after, before), (two * step).recip()));
+    }
+    // one-sided difference of second order, stepping into the domain
+    let step = if input - step < start { step } else { -step };
+    let one = curve.derivative(input + step);
+    let two_steps = curve.derivative(input + two * step);
+    let second = scale(
+        sub(
+            sub(scale(one, two + two), scale(first, two + R::one())),
+            two_steps,
+        ),
+        (two * step).recip(),
+    );
+    (first, second)
+}
+
+/// Calculate the curvature from the first and second derivative of a curve.
+pub(crate) fn curvature_of<R: Real>(first: [R; 3], second: [R; 3]) -> R {
+    let speed = norm(first);
+    norm(cross(first, second)) / (speed * speed * speed)
+}
+
+/// Calculate the Frenet frame from the first and second derivative of a curve.
+///
+/// If the normal is not defined, the given normal of the previous frame is transported to the new tangent.
+/// Without a previous frame, the coordinate axis least aligned with the tangent is used instead.
+pub(crate) fn frame_of<R: Real>(
+    first: [R; 3],
+    second: [R; 3],
+    domain: [R; 2],
+    previous: Option<[R; 3]>,
+) -> ([R; 3], [R; 3], [R; 3]) {
+    let tangent = normalize(first);
+    let binormal = cross(first, second);
+    // the angle the tangent turns through over the whole domain, if the curvature would be constant
+    let turn = norm(binormal) / dot(first, first) * (domain[1] - domain[0]).abs();
+    let normal = if turn > tolerance::<R>() {
+        normalize(cross(binormal, tangent))
+    } else {
+        previous
+            .map(|normal| sub(normal, scale(tangent, dot(normal, tangent))))
+            .filter(|normal| norm(*normal) > tolerance::<R>())
+            .map_or_else(|| orthogonal(tangent), normalize)
+    };
+    (tangent, normal, cross(tangent, normal))
+}
+
+/// Returns the index of the segment in which the scalar lies, the position of the scalar
+/// within the segment normalized to [0.0,1.0] and the width of the segment.
+///
+/// Only the segments ending at the knots with indices in `min..=max` are considered,
+/// such that scalars outside of them are extrapolated by the nearest of these segments.
+#[cfg(any(
+    feature = "catmullrom",
+    feature = "cubic",
+    feature = "hermite",
+    feature = "kochanek"
+))]
+pub(crate) fn hermite_segment<K, R>(knots: &K, scalar: R, min: usize, max: usize) -> (usize, R, R)
+where
+    K: SortedGenerator<Output = R>,
+    R: Real,
+{
+    // The strict_upper_bound is easier to calculate and behaves nicely on the edges of the array.
+    let upper = knots.strict_upper_bound_clamped(scalar, min, max);
+    let lower = upper - 1;
+    let start = knots.gen(lower);
+    let width = knots.gen(upper) - start;
+    (lower, (scalar - start) / width, width)
+}
+
+/// Evaluate a cubic hermite segment and its derivative, in this order.
+///
+/// The segment goes from `points[0]` to `points[1]` with the given tangents, where `t` is the position
+/// within the segment normalized to [0.0,1.0] and `width` the width of the segment.
+/// As such, the tangents and the returned derivative are given with respect to the knots.
+#[cfg(any(
+    feature = "catmullrom",
+    feature = "cubic",
+    feature = "hermite",
+    feature = "kochanek"
+))]
+pub(crate) fn hermite<T, R>(points: [T; 2], tangents: [T; 2], t: R, width: R) -> [T; 2]
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real,
+{
+    let [start, end] = points;
+    let [start_tangent, end_tangent] = tangents;
+    let one = R::one();
+    let two = one + one;
+    let three = two + one;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    // cubic hermite basis functions and their derivatives
+    let value = start * (two * t3 - three * t2 + one)
+        + end * (three * t2 - two * t3)
+        + start_tangent * ((t3 - two * t2 + t) * width)
+        + end_tangent * ((t3 - t2) * width);
+    let tangent = (end - start) * ((two * three) * (t - t2) / width)
+        + start_tangent * (three * t2 - two * two * t + one)
+        + end_tangent * (three * t2 - two * t);
+    [value, tangent]
+}
+
+/// Approximates the first and second derivative of the curve at the given input.
+///
+/// The step size is chosen relative to the domain. Near the borders of the domain one-sided differences
+/// are used, such the curve is never evaluated outside of its domain.
+fn derivatives<C, R, F>(curve: &C, input: R, accessor: &F) -> ([R; 3], [R; 3])
+where
+    C: Curve<R>,
+    R: Real,
+    F: Fn(C::Output) -> [R; 3],
+{
+    let [start, end] = curve.domain();
+    let step = tolerance::<R>() * (end - start);
+    let two = R::one() + R::one();
+    let at = accessor(curve.gen(input));
+    if input - step >= start && input + step <= end {
+        let before = accessor(curve.gen(input - step));
+        let after = accessor(curve.gen(input + step));
+        let first = scale(sub(after, before), (two * step).recip());
+        let second = scale(
+            sub(add(after, before), scale(at, two)),
+            (step * step).recip(),
+        );
+        return (first, second);
+    }
+    // one-sided differences of second order, stepping into the domain
+    let step = if input - step < start { step } else { -step };
+    let three = two + R::one();
+    let four = two + two;
+    let one = accessor(curve.gen(input + step));
+    let two_steps = accessor(curve.gen(input + two * step));
+    let three_steps = accessor(curve.gen(input + three * step));
+    let first = scale(
+        sub(sub(scale(one, four), scale(at, three)), two_steps),
+        (two * step).recip(),
+    );
+    let second = scale(
+        sub(
+            add(scale(at, two), scale(two_steps, four)),
+            add(scale(one, four + R::one()), three_steps),
+        ),
+        (step * step).recip(),
+    );
+    (first, second)
+}
+
+/// Relative tolerance used for the numerical approximations.
+///
+/// The fourth root of the machine epsilon balances the truncation and rounding error of second differences.
+fn tolerance<R: Real>() -> R {
+    R::epsilon().sqrt().sqrt()
+}
+
+/// Returns a normalized vector orthogonal to the given normalized vector.
+fn orthogonal<R: Real>(vector: [R; 3]) -> [R; 3] {
+    // choose the axis least aligned with the vector
+    let mut axis = [R::zero(); 3];
+    let mut least = 0;
+    for i in 1..3 {
+        if vector[i].abs() < vector[least].abs() {
+            least = i;
+        }
+    }
+    axis[least] = R::one();
+    normalize(sub(axis, scale(vector, dot(axis, vector))))
+}
+
+/// Reflect the vector at the plane with the given normal, where `length` is the squared norm of the normal.
+#[cfg(feature = "alloc")]
+fn reflect<R: Real>(vector: [R; 3], normal: [R; 3], length: R) -> [R; 3] {
+    let two = R::one() + R::one();
+    sub(vector, scale(normal, two * dot(normal, vector) / length))
+}
+
+fn add<R: Real>(a: [R; 3], b: [R; 3]) -> [R; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub<R: Real>(a: [R; 3], b: [R; 3]) -> [R; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn scale<R: Real>(a: [R; 3], factor: R) -> [R; 3] {
+    [a[0] * factor, a[1] * factor, a[2] * factor]
+}
+
+fn dot<R: Real>(a: [R; 3], b: [R; 3]) -> R {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross<R: Real>(a: [R; 3], b: [R; 3]) -> [R; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn norm<R: Real>(a: [R; 3]) -> R {
+    dot(a, a).sqrt()
+}
+
+fn normalize<R: Real>(a: [R; 3]) -> [R; 3] {
+    scale(a, norm(a).recip())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Generator;
+
+    /// Helix around the z-axis with radius 1 and a pitch of 2*PI.
+    struct Helix;
+
+    impl Generator<f64> for Helix {
+        type Output = [f64; 3];
+        fn gen(&self, input: f64) -> Self::Output {
+            [input.cos(), input.sin(), input]
+        }
+    }
+
+    impl Curve<f64> for Helix {
+        fn domain(&self) -> [f64; 2] {
+            [0.0, 10.0]
+        }
+    }
+
+    impl Derivative<f64> for Helix {
+        fn derivative(&self, input: f64) -> Self::Output {
+            [-input.sin(), input.cos(), 1.0]
+        }
+    }
+
+    /// Curve in the xz-plane which bends until 5.0 and is a straight line afterwards.
+    struct Bend;
+
+    impl Generator<f64> for Bend {
+        type Output = [f64; 3];
+        fn gen(&self, input: f64) -> Self::Output {
+            [input, 0.0, (5.0 - input).max(0.0).powi(3)]
+        }
+    }
+
+    impl Curve<f64> for Bend {
+        fn domain(&self) -> [f64; 2] {
+            [0.0, 10.0]
+        }
+    }
+
+    impl Derivative<f64> for Bend {
+        fn derivative(&self, input: f64) -> Self::Output {
+            [1.0, 0.0, -3.0 * (5.0 - input).max(0.0).powi(2)]
+        }
+    }
+
+    /// Curve in the xy-plane with inflection points at multiples of PI.
+    #[cfg(feature = "alloc")]
+    struct Wave;
+
+    #[cfg(feature = "alloc")]
+    impl Generator<f64> for Wave {
+        type Output = [f64; 3];
+        fn gen(&self, input: f64) -> Self::Output {
+            [input, input.sin(), 0.0]
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl Curve<f64> for Wave {
+        fn domain(&self) -> [f64; 2] {
+            [1.0, 10.0]
+        }
+    }
+
+    fn assert_orthonormal((tangent, normal, binormal): ([f64; 3], [f64; 3], [f64; 3])) {
+        assert_float_absolute_eq!(norm(tangent), 1.0, 1e-6);
+        assert_float_absolute_eq!(norm(normal), 1.0, 1e-6);
+        assert_float_absolute_eq!(norm(binormal), 1.0, 1e-6);
+        assert_float_absolute_eq!(dot(tangent, normal), 0.0, 1e-6);
+        assert_float_absolute_eq!(dot(tangent, binormal), 0.0, 1e-6);
+        assert_float_absolute_eq!(dot(normal, binormal), 0.0, 1e-6);
+    }
+
+    #[test]
+    fn intersection() {
+        // overlapping
+        assert_eq!(intersect_domains([0.0, 2.0], [1.0, 3.0]), Some([1.0, 2.0]));
+        assert_eq!(intersect_domains([1.0, 3.0], [0.0, 2.0]), Some([1.0, 2.0]));
+        assert_eq!(intersect_domains([0.0, 5.0], [1.0, 3.0]), Some([1.0, 3.0]));
+        // reversed
+        assert_eq!(intersect_domains([2.0, 0.0], [3.0, 1.0]), Some([1.0, 2.0]));
+        // touching
+        assert_eq!(intersect_domains([0.0, 1.0], [1.0, 2.0]), Some([1.0, 1.0]));
+        assert_eq!(intersect_domains([1.0, 0.0], [2.0, 1.0]), Some([1.0, 1.0]));
+        // disjoint
+        assert_eq!(intersect_domains([0.0, 1.0], [2.0, 3.0]), None);
+        assert_eq!(intersect_domains([3.0, 2.0], [0.0, 1.0]), None);
+    }
+
+    #[test]
+    fn saturating_merge() {
+        let black = SaturatingMerge(0u8);
+        let white = SaturatingMerge(255u8);
+        let mut last = 0;
+        for factor in crate::Stepper::new(101, -1.0, 2.0) {
+            let SaturatingMerge(value) = black.merge(white, factor);
+            assert!(value >= last);
+            last = value;
+        }
+        assert_eq!(black.merge(white, 0.0), black);
+        assert_eq!(black.merge(white, 1.0), white);
+        assert_eq!(black.merge(white, 10.0), white);
+        assert_eq!(white.merge(black, 10.0), black);
+        assert_eq!(black.merge(white, f64::NAN), black);
+        assert_eq!(
+            SaturatingMerge(i8::MIN).merge(SaturatingMerge(i8::MAX), 2.0f32),
+            SaturatingMerge(i8::MAX)
+        );
+    }
+
+    #[cfg(all(feature = "std", feature = "linear"))]
+    #[test]
+    fn duration_merge() {
+        use crate::linear::Linear;
+        let ramp = Linear::builder()
+            .elements([
+                DurationMerge(Duration::from_millis(100)),
+                DurationMerge(Duration::from_secs(1)),
+            ])
+            .equidistant::<f64>()
+            .domain(0.0, 10.0)
+            .build()
+            .unwrap();
+        assert_eq!(ramp.gen(0.0).0, Duration::from_millis(100));
+        assert_eq!(ramp.gen(3.0).0, Duration::from_millis(370));
+        assert_eq!(ramp.gen(10.0).0, Duration::from_secs(1));
+        // extrapolation saturates
+        assert_eq!(ramp.gen(-10.0).0, Duration::ZERO);
+        let max = DurationMerge(Duration::MAX);
+        assert_eq!(max.merge(DurationMerge(Duration::ZERO), -1.0), max);
+        assert_eq!(
+            DurationMerge(Duration::from_nanos(1)).merge(max, f64::NAN),
+            DurationMerge(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn frenet_helix() {
+        for input in [0.0, 0.3, 1.0, 2.5, 7.0, 10.0] {
+            let frame = frenet_frame(&Helix, input, |p| p);
+            assert_orthonormal(frame);
+            // the normal of a helix points towards its axis
+            let (_, normal, _) = frame;
+            assert_float_absolute_eq!(normal[0], -input.cos(), 1e-6);
+            assert_float_absolute_eq!(normal[1], -input.sin(), 1e-6);
+            assert_float_absolute_eq!(normal[2], 0.0, 1e-6);
+        }
+    }
+
+    #[test]
+    fn frenet_straight() {
+        // the accessor projects the helix onto a straight line
+        let frame = frenet_frame(&Helix, 3.0, |p| [p[2], 2.0 * p[2], 0.0]);
+        assert_orthonormal(frame);
+        // the previous normal is kept if it is orthogonal to the tangent
+        let frame = frenet_frame_with_hint(&Helix, 3.0, Some([0.0, 0.0, 1.0]), |p| {
+            [p[2], 2.0 * p[2], 0.0]
+        });
+        assert_orthonormal(frame);
+        assert_eq!(frame.1, [0.0, 0.0, 1.0]);
+        // a curved part ignores the previous normal
+        assert_eq!(
+            frenet_frame_with_hint(&Helix, 3.0, Some([0.0, 0.0, 1.0]), |p| p),
+            frenet_frame(&Helix, 3.0, |p| p)
+        );
+    }
+
+    #[test]
+    fn curvature() {
+        for input in [0.0, 0.3, 1.0, 2.5, 7.0, 10.0] {
+            // a helix with radius 1 and pitch 2*PI has a constant curvature of 1/2
+            assert_float_absolute_eq!(Helix.curvature(input), 0.5, 1e-6);
+            let frame = Helix.frenet_frame(input);
+            assert_orthonormal(frame);
+            let (_, normal, _) = frame;
+            assert_float_absolute_eq!(normal[0], -input.cos(), 1e-6);
+            assert_float_absolute_eq!(normal[1], -input.sin(), 1e-6);
+            assert_float_absolute_eq!(normal[2], 0.0, 1e-6);
+        }
+        assert_float_absolute_eq!(Bend.curvature(2.0), 18.0 / 730f64.powf(1.5), 1e-9);
+        assert_eq!(Bend.curvature(7.0), 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn frenet_frames_straight() {
+        let frames = Bend.frenet_frames(101);
+        assert_eq!(frames.len(), 101);
+        for frame in frames.iter().copied() {
+            assert_orthonormal(frame);
+        }
+        // the normal of the bend is transported onto its straight part
+        for (tangent, normal, _) in frames[60..].iter().copied() {
+            assert_eq!(tangent, [1.0, 0.0, 0.0]);
+            assert_float_absolute_eq!(normal[2], 1.0, 1e-6);
+        }
+        // without a previous frame, the normal falls back to an axis
+        let (_, normal, _) = Bend.frenet_frame(7.0);
+        assert_eq!(normal, [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn rmf_planar() {
+        let frames = rmf(&Wave, 100, |p| p);
+        assert_eq!(frames.len(), 100);
+        let (_, _, first) = frames[0];
+        for frame in frames {
+            assert_orthonormal(frame);
+            // as the curve is planar, a rotation minimizing frame does not twist at all,
+            // even when passing inflection points
+            let (_, _, binormal) = frame;
+            assert_float_absolute_eq!(dot(binormal, first), 1.0, 1e-6);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn rmf_helix() {
+        for frame in rmf(&Helix, 50, |p| p) {
+            assert_orthonormal(frame);
+        }
+    }
+}
@@ -62,6 +62,16 @@ where
     }
 }
 
+impl<E, R> Homogeneous<E, R>
+where
+    R: Copy,
+{
+    /// Return the weight of the coordinate, which is zero for coordinates at infinity.
+    pub fn weight(&self) -> R {
+        self.rational
+    }
+}
+
 impl<E, R> Homogeneous<E, R>
 where
     E: Mul<R, Output = E>,
@@ -124,6 +134,23 @@ where
     }
 }
 
+impl<E, R> Homogeneous<E, R>
+where
+    E: Mul<R, Output = E>,
+    R: Mul<Output = R> + Copy,
+{
+    /// Scale the element and the weight of the coordinate by the same factor.
+    ///
+    /// The projected element stays the same, such that uniformly scaling all coordinates of a
+    /// rational curve does not change its shape.
+    pub fn scaled(self, factor: R) -> Self {
+        Homogeneous {
+            element: self.element * factor,
+            rational: self.rational * factor,
+        }
+    }
+}
+
 impl<E, R> Homogeneous<E, R>
 where
     E: Div<R, Output = E>,
@@ -5,6 +5,8 @@ mod homogeneous;
 mod weighted;
 
 pub use homogeneous::Homogeneous;
+#[cfg(feature = "bspline")]
+pub(crate) use weighted::Project;
 pub use weighted::Weighted;
 
 use crate::{ConstDiscreteGenerator, Curve, DiscreteGenerator, Generator};
@@ -26,6 +28,10 @@ impl<G> Weights<G> {
     pub fn new(gen: G) -> Self {
         Weights { gen }
     }
+    /// Return the inner generator.
+    pub fn inner(self) -> G {
+        self.gen
+    }
 }
 
 impl<G, Input> Generator<Input> for Weights<G>
@@ -68,6 +74,77 @@ where
     }
 }
 
+/// Generator adaptor to scale the homogeneous data of a generator by the same factor.
+///
+/// As the element and the weight get scaled alike, the projected elements stay the same.
+/// This is used to bring weights into a numerically convenient range.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Rescaled<G, R> {
+    gen: G,
+    factor: R,
+}
+
+impl<G, R> Rescaled<G, R> {
+    /// Scale all homogeneous data of the given generator by `factor`.
+    pub fn new(gen: G, factor: R) -> Self {
+        Rescaled { gen, factor }
+    }
+}
+
+impl<G, R> Rescaled<G, R>
+where
+    G: DiscreteGenerator,
+    G::Output: IntoWeight<Weight = R>,
+    R: Real,
+{
+    /// Scale all homogeneous data of the given generator such that the largest weight
+    /// in absolute value is one.
+    ///
+    /// If all weights are zero, the data is not scaled at all.
+    pub fn normalized(gen: G) -> Self {
+        let max = (0..gen.len())
+            .map(|i| gen.gen(i).into_weight().weight().abs())
+            .fold(R::zero(), R::max);
+        let factor = if max.is_zero() { R::one() } else { max.recip() };
+        Rescaled::new(gen, factor)
+    }
+}
+
+impl<G, R, Input> Generator<Input> for Rescaled<G, R>
+where
+    G: Generator<Input>,
+    G::Output: IntoWeight<Weight = R>,
+    <G::Output as IntoWeight>::Element: Mul<R, Output = <G::Output as IntoWeight>::Element>,
+    R: Mul<Output = R> + Copy,
+{
+    type Output = Homogeneous<<G::Output as IntoWeight>::Element, R>;
+    fn gen(&self, input: Input) -> Self::Output {
+        self.gen.gen(input).into_weight().scaled(self.factor)
+    }
+}
+
+impl<G, R> DiscreteGenerator for Rescaled<G, R>
+where
+    G: DiscreteGenerator,
+    G::Output: IntoWeight<Weight = R>,
+    <G::Output as IntoWeight>::Element: Mul<R, Output = <G::Output as IntoWeight>::Element>,
+    R: Mul<Output = R> + Copy,
+{
+    fn len(&self) -> usize {
+        self.gen.len()
+    }
+}
+
+impl<G, R, const N: usize> ConstDiscreteGenerator<N> for Rescaled<G, R>
+where
+    G: ConstDiscreteGenerator<N>,
+    G::Output: IntoWeight<Weight = R>,
+    <G::Output as IntoWeight>::Element: Mul<R, Output = <G::Output as IntoWeight>::Element>,
+    R: Mul<Output = R> + Copy,
+{
+}
+
 /// Trait for all structs which can be transformed into homogeneous data.
 ///
 /// This trait is used to be able to implement Generator for Weights without having to add other generic variables.
@@ -2,6 +2,8 @@
 
 use crate::weights::Homogeneous;
 use crate::{Curve, Generator};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::ops::Div;
 use num_traits::real::Real;
 
@@ -45,6 +47,10 @@ where
     fn domain(&self) -> [R; 2] {
         self.inner.domain()
     }
+    #[cfg(feature = "alloc")]
+    fn knot_midpoints(&self) -> Vec<R> {
+        self.inner.knot_midpoints()
+    }
 }
 
 /// This trait is used to be able to implement Generator for Weights without having to add other generic variables.
@@ -52,6 +58,9 @@ pub trait Project {
     type Element;
     type Weight;
     fn project(self) -> Self::Element;
+    fn weight(&self) -> Self::Weight
+    where
+        Self::Weight: Copy;
 }
 
 impl<T, R> Project for Homogeneous<T, R>
@@ -63,4 +72,10 @@ where
     fn project(self) -> Self::Element {
         self.project()
     }
+    fn weight(&self) -> Self::Weight
+    where
+        R: Copy,
+    {
+        self.weight()
+    }
 }